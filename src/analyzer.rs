@@ -1,4 +1,18 @@
 use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::extract::url_len;
+use crate::formatter::char_width;
+use crate::locale::case_fold;
+use crate::segmenter::{graphemes, is_closing_punctuation, is_sentence_end, is_sentence_terminator, sentences};
+
+/// Base text direction returned by base_direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Ltr,
+    Rtl,
+    Neutral,
+}
 
 /// Counts words in a string using rules that closely match
 ///
@@ -34,29 +48,94 @@ use std::collections::HashMap;
 /// # Returns
 /// The number of words.
 pub fn word_count(text: &str) -> usize {
-    let mut count = 0;
+    word_tokens(text).count()
+}
 
-    for token in text.split_whitespace() {
-        // Trim leading/trailing punctuation (Word ignores it)
-        let trimmed = token.trim_matches(|c: char| {
-            c.is_ascii_punctuation() && c != '\'' && c != '-'
-        });
+/// Splits a text into the words counted by [`word_count`], with the same rules:
+/// tokens are separated by whitespace, leading and trailing punctuation is
+/// removed, hyphenated words, contractions and URLs are one token, and each
+/// CJK character is a token of its own. URLs keep the boundaries of
+/// [`extract_urls`](crate::extract::extract_urls), so a trailing `/` stays.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::tokenize;
+/// assert_eq!(tokenize("Hello, state-of-the-art world! 你好"), vec!["Hello", "state-of-the-art", "world", "你", "好"]);
+/// ```
+pub fn tokenize(text: &str) -> Vec<&str> {
+    word_tokens(text).collect()
+}
 
-        if trimmed.is_empty() {
-            continue;
-        }
+/// The tokens of [`tokenize`], without collecting them.
+pub(crate) fn word_tokens(text: &str) -> impl Iterator<Item = &str> {
+    text.split_whitespace().flat_map(token_words)
+}
 
-        // Word treats CJK characters as individual words
-        if trimmed.chars().all(is_cjk) {
-            count += trimmed.chars().count();
+/// Options for the word_frequencies function
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FrequencyOptions {
+    /// Counts words case-insensitively, keyed by their full case folding
+    /// (`"Straße"` and `"STRASSE"` are both `"strasse"`).
+    pub case_fold: bool,
+    /// Words shorter than this many characters are not counted.
+    pub min_length: usize,
+}
+
+/// Counts how often each word of the text occurs, with the words of
+/// [`tokenize`]: punctuation around words is removed, hyphenated words,
+/// contractions and URLs are one word, and each CJK character is a word.
+///
+/// The counts add up to [`word_count`] when no word is filtered out by
+/// `min_length`.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::{word_frequencies, FrequencyOptions};
+/// let text = "The cat saw the dog. THE END, don't-stop!";
+/// let counts = word_frequencies(text, FrequencyOptions::default());
+/// assert_eq!((counts["The"], counts["the"], counts["don't-stop"]), (1, 1, 1));
+///
+/// let counts = word_frequencies(text, FrequencyOptions { case_fold: true, min_length: 4 });
+/// assert_eq!(counts.len(), 1);
+/// assert_eq!(counts["don't-stop"], 1);
+/// assert_eq!(word_frequencies(text, FrequencyOptions { case_fold: true, min_length: 0 })["the"], 3);
+/// ```
+pub fn word_frequencies(text: &str, options: FrequencyOptions) -> HashMap<String, usize> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for word in word_tokens(text) {
+        if options.min_length > 0 && word.chars().nth(options.min_length - 1).is_none() {
             continue;
         }
-
-        // Hyphenated words and contractions count as one
-        count += 1;
+        let key = if options.case_fold { case_fold(word) } else { word.to_owned() };
+        *counts.entry(key).or_insert(0) += 1;
     }
+    counts
+}
 
-    count
+/// The words of one whitespace-separated token of [`tokenize`].
+fn token_words(token: &str) -> impl Iterator<Item = &str> {
+    // Trim leading/trailing punctuation (Word ignores it)
+    let is_ignored = |c: char| c.is_ascii_punctuation() && c != '\'' && c != '-';
+    let lead = token.trim_start_matches(is_ignored);
+    // URLs end where extract_urls ends them
+    let trimmed = match url_len(lead) {
+        Some(len) => &lead[..len],
+        None => lead.trim_end_matches(is_ignored),
+    };
+
+    // Word treats CJK characters as individual words
+    let cjk = !trimmed.is_empty() && trimmed.chars().all(is_cjk);
+    let mut rest = trimmed;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        // Hyphenated words and contractions are one token
+        let len = if cjk { rest.chars().next().map_or(0, char::len_utf8) } else { rest.len() };
+        let (token, tail) = rest.split_at(len);
+        rest = tail;
+        Some(token)
+    })
 }
 
 /// Returns `true` if the character belongs to a CJK (Chinese/Japanese/Korean)
@@ -72,9 +151,10 @@ pub fn word_count(text: &str) -> usize {
 /// assert!(is_cjk('界'));
 /// assert!(!is_cjk('a'));
 /// assert!(!is_cjk('🙂'));
-///
+/// const IDEOGRAPH: bool = is_cjk('漢');
+/// assert!(IDEOGRAPH);
 /// ```
-pub fn is_cjk(c: char) -> bool {
+pub const fn is_cjk(c: char) -> bool {
     matches!(c as u32,
         0x4E00..=0x9FFF   | // CJK Unified Ideographs
         0x3400..=0x4DBF   | // CJK Extension A
@@ -87,6 +167,69 @@ pub fn is_cjk(c: char) -> bool {
     )
 }
 
+/// Code point ranges of emoji characters, sorted and non-overlapping.
+const EMOJI_RANGES: &[(u32, u32)] = &[
+    (0x00A9, 0x00A9), (0x00AE, 0x00AE),       // © ®
+    (0x203C, 0x203C), (0x2049, 0x2049),       // ‼ ⁉
+    (0x2122, 0x2122), (0x2139, 0x2139),       // ™ ℹ
+    (0x2194, 0x2199), (0x21A9, 0x21AA),       // arrows
+    (0x231A, 0x231B), (0x2328, 0x2328),       // watch, hourglass, keyboard
+    (0x23CF, 0x23CF), (0x23E9, 0x23F3), (0x23F8, 0x23FA),
+    (0x24C2, 0x24C2),
+    (0x25AA, 0x25AB), (0x25B6, 0x25B6), (0x25C0, 0x25C0), (0x25FB, 0x25FE),
+    (0x2600, 0x27BF),                         // Miscellaneous Symbols, Dingbats
+    (0x2934, 0x2935),
+    (0x2B05, 0x2B07), (0x2B1B, 0x2B1C), (0x2B50, 0x2B50), (0x2B55, 0x2B55),
+    (0x3030, 0x3030), (0x303D, 0x303D), (0x3297, 0x3297), (0x3299, 0x3299),
+    (0x1F000, 0x1F0FF),                       // Mahjong, domino and playing cards
+    (0x1F170, 0x1F171), (0x1F17E, 0x1F17F), (0x1F18E, 0x1F18E), (0x1F191, 0x1F19A),
+    (0x1F1E6, 0x1F1FF),                       // regional indicators (flags)
+    (0x1F201, 0x1F202), (0x1F21A, 0x1F21A), (0x1F22F, 0x1F22F),
+    (0x1F232, 0x1F23A), (0x1F250, 0x1F251),
+    (0x1F300, 0x1F6FF),                       // pictographs, emoticons, transport
+    (0x1F7E0, 0x1F7EB),                       // colored circles and squares
+    (0x1F90C, 0x1F9FF),                       // Supplemental Symbols and Pictographs
+    (0x1FA70, 0x1FAFF),                       // Symbols and Pictographs Extended-A
+];
+
+/// Returns `true` if the character is an emoji: pictographs, emoticons,
+/// symbols with an emoji form (`©`, `☀`, `✂`...), skin-tone modifiers and
+/// the regional indicators that make up flags.
+///
+/// ASCII digits, `#` and `*` are not emoji on their own (only in keycap
+/// sequences), and joiners and variation selectors are not counted.
+///
+/// Uses a binary search over a constant table, so it can be used in const
+/// contexts.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::is_emoji_char;
+/// assert!(is_emoji_char('🙂'));
+/// assert!(is_emoji_char('☀'));
+/// assert!(is_emoji_char('🇫'));
+/// assert!(!is_emoji_char('a'));
+/// assert!(!is_emoji_char('7'));
+/// const SMILE: bool = is_emoji_char('😀');
+/// assert!(SMILE);
+/// ```
+pub const fn is_emoji_char(c: char) -> bool {
+    let u = c as u32;
+    let (mut low, mut high) = (0, EMOJI_RANGES.len());
+    while low < high {
+        let mid = (low + high) / 2;
+        let (start, end) = EMOJI_RANGES[mid];
+        if u < start {
+            high = mid;
+        } else if u > end {
+            low = mid + 1;
+        } else {
+            return true;
+        }
+    }
+    false
+}
+
 ///Find different words in two similar string vectors and find the difference
 /// in the number of words.
 /// It is useful when there are two almost identical documents and minimal changes need to be verified
@@ -148,3 +291,712 @@ pub fn count_paragraphs(text: &str) -> usize {
     // Otherwise: paragraphs = newlines + 1
     newline_count + 1
 }
+
+/// Returns `true` if the character is a Unicode combining mark, i.e. a
+/// character that attaches to the preceding base character and takes no
+/// space of its own (e.g. the acute accent in `"e\u{301}"`).
+///
+/// Covers the combining diacritical blocks used by Latin, Greek and Cyrillic
+/// text plus the Hebrew and Arabic points.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::is_combining_mark;
+/// assert!(is_combining_mark('\u{301}'));
+/// assert!(!is_combining_mark('e'));
+/// assert!(!is_combining_mark('é'));
+/// ```
+pub const fn is_combining_mark(c: char) -> bool {
+    matches!(c as u32,
+        0x0300..=0x036F | // Combining Diacritical Marks
+        0x0483..=0x0489 | // Cyrillic combining marks
+        0x0591..=0x05BD | // Hebrew points
+        0x05BF | 0x05C1 | 0x05C2 | 0x05C4 | 0x05C5 | 0x05C7 |
+        0x0610..=0x061A | // Arabic marks
+        0x064B..=0x065F | // Arabic harakat
+        0x0670          |
+        0x1AB0..=0x1AFF | // Combining Diacritical Marks Extended
+        0x1DC0..=0x1DFF | // Combining Diacritical Marks Supplement
+        0x20D0..=0x20FF | // Combining Diacritical Marks for Symbols
+        0xFE20..=0xFE2F   // Combining Half Marks
+    )
+}
+
+/// Returns `true` if the character is punctuation: ASCII punctuation or a
+/// character from the common Unicode punctuation blocks (general punctuation
+/// such as `’ “ … —`, Latin-1 `¡ ¿ « »`, CJK and fullwidth punctuation).
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::is_punctuation;
+/// assert!(is_punctuation('!'));
+/// assert!(is_punctuation('…'));
+/// assert!(is_punctuation('。'));
+/// assert!(!is_punctuation('a'));
+/// assert!(!is_punctuation('🙂'));
+/// ```
+pub const fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation() || matches!(c as u32,
+        0x00A1 | 0x00A7 | 0x00AB | 0x00B6 | 0x00B7 | 0x00BB | 0x00BF |
+        0x2010..=0x2027 | // General Punctuation: dashes, quotes, ellipsis
+        0x2030..=0x205E | // General Punctuation: primes, reference marks
+        0x3001..=0x3003 | // CJK comma and full stops
+        0x3008..=0x3011 | // CJK brackets
+        0x3014..=0x301F | // CJK brackets and quotes
+        0xFE10..=0xFE19 | // Vertical forms
+        0xFE30..=0xFE4F | // CJK Compatibility Forms
+        0xFF01..=0xFF0F | // Fullwidth ASCII punctuation
+        0xFF1A..=0xFF20 |
+        0xFF3B..=0xFF40 |
+        0xFF5B..=0xFF65
+    )
+}
+
+/// Finds paragraphs that repeat an earlier paragraph, ignoring differences in
+/// whitespace, case and punctuation.
+///
+/// Paragraphs come from [`crate::splitter::split_paragraphs`] and are compared
+/// with [`crate::hasher::canonical_fingerprint`]. Blank paragraphs are ignored.
+///
+/// # Returns
+/// A `Vec` of `(first, duplicate)` paragraph index pairs, where `first` is the
+/// first paragraph with that content and `duplicate` a later repetition.
+/// Pairs are sorted by `duplicate`.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::find_duplicate_paragraphs;
+/// let text = "Intro.\nSome text here.\nMiddle.\nsome text  here\nSome text here.";
+/// assert_eq!(find_duplicate_paragraphs(text), vec![(1, 3), (1, 4)]);
+/// ```
+pub fn find_duplicate_paragraphs(text: &str) -> Vec<(usize, usize)> {
+    let mut first_seen = HashMap::new();
+    let mut duplicates = Vec::new();
+
+    for (i, paragraph) in crate::splitter::split_paragraphs(text).into_iter().enumerate() {
+        if paragraph.trim().is_empty() {
+            continue;
+        }
+        let fingerprint = crate::hasher::canonical_fingerprint(paragraph);
+        match first_seen.get(&fingerprint) {
+            Some(first) => duplicates.push((*first, i)),
+            None => {
+                first_seen.insert(fingerprint, i);
+            }
+        }
+    }
+
+    duplicates
+}
+
+/// Returns the share of combining marks among all characters of the text,
+/// between `0.0` and `1.0` (`0.0` for an empty text).
+///
+/// Ordinary accented text in decomposed form stays well below `0.5`, while
+/// "zalgo" text stacking dozens of marks on each letter approaches `1.0`.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::combining_mark_density;
+/// assert_eq!(combining_mark_density("plain"), 0.0);
+/// assert_eq!(combining_mark_density("e\u{301}"), 0.5);
+/// assert!(combining_mark_density("z\u{33f}\u{344}\u{35b}\u{346}a\u{34a}\u{342}\u{33e}") > 0.7);
+/// ```
+pub fn combining_mark_density(text: &str) -> f64 {
+    let (marks, total) = text.chars().fold((0usize, 0usize), |(marks, total), c| {
+        (marks + is_combining_mark(c) as usize, total + 1)
+    });
+    if total == 0 {
+        return 0.0;
+    }
+    marks as f64 / total as f64
+}
+
+/// Returns `true` if the character is a strong right-to-left character:
+/// Hebrew, Arabic, Syriac, Thaana, N'Ko and their presentation forms, and the
+/// right-to-left mark. Arabic-Indic digits and combining marks are not strong.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::is_strong_rtl;
+/// assert!(is_strong_rtl('ש'));
+/// assert!(is_strong_rtl('م'));
+/// assert!(!is_strong_rtl('a'));
+/// assert!(!is_strong_rtl('٣'));
+/// ```
+pub fn is_strong_rtl(c: char) -> bool {
+    let u = c as u32;
+    if is_combining_mark(c) || matches!(u, 0x0660..=0x0669 | 0x06F0..=0x06F9) {
+        return false;
+    }
+    matches!(u,
+        0x200F            | // right-to-left mark
+        0x0590..=0x08FF   | // Hebrew, Arabic, Syriac, Thaana, N'Ko, Samaritan, Mandaic
+        0xFB1D..=0xFDFF   | // Hebrew and Arabic presentation forms A
+        0xFE70..=0xFEFE   | // Arabic presentation forms B
+        0x10800..=0x10FFF | // historic RTL scripts
+        0x1E800..=0x1EFFF   // Mende Kikakui, Adlam, Arabic mathematical symbols
+    ) && (c.is_alphabetic() || u == 0x200F)
+}
+
+/// Returns the base direction of the text from its first strong character,
+/// as the Unicode bidirectional algorithm does (rules P2 and P3).
+///
+/// Letters of right-to-left scripts give [`Direction::Rtl`], other letters
+/// [`Direction::Ltr`]. Text without letters (digits, punctuation, emoji)
+/// is [`Direction::Neutral`].
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::{base_direction, Direction};
+/// assert_eq!(base_direction("Hello שלום"), Direction::Ltr);
+/// assert_eq!(base_direction("123 שלום world"), Direction::Rtl);
+/// assert_eq!(base_direction("مرحبا"), Direction::Rtl);
+/// assert_eq!(base_direction("42 🙂!"), Direction::Neutral);
+/// ```
+pub fn base_direction(text: &str) -> Direction {
+    for c in text.chars() {
+        if is_strong_rtl(c) {
+            return Direction::Rtl;
+        }
+        if c == '\u{200E}' || (c.is_alphabetic() && !is_combining_mark(c)) {
+            return Direction::Ltr;
+        }
+    }
+    Direction::Neutral
+}
+
+/// Line ending counts returned by the newline_stats function.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NewlineStats {
+    /// Unix line endings (`\n`).
+    pub lf: usize,
+    /// Windows line endings (`\r\n`).
+    pub crlf: usize,
+    /// Classic Mac line endings (a lone `\r`).
+    pub cr: usize,
+    /// `true` if more than one kind of line ending is present.
+    pub mixed: bool,
+}
+
+/// Counts the line endings of the text by kind. A `\r\n` pair counts once,
+/// as CRLF.
+///
+/// Use it to warn before rewriting the line endings of a file, so diffs stay
+/// minimal.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::{newline_stats, NewlineStats};
+/// assert_eq!(newline_stats("a\r\nb\nc\r"), NewlineStats { lf: 1, crlf: 1, cr: 1, mixed: true });
+/// assert_eq!(newline_stats("a\nb\n"), NewlineStats { lf: 2, crlf: 0, cr: 0, mixed: false });
+/// ```
+pub fn newline_stats(text: &str) -> NewlineStats {
+    let mut stats = NewlineStats::default();
+    let mut bytes = text.bytes().peekable();
+    while let Some(b) = bytes.next() {
+        match b {
+            b'\n' => stats.lf += 1,
+            b'\r' if bytes.peek() == Some(&b'\n') => {
+                bytes.next();
+                stats.crlf += 1;
+            }
+            b'\r' => stats.cr += 1,
+            _ => {}
+        }
+    }
+    stats.mixed = [stats.lf, stats.crlf, stats.cr].iter().filter(|n| **n > 0).count() > 1;
+    stats
+}
+
+/// Returns `true` if the text uses more than one kind of line ending
+/// (`\n`, `\r\n` or a lone `\r`).
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::has_mixed_line_endings;
+/// assert!(has_mixed_line_endings("one\r\ntwo\nthree"));
+/// assert!(!has_mixed_line_endings("one\r\ntwo\r\n"));
+/// assert!(!has_mixed_line_endings("no line ending"));
+/// ```
+pub fn has_mixed_line_endings(text: &str) -> bool {
+    newline_stats(text).mixed
+}
+
+/// A list item found by the detect_list_items function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListItem {
+    /// Index of the line (0-based) holding the item.
+    pub line: usize,
+    /// Index of the list the item belongs to, counting from 0 in the text.
+    pub list: usize,
+    /// Nesting level, 0 for top-level items.
+    pub level: usize,
+    /// Width of the indentation before the marker, tabs counting 4 columns.
+    pub indent: usize,
+    /// The marker: `-`, `*`, `+`, or a number followed by `.` or `)`.
+    pub marker: String,
+    /// The number of an ordered item, `None` for bullets.
+    pub number: Option<u64>,
+}
+
+/// Detects bullet (`-`, `*`, `+`) and numbered (`1.`, `1)`) list items, with
+/// their nesting level from indentation.
+///
+/// A marker must be followed by whitespace. Blank lines and indented
+/// continuation lines keep the list open; a non-item line without
+/// indentation ends it, and the next item starts a new list.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::detect_list_items;
+/// let items = detect_list_items("Todo:\n- buy milk\n  1. whole\n  2) skim\n- call Bob");
+/// assert_eq!(items.len(), 4);
+/// assert_eq!((items[0].line, items[0].level, items[0].marker.as_str()), (1, 0, "-"));
+/// assert_eq!((items[2].level, items[2].number), (1, Some(2)));
+/// assert_eq!(items[3].level, 0);
+/// ```
+pub fn detect_list_items(text: &str) -> Vec<ListItem> {
+    let mut items = Vec::new();
+    let mut indents: Vec<usize> = Vec::new();
+    let mut list = 0;
+
+    for (line_index, line) in text.lines().enumerate() {
+        let body = line.trim_start();
+        let indent: usize = line[..line.len() - body.len()].chars().map(|c| if c == '\t' { 4 } else { 1 }).sum();
+
+        let Some(marker) = list_marker(body) else {
+            if !body.is_empty() && indent == 0 && !indents.is_empty() {
+                indents.clear();
+                list += 1;
+            }
+            continue;
+        };
+
+        while indents.last().is_some_and(|last| *last > indent) {
+            indents.pop();
+        }
+        if indents.last().is_none_or(|last| *last < indent) {
+            indents.push(indent);
+        }
+
+        items.push(ListItem {
+            line: line_index,
+            list,
+            level: indents.len() - 1,
+            indent,
+            marker: marker.to_owned(),
+            number: marker[..marker.len() - 1].parse().ok(),
+        });
+    }
+
+    items
+}
+
+/// Returns the list marker (`-`, `*`, `+`, `12.` or `12)`) at the start of
+/// the line, if it is followed by whitespace.
+pub(crate) fn list_marker(line: &str) -> Option<&str> {
+    let digits = line.bytes().take_while(u8::is_ascii_digit).count();
+    let len = match line.as_bytes().get(digits)? {
+        b'-' | b'*' | b'+' if digits == 0 => 1,
+        b'.' | b')' if digits > 0 => digits + 1,
+        _ => return None,
+    };
+    line[len..].starts_with(char::is_whitespace).then(|| &line[..len])
+}
+
+/// Returns the `n` most frequent substrings of `k` characters of the text,
+/// with their number of occurrences.
+///
+/// Every position is counted, so occurrences may overlap (`"aaaa"` has three
+/// `"aa"`). Substrings are found with a Rabin-Karp rolling hash, in one pass
+/// over the text; hash collisions are resolved by comparing the substrings.
+///
+/// Useful to explore a corpus or to find a marker that splits it well, such
+/// as a recurring header line.
+///
+/// # Arguments
+/// * `text` - The text to analyze.
+/// * `k` - The length of the substrings, in characters (Unicode scalar values).
+/// * `n` - The maximum number of substrings to return.
+///
+/// # Returns
+/// `(substring, count)` pairs, most frequent first; substrings with the same
+/// count are in order of first occurrence. Empty if `k` is 0 or longer than
+/// the text.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::analyzer::top_kgrams;
+/// assert_eq!(top_kgrams("abracadabra", 3, 2), vec![("abr".to_owned(), 2), ("bra".to_owned(), 2)]);
+/// assert_eq!(top_kgrams("aaaa", 2, 5), vec![("aa".to_owned(), 3)]);
+/// ```
+pub fn top_kgrams(text: &str, k: usize, n: usize) -> Vec<(String, usize)> {
+    const BASE: u64 = 0x100000001b3;
+
+    let boundaries: Vec<usize> = text.char_indices().map(|(i, _)| i).chain(std::iter::once(text.len())).collect();
+    let chars: Vec<u64> = text.chars().map(|c| c as u64).collect();
+    if k == 0 || k > chars.len() || n == 0 {
+        return Vec::new();
+    }
+
+    // Weight of the character leaving the window: BASE^(k-1)
+    let leading_weight = (1..k).fold(1u64, |weight, _| weight.wrapping_mul(BASE));
+    let mut hash = chars[..k].iter().fold(0u64, |hash, c| hash.wrapping_mul(BASE).wrapping_add(*c));
+    // Per hash: (first occurrence, count) of each distinct substring
+    let mut counts: HashMap<u64, Vec<(usize, usize)>> = HashMap::new();
+    for start in 0..=chars.len() - k {
+        if start > 0 {
+            hash = hash
+                .wrapping_sub(chars[start - 1].wrapping_mul(leading_weight))
+                .wrapping_mul(BASE)
+                .wrapping_add(chars[start + k - 1]);
+        }
+        let gram = &text[boundaries[start]..boundaries[start + k]];
+        let bucket = counts.entry(hash).or_default();
+        match bucket.iter_mut().find(|(first, _)| &text[boundaries[*first]..boundaries[*first + k]] == gram) {
+            Some((_, count)) => *count += 1,
+            None => bucket.push((start, 1)),
+        }
+    }
+
+    let mut grams: Vec<(usize, usize)> = counts.into_values().flatten().collect();
+    grams.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    grams
+        .into_iter()
+        .take(n)
+        .map(|(first, count)| (text[boundaries[first]..boundaries[first + k]].to_owned(), count))
+        .collect()
+}
+
+/// Unit of the n-grams built by the ngrams function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NgramKind {
+    /// `n` consecutive grapheme clusters, whitespace included.
+    Characters,
+    /// `n` consecutive words of [`tokenize`], joined by a single space.
+    Words,
+}
+
+/// Returns every n-gram of the text, in order and with repetitions, for
+/// similarity scoring (shingling) and duplicate detection.
+///
+/// Character n-grams are made of grapheme clusters, so accents, flags and
+/// emoji with modifiers are never split; normalize whitespace first (e.g.
+/// with [`collapse_whitespace`](crate::cleanser::collapse_whitespace)) if it
+/// should not matter. Word n-grams use the words of [`word_count`], without
+/// their surrounding punctuation.
+///
+/// # Returns
+/// The n-grams; empty if `n` is 0 or the text has fewer than `n` units.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::analyzer::{ngrams, NgramKind};
+/// assert_eq!(ngrams("Olé!", 2, NgramKind::Characters), vec!["Ol", "lé", "é!"]);
+/// assert_eq!(ngrams("The state-of-the-art fox, again.", 2, NgramKind::Words),
+///            vec!["The state-of-the-art", "state-of-the-art fox", "fox again"]);
+/// assert!(ngrams("one", 2, NgramKind::Words).is_empty());
+/// ```
+pub fn ngrams(text: &str, n: usize, kind: NgramKind) -> Vec<String> {
+    if n == 0 {
+        return Vec::new();
+    }
+    match kind {
+        NgramKind::Characters => {
+            let starts: Vec<usize> = graphemes(text)
+                .scan(0, |offset, grapheme| {
+                    let start = *offset;
+                    *offset += grapheme.len();
+                    Some(start)
+                })
+                .chain(std::iter::once(text.len()))
+                .collect();
+            // `starts` has one more entry than there are graphemes
+            if n >= starts.len() {
+                return Vec::new();
+            }
+            starts.windows(n + 1).map(|window| text[window[0]..window[n]].to_owned()).collect()
+        }
+        NgramKind::Words => {
+            let words: Vec<&str> = word_tokens(text).collect();
+            words.windows(n).map(|window| window.join(" ")).collect()
+        }
+    }
+}
+
+/// Statistics of a text, computed by [`text_stats`] in one pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TextStats {
+    /// Words, as counted by [`word_count`].
+    pub words: usize,
+    /// Characters (Unicode scalar values), spaces included, line breaks
+    /// excluded.
+    pub characters: usize,
+    /// Characters that are not whitespace.
+    pub characters_no_spaces: usize,
+    /// Sentences, as counted by [`sentence_count`].
+    pub sentences: usize,
+    /// Paragraphs, as counted by [`count_paragraphs`].
+    pub paragraphs: usize,
+    /// Lines: `\n`, `\r\n` and `\r` end a line; a last line without a line
+    /// break counts too.
+    pub lines: usize,
+    /// Length in bytes (UTF-8).
+    pub bytes: usize,
+}
+
+/// Computes word, character, sentence, paragraph and line counts of a text in
+/// a single pass, instead of walking it once per count.
+///
+/// Words follow the rules of [`word_count`] (CJK characters, URLs,
+/// hyphenated words and contractions) and paragraphs those of
+/// [`count_paragraphs`]; see [`TextStats`] for the other counts. The result is
+/// `Copy`, so it can be memoized with [`MetricsCache`](crate::cache::MetricsCache).
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::text_stats;
+/// use bt_string_utils::cache::MetricsCache;
+/// let stats = text_stats("Hello, world! How are you?\nFine.");
+/// assert_eq!(stats.words, 6);
+/// assert_eq!(stats.characters, 31);
+/// assert_eq!(stats.characters_no_spaces, 27);
+/// assert_eq!(stats.sentences, 3);
+/// assert_eq!((stats.paragraphs, stats.lines, stats.bytes), (2, 2, 32));
+///
+/// let cache = MetricsCache::new(16);
+/// assert_eq!(cache.get_or_compute("Hi there.", text_stats).words, 2);
+/// ```
+pub fn text_stats(text: &str) -> TextStats {
+    let mut stats = TextStats { bytes: text.len(), ..TextStats::default() };
+    let mut token_start = None;
+    let mut line_breaks = 0;
+    let mut starts_with_break = false;
+    let mut ends_with_break = false;
+    // Sentence state: content seen since the last end, and the start and last
+    // terminator of a run of terminators and closing punctuation
+    let mut in_sentence = false;
+    let mut terminator: Option<(usize, char)> = None;
+    let mut previous = None;
+
+    for (i, c) in text.char_indices() {
+        let is_break = c == '\n' || c == '\r';
+        if is_break {
+            // "\r\n" is one line break
+            if !(c == '\n' && previous == Some('\r')) {
+                line_breaks += 1;
+                starts_with_break |= i == 0;
+            }
+        } else {
+            stats.characters += 1;
+        }
+        ends_with_break = is_break;
+
+        if is_sentence_terminator(c) {
+            terminator = Some((terminator.map_or(i, |(start, _)| start), c));
+        } else if !(terminator.is_some() && is_closing_punctuation(c)) {
+            if let Some((start, last)) = terminator.take()
+                && in_sentence
+                && is_sentence_end(text, start, i, last)
+            {
+                stats.sentences += 1;
+                in_sentence = false;
+            }
+            in_sentence |= c.is_alphanumeric();
+        }
+
+        if c.is_whitespace() {
+            if let Some(start) = token_start.take() {
+                stats.words += token_words(&text[start..i]).count();
+            }
+        } else {
+            stats.characters_no_spaces += 1;
+            token_start.get_or_insert(i);
+        }
+        previous = Some(c);
+    }
+    if let Some(start) = token_start {
+        stats.words += token_words(&text[start..]).count();
+    }
+    if in_sentence {
+        stats.sentences += 1;
+    }
+
+    if !text.is_empty() {
+        stats.paragraphs = if starts_with_break { line_breaks } else { line_breaks + 1 };
+        stats.lines = line_breaks + usize::from(!ends_with_break);
+    }
+    stats
+}
+
+/// Counts the sentences of a text, with the rules of
+/// [`sentences`](crate::segmenter::sentences): abbreviations, initials,
+/// decimal numbers and ellipses followed by a lowercase word do not end a
+/// sentence.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::sentence_count;
+/// assert_eq!(sentence_count("Mr. Brown arrived at 9.30 a.m. today. He left... later. Bye!"), 3);
+/// assert_eq!(sentence_count(""), 0);
+/// ```
+pub fn sentence_count(text: &str) -> usize {
+    sentences(text).count()
+}
+
+/// Default reading speed of [`reading_time`], in words per minute.
+const READING_WPM: u32 = 265;
+/// Default reading speed of [`reading_time`] for CJK text, in characters per minute.
+const READING_CJK_CPM: u32 = 500;
+
+/// Estimates the time needed to read a text, for "X min read" labels.
+///
+/// Words are the tokens of [`word_count`], read at `wpm` words per minute
+/// (265 by default, 0 also means the default). CJK characters, which
+/// [`word_count`] counts one by one, are read at 500 characters per minute
+/// instead; that speed is scaled with a custom `wpm`, so slower readers are
+/// slower in every script.
+///
+/// The result is not rounded: use `as_secs().div_ceil(60)` for whole minutes.
+///
+/// ### Examples
+/// ```
+/// use std::time::Duration;
+/// use bt_string_utils::analyzer::reading_time;
+/// let post = "word ".repeat(530);
+/// assert_eq!(reading_time(&post, None), Duration::from_secs(120));
+/// assert_eq!(reading_time(&post, Some(200)).as_secs().div_ceil(60), 3);
+/// assert_eq!(reading_time(&"你好".repeat(250), None), Duration::from_secs(60));
+/// ```
+pub fn reading_time(text: &str, wpm: Option<u32>) -> Duration {
+    let wpm = wpm.filter(|&wpm| wpm > 0).unwrap_or(READING_WPM);
+    let cpm = f64::from(READING_CJK_CPM) * f64::from(wpm) / f64::from(READING_WPM);
+
+    let (mut words, mut cjk) = (0usize, 0usize);
+    for token in word_tokens(text) {
+        let mut chars = token.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if is_cjk(c) => cjk += 1,
+            _ => words += 1,
+        }
+    }
+    Duration::from_secs_f64(60.0 * (words as f64 / f64::from(wpm) + cjk as f64 / cpm))
+}
+
+/// What the char_count function counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CountModeEnum {
+    /// Unicode scalar values, like `str::chars().count()`: `"🙂"` is 1 but
+    /// `"👍🏽"` is 2 and `"e\u{301}"` is 2.
+    Scalars,
+    /// Grapheme clusters, the characters users see: `"👍🏽"` and `"e\u{301}"`
+    /// are 1.
+    #[default]
+    Graphemes,
+    /// Grapheme clusters that are not whitespace.
+    NoWhitespace,
+    /// Grapheme clusters that are not punctuation (see [`is_punctuation`]).
+    NoPunctuation,
+    /// Grapheme clusters weighted by their width with [`char_width`]: CJK,
+    /// fullwidth forms and emoji count 2, combining sequences count once.
+    Wide,
+}
+
+/// Counts the characters of a text in the way selected by `mode`.
+///
+/// Document statistics usually want [`CountModeEnum::Graphemes`]: a flag, an
+/// emoji with a skin tone or a letter with combining accents is one character
+/// to the reader, but several Unicode scalar values.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::{char_count, CountModeEnum};
+/// let text = "Hi 👍🏽, 你好!";
+/// assert_eq!(char_count(text, CountModeEnum::Scalars), 10);
+/// assert_eq!(char_count(text, CountModeEnum::Graphemes), 9);
+/// assert_eq!(char_count(text, CountModeEnum::NoWhitespace), 7);
+/// assert_eq!(char_count(text, CountModeEnum::NoPunctuation), 7);
+/// assert_eq!(char_count(text, CountModeEnum::Wide), 12);
+/// ```
+pub fn char_count(text: &str, mode: CountModeEnum) -> usize {
+    match mode {
+        CountModeEnum::Scalars => text.chars().count(),
+        CountModeEnum::Graphemes => graphemes(text).count(),
+        CountModeEnum::NoWhitespace => graphemes(text).filter(|g| !g.starts_with(char::is_whitespace)).count(),
+        CountModeEnum::NoPunctuation => graphemes(text).filter(|g| !g.starts_with(is_punctuation)).count(),
+        CountModeEnum::Wide => graphemes(text).map(|g| g.chars().map(char_width).max().unwrap_or(0)).sum(),
+    }
+}
+
+/// What the count_lines function counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineKindEnum {
+    /// Every line, empty or not, like [`TextStats::lines`].
+    #[default]
+    Physical,
+    /// Lines with at least one character that is not whitespace.
+    NonEmpty,
+    /// Non-empty lines that are not comments: lines whose first non-blank
+    /// text starts with one of the comment prefixes are not counted.
+    Code,
+}
+
+/// Options for the count_lines function
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LineCountOptions<'a> {
+    pub kind: LineKindEnum,
+    /// Prefixes of comment lines for [`LineKindEnum::Code`], such as `"//"`
+    /// or `"#"`. Empty prefixes are ignored.
+    pub comment_prefixes: &'a [&'a str],
+}
+
+/// Counts the lines of a text: all of them, the non-empty ones, or the lines
+/// of code (non-empty and not a comment).
+///
+/// `\n`, `\r\n` and `\r` end a line, and a last line without a line break
+/// counts too. Unlike [`count_paragraphs`], a text starting with a line break
+/// has an empty first line, and the count of code-like inputs does not depend
+/// on blank lines. Only line comments are recognized: the lines inside a
+/// `/* ... */` block count as code.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::analyzer::{count_lines, LineCountOptions, LineKindEnum};
+/// let code = "// Entry point\nfn main() {\n\n    # not a comment in Rust\n    run(); // trailing\n}\n";
+/// let rust = LineCountOptions { kind: LineKindEnum::Code, comment_prefixes: &["//"] };
+/// assert_eq!(count_lines(code, LineCountOptions::default()), 6);
+/// assert_eq!(count_lines(code, LineCountOptions { kind: LineKindEnum::NonEmpty, ..rust }), 5);
+/// assert_eq!(count_lines(code, rust), 4);
+/// ```
+pub fn count_lines(text: &str, options: LineCountOptions) -> usize {
+    let counts = |line: &str| {
+        let content = line.trim_start();
+        match options.kind {
+            LineKindEnum::Physical => true,
+            LineKindEnum::NonEmpty => !content.is_empty(),
+            LineKindEnum::Code => {
+                !content.is_empty()
+                    && !options.comment_prefixes.iter().any(|prefix| !prefix.is_empty() && content.starts_with(prefix))
+            }
+        }
+    };
+
+    let mut count = 0;
+    let mut start = 0;
+    let mut previous = None;
+    for (i, c) in text.char_indices() {
+        if c == '\n' && previous == Some('\r') {
+            // The line already ended at the '\r' of "\r\n"
+            start = i + 1;
+        } else if c == '\n' || c == '\r' {
+            count += usize::from(counts(&text[start..i]));
+            start = i + 1;
+        }
+        previous = Some(c);
+    }
+    if start < text.len() {
+        count += usize::from(counts(&text[start..]));
+    }
+    count
+}
@@ -0,0 +1,265 @@
+use crate::checkdigit::luhn_mod_n;
+use crate::extract::email_len;
+use crate::hasher::{fnv1a_64, siphash24};
+use crate::random::SplitMix64;
+
+/// Options for the anonymize function.
+///
+/// The same `seed` and input always produce the same output, and the same
+/// original value is always replaced by the same fake inside a text, so
+/// relationships between records are kept in the generated fixtures.
+pub struct Anonymizer {
+    /// Seed for the deterministic replacements.
+    pub seed: u64,
+    /// Replace capitalized words that look like names.
+    pub names: bool,
+    /// Replace email addresses.
+    pub emails: bool,
+    /// Replace digits in numbers (card numbers keep a valid Luhn check digit).
+    pub numbers: bool,
+    /// Words always treated as names, even at the start of a sentence.
+    pub known_names: Vec<String>,
+}
+
+impl Anonymizer {
+    /// Creates an anonymizer with the given seed and every replacement enabled.
+    pub fn new(seed: u64) -> Self {
+        Anonymizer {
+            seed,
+            names: true,
+            emails: true,
+            numbers: true,
+            known_names: Vec::new(),
+        }
+    }
+}
+
+/// Deterministically replaces names, emails and numbers in `text` with
+/// realistic fakes, so production logs can be turned into shareable fixtures.
+///
+/// ### Rules implemented:
+/// - **Names**: capitalized words (`"John"`) that do not start a sentence, plus
+///   any word listed in `known_names`, are replaced by a pronounceable fake
+///   of the same length and capitalization (`"John"` → e.g. `"Keva"`).
+/// - **Emails**: letters and digits of the local part and of the domain labels
+///   are replaced; punctuation and the top-level domain are kept.
+/// - **Numbers**: every digit is replaced, keeping grouping separators
+///   (`' '`, `'-'`) and leading zeros. Runs of 13 to 19 digits that pass the
+///   Luhn check (card numbers) get a new valid Luhn check digit.
+/// - Everything else, including whitespace and punctuation, is preserved.
+///
+/// # Arguments
+/// * `text` - The input text.
+/// * `options` - The [`Anonymizer`] configuration.
+///
+/// # Returns
+/// A new `String` with the sensitive parts replaced.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::anonymizer::{anonymize, Anonymizer};
+/// let options = Anonymizer::new(42);
+/// let out = anonymize("Ticket from John at john@mail.com, card 4111 1111 1111 1111", &options);
+/// assert!(!out.contains("John"));
+/// assert!(!out.contains("john@mail.com"));
+/// assert!(out.starts_with("Ticket from "));
+/// assert!(out.contains(".com, card "));
+/// // Deterministic
+/// assert_eq!(out, anonymize("Ticket from John at john@mail.com, card 4111 1111 1111 1111", &options));
+/// ```
+pub fn anonymize(text: &str, options: &Anonymizer) -> String {
+    trace_span!("anonymize", bytes = text.len());
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    let mut sentence_start = true;
+
+    while i < text.len() {
+        let rest = &text[i..];
+        let c = rest.chars().next().unwrap();
+
+        if options.emails && let Some(len) = email_len(rest) {
+            trace_event!(trace, position = i, len, "email replaced");
+            out.push_str(&fake_email(&rest[..len], options.seed));
+            sentence_start = false;
+            i += len;
+        } else if c.is_ascii_digit() {
+            let len = number_len(rest);
+            if options.numbers {
+                trace_event!(trace, position = i, len, "number replaced");
+                out.push_str(&fake_number(&rest[..len], options.seed));
+            } else {
+                out.push_str(&rest[..len]);
+            }
+            sentence_start = false;
+            i += len;
+        } else if c.is_alphabetic() {
+            let len = rest.find(|ch: char| !ch.is_alphabetic()).unwrap_or(rest.len());
+            let word = &rest[..len];
+            if options.names && is_name(word, sentence_start, &options.known_names) {
+                trace_event!(trace, position = i, len, "name replaced");
+                out.push_str(&fake_name(word, options.seed));
+            } else {
+                out.push_str(word);
+            }
+            sentence_start = false;
+            i += len;
+        } else {
+            if matches!(c, '.' | '!' | '?') {
+                sentence_start = true;
+            }
+            out.push(c);
+            i += c.len_utf8();
+        }
+    }
+
+    out
+}
+
+/// Produces a stable keyed pseudonym for `value`.
+///
+/// The same `value` and `key` always map to the same pseudonym, across runs
+/// and machines, so redacted records stay joinable. Without the key the
+/// pseudonym cannot be linked back to the value (SipHash-2-4 keyed hash).
+///
+/// # Arguments
+/// * `value` - The value to hide (user name, email, id...).
+/// * `key` - The 16-byte secret key. Keep it private and stable.
+///
+/// # Returns
+/// A `String` with 16 lowercase hexadecimal characters.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::anonymizer::pseudonymize;
+/// let key = *b"0123456789abcdef";
+/// let token = pseudonymize("john@mail.com", &key);
+/// assert_eq!(token.len(), 16);
+/// assert_eq!(token, pseudonymize("john@mail.com", &key));
+/// assert_ne!(token, pseudonymize("jane@mail.com", &key));
+/// ```
+pub fn pseudonymize(value: &str, key: &[u8; 16]) -> String {
+    format!("{:016x}", siphash24(key, value.as_bytes()))
+}
+
+/// Same as [`pseudonymize`] but the pseudonym starts with `prefix`, so the
+/// kind of value stays visible (e.g. `"usr_"` → `"usr_3fa1b2c4d5e6f708"`).
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::anonymizer::pseudonymize_with_prefix;
+/// let key = *b"0123456789abcdef";
+/// let token = pseudonymize_with_prefix("john", &key, "usr_");
+/// assert!(token.starts_with("usr_"));
+/// assert_eq!(token.len(), 20);
+/// ```
+pub fn pseudonymize_with_prefix(value: &str, key: &[u8; 16], prefix: &str) -> String {
+    format!("{prefix}{}", pseudonymize(value, key))
+}
+
+/// Small deterministic generator derived from the seed and the original value,
+/// so equal values always get the same replacement.
+struct FakeRng(SplitMix64);
+
+impl FakeRng {
+    fn new(seed: u64, original: &str) -> Self {
+        FakeRng(SplitMix64(fnv1a_64(original.as_bytes()) ^ seed.rotate_left(17)))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn pick(&mut self, choices: &[u8]) -> char {
+        choices[(self.next() % choices.len() as u64) as usize] as char
+    }
+}
+
+const CONSONANTS: &[u8] = b"bcdfghjklmnprstvz";
+const VOWELS: &[u8] = b"aeiou";
+const DIGITS: &[u8] = b"0123456789";
+
+fn is_name(word: &str, sentence_start: bool, known_names: &[String]) -> bool {
+    if known_names.iter().any(|n| n == word) {
+        return true;
+    }
+    let mut chars = word.chars();
+    let first_upper = chars.next().is_some_and(char::is_uppercase);
+    let rest_lower = chars.as_str().chars().all(char::is_lowercase);
+    !sentence_start && first_upper && rest_lower && word.chars().count() > 1
+}
+
+/// Pronounceable fake of the same length, alternating consonants and vowels,
+/// keeping the capitalization of each character.
+fn fake_name(word: &str, seed: u64) -> String {
+    let mut rng = FakeRng::new(seed, word);
+    word.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let fake = rng.pick(if i % 2 == 0 { CONSONANTS } else { VOWELS });
+            if c.is_uppercase() { fake.to_ascii_uppercase() } else { fake }
+        })
+        .collect()
+}
+
+fn fake_email(email: &str, seed: u64) -> String {
+    let mut rng = FakeRng::new(seed, email);
+    let tld_start = email.rfind('.').unwrap_or(email.len());
+    let mut out = String::with_capacity(email.len());
+    for (i, c) in email.char_indices() {
+        let fake = if i >= tld_start || !c.is_ascii_alphanumeric() {
+            c
+        } else if c.is_ascii_digit() {
+            rng.pick(DIGITS)
+        } else {
+            rng.pick(if i % 2 == 0 { CONSONANTS } else { VOWELS })
+        };
+        out.push(fake);
+    }
+    out
+}
+
+/// Returns the byte length of the number at the start of `s`: digits, with
+/// single spaces or hyphens allowed between digit groups.
+fn number_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let separator = matches!(bytes[i], b' ' | b'-') && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+        if !bytes[i].is_ascii_digit() && !separator {
+            break;
+        }
+        i += 1;
+    }
+    i
+}
+
+fn fake_number(number: &str, seed: u64) -> String {
+    let mut rng = FakeRng::new(seed, number);
+    let mut digits: Vec<u8> = number.bytes().filter(u8::is_ascii_digit).map(|b| b - b'0').collect();
+    let card = (13..=19).contains(&digits.len()) && luhn_valid(&digits);
+
+    for (i, d) in digits.iter_mut().enumerate() {
+        let original_zero = *d == 0;
+        *d = (rng.next() % 10) as u8;
+        // Keep leading zeros meaningful and avoid introducing new ones
+        if i == 0 {
+            *d = if original_zero { 0 } else { 1 + (*d % 9) };
+        }
+    }
+
+    if card {
+        let last = digits.len() - 1;
+        digits[last] = luhn_mod_n(&digits[..last], 10);
+    }
+
+    let mut fake_digits = digits.into_iter();
+    number
+        .chars()
+        .map(|c| if c.is_ascii_digit() { (b'0' + fake_digits.next().unwrap()) as char } else { c })
+        .collect()
+}
+
+fn luhn_valid(digits: &[u8]) -> bool {
+    let (payload, check) = digits.split_at(digits.len() - 1);
+    luhn_mod_n(payload, 10) == check[0]
+}
@@ -0,0 +1,84 @@
+//! Variants of the split, kv and tokenize functions that allocate their
+//! results in a caller-provided arena (feature `arena`).
+//!
+//! Parsers handling many small inputs (such as log lines) spend much of their
+//! time allocating and freeing small vectors and strings. With these
+//! variants everything is allocated in a [`bumpalo::Bump`] arena, freed at
+//! once when the arena is reset or dropped.
+
+pub use bumpalo::Bump as Arena;
+use bumpalo::collections::Vec as ArenaVec;
+
+/// Same as [`tokenize`](crate::analyzer::tokenize), with the vector
+/// allocated in the arena.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::arena::{tokenize_in, Arena};
+/// let mut arena = Arena::new();
+/// for line in ["GET /index.html 200", "POST /login 302"] {
+///     let tokens = tokenize_in(line, &arena);
+///     assert_eq!(tokens.len(), 3);
+/// }
+/// arena.reset();
+/// ```
+pub fn tokenize_in<'b>(text: &'b str, arena: &'b Arena) -> ArenaVec<'b, &'b str> {
+    let mut tokens = ArenaVec::new_in(arena);
+    tokens.extend(crate::analyzer::word_tokens(text));
+    tokens
+}
+
+/// Same as [`split_into_chunks`](crate::splitter::split_into_chunks), with
+/// the chunks borrowed from `content` and the vector allocated in the arena.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::arena::{split_into_chunks_in, Arena};
+/// let arena = Arena::new();
+/// assert_eq!(split_into_chunks_in("héllo", 2, &arena).as_slice(), ["h", "é", "ll", "o"]);
+/// ```
+pub fn split_into_chunks_in<'b>(content: &'b str, chunk_size_bytes: usize, arena: &'b Arena) -> ArenaVec<'b, &'b str> {
+    let mut chunks = ArenaVec::new_in(arena);
+    let mut offset = 0;
+    while offset < content.len() {
+        let mut end = (offset + chunk_size_bytes).min(content.len());
+        while !content.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == offset {
+            // A character larger than the chunk size
+            end = offset + content[offset..].chars().next().map_or(1, char::len_utf8);
+        }
+        chunks.push(&content[offset..end]);
+        offset = end;
+    }
+    chunks
+}
+
+/// Same as [`parse_properties`](crate::kv::parse_properties), returning the
+/// entries in file order (a key may appear several times; the last one wins
+/// in `parse_properties`).
+///
+/// Keys and values without escapes are borrowed from `text`; unescaped ones
+/// are allocated in the arena.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::arena::{parse_properties_in, Arena};
+/// let arena = Arena::new();
+/// let entries = parse_properties_in("# db\nhost = db1\npath = C:\\\\temp", &arena);
+/// assert_eq!(entries.as_slice(), [("host", "db1"), ("path", "C:\\temp")]);
+/// ```
+pub fn parse_properties_in<'b>(text: &'b str, arena: &'b Arena) -> ArenaVec<'b, (&'b str, &'b str)> {
+    let mut entries = ArenaVec::new_in(arena);
+    let store = |s: std::borrow::Cow<'b, str>| -> &'b str {
+        match s {
+            std::borrow::Cow::Borrowed(s) => s,
+            std::borrow::Cow::Owned(s) => arena.alloc_str(&s),
+        }
+    };
+    for (key, value) in crate::kv::property_entries(text) {
+        entries.push((store(key), store(value)));
+    }
+    entries
+}
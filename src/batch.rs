@@ -0,0 +1,98 @@
+use crate::case::to_snake_case_into;
+use crate::cleanser::collapse_whitespace_into;
+
+/// Transformation applied by the batch functions
+#[derive(Clone, Copy)]
+pub enum Transform<'a> {
+    /// Removes leading and trailing whitespace.
+    Trim,
+    /// Converts to lowercase.
+    Lowercase,
+    /// Converts to uppercase.
+    Uppercase,
+    /// Replaces each run of whitespace with a single space.
+    CollapseWhitespace,
+    /// Converts to `snake_case` (see [`to_snake_case`](crate::case::to_snake_case)).
+    SnakeCase,
+    /// A custom transformation that appends its result for the input to the
+    /// given buffer (which is empty when called).
+    Custom(&'a (dyn Fn(&str, &mut String) + Sync)),
+}
+
+/// Applies one transformation to every item and returns the results in order.
+///
+/// The output vector is allocated once with the right size, and the
+/// transformations that build their result character by character share one
+/// scratch buffer, so each result costs a single allocation of its exact size.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::batch::{batch_apply, Transform};
+/// let names = ["  Ada Lovelace ", "grace\thopper"];
+/// assert_eq!(batch_apply(&names, Transform::SnakeCase), vec!["ada_lovelace", "grace_hopper"]);
+///
+/// let reverse = |s: &str, out: &mut String| out.extend(s.chars().rev());
+/// assert_eq!(batch_apply(&["abc"], Transform::Custom(&reverse)), vec!["cba"]);
+/// ```
+pub fn batch_apply<S: AsRef<str>>(items: &[S], transform: Transform) -> Vec<String> {
+    let mut out = Vec::with_capacity(items.len());
+    let mut scratch = String::new();
+    for item in items {
+        out.push(apply(item.as_ref(), transform, &mut scratch));
+    }
+    out
+}
+
+/// Same as [`batch_apply`], splitting the items between `threads` threads
+/// (`0` uses the number of available CPUs). Results are in input order.
+///
+/// Worth it for large batches; for a few thousand short strings the cost of
+/// starting threads is higher than the work.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::batch::{batch_apply_parallel, Transform};
+/// let items: Vec<String> = (0..1000).map(|i| format!(" Item {i} ")).collect();
+/// let out = batch_apply_parallel(&items, Transform::Trim, 4);
+/// assert_eq!(out[999], "Item 999");
+/// ```
+pub fn batch_apply_parallel<S: AsRef<str> + Sync>(items: &[S], transform: Transform, threads: usize) -> Vec<String> {
+    let threads = match threads {
+        0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    };
+    if threads <= 1 || items.len() < 2 {
+        return batch_apply(items, transform);
+    }
+
+    let chunk_size = items.len().div_ceil(threads);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || batch_apply(chunk, transform)))
+            .collect();
+
+        let mut out = Vec::with_capacity(items.len());
+        for handle in handles {
+            match handle.join() {
+                Ok(results) => out.extend(results),
+                Err(panic) => std::panic::resume_unwind(panic),
+            }
+        }
+        out
+    })
+}
+
+fn apply(item: &str, transform: Transform, scratch: &mut String) -> String {
+    let write: &dyn Fn(&str, &mut String) = match transform {
+        Transform::Trim => return item.trim().to_owned(),
+        Transform::Lowercase => return item.to_lowercase(),
+        Transform::Uppercase => return item.to_uppercase(),
+        Transform::CollapseWhitespace => &collapse_whitespace_into,
+        Transform::SnakeCase => &to_snake_case_into,
+        Transform::Custom(f) => f,
+    };
+    scratch.clear();
+    write(item, scratch);
+    scratch.as_str().to_owned()
+}
@@ -0,0 +1,158 @@
+//! `bt-strings`: command line access to the crate's functions (feature `cli`).
+//!
+//! Reads the files given as arguments, or standard input when there are none
+//! (or for `-`), and writes the result to standard output.
+
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+
+use bt_string_utils::analyzer::{count_paragraphs, word_count};
+use bt_string_utils::anonymizer::{anonymize, Anonymizer};
+use bt_string_utils::kv::parse_properties;
+use bt_string_utils::slugify;
+use bt_string_utils::splitter::split_into_chunks;
+
+const USAGE: &str = "\
+Usage: bt-strings <COMMAND> [OPTIONS] [FILE...]
+
+Commands:
+  count [--paragraphs]     Print the number of words (or paragraphs)
+  chunk --size BYTES       Split into chunks of at most BYTES bytes, separated by NUL bytes
+  kv get KEY               Print the value of KEY in a properties file
+  redact [--seed N]        Replace names, emails and numbers with deterministic fakes (anonymize)
+  slugify [--separator C]  Turn each line into a URL-safe slug (default separator -)
+  help                     Print this message
+
+Reads the FILEs, or standard input when no FILE is given or FILE is -.";
+
+/// A parsed command line.
+enum Command {
+    Count { paragraphs: bool },
+    Chunk { size: usize },
+    KvGet { key: String },
+    Redact { seed: u64 },
+    Slugify { separator: char },
+}
+
+/// Why the command failed, with its exit code.
+enum CliError {
+    /// Invalid arguments (exit code 2).
+    Usage(String),
+    /// The key asked by `kv get` is missing (exit code 1, nothing printed).
+    NotFound,
+    /// Reading or writing failed (exit code 1).
+    Io(String),
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(CliError::NotFound) => ExitCode::from(1),
+        Err(CliError::Io(message)) => {
+            eprintln!("bt-strings: {message}");
+            ExitCode::from(1)
+        }
+        Err(CliError::Usage(message)) => {
+            eprintln!("bt-strings: {message}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), CliError> {
+    if let Some("help" | "-h" | "--help") = args.first().map(String::as_str) {
+        println!("{USAGE}");
+        return Ok(());
+    }
+    let (command, files) = parse_args(args)?;
+
+    let input = read_input(&files)?;
+    let output = match command {
+        Command::Count { paragraphs: false } => format!("{}\n", word_count(&input)),
+        Command::Count { paragraphs: true } => format!("{}\n", count_paragraphs(&input)),
+        Command::Chunk { size } => split_into_chunks(&input, size).join("\0"),
+        Command::KvGet { key } => match parse_properties(&input).remove(&key) {
+            Some(value) => format!("{value}\n"),
+            None => return Err(CliError::NotFound),
+        },
+        Command::Redact { seed } => anonymize(&input, &Anonymizer::new(seed)),
+        Command::Slugify { separator } => input.lines().map(|line| slugify(line, separator) + "\n").collect(),
+    };
+    io::stdout().write_all(output.as_bytes()).map_err(|e| CliError::Io(e.to_string()))
+}
+
+/// Splits the arguments into the command and the input files.
+fn parse_args(args: &[String]) -> Result<(Command, Vec<String>), CliError> {
+    let Some((name, rest)) = args.split_first() else {
+        return Err(CliError::Usage("missing command".to_owned()));
+    };
+    let mut rest = rest.iter();
+    let mut files = Vec::new();
+    let value = |rest: &mut std::slice::Iter<String>, option: &str| {
+        rest.next().cloned().ok_or_else(|| CliError::Usage(format!("missing value for {option}")))
+    };
+
+    let mut command = match name.as_str() {
+        "count" => Command::Count { paragraphs: false },
+        "chunk" => Command::Chunk { size: 0 },
+        "kv" => match rest.next().map(String::as_str) {
+            Some("get") => Command::KvGet { key: value(&mut rest, "kv get")? },
+            _ => return Err(CliError::Usage("expected kv get KEY".to_owned())),
+        },
+        "redact" => Command::Redact { seed: 0 },
+        "slugify" => Command::Slugify { separator: '-' },
+        other => return Err(CliError::Usage(format!("unknown command {other}"))),
+    };
+
+    while let Some(arg) = rest.next() {
+        match (&mut command, arg.as_str()) {
+            (Command::Count { paragraphs }, "--paragraphs") => *paragraphs = true,
+            (Command::Chunk { size }, "--size") => *size = number(value(&mut rest, "--size")?, "--size")?,
+            (Command::Redact { seed }, "--seed") => *seed = number(value(&mut rest, "--seed")?, "--seed")?,
+            (Command::Slugify { separator }, "--separator") => {
+                *separator = single_char(value(&mut rest, "--separator")?)?
+            }
+            (_, option) if option.starts_with("--") => {
+                return Err(CliError::Usage(format!("unknown option {option}")));
+            }
+            (_, file) => files.push(file.to_owned()),
+        }
+    }
+
+    if let Command::Chunk { size: 0 } = command {
+        return Err(CliError::Usage("chunk needs --size BYTES greater than 0".to_owned()));
+    }
+    Ok((command, files))
+}
+
+/// Parses the numeric value of an option.
+fn number<T: std::str::FromStr>(text: String, option: &str) -> Result<T, CliError> {
+    text.parse().map_err(|_| CliError::Usage(format!("invalid value for {option}: {text}")))
+}
+
+/// Parses the value of --separator, which must be exactly one character.
+fn single_char(text: String) -> Result<char, CliError> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(CliError::Usage(format!("--separator expects a single character, got {text:?}"))),
+    }
+}
+
+/// Concatenates the files, or reads standard input.
+fn read_input(files: &[String]) -> Result<String, CliError> {
+    if files.is_empty() {
+        return read_input(&["-".to_owned()]);
+    }
+    let mut input = String::new();
+    for file in files {
+        let result = if file == "-" {
+            io::stdin().read_to_string(&mut input).map(|_| ())
+        } else {
+            std::fs::read_to_string(file).map(|text| input.push_str(&text))
+        };
+        result.map_err(|e| CliError::Io(format!("{file}: {e}")))?;
+    }
+    Ok(input)
+}
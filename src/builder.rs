@@ -0,0 +1,196 @@
+/// Concatenates whole parts, in order, while the result fits in `max_bytes`.
+///
+/// Parts are never cut, so the result is always valid UTF-8 and each part is
+/// either fully included or not at all. Concatenation stops at the first part
+/// that does not fit; later (possibly smaller) parts are not tried, so the
+/// consumed parts are always a prefix of `parts`.
+///
+/// # Arguments
+/// * `parts` - The strings to concatenate.
+/// * `max_bytes` - The maximum length of the result in bytes.
+///
+/// # Returns
+/// A tuple with the concatenated `String` and the number of parts consumed.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::builder::concat_within_limit;
+/// let parts = ["Hello ", "wörld ", "and more"];
+/// assert_eq!(concat_within_limit(&parts, 13), ("Hello wörld ".to_owned(), 2));
+/// assert_eq!(concat_within_limit(&parts, 5), ("".to_owned(), 0));
+/// assert_eq!(concat_within_limit(&parts, 100), ("Hello wörld and more".to_owned(), 3));
+/// ```
+pub fn concat_within_limit<S: AsRef<str>>(parts: &[S], max_bytes: usize) -> (String, usize) {
+    concat_within(parts, max_bytes, str::len)
+}
+
+/// Same as [`concat_within_limit`] but the budget is measured in characters
+/// (Unicode scalar values) instead of bytes.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::builder::concat_within_char_limit;
+/// let parts = ["héllo", "wörld"];
+/// assert_eq!(concat_within_char_limit(&parts, 10), ("héllowörld".to_owned(), 2));
+/// assert_eq!(concat_within_char_limit(&parts, 9), ("héllo".to_owned(), 1));
+/// ```
+pub fn concat_within_char_limit<S: AsRef<str>>(parts: &[S], max_chars: usize) -> (String, usize) {
+    concat_within(parts, max_chars, |s| s.chars().count())
+}
+
+fn concat_within<S: AsRef<str>>(parts: &[S], budget: usize, measure: impl Fn(&str) -> usize) -> (String, usize) {
+    let mut out = String::new();
+    let mut used = 0;
+    let mut consumed = 0;
+
+    for part in parts {
+        let part = part.as_ref();
+        let size = measure(part);
+        if used + size > budget {
+            break;
+        }
+        out.push_str(part);
+        used += size;
+        consumed += 1;
+    }
+
+    (out, consumed)
+}
+
+struct PromptSection {
+    text: String,
+    priority: i32,
+}
+
+/// Assembles a prompt from sections with priorities so that it fits in a
+/// budget of units (bytes by default, or any measure such as a token counter).
+///
+/// When the assembled prompt is over budget, [`PromptBuilder::build`]
+/// truncates the **lowest-priority** section first (the last added one on
+/// ties), keeping as much of it as fits. Sections are cut at word boundaries;
+/// text without whitespace (e.g. CJK) is cut at grapheme boundaries, so the
+/// output is always valid UTF-8 and never splits a character. A section that
+/// cannot keep any text is dropped and the next lowest-priority section is
+/// truncated.
+///
+/// Sections keep the order in which they were added and are joined with the
+/// separator (`"\n\n"` by default); empty sections are skipped.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::builder::PromptBuilder;
+/// let prompt = PromptBuilder::new(39)
+///     .section("You are a helpful assistant.", 10)
+///     .section("Context: a very long document that will not fit entirely", 1)
+///     .build();
+/// assert_eq!(prompt, "You are a helpful assistant.\n\nContext:");
+///
+/// // Token budget with a custom measure (here: words)
+/// let prompt = PromptBuilder::with_measure(4, |s: &str| s.split_whitespace().count())
+///     .section("one two three", 1)
+///     .section("four five", 2)
+///     .build();
+/// assert_eq!(prompt, "one two\n\nfour five");
+/// ```
+pub struct PromptBuilder<'a> {
+    sections: Vec<PromptSection>,
+    budget: usize,
+    separator: String,
+    measure: Box<dyn Fn(&str) -> usize + 'a>,
+}
+
+impl<'a> PromptBuilder<'a> {
+    /// Creates a builder whose budget is measured in bytes.
+    pub fn new(max_bytes: usize) -> Self {
+        Self::with_measure(max_bytes, str::len)
+    }
+
+    /// Creates a builder whose budget is measured with `measure` (e.g. a
+    /// tokenizer's token count). The measure must not decrease when text is
+    /// appended.
+    pub fn with_measure(budget: usize, measure: impl Fn(&str) -> usize + 'a) -> Self {
+        PromptBuilder {
+            sections: Vec::new(),
+            budget,
+            separator: "\n\n".to_owned(),
+            measure: Box::new(measure),
+        }
+    }
+
+    /// Sets the separator placed between sections.
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_owned();
+        self
+    }
+
+    /// Adds a section. Higher `priority` sections are truncated last.
+    pub fn section(mut self, text: &str, priority: i32) -> Self {
+        self.sections.push(PromptSection { text: text.to_owned(), priority });
+        self
+    }
+
+    /// Assembles the prompt, truncating the lowest-priority sections until it
+    /// fits in the budget.
+    pub fn build(mut self) -> String {
+        let mut order: Vec<usize> = (0..self.sections.len()).collect();
+        // Lowest priority first; on ties the last added section goes first
+        order.sort_by(|a, b| self.sections[*a].priority.cmp(&self.sections[*b].priority).then(b.cmp(a)));
+
+        for idx in order {
+            if self.fits(&self.assemble()) {
+                break;
+            }
+            let keep = self.longest_fitting_prefix(idx);
+            let text = &mut self.sections[idx].text;
+            text.truncate(keep);
+            text.truncate(text.trim_end().len());
+        }
+
+        self.assemble()
+    }
+
+    fn fits(&self, prompt: &str) -> bool {
+        (self.measure)(prompt) <= self.budget
+    }
+
+    fn assemble(&self) -> String {
+        let mut out = String::new();
+        for section in self.sections.iter().filter(|s| !s.text.is_empty()) {
+            if !out.is_empty() {
+                out.push_str(&self.separator);
+            }
+            out.push_str(&section.text);
+        }
+        out
+    }
+
+    /// Byte length of the longest prefix of section `idx` that keeps the prompt
+    /// within budget, cut at a word boundary, or at a grapheme boundary if the
+    /// text has no whitespace.
+    fn longest_fitting_prefix(&mut self, idx: usize) -> usize {
+        let text = std::mem::take(&mut self.sections[idx].text);
+
+        let mut cuts: Vec<usize> = text
+            .char_indices()
+            .filter(|(i, c)| c.is_whitespace() && *i > 0)
+            .map(|(i, _)| i)
+            .collect();
+        if cuts.is_empty() {
+            let mut pos = 0;
+            while pos < text.len() {
+                pos = crate::segmenter::next_grapheme_end(&text, pos);
+                cuts.push(pos);
+            }
+        } else {
+            cuts.push(text.len());
+        }
+
+        let fitting = cuts.partition_point(|&cut| {
+            self.sections[idx].text = text[..cut].to_owned();
+            self.fits(&self.assemble())
+        });
+
+        self.sections[idx].text = text;
+        if fitting > 0 { cuts[fitting - 1] } else { 0 }
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+use crate::hasher::{fnv1a_64, siphash24};
+
+/// Fixed key for the second half of the content hash.
+const CONTENT_KEY: [u8; 16] = *b"bt_string_utils!";
+
+/// Thread-safe memoization of an expensive text metric (statistics,
+/// fingerprints, counts...), keyed by a hash of the content.
+///
+/// Render loops that recompute metrics of the same documents get the stored
+/// result instead. The cache holds at most `capacity` results and evicts the
+/// least recently used one when full.
+///
+/// Contents are identified by a 128-bit hash (FNV-1a and SipHash-2-4), so
+/// texts are not kept in memory. The metric is computed without holding the
+/// lock; two threads missing the same text at once may both compute it.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::analyzer::word_count;
+/// use bt_string_utils::cache::MetricsCache;
+/// let cache = MetricsCache::new(100);
+/// let document = "A long document that is rendered many times.";
+/// assert_eq!(cache.get_or_compute(document, word_count), 8);
+/// assert_eq!(cache.get_or_compute(document, |_| unreachable!()), 8);
+/// assert_eq!(cache.len(), 1);
+/// ```
+pub struct MetricsCache<V> {
+    capacity: usize,
+    inner: Mutex<CacheState<V>>,
+}
+
+struct CacheState<V> {
+    entries: HashMap<u128, (V, u64)>,
+    /// Keys by last use, oldest first.
+    recency: BTreeMap<u64, u128>,
+    tick: u64,
+}
+
+impl<V: Clone> MetricsCache<V> {
+    /// Creates a cache holding at most `capacity` results (at least one).
+    pub fn new(capacity: usize) -> Self {
+        MetricsCache {
+            capacity: capacity.max(1),
+            inner: Mutex::new(CacheState { entries: HashMap::new(), recency: BTreeMap::new(), tick: 0 }),
+        }
+    }
+
+    /// Returns the stored result for `text`, or computes it with `compute`,
+    /// stores it and returns it.
+    pub fn get_or_compute(&self, text: &str, compute: impl FnOnce(&str) -> V) -> V {
+        let key = content_hash(text);
+        if let Some(value) = self.lock().touch(key) {
+            return value;
+        }
+
+        let value = compute(text);
+        let mut state = self.lock();
+        if state.touch(key).is_none() {
+            if state.entries.len() >= self.capacity
+                && let Some((_, oldest)) = state.recency.pop_first()
+            {
+                state.entries.remove(&oldest);
+            }
+            let tick = state.next_tick();
+            state.entries.insert(key, (value.clone(), tick));
+            state.recency.insert(tick, key);
+        }
+        value
+    }
+
+    /// Returns the stored result for `text` without computing it.
+    pub fn get(&self, text: &str) -> Option<V> {
+        self.lock().touch(content_hash(text))
+    }
+
+    /// Returns the number of stored results.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns `true` if no result is stored.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every stored result.
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.entries.clear();
+        state.recency.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheState<V>> {
+        // A panic in another thread cannot leave the maps inconsistent
+        self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<V: Clone> CacheState<V> {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    /// Returns the value for `key` and marks it as the most recently used.
+    fn touch(&mut self, key: u128) -> Option<V> {
+        let tick = self.next_tick();
+        let (value, last_use) = self.entries.get_mut(&key)?;
+        self.recency.remove(last_use);
+        *last_use = tick;
+        self.recency.insert(tick, key);
+        Some(value.clone())
+    }
+}
+
+fn content_hash(text: &str) -> u128 {
+    ((fnv1a_64(text.as_bytes()) as u128) << 64) | siphash24(&CONTENT_KEY, text.as_bytes()) as u128
+}
@@ -0,0 +1,404 @@
+use std::ops::Range;
+
+use crate::locale::{capitalize_locale, Locale};
+use crate::segmenter::{next_grapheme_end, TextCursor};
+
+/// Words kept lowercase by [`to_title_case`] unless first or last: articles,
+/// coordinating conjunctions and short prepositions.
+const TITLE_SMALL_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "en", "for", "if", "in", "nor", "of", "off", "on", "or", "per", "so",
+    "the", "to", "up", "v", "via", "vs", "yet",
+];
+
+/// Naming convention of an identifier, as reported by [`detect_case`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaseStyle {
+    /// `snake_case`, also a single lowercase word.
+    Snake,
+    /// `SCREAMING_SNAKE_CASE`, also a single uppercase word.
+    ScreamingSnake,
+    /// `kebab-case`.
+    Kebab,
+    /// `camelCase`.
+    Camel,
+    /// `PascalCase`.
+    Pascal,
+    /// Mixes conventions, such as `Snake_Case`, `kebab_and-snake` or
+    /// `double__underscore`.
+    Mixed,
+    /// Not an identifier: empty, or with spaces or other punctuation.
+    Unknown,
+}
+
+impl CaseStyle {
+    /// Converts `s` to this style with the matching conversion function;
+    /// [`CaseStyle::Mixed`] and [`CaseStyle::Unknown`] return `s` unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use bt_string_utils::case::{detect_case, CaseStyle};
+    /// let style = detect_case("user-id");
+    /// assert_eq!(style.apply("AccountOwner"), "account-owner");
+    /// assert_eq!(CaseStyle::Unknown.apply("a b"), "a b");
+    /// ```
+    pub fn apply(self, s: &str) -> String {
+        match self {
+            CaseStyle::Snake => to_snake_case(s),
+            CaseStyle::ScreamingSnake => to_screaming_snake_case(s),
+            CaseStyle::Kebab => to_kebab_case(s),
+            CaseStyle::Camel => to_camel_case(s),
+            CaseStyle::Pascal => to_pascal_case(s),
+            CaseStyle::Mixed | CaseStyle::Unknown => s.to_owned(),
+        }
+    }
+}
+
+/// Converts the text to `snake_case`: lowercase words joined with `_`.
+///
+/// Words are separated by non-alphanumeric characters and by case changes,
+/// keeping acronyms together (`HTTPServer` → `http_server`).
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::case::to_snake_case;
+/// assert_eq!(to_snake_case("HTTPServer"), "http_server");
+/// assert_eq!(to_snake_case("  Monthly Sales-Report "), "monthly_sales_report");
+/// ```
+pub fn to_snake_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 4);
+    to_snake_case_into(s, &mut out);
+    out
+}
+
+/// Same as [`to_snake_case`], appending the result to `out` so its capacity
+/// can be reused.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::case::to_snake_case_into;
+/// let mut out = String::new();
+/// for name in ["userId", "XMLParser"] {
+///     out.clear();
+///     to_snake_case_into(name, &mut out);
+///     assert!(out.contains('_'));
+/// }
+/// ```
+pub fn to_snake_case_into(s: &str, out: &mut String) {
+    join_words(s, Some('_'), out, |word, _, out| out.extend(word.chars().flat_map(char::to_lowercase)));
+}
+
+/// Converts the text to `kebab-case`: lowercase words joined with `-`.
+///
+/// Words are split as in [`to_snake_case`].
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::case::to_kebab_case;
+/// assert_eq!(to_kebab_case("HTTPServer"), "http-server");
+/// assert_eq!(to_kebab_case("user_id2Name"), "user-id2-name");
+/// ```
+pub fn to_kebab_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 4);
+    join_words(s, Some('-'), &mut out, |word, _, out| out.extend(word.chars().flat_map(char::to_lowercase)));
+    out
+}
+
+/// Converts the text to `SCREAMING_SNAKE_CASE`: uppercase words joined with
+/// `_`.
+///
+/// Words are split as in [`to_snake_case`].
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::case::to_screaming_snake_case;
+/// assert_eq!(to_screaming_snake_case("maxRetryCount"), "MAX_RETRY_COUNT");
+/// assert_eq!(to_screaming_snake_case("HTTPServer v2"), "HTTP_SERVER_V2");
+/// ```
+pub fn to_screaming_snake_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 4);
+    join_words(s, Some('_'), &mut out, |word, _, out| out.extend(word.chars().flat_map(char::to_uppercase)));
+    out
+}
+
+/// Converts the text to `camelCase`: the first word in lowercase, the others
+/// capitalized, without separators.
+///
+/// Words are split as in [`to_snake_case`]; acronyms are capitalized like
+/// other words (`HTTPServer` → `httpServer`).
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::case::to_camel_case;
+/// assert_eq!(to_camel_case("http_server"), "httpServer");
+/// assert_eq!(to_camel_case("XMLHttpRequest"), "xmlHttpRequest");
+/// assert_eq!(to_camel_case("Monthly sales 2024 report"), "monthlySales2024Report");
+/// ```
+pub fn to_camel_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    join_words(s, None, &mut out, |word, index, out| {
+        if index == 0 {
+            out.extend(word.chars().flat_map(char::to_lowercase));
+        } else {
+            push_capitalized(word, out);
+        }
+    });
+    out
+}
+
+/// Converts the text to `PascalCase`: every word capitalized, without
+/// separators.
+///
+/// Words are split as in [`to_snake_case`]; acronyms are capitalized like
+/// other words (`HTTP_SERVER` → `HttpServer`).
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::case::to_pascal_case;
+/// assert_eq!(to_pascal_case("HTTP_SERVER"), "HttpServer");
+/// assert_eq!(to_pascal_case("user-id"), "UserId");
+/// ```
+pub fn to_pascal_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    join_words(s, None, &mut out, |word, _, out| push_capitalized(word, out));
+    out
+}
+
+/// Splits `s` into words and appends them to `out` with `push_word`, placing
+/// `separator` between them.
+fn join_words(s: &str, separator: Option<char>, out: &mut String, mut push_word: impl FnMut(&str, usize, &mut String)) {
+    for (index, range) in identifier_words(s).into_iter().enumerate() {
+        if index > 0 && let Some(separator) = separator {
+            out.push(separator);
+        }
+        push_word(&s[range], index, out);
+    }
+}
+
+/// Returns the byte ranges of the words of an identifier or phrase.
+///
+/// Words are separated by non-alphanumeric characters and by case changes:
+/// before an uppercase letter that follows a lowercase letter or a digit, and
+/// before the last letter of an acronym followed by a lowercase letter
+/// ("HTTPServer" -> "HTTP" "Server"). Digits stay with the preceding letters.
+pub(crate) fn identifier_words(s: &str) -> Vec<Range<usize>> {
+    let chars: Vec<(usize, char)> = s.char_indices().collect();
+    let mut words = Vec::new();
+    let mut word_start = None;
+    for (i, &(offset, c)) in chars.iter().enumerate() {
+        let previous = i.checked_sub(1).map(|p| chars[p].1);
+        let next = chars.get(i + 1).map(|&(_, n)| n);
+        let boundary = !c.is_alphanumeric()
+            || (c.is_uppercase()
+                && previous.is_some_and(|p| p.is_lowercase() || p.is_numeric()
+                    || (p.is_uppercase() && next.is_some_and(char::is_lowercase))));
+        if boundary && let Some(start) = word_start.take() {
+            words.push(start..offset);
+        }
+        if c.is_alphanumeric() && word_start.is_none() {
+            word_start = Some(offset);
+        }
+    }
+    if let Some(start) = word_start {
+        words.push(start..s.len());
+    }
+    words
+}
+
+/// Appends `word` with its first character in uppercase and the rest in
+/// lowercase.
+fn push_capitalized(word: &str, out: &mut String) {
+    let mut chars = word.chars();
+    if let Some(first) = chars.next() {
+        out.extend(first.to_uppercase());
+        out.extend(chars.flat_map(char::to_lowercase));
+    }
+}
+
+/// Detects the naming convention of an identifier, so generated code can keep
+/// the style of the names it was given.
+///
+/// ### Rules implemented:
+/// - Only letters, digits, `_` and `-` are allowed; anything else (spaces,
+///   dots...) or an empty string is [`CaseStyle::Unknown`].
+/// - With `_`: all lowercase is `Snake`, all uppercase `ScreamingSnake`.
+/// - With `-`: all lowercase is `Kebab`.
+/// - Without separators: a lowercase first letter with uppercase letters is
+///   `Camel`, an uppercase first letter with lowercase letters is `Pascal`.
+///   A single lowercase word is `Snake` and a single uppercase word
+///   `ScreamingSnake`.
+/// - Both separators, separators at either end or doubled, or letter cases
+///   that fit none of the above are [`CaseStyle::Mixed`].
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::case::{detect_case, CaseStyle};
+/// assert_eq!(detect_case("http_server"), CaseStyle::Snake);
+/// assert_eq!(detect_case("httpServer"), CaseStyle::Camel);
+/// assert_eq!(detect_case("HTTPServer"), CaseStyle::Pascal);
+/// assert_eq!(detect_case("Http_Server"), CaseStyle::Mixed);
+/// assert_eq!(detect_case("http server"), CaseStyle::Unknown);
+/// ```
+pub fn detect_case(s: &str) -> CaseStyle {
+    if s.is_empty() || !s.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
+        return CaseStyle::Unknown;
+    }
+    let has_lower = s.chars().any(char::is_lowercase);
+    let has_upper = s.chars().any(char::is_uppercase);
+    let separator = match (s.contains('_'), s.contains('-')) {
+        (true, true) => return CaseStyle::Mixed,
+        (true, false) => Some('_'),
+        (false, true) => Some('-'),
+        (false, false) => None,
+    };
+
+    match separator {
+        Some(separator) if s.split(separator).any(str::is_empty) => CaseStyle::Mixed,
+        Some('_') if !has_upper => CaseStyle::Snake,
+        Some('_') if !has_lower => CaseStyle::ScreamingSnake,
+        Some('-') if !has_upper => CaseStyle::Kebab,
+        Some(_) => CaseStyle::Mixed,
+        None => match (s.chars().find(|c| c.is_alphabetic()).map(char::is_uppercase), has_lower, has_upper) {
+            (Some(false), _, false) | (None, _, _) => CaseStyle::Snake,
+            (Some(false), _, true) => CaseStyle::Camel,
+            (Some(true), false, _) => CaseStyle::ScreamingSnake,
+            (Some(true), true, _) => CaseStyle::Pascal,
+        },
+    }
+}
+
+/// Converts a heading to title case with publication-style rules.
+///
+/// ### Rules implemented:
+/// - Major words are capitalized (`quick` → `Quick`); the rest of each word is
+///   left as written.
+/// - Articles, coordinating conjunctions and short prepositions (`a`, `and`,
+///   `of`, `the`, `to`, `vs`...) are lowercase, except as the first or last
+///   word and after a colon, period, question or exclamation mark.
+/// - Words with an uppercase letter after the first one are kept as written:
+///   acronyms (`NASA`, `API`) and brand names (`iPhone`, `McDonald`).
+/// - URLs, emails and file names (`@` or a `.` inside the word) are kept as
+///   written.
+/// - Each part of a hyphenated word follows the same rules
+///   (`step-by-step` → `Step-by-Step`).
+/// - Whitespace and punctuation are preserved.
+///
+/// Text written entirely in capitals is kept as is, since every word looks like
+/// an acronym.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::case::to_title_case;
+/// assert_eq!(to_title_case("the lord of the rings"), "The Lord of the Rings");
+/// assert_eq!(to_title_case("a guide to the NASA API: what it is for"), "A Guide to the NASA API: What It Is For");
+/// assert_eq!(to_title_case("step-by-step setup of an iPhone"), "Step-by-Step Setup of an iPhone");
+/// ```
+pub fn to_title_case(s: &str) -> String {
+    let mut words = Vec::new();
+    let mut word_start = None;
+    for (i, c) in s.char_indices() {
+        match (c.is_whitespace(), word_start) {
+            (true, Some(start)) => {
+                words.push(start..i);
+                word_start = None;
+            }
+            (false, None) => word_start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(start) = word_start {
+        words.push(start..s.len());
+    }
+
+    let mut out = String::with_capacity(s.len());
+    let mut position = 0;
+    let mut starts_phrase = true;
+    for (index, range) in words.iter().enumerate() {
+        let word = &s[range.clone()];
+        out.push_str(&s[position..range.start]);
+        position = range.end;
+
+        let is_last = index + 1 == words.len();
+        let parts: Vec<&str> = word.split('-').collect();
+        let verbatim = word.contains('@') || word.trim_end_matches(|c: char| c.is_ascii_punctuation()).contains('.');
+        for (part_index, part) in parts.iter().enumerate() {
+            if part_index > 0 {
+                out.push('-');
+            }
+            let forced = (starts_phrase && part_index == 0) || (is_last && part_index + 1 == parts.len());
+            out.push_str(&title_case_word(part, forced, verbatim));
+        }
+        starts_phrase = word.ends_with([':', '.', '?', '!']);
+    }
+    out.push_str(&s[position..]);
+    out
+}
+
+/// Applies the rules of [`to_title_case`] to one word (or hyphenated part);
+/// `forced` capitalizes small words too, `verbatim` keeps the word as is.
+fn title_case_word(word: &str, forced: bool, verbatim: bool) -> String {
+    let letters = word.trim_matches(|c: char| !c.is_alphanumeric());
+    let keeps_case = verbatim || letters.chars().skip(1).any(char::is_uppercase);
+    if keeps_case {
+        word.to_owned()
+    } else if !forced && TITLE_SMALL_WORDS.iter().any(|small| letters.eq_ignore_ascii_case(small)) {
+        word.to_lowercase()
+    } else {
+        capitalize_locale(word, Locale::Root)
+    }
+}
+
+/// Capitalizes the first letter of the text, optionally lowercasing the rest.
+///
+/// The whole first grapheme is handled, so capitalization is correct where
+/// indexing the first byte or `char` is not: a letter followed by combining
+/// accents keeps them (`e` + `◌́`), and ligatures and digraphs use their
+/// titlecase form (`ﬁ` → `Fi`, `ǆ` → `ǅ`). Leading spaces and punctuation are
+/// skipped; text starting with a digit is not capitalized. See
+/// [`capitalize_locale`] for Turkish and other locale rules.
+///
+/// # Arguments
+/// * `s` - The text to capitalize.
+/// * `lowercase_rest` - Whether to lowercase everything after the first
+///   grapheme (`hELLO` → `Hello`) or leave it as written (`hELLO` → `HELLO`).
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::case::capitalize_first;
+/// assert_eq!(capitalize_first("ﬁnal ANSWER", true), "Final answer");
+/// assert_eq!(capitalize_first("ﬁnal ANSWER", false), "Final ANSWER");
+/// assert_eq!(capitalize_first("e\u{301}cole", false), "E\u{301}cole");
+/// assert_eq!(capitalize_first("¿qué?", false), "¿Qué?");
+/// ```
+pub fn capitalize_first(s: &str, lowercase_rest: bool) -> String {
+    if !lowercase_rest {
+        return capitalize_locale(s, Locale::Root);
+    }
+    let end = s.char_indices().find(|(_, c)| c.is_alphanumeric()).map_or(s.len(), |(i, _)| next_grapheme_end(s, i));
+    let mut out = capitalize_locale(&s[..end], Locale::Root);
+    out.push_str(&s[end..].to_lowercase());
+    out
+}
+
+/// Converts the text to sentence case: the first letter of each sentence
+/// capitalized with [`capitalize_first`], everything else lowercase.
+///
+/// Sentences end with `.`, `!`, `?` or `…` followed by whitespace, as with
+/// [`TextCursor::next_sentence`]. Proper nouns and acronyms are lowercased
+/// too, since they cannot be told apart from shouted words.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::case::to_sentence_case;
+/// assert_eq!(to_sentence_case("HELLO THERE. how ARE you? ﬁne!"), "Hello there. How are you? Fine!");
+/// assert_eq!(to_sentence_case("«ÉCOLE» fermée"), "«École» fermée");
+/// ```
+pub fn to_sentence_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut cursor = TextCursor::new(s);
+    let mut start = 0;
+    while cursor.next_sentence() {
+        out.push_str(&capitalize_first(&s[start..cursor.position()], true));
+        start = cursor.position();
+    }
+    out
+}
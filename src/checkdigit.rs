@@ -0,0 +1,168 @@
+use crate::random::READABLE_ALPHABET;
+
+/// Check character algorithm for the append_check_char function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    /// Luhn (mod 10) for digits, as on payment cards. Codes with letters use
+    /// Luhn mod 32 over [`READABLE_ALPHABET`]. Detects every single-character
+    /// error and most swaps of adjacent characters.
+    ///
+    /// A readable code that happens to have only digits gets a decimal check
+    /// digit. The digits, `0` and `1` included, are part of the readable
+    /// alphabet, so such codes still go through
+    /// [`normalize_entered_code`](crate::random::normalize_entered_code).
+    Luhn,
+    /// Damm (digits only). Detects every single-digit error and every swap
+    /// of adjacent digits.
+    Damm,
+    /// Verhoeff (digits only). Detects every single-digit error and every
+    /// swap of adjacent digits.
+    Verhoeff,
+}
+
+const DAMM_TABLE: [[u8; 10]; 10] = [
+    [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+    [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+    [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+    [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+    [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+    [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+    [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+    [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+    [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+    [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+];
+
+/// Verhoeff multiplication table of the dihedral group D5.
+const VERHOEFF_D: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+/// Verhoeff permutation table, by position modulo 8.
+const VERHOEFF_P: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+const VERHOEFF_INV: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+/// Appends a check character to a code so that typing errors can be caught
+/// with [`verify_check_char`] before looking the code up.
+///
+/// Spaces and `-` separators are ignored when computing the check character,
+/// which is appended right after the last character. Letters are read
+/// case-insensitively.
+///
+/// # Returns
+/// `None` if the code is empty, or has characters the scheme cannot encode:
+/// anything but digits for `Damm` and `Verhoeff`, anything outside
+/// [`READABLE_ALPHABET`] for `Luhn`.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::checkdigit::{append_check_char, verify_check_char, Scheme};
+/// assert_eq!(append_check_char("7992739871", Scheme::Luhn).as_deref(), Some("79927398713"));
+/// assert_eq!(append_check_char("572", Scheme::Damm).as_deref(), Some("5724"));
+/// assert_eq!(append_check_char("236", Scheme::Verhoeff).as_deref(), Some("2363"));
+///
+/// let code = append_check_char("AB7K-9XQ2", Scheme::Luhn).unwrap();
+/// assert!(verify_check_char(&code, Scheme::Luhn));
+/// assert!(!verify_check_char(&code.replace('7', "1"), Scheme::Luhn));
+/// ```
+pub fn append_check_char(code: &str, scheme: Scheme) -> Option<String> {
+    let (values, radix) = code_values(code, scheme)?;
+    if values.is_empty() {
+        return None;
+    }
+    let check = check_value(&values, radix, scheme);
+    let mut out = code.to_owned();
+    out.push(READABLE_ALPHABET.as_bytes()[check as usize] as char);
+    Some(out)
+}
+
+/// Returns `true` if the last character of `code` is the check character of
+/// the rest, as added by [`append_check_char`] with the same scheme.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::checkdigit::{verify_check_char, Scheme};
+/// assert!(verify_check_char("4111 1111 1111 1111", Scheme::Luhn));
+/// assert!(!verify_check_char("5727", Scheme::Damm));
+/// assert!(!verify_check_char("", Scheme::Verhoeff));
+/// ```
+pub fn verify_check_char(code: &str, scheme: Scheme) -> bool {
+    let Some((values, radix)) = code_values(code, scheme) else {
+        return false;
+    };
+    match values.split_last() {
+        Some((check, payload)) if !payload.is_empty() => check_value(payload, radix, scheme) == *check,
+        _ => false,
+    }
+}
+
+/// Luhn mod N check value of `payload` (values below `n`).
+pub(crate) fn luhn_mod_n(payload: &[u8], n: u32) -> u8 {
+    let sum: u32 = payload
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &v)| {
+            let addend = if i % 2 == 0 { v as u32 * 2 } else { v as u32 };
+            addend / n + addend % n
+        })
+        .sum();
+    ((n - sum % n) % n) as u8
+}
+
+fn damm(payload: &[u8]) -> u8 {
+    payload.iter().fold(0, |interim, &d| DAMM_TABLE[interim as usize][d as usize])
+}
+
+fn verhoeff(payload: &[u8]) -> u8 {
+    let c = payload
+        .iter()
+        .rev()
+        .enumerate()
+        .fold(0u8, |c, (i, &d)| VERHOEFF_D[c as usize][VERHOEFF_P[(i + 1) % 8][d as usize] as usize]);
+    VERHOEFF_INV[c as usize]
+}
+
+fn check_value(payload: &[u8], radix: u32, scheme: Scheme) -> u8 {
+    match scheme {
+        Scheme::Luhn => luhn_mod_n(payload, radix),
+        Scheme::Damm => damm(payload),
+        Scheme::Verhoeff => verhoeff(payload),
+    }
+}
+
+/// The values of the characters of `code` and their radix: 10 for a code of
+/// digits, 32 (readable alphabet) for a Luhn code with letters.
+fn code_values(code: &str, scheme: Scheme) -> Option<(Vec<u8>, u32)> {
+    let chars: Vec<char> = code.chars().filter(|c| !matches!(c, ' ' | '-')).collect();
+    if chars.iter().all(char::is_ascii_digit) {
+        return Some((chars.iter().map(|c| *c as u8 - b'0').collect(), 10));
+    }
+    if scheme != Scheme::Luhn {
+        return None;
+    }
+    let values = chars
+        .iter()
+        .map(|c| READABLE_ALPHABET.find(c.to_ascii_uppercase()).map(|i| i as u8))
+        .collect::<Option<Vec<u8>>>()?;
+    Some((values, READABLE_ALPHABET.len() as u32))
+}
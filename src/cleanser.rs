@@ -1,14 +1,15 @@
-/// Remove Location for remove_char function
+/// Remove Location for remove_char and strip_chars functions
 pub enum RemoveLocationEnum {
     Begin,
     End,
+    Both,
 }
 
-/// Removes the first or last character of a string if it matches the given target character.
+/// Removes the first or last character (or both) of a string if it matches the given target character.
 ///
 /// # Arguments
 ///
-/// * `begin` - A boolean indicating whether to remove the first (`true`) or last (`false`) character.
+/// * `remove_from` - Whether to remove the first (`Begin`), last (`End`) or both (`Both`) characters.
 /// * `input` - A `String` to process.
 /// * `target` - The character to remove.
 ///
@@ -34,25 +35,109 @@ pub enum RemoveLocationEnum {
 /// let modified = remove_char(RemoveLocationEnum::Begin, &"rust".to_string(), 'x');
 /// assert_eq!(modified, "rust");
 /// ```
+#[allow(clippy::ptr_arg)] // Signature kept for compatibility; see v2::strip_char.
 pub fn remove_char(remove_from: RemoveLocationEnum, input: &String, target: char) -> String {
-    match remove_from{
-        RemoveLocationEnum::Begin => if input.starts_with(target) {
-                                        return input.chars().skip(1).collect();
-                                     },
-        RemoveLocationEnum::End => if input.ends_with(target) {
-                                        return input.chars().take(input.len() - 1).collect();
-                                    },
+    strip_chars(remove_from, input, &[target], Some(1))
+}
+
+/// Strips the characters found in `set` from the start, the end or both ends
+/// of a string.
+///
+/// # Arguments
+///
+/// * `location` - Where to strip: `Begin`, `End` or `Both` ends.
+/// * `input` - The string to process.
+/// * `set` - The characters to strip, in any order and combination.
+/// * `max` - The maximum number of characters stripped at each end, or `None`
+///   to strip as many as possible.
+///
+/// # Returns
+///
+/// Returns a new `String` without the stripped characters.
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::cleanser::{strip_chars, RemoveLocationEnum};
+/// assert_eq!(strip_chars(RemoveLocationEnum::Both, "\"'token'\"  ", &['"', '\'', ' '], None), "token");
+/// assert_eq!(strip_chars(RemoveLocationEnum::Begin, "000042", &['0'], Some(2)), "0042");
+/// assert_eq!(strip_chars(RemoveLocationEnum::End, "wow!!!", &['!'], None), "wow");
+/// ```
+pub fn strip_chars(location: RemoveLocationEnum, input: &str, set: &[char], max: Option<usize>) -> String {
+    let limit = max.unwrap_or(usize::MAX);
+    let mut s = input;
+    if matches!(location, RemoveLocationEnum::Begin | RemoveLocationEnum::Both) {
+        let stripped: usize = s.chars().take(limit).take_while(|c| set.contains(c)).map(char::len_utf8).sum();
+        s = &s[stripped..];
+    }
+    if matches!(location, RemoveLocationEnum::End | RemoveLocationEnum::Both) {
+        let stripped: usize = s.chars().rev().take(limit).take_while(|c| set.contains(c)).map(char::len_utf8).sum();
+        s = &s[..s.len() - stripped];
+    }
+    s.to_string()
+}
+
+/// Removes `prefix` from the start of `input`, if present.
+///
+/// With `case_insensitive`, characters are compared by their Unicode
+/// lowercase forms.
+///
+/// # Returns
+///
+/// The remaining string slice and `true` if the prefix was removed, or
+/// `input` unchanged and `false`.
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::cleanser::strip_prefix_str;
+/// assert_eq!(strip_prefix_str("-- comment", "-- ", false), ("comment", true));
+/// assert_eq!(strip_prefix_str("<<eof data", "<<EOF", true), (" data", true));
+/// assert_eq!(strip_prefix_str("data", "<<EOF", true), ("data", false));
+/// ```
+pub fn strip_prefix_str<'a>(input: &'a str, prefix: &str, case_insensitive: bool) -> (&'a str, bool) {
+    if !case_insensitive {
+        return input.strip_prefix(prefix).map_or((input, false), |rest| (rest, true));
     }
-    /*if begin {
-        if input.starts_with(target) {
-            return input.chars().skip(1).collect();
+    let mut chars = input.char_indices();
+    for p in prefix.chars() {
+        match chars.next() {
+            Some((_, c)) if c.to_lowercase().eq(p.to_lowercase()) => {}
+            _ => return (input, false),
         }
-    } else {
-        if input.ends_with(target) {
-            return input.chars().take(input.len() - 1).collect();
+    }
+    (chars.as_str(), true)
+}
+
+/// Removes `suffix` from the end of `input`, if present.
+///
+/// With `case_insensitive`, characters are compared by their Unicode
+/// lowercase forms.
+///
+/// # Returns
+///
+/// The remaining string slice and `true` if the suffix was removed, or
+/// `input` unchanged and `false`.
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::cleanser::strip_suffix_str;
+/// assert_eq!(strip_suffix_str("report.TXT", ".txt", true), ("report", true));
+/// assert_eq!(strip_suffix_str("report.TXT", ".txt", false), ("report.TXT", false));
+/// ```
+pub fn strip_suffix_str<'a>(input: &'a str, suffix: &str, case_insensitive: bool) -> (&'a str, bool) {
+    if !case_insensitive {
+        return input.strip_suffix(suffix).map_or((input, false), |rest| (rest, true));
+    }
+    let mut chars = input.chars();
+    for p in suffix.chars().rev() {
+        match chars.next_back() {
+            Some(c) if c.to_lowercase().eq(p.to_lowercase()) => {}
+            _ => return (input, false),
         }
-    }*/
-    input.to_string() // Return unchanged if no removal occurs
+    }
+    (chars.as_str(), true)
 }
 
 /// The remove_first_n function removes the first n characters from a string slice, 
@@ -238,6 +323,120 @@ pub fn replace_whitespace(input: &str, replacement: char) -> String {
         .collect()
 }
 
+/// Replaces each run of whitespace (including newlines, tabs and Unicode
+/// spaces such as NBSP) with a single ASCII space.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::cleanser::collapse_whitespace;
+/// assert_eq!(collapse_whitespace(" a \t\u{a0} b\n"), " a b ");
+/// ```
+pub fn collapse_whitespace(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    collapse_whitespace_into(input, &mut out);
+    out
+}
+
+/// Same as [`collapse_whitespace`], appending the result to `out` so its
+/// capacity can be reused.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::cleanser::collapse_whitespace_into;
+/// let mut out = String::from("> ");
+/// collapse_whitespace_into("a  b", &mut out);
+/// assert_eq!(out, "> a b");
+/// ```
+pub fn collapse_whitespace_into(input: &str, out: &mut String) {
+    let mut in_space = false;
+    for c in input.chars() {
+        if c.is_whitespace() {
+            if !in_space {
+                out.push(' ');
+            }
+            in_space = true;
+        } else {
+            out.push(c);
+            in_space = false;
+        }
+    }
+}
+
+/// How the normalize_spaces function treats line breaks
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LineBreakEnum {
+    /// Line breaks are whitespace like any other: runs become one space.
+    #[default]
+    Collapse,
+    /// Every line break is kept as `\n`; the spaces around them are removed.
+    Preserve,
+    /// Runs with two or more line breaks (blank lines) become `"\n\n"`;
+    /// single line breaks become a space.
+    Paragraphs,
+}
+
+/// Options for the normalize_spaces function
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SpaceOptions {
+    pub line_breaks: LineBreakEnum,
+    /// Removes the whitespace at the start and end of the text.
+    pub trim: bool,
+}
+
+/// Replaces each run of whitespace (tabs, NBSP, Unicode spaces...) with a
+/// single ASCII space, optionally keeping line or paragraph breaks.
+///
+/// `\n`, `\r\n`, a lone `\r` and `U+2028` count as one line break and
+/// `U+2029` (paragraph separator) as two. With the default options this is
+/// [`collapse_whitespace`].
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::cleanser::{normalize_spaces, LineBreakEnum, SpaceOptions};
+/// let text = " Title\u{a0}\t\r\n\r\n  first  line\n second\u{3000}line \n";
+/// let lines = SpaceOptions { line_breaks: LineBreakEnum::Preserve, trim: true };
+/// assert_eq!(normalize_spaces(text, lines), "Title\n\nfirst line\nsecond line");
+/// let paragraphs = SpaceOptions { line_breaks: LineBreakEnum::Paragraphs, trim: true };
+/// assert_eq!(normalize_spaces(text, paragraphs), "Title\n\nfirst line second line");
+/// assert_eq!(normalize_spaces(text, SpaceOptions::default()), " Title first line second line ");
+/// ```
+pub fn normalize_spaces(input: &str, options: SpaceOptions) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        if !c.is_whitespace() {
+            out.push(c);
+            continue;
+        }
+
+        let mut breaks = 0;
+        let mut current = Some(c);
+        while let Some(w) = current {
+            breaks += match w {
+                '\n' | '\u{2028}' => 1,
+                '\r' if chars.peek() != Some(&'\n') => 1,
+                '\u{2029}' => 2,
+                _ => 0,
+            };
+            current = chars.next_if(|next| next.is_whitespace());
+        }
+
+        match options.line_breaks {
+            LineBreakEnum::Preserve if breaks > 0 => out.extend(std::iter::repeat_n('\n', breaks)),
+            LineBreakEnum::Paragraphs if breaks > 1 => out.push_str("\n\n"),
+            _ => out.push(' '),
+        }
+    }
+
+    if options.trim {
+        let end = out.trim_end().len();
+        out.truncate(end);
+        let start = out.len() - out.trim_start().len();
+        out.drain(..start);
+    }
+    out
+}
+
 ///Removed any no visible UTF-8 character from string
 pub fn remove_whitespace(input: &str) -> String {
     let mut out = String::with_capacity(input.len());
@@ -265,4 +464,298 @@ pub fn remove_whitespace(input: &str) -> String {
         true
     })); 
     out
-}
\ No newline at end of file
+}
+
+/// What the sanitize_control_chars function does with the characters it removes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlCharActionEnum {
+    /// Remove the character.
+    Strip,
+    /// Replace the character with a placeholder, e.g. `U+FFFD`.
+    Replace(char),
+}
+
+/// Returns `true` for the characters removed by [`sanitize_control_chars`].
+const fn is_unsafe_control(c: char) -> bool {
+    matches!(c,
+        '\u{0}'..='\u{8}' | '\u{b}' | '\u{c}' | '\u{e}'..='\u{1f}' | '\u{7f}'..='\u{9f}'
+        | '\u{200b}' | '\u{2060}' | '\u{feff}' | '\u{180e}'
+        | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}')
+}
+
+/// Removes (or replaces) control and invisible characters from user input
+/// before it is stored.
+///
+/// ### Characters handled:
+/// - C0 controls except tab, line feed and carriage return; `DEL` and the C1
+///   controls (`U+0080`–`U+009F`).
+/// - Zero-width space (`U+200B`), word joiner (`U+2060`), byte order mark
+///   (`U+FEFF`) and Mongolian vowel separator (`U+180E`).
+/// - Bidi embeddings, overrides and isolates (`U+202A`–`U+202E`,
+///   `U+2066`–`U+2069`), used in "Trojan Source" attacks.
+///
+/// Zero-width joiners and non-joiners are kept: emoji sequences and several
+/// scripts need them.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::cleanser::{sanitize_control_chars, ControlCharActionEnum};
+/// let input = "\u{feff}admin\u{200b}\u{202e}txt.exe\u{7}\tok\n";
+/// assert_eq!(sanitize_control_chars(input, ControlCharActionEnum::Strip), "admintxt.exe\tok\n");
+/// assert_eq!(sanitize_control_chars("a\u{0}b", ControlCharActionEnum::Replace('\u{fffd}')), "a\u{fffd}b");
+/// ```
+pub fn sanitize_control_chars(input: &str, action: ControlCharActionEnum) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        match (is_unsafe_control(c), action) {
+            (false, _) => out.push(c),
+            (true, ControlCharActionEnum::Strip) => {}
+            (true, ControlCharActionEnum::Replace(placeholder)) => out.push(placeholder),
+        }
+    }
+    out
+}
+
+/// Removes diacritics (accents, cedillas, strokes...) from letters, for
+/// accent-insensitive search and key comparison: `é` → `e`, `ñ` → `n`,
+/// `ü` → `u`, `ø` → `o`, `ł` → `l`.
+///
+/// The text is processed by grapheme cluster (see
+/// [`crate::segmenter::graphemes`]): precomposed Latin letters are decomposed,
+/// then the combining marks of clusters whose base is a letter are removed,
+/// whether they were precomposed or not. Marks attached to other characters,
+/// like the keycap of `1️⃣`, are kept. Letters that are not a base letter with
+/// a mark (`æ`, `ß`, `þ`) are unchanged, and so are precomposed letters of
+/// other scripts (`ά`); their decomposed forms (`α` + `◌́`) are handled.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::cleanser::remove_diacritics;
+/// assert_eq!(remove_diacritics("Crème brûlée à São Paulo"), "Creme brulee a Sao Paulo");
+/// assert_eq!(remove_diacritics("Z\u{307}o\u{301}łw"), "Zolw");
+/// assert_eq!(remove_diacritics("Ærø 1\u{fe0f}\u{20e3}"), "Æro 1\u{fe0f}\u{20e3}");
+/// ```
+pub fn remove_diacritics(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for grapheme in crate::segmenter::graphemes(text) {
+        let mut chars = grapheme.chars();
+        let Some(base) = chars.next() else { continue };
+        if !base.is_alphabetic() {
+            out.push_str(grapheme);
+            continue;
+        }
+
+        let decomposed = crate::locale::decompose_latin(base)
+            .or_else(|| crate::locale::fold_latin(base).filter(|folded| folded.contains('\u{338}')));
+        match decomposed {
+            Some(decomposed) => out.extend(decomposed.chars().filter(|c| !crate::analyzer::is_combining_mark(*c))),
+            None => out.push(base),
+        }
+        out.extend(chars.filter(|c| !crate::analyzer::is_combining_mark(*c)));
+    }
+    out
+}
+
+/// Removes combining marks beyond `max_per_base` after each base character,
+/// defending chat and display systems against "zalgo" text while keeping
+/// legitimate accents (`e` + `◌́`) intact.
+///
+/// Marks at the start of the text, with no base character, are limited the same way.
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::cleanser::strip_excess_combining_marks;
+/// let zalgo = "h\u{336}\u{35b}\u{344}i\u{33f}\u{346}\u{34a}";
+/// assert_eq!(strip_excess_combining_marks(zalgo, 1), "h\u{336}i\u{33f}");
+/// assert_eq!(strip_excess_combining_marks(zalgo, 0), "hi");
+///
+/// // Vietnamese stacks two marks on some letters
+/// assert_eq!(strip_excess_combining_marks("e\u{302}\u{301}", 2), "e\u{302}\u{301}");
+/// ```
+pub fn strip_excess_combining_marks(text: &str, max_per_base: usize) -> String {
+    let mut marks = 0;
+    text.chars()
+        .filter(|c| {
+            if !crate::analyzer::is_combining_mark(*c) {
+                marks = 0;
+                return true;
+            }
+            marks += 1;
+            marks <= max_per_base
+        })
+        .collect()
+}
+
+/// Options for the fix_whitespace function
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WhitespaceFixOptions {
+    /// Expand tabs to spaces using this tab stop width. `None` keeps tabs.
+    pub tab_width: Option<usize>,
+}
+
+/// Changes made by the fix_whitespace function, per category
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FixReport {
+    /// Lines that had trailing whitespace removed.
+    pub trailing_whitespace: usize,
+    /// Blank lines removed from the end of the text.
+    pub trailing_blank_lines: usize,
+    /// `true` if a final newline was added.
+    pub final_newline_added: bool,
+    /// Tabs expanded to spaces.
+    pub tabs_converted: usize,
+}
+
+impl FixReport {
+    /// Returns `true` if the text was left unchanged.
+    pub fn is_clean(&self) -> bool {
+        *self == FixReport::default()
+    }
+}
+
+/// Fixes whitespace the way pre-commit hooks do and reports what changed:
+/// - Trailing spaces and tabs are removed from every line.
+/// - The text ends with exactly one newline. Blank lines at the end are
+///   removed, and text made only of whitespace becomes empty.
+/// - With `tab_width` set, tabs are expanded to the next tab stop.
+///
+/// Line endings are preserved; an added final newline is `\r\n` if the text
+/// mostly uses CRLF.
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::cleanser::{fix_whitespace, FixReport, WhitespaceFixOptions};
+/// let (fixed, report) = fix_whitespace("a  \n\tb\n\n\n", WhitespaceFixOptions { tab_width: Some(4) });
+/// assert_eq!(fixed, "a\n    b\n");
+/// assert_eq!(report, FixReport { trailing_whitespace: 1, trailing_blank_lines: 2, final_newline_added: false, tabs_converted: 1 });
+///
+/// let (fixed, report) = fix_whitespace("no newline", WhitespaceFixOptions::default());
+/// assert_eq!(fixed, "no newline\n");
+/// assert!(report.final_newline_added);
+/// ```
+pub fn fix_whitespace(text: &str, options: WhitespaceFixOptions) -> (String, FixReport) {
+    let mut report = FixReport::default();
+    let mut lines: Vec<(String, &str)> = Vec::new();
+
+    for line in text.split_inclusive('\n') {
+        let (content, ending) = match line.strip_suffix("\r\n") {
+            Some(content) => (content, "\r\n"),
+            None => match line.strip_suffix('\n') {
+                Some(content) => (content, "\n"),
+                None => (line, ""),
+            },
+        };
+
+        let trimmed = content.trim_end_matches(|c: char| c.is_whitespace() && c != '\r');
+        if trimmed.len() != content.len() {
+            report.trailing_whitespace += 1;
+        }
+
+        let fixed = match options.tab_width {
+            Some(width) if trimmed.contains('\t') => {
+                report.tabs_converted += trimmed.matches('\t').count();
+                expand_tabs(trimmed, width)
+            }
+            _ => trimmed.to_owned(),
+        };
+        lines.push((fixed, ending));
+    }
+
+    while lines.last().is_some_and(|(content, _)| content.is_empty()) {
+        lines.pop();
+        report.trailing_blank_lines += 1;
+    }
+    // A missing newline after the last line is not a blank line
+    if !text.is_empty() && !text.ends_with('\n') && report.trailing_blank_lines > 0 {
+        report.trailing_blank_lines -= 1;
+    }
+
+    if let Some(last) = lines.last_mut()
+        && last.1.is_empty()
+    {
+        let stats = crate::analyzer::newline_stats(text);
+        last.1 = if stats.crlf > stats.lf { "\r\n" } else { "\n" };
+        report.final_newline_added = true;
+    }
+
+    let mut out = String::with_capacity(text.len() + 1);
+    for (content, ending) in &lines {
+        out.push_str(content);
+        out.push_str(ending);
+    }
+    (out, report)
+}
+
+/// Expands tabs to spaces up to the next multiple of `width` columns.
+fn expand_tabs(line: &str, width: usize) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut column = 0;
+    for c in line.chars() {
+        if c == '\t' {
+            let spaces = if width == 0 { 0 } else { width - column % width };
+            out.extend(std::iter::repeat_n(' ', spaces));
+            column += spaces;
+        } else {
+            out.push(c);
+            column += 1;
+        }
+    }
+    out
+}
+
+/// Removes the quoted reply and the signature of an email body, keeping only
+/// the new content (for support-ticket analytics, word counts, etc.).
+///
+/// ### Rules implemented:
+/// - Everything from a signature delimiter line (`-- `, or `--` when the
+///   trailing space was lost) is removed.
+/// - Everything from a reply header is removed: `On … wrote:`, possibly
+///   wrapped over two lines, or Outlook's `-----Original Message-----`.
+/// - Lines quoted with `>` are removed wherever they are, so inline replies
+///   keep the answers written between the quotes.
+/// - Runs of blank lines left are collapsed to one, and blank lines at the
+///   start and the end are removed.
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::cleanser::strip_email_reply;
+/// let email = "Thanks, that fixed it!\n\n-- \nAna\nSupport Lead\n\nOn Mon, Jan 6, 2025 at 9:00 AM Bo <bo@mail.com> wrote:\n> Try restarting.";
+/// assert_eq!(strip_email_reply(email), "Thanks, that fixed it!");
+///
+/// let inline = "> Which version?\n2.1\n\n> Which OS?\nLinux";
+/// assert_eq!(strip_email_reply(inline), "2.1\n\nLinux");
+/// ```
+pub fn strip_email_reply(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut kept: Vec<&str> = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if line.trim_end_matches('\r') == "-- " || trimmed == "--" || is_reply_header(trimmed, lines.get(i + 1).map(|next| next.trim())) {
+            break;
+        }
+        if trimmed.starts_with('>') || (trimmed.is_empty() && kept.last().is_none_or(|last| last.is_empty())) {
+            continue;
+        }
+        kept.push(line.trim_end());
+    }
+
+    while kept.last().is_some_and(|last| last.is_empty()) {
+        kept.pop();
+    }
+    kept.join("\n")
+}
+
+/// Returns `true` if `line` starts the header of a quoted reply. `next` is the
+/// following line, as mail clients wrap long `On … wrote:` headers.
+fn is_reply_header(line: &str, next: Option<&str>) -> bool {
+    let dashes = line.trim_matches('-');
+    if line.starts_with("-----") && dashes.len() < line.len() && dashes.trim().eq_ignore_ascii_case("original message") {
+        return true;
+    }
+    line.starts_with("On ")
+        && (line.ends_with("wrote:") || next.is_some_and(|next| !next.contains(' ') && next.ends_with("wrote:")))
+}
@@ -0,0 +1,163 @@
+/// Number of strings per block. The first string of every block is stored in
+/// full so any block can be decoded without reading the previous ones.
+const BLOCK_SIZE: usize = 16;
+
+/// A front-coded (prefix-compressed) list of sorted strings.
+///
+/// Each string is stored as the length of the prefix it shares with the
+/// previous string plus the remaining suffix. Sorted keyword lists
+/// (blocklists, dictionaries) share long prefixes, so this usually takes a
+/// fraction of the memory of a `Vec<String>`.
+///
+/// Create it with [`front_code`].
+pub struct FrontCoded {
+    data: Vec<u8>,
+    block_offsets: Vec<usize>,
+    len: usize,
+}
+
+/// Compresses a sorted list of strings with front coding.
+///
+/// # Arguments
+/// * `sorted` - The strings, sorted in ascending byte order. Unsorted input is
+///   still encoded correctly but compresses worse, and
+///   [`FrontCoded::contains`] requires sorted input.
+///
+/// # Returns
+/// A [`FrontCoded`] list that can be decoded with [`FrontCoded::get`],
+/// [`FrontCoded::iter`] or [`FrontCoded::decode`].
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::compressor::front_code;
+/// let words = vec!["apple".to_owned(), "applet".to_owned(), "apply".to_owned()];
+/// let coded = front_code(&words);
+/// assert_eq!(coded.len(), 3);
+/// assert_eq!(coded.get(1), Some("applet".to_owned()));
+/// assert_eq!(coded.decode(), words);
+/// assert!(coded.contains("apply"));
+/// ```
+pub fn front_code<S: AsRef<str>>(sorted: &[S]) -> FrontCoded {
+    let mut data = Vec::new();
+    let mut block_offsets = Vec::with_capacity(sorted.len().div_ceil(BLOCK_SIZE));
+    let mut previous = "";
+
+    for (i, item) in sorted.iter().enumerate() {
+        let s = item.as_ref();
+        let shared = if i % BLOCK_SIZE == 0 {
+            block_offsets.push(data.len());
+            0
+        } else {
+            shared_prefix_len(previous, s)
+        };
+
+        write_varint(&mut data, shared);
+        write_varint(&mut data, s.len() - shared);
+        data.extend_from_slice(&s.as_bytes()[shared..]);
+        previous = s;
+    }
+
+    FrontCoded { data, block_offsets, len: sorted.len() }
+}
+
+impl FrontCoded {
+    /// Returns the number of strings.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no strings.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bytes used by the encoded strings.
+    pub fn encoded_size(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the string at `index`, or `None` if out of bounds.
+    /// Only the block containing `index` is decoded.
+    pub fn get(&self, index: usize) -> Option<String> {
+        if index >= self.len {
+            return None;
+        }
+        self.block(index / BLOCK_SIZE).nth(index % BLOCK_SIZE)
+    }
+
+    /// Returns `true` if `s` is in the list. Requires sorted input.
+    ///
+    /// Uses a binary search over the blocks and decodes a single block.
+    pub fn contains(&self, s: &str) -> bool {
+        // Last block whose first string is <= s
+        let block = self.block_offsets.partition_point(|&offset| {
+            first_of_block(&self.data[offset..]).as_bytes() <= s.as_bytes()
+        });
+        block > 0 && self.block(block - 1).any(|item| item == s)
+    }
+
+    /// Iterates over all strings in order.
+    pub fn iter(&self) -> impl Iterator<Item = String> + '_ {
+        (0..self.block_offsets.len()).flat_map(|b| self.block(b))
+    }
+
+    /// Decodes all strings into a `Vec<String>`.
+    pub fn decode(&self) -> Vec<String> {
+        self.iter().collect()
+    }
+
+    fn block(&self, block: usize) -> impl Iterator<Item = String> + '_ {
+        let start = self.block_offsets[block];
+        let count = BLOCK_SIZE.min(self.len - block * BLOCK_SIZE);
+        let mut pos = start;
+        let mut current: Vec<u8> = Vec::new();
+
+        (0..count).map(move |_| {
+            let shared = read_varint(&self.data, &mut pos);
+            let suffix_len = read_varint(&self.data, &mut pos);
+            current.truncate(shared);
+            current.extend_from_slice(&self.data[pos..pos + suffix_len]);
+            pos += suffix_len;
+            // Prefixes are always cut at char boundaries, so this is valid UTF-8
+            String::from_utf8(current.clone()).unwrap()
+        })
+    }
+}
+
+fn first_of_block(data: &[u8]) -> &str {
+    let mut pos = 0;
+    let _shared = read_varint(data, &mut pos);
+    let len = read_varint(data, &mut pos);
+    std::str::from_utf8(&data[pos..pos + len]).unwrap()
+}
+
+/// Length in bytes of the common prefix of `a` and `b`, at a char boundary.
+fn shared_prefix_len(a: &str, b: &str) -> usize {
+    let mut len = a.bytes().zip(b.bytes()).take_while(|(x, y)| x == y).count();
+    while !b.is_char_boundary(len) {
+        len -= 1;
+    }
+    len
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn read_varint(data: &[u8], pos: &mut usize) -> usize {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let b = data[*pos];
+        *pos += 1;
+        value |= ((b & 0x7f) as usize) << shift;
+        if b < 0x80 {
+            return value;
+        }
+        shift += 7;
+    }
+}
@@ -0,0 +1,659 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Range;
+
+use crate::locale::{case_fold, compose_latin};
+
+/// Kind of a [`DiffOp`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKindEnum {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// A run of lines of a line diff: `old` and `new` are line index ranges in
+/// the old and new texts. `Delete` ops have an empty `new` range (and
+/// `Insert` ops an empty `old` range) giving the position in the other text.
+/// `Equal` ranges usually have the same length, but not when
+/// [`diff_lines_with_options`] folds blank lines into them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffOp {
+    pub kind: DiffKindEnum,
+    pub old: Range<usize>,
+    pub new: Range<usize>,
+}
+
+/// Splits a text into lines, each keeping its line ending.
+pub(crate) fn split_lines(text: &str) -> Vec<&str> {
+    text.split_inclusive('\n').collect()
+}
+
+/// Computes a minimal line diff (Myers' algorithm) between two texts.
+///
+/// Lines keep their line ending when compared, so a missing final newline is
+/// a change. Within a change, deletions come before insertions.
+///
+/// # Returns
+/// The ops covering both texts in order; consecutive ops of the same kind are
+/// merged.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::diff::{diff_lines, DiffKindEnum};
+/// let ops = diff_lines("a\nb\nc\n", "a\nc\nd\n");
+/// let kinds: Vec<_> = ops.iter().map(|op| op.kind).collect();
+/// assert_eq!(kinds, vec![DiffKindEnum::Equal, DiffKindEnum::Delete, DiffKindEnum::Equal, DiffKindEnum::Insert]);
+/// assert_eq!(ops[1].old, 1..2);
+/// assert_eq!(ops[3].new, 2..3);
+/// ```
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffOp> {
+    let old_lines = split_lines(old);
+    let new_lines = split_lines(new);
+    ops_from_matches(&matching_lines(&old_lines, &new_lines), old_lines.len(), new_lines.len())
+}
+
+/// Differences ignored by the diff_lines_with_options function
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DiffOptions {
+    /// Compares lines case-insensitively (full case folding: `ß` = `SS`).
+    pub ignore_case: bool,
+    /// Ignores all whitespace within lines, line endings included, like
+    /// `diff -w`: `a = b` equals `a=b`.
+    pub ignore_whitespace: bool,
+    /// Ignores blank lines added or removed, like `diff -B`.
+    pub ignore_blank_lines: bool,
+    /// Compares Latin letters composed (NFC): `e` + `U+0301` equals `é`.
+    pub normalize_unicode: bool,
+}
+
+/// Same as [`diff_lines`], but lines are compared after the canonicalization
+/// chosen in `options`, so reformatted text only shows real changes.
+///
+/// The ops still give line ranges in the original texts. With
+/// `ignore_blank_lines`, a change made only of blank lines (between two
+/// unchanged lines or at either end of the texts) becomes part of an `Equal`
+/// op, which may then cover a different number of lines in each text. A
+/// change that also deletes or inserts other lines is kept whole, blank lines
+/// included.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::diff::{diff_lines_with_options, DiffKindEnum, DiffOptions};
+/// let old = "fn main() {\n    Run();\n}\n";
+/// let new = "fn main() {\n\n  run();\n}";
+/// let options = DiffOptions { ignore_case: true, ignore_whitespace: true, ignore_blank_lines: true, ..DiffOptions::default() };
+/// let ops = diff_lines_with_options(old, new, options);
+/// assert_eq!(ops.len(), 1);
+/// assert_eq!((ops[0].kind, ops[0].old.clone(), ops[0].new.clone()), (DiffKindEnum::Equal, 0..3, 0..4));
+/// assert_eq!(diff_lines_with_options(old, new, DiffOptions::default()).len(), 3);
+/// ```
+pub fn diff_lines_with_options(old: &str, new: &str, options: DiffOptions) -> Vec<DiffOp> {
+    let old_lines = split_lines(old);
+    let new_lines = split_lines(new);
+    let (old_index, old_keys) = comparison_keys(&old_lines, options);
+    let (new_index, new_keys) = comparison_keys(&new_lines, options);
+    let pairs: Vec<(usize, usize)> = matching_lines(&old_keys, &new_keys)
+        .into_iter()
+        .map(|(i, j)| (old_index[i], new_index[j]))
+        .collect();
+    let ops = ops_from_matches(&pairs, old_lines.len(), new_lines.len());
+    if !options.ignore_blank_lines {
+        return ops;
+    }
+
+    // Changes made only of blank lines are not differences
+    let mut merged: Vec<DiffOp> = Vec::with_capacity(ops.len());
+    for change in ops.chunk_by(|a, b| a.kind != DiffKindEnum::Equal && b.kind != DiffKindEnum::Equal) {
+        let old = change[0].old.start..change[change.len() - 1].old.end;
+        let new = change[0].new.start..change[change.len() - 1].new.end;
+        let blank = old_lines[old.clone()].iter().chain(&new_lines[new.clone()]).all(|line| line.trim().is_empty());
+        let folded;
+        let change = if blank {
+            folded = [DiffOp { kind: DiffKindEnum::Equal, old, new }];
+            &folded[..]
+        } else {
+            change
+        };
+        for op in change {
+            match merged.last_mut() {
+                Some(last) if last.kind == DiffKindEnum::Equal && op.kind == DiffKindEnum::Equal => {
+                    last.old.end = op.old.end;
+                    last.new.end = op.new.end;
+                }
+                _ => merged.push(op.clone()),
+            }
+        }
+    }
+    merged
+}
+
+/// Returns the indexes of the lines compared by [`diff_lines_with_options`]
+/// and their canonical forms.
+fn comparison_keys<'a>(lines: &[&'a str], options: DiffOptions) -> (Vec<usize>, Vec<Cow<'a, str>>) {
+    let mut indexes = Vec::with_capacity(lines.len());
+    let mut keys = Vec::with_capacity(lines.len());
+    for (i, &line) in lines.iter().enumerate() {
+        if options.ignore_blank_lines && line.trim().is_empty() {
+            continue;
+        }
+        let mut key = Cow::Borrowed(line);
+        if options.normalize_unicode {
+            key = Cow::Owned(compose_latin(&key));
+        }
+        if options.ignore_case {
+            key = Cow::Owned(case_fold(&key));
+        }
+        if options.ignore_whitespace {
+            key = Cow::Owned(key.chars().filter(|c| !c.is_whitespace()).collect());
+        }
+        indexes.push(i);
+        keys.push(key);
+    }
+    (indexes, keys)
+}
+
+/// Returns the `(old, new)` index pairs of the lines kept by a minimal diff,
+/// in increasing order.
+///
+/// Uses the linear-space variant of Myers' O(ND) algorithm: the middle snake
+/// of the edit graph is found by searching from both ends at once, then each
+/// side is diffed recursively. Memory stays O(N + M) whatever the distance.
+pub(crate) fn matching_lines<T: PartialEq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    let mut forward = vec![0isize; a.len() + b.len() + 4];
+    let mut backward = forward.clone();
+    diff_region(a, b, (0, 0), &mut pairs, &mut forward, &mut backward);
+    pairs
+}
+
+/// Appends to `pairs` the matched lines of `a` and `b`, whose first lines are
+/// at `origin` in the whole texts.
+fn diff_region<T: PartialEq>(
+    a: &[T],
+    b: &[T],
+    origin: (usize, usize),
+    pairs: &mut Vec<(usize, usize)>,
+    forward: &mut [isize],
+    backward: &mut [isize],
+) {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..].iter().rev().zip(b[prefix..].iter().rev()).take_while(|(x, y)| x == y).count();
+    let (mid_a, mid_b) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+    let (x0, y0) = (origin.0 + prefix, origin.1 + prefix);
+
+    pairs.extend((0..prefix).map(|k| (origin.0 + k, origin.1 + k)));
+    if !mid_a.is_empty() && !mid_b.is_empty() {
+        // Without a common prefix or suffix the distance is at least 2, so
+        // both sides of the middle snake are smaller problems
+        let (start, end) = middle_snake(mid_a, mid_b, forward, backward);
+        diff_region(&mid_a[..start.0], &mid_b[..start.1], (x0, y0), pairs, forward, backward);
+        pairs.extend((0..end.0 - start.0).map(|k| (x0 + start.0 + k, y0 + start.1 + k)));
+        diff_region(&mid_a[end.0..], &mid_b[end.1..], (x0 + end.0, y0 + end.1), pairs, forward, backward);
+    }
+    pairs.extend((0..suffix).map(|k| (x0 + mid_a.len() + k, y0 + mid_b.len() + k)));
+}
+
+/// Finds the middle snake of a minimal edit path from `(0, 0)` to the end:
+/// the run of matching lines crossed halfway through the edits. Returns its
+/// start and end `(x, y)` positions.
+///
+/// `forward` and `backward` hold the furthest x reached on each diagonal;
+/// they must have room for `a.len() + b.len() + 4` diagonals.
+fn middle_snake<T: PartialEq>(a: &[T], b: &[T], forward: &mut [isize], backward: &mut [isize]) -> ((usize, usize), (usize, usize)) {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let delta = n - m;
+    let odd = delta % 2 != 0;
+    let max_d = (n + m + 1) / 2;
+    let offset = max_d + 1;
+    let at = |k: isize| (k + offset) as usize;
+    forward[at(1)] = 0;
+    backward[at(1)] = 0;
+
+    for d in 0..=max_d {
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && forward[at(k - 1)] < forward[at(k + 1)]) { forward[at(k + 1)] } else { forward[at(k - 1)] + 1 };
+            let (start_x, start_y) = (x, x - k);
+            let mut y = start_y;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            forward[at(k)] = x;
+            // The backward search counts x from the end, on diagonal delta - k
+            let reverse_k = delta - k;
+            if odd && (-(d - 1)..=d - 1).contains(&reverse_k) && x + backward[at(reverse_k)] >= n {
+                return ((start_x as usize, start_y as usize), (x as usize, y as usize));
+            }
+        }
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && backward[at(k - 1)] < backward[at(k + 1)]) { backward[at(k + 1)] } else { backward[at(k - 1)] + 1 };
+            let (start_x, start_y) = (x, x - k);
+            let mut y = start_y;
+            while x < n && y < m && a[(n - 1 - x) as usize] == b[(m - 1 - y) as usize] {
+                x += 1;
+                y += 1;
+            }
+            backward[at(k)] = x;
+            let forward_k = delta - k;
+            if !odd && (-d..=d).contains(&forward_k) && x + forward[at(forward_k)] >= n {
+                return (((n - x) as usize, (m - y) as usize), ((n - start_x) as usize, (m - start_y) as usize));
+            }
+        }
+    }
+    unreachable!("the forward and backward searches meet within (n + m + 1) / 2 steps")
+}
+
+fn ops_from_matches(pairs: &[(usize, usize)], old_len: usize, new_len: usize) -> Vec<DiffOp> {
+    let mut ops: Vec<DiffOp> = Vec::new();
+    let mut push = |kind: DiffKindEnum, old: Range<usize>, new: Range<usize>| {
+        if old.is_empty() && new.is_empty() {
+            return;
+        }
+        match ops.last_mut() {
+            Some(last) if last.kind == kind => {
+                last.old.end = old.end;
+                last.new.end = new.end;
+            }
+            _ => ops.push(DiffOp { kind, old, new }),
+        }
+    };
+
+    let (mut i, mut j) = (0, 0);
+    for &(a, b) in pairs.iter().chain(std::iter::once(&(old_len, new_len))) {
+        push(DiffKindEnum::Delete, i..a, j..j);
+        push(DiffKindEnum::Insert, a..a, j..b);
+        if a < old_len {
+            push(DiffKindEnum::Equal, a..a + 1, b..b + 1);
+        }
+        i = a + 1;
+        j = b + 1;
+    }
+    ops
+}
+
+/// A conflict found by the merge3 function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConflict {
+    /// Line number (1-based) of the `<<<<<<<` marker in the merged text.
+    pub line: usize,
+    /// The conflicting lines in the base version.
+    pub base: String,
+    /// The conflicting lines in our version.
+    pub ours: String,
+    /// The conflicting lines in their version.
+    pub theirs: String,
+}
+
+/// Result of the merge3 function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeResult {
+    /// The merged text, with conflict markers around each conflict.
+    pub text: String,
+    /// The conflicts, in text order.
+    pub conflicts: Vec<MergeConflict>,
+}
+
+impl MergeResult {
+    /// Returns `true` if the merge had no conflict.
+    pub fn is_clean(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// Merges two versions of a text derived from a common `base`, line by line
+/// (diff3).
+///
+/// ### Rules implemented:
+/// - Lines unchanged in both versions are kept.
+/// - A region changed in only one version takes that version's lines; a
+///   region changed identically in both is taken once.
+/// - A region changed differently in both versions is a conflict: the output
+///   contains both sides between git-style markers
+///   (`<<<<<<< ours`, `=======`, `>>>>>>> theirs`).
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::diff::merge3;
+/// let base = "host=a\nport=1\nuser=x\n";
+/// let clean = merge3(base, "host=b\nport=1\nuser=x\n", "host=a\nport=1\nuser=y\n");
+/// assert!(clean.is_clean());
+/// assert_eq!(clean.text, "host=b\nport=1\nuser=y\n");
+///
+/// let conflict = merge3(base, "host=b\nport=1\nuser=x\n", "host=c\nport=1\nuser=x\n");
+/// assert_eq!(conflict.text, "<<<<<<< ours\nhost=b\n=======\nhost=c\n>>>>>>> theirs\nport=1\nuser=x\n");
+/// assert_eq!(conflict.conflicts[0].line, 1);
+/// assert_eq!(conflict.conflicts[0].base, "host=a\n");
+/// ```
+pub fn merge3(base: &str, ours: &str, theirs: &str) -> MergeResult {
+    let base_lines = split_lines(base);
+    let ours_lines = split_lines(ours);
+    let theirs_lines = split_lines(theirs);
+
+    let mut in_ours = vec![None; base_lines.len()];
+    for (o, a) in matching_lines(&base_lines, &ours_lines) {
+        in_ours[o] = Some(a);
+    }
+    let mut in_theirs = vec![None; base_lines.len()];
+    for (o, b) in matching_lines(&base_lines, &theirs_lines) {
+        in_theirs[o] = Some(b);
+    }
+
+    let mut text = String::with_capacity(base.len().max(ours.len()).max(theirs.len()));
+    let mut conflicts = Vec::new();
+    let mut line = 1;
+    let (mut o, mut a, mut b) = (0, 0, 0);
+
+    loop {
+        // Next base line kept by both versions: the end of an unstable region
+        let stable = (o..base_lines.len()).find_map(|i| Some((i, in_ours[i]?, in_theirs[i]?)));
+        let (end_o, end_a, end_b) = stable.unwrap_or((base_lines.len(), ours_lines.len(), theirs_lines.len()));
+
+        if (end_o, end_a, end_b) != (o, a, b) {
+            let base_chunk = base_lines[o..end_o].concat();
+            let ours_chunk = ours_lines[a..end_a].concat();
+            let theirs_chunk = theirs_lines[b..end_b].concat();
+            if ours_chunk == base_chunk || ours_chunk == theirs_chunk {
+                text.push_str(&theirs_chunk);
+                line += end_b - b;
+            } else if theirs_chunk == base_chunk {
+                text.push_str(&ours_chunk);
+                line += end_a - a;
+            } else {
+                let start_line = line;
+                for (marker, chunk) in [("<<<<<<< ours\n", &ours_chunk), ("=======\n", &theirs_chunk)] {
+                    text.push_str(marker);
+                    text.push_str(chunk);
+                    if !chunk.is_empty() && !chunk.ends_with('\n') {
+                        text.push('\n');
+                    }
+                }
+                text.push_str(">>>>>>> theirs\n");
+                line += 3 + (end_a - a) + (end_b - b);
+                conflicts.push(MergeConflict { line: start_line, base: base_chunk, ours: ours_chunk, theirs: theirs_chunk });
+            }
+        }
+
+        if stable.is_none() {
+            break;
+        }
+        text.push_str(base_lines[end_o]);
+        line += 1;
+        (o, a, b) = (end_o + 1, end_a + 1, end_b + 1);
+    }
+
+    MergeResult { text, conflicts }
+}
+
+/// Maximum number of context lines ignored at each end of a hunk by
+/// [`apply_unified_diff`], as in GNU patch.
+pub const DEFAULT_FUZZ: usize = 2;
+
+/// Formats the differences between two texts as a unified diff (`diff -u`),
+/// with `context` unchanged lines around each change.
+///
+/// Lines without a final newline are followed by
+/// `\ No newline at end of file`. Identical texts give an empty string.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::diff::format_unified_diff;
+/// let patch = format_unified_diff("a\nb\nc\n", "a\nB\nc\n", "old.txt", "new.txt", 1);
+/// assert_eq!(patch, "--- old.txt\n+++ new.txt\n@@ -1,3 +1,3 @@\n a\n-b\n+B\n c\n");
+/// ```
+pub fn format_unified_diff(old: &str, new: &str, old_name: &str, new_name: &str, context: usize) -> String {
+    let old_lines = split_lines(old);
+    let new_lines = split_lines(new);
+    let ops = ops_from_matches(&matching_lines(&old_lines, &new_lines), old_lines.len(), new_lines.len());
+
+    // Group the changes whose context overlaps into hunks of op indexes
+    let mut hunks: Vec<Range<usize>> = Vec::new();
+    for (i, op) in ops.iter().enumerate() {
+        if op.kind == DiffKindEnum::Equal {
+            continue;
+        }
+        match hunks.last_mut() {
+            Some(hunk) if ops[hunk.end..i].iter().all(|op| op.kind != DiffKindEnum::Equal || op.old.len() <= 2 * context) => {
+                hunk.end = i + 1
+            }
+            _ => hunks.push(i..i + 1),
+        }
+    }
+    if hunks.is_empty() {
+        return String::new();
+    }
+
+    let mut out = format!("--- {old_name}\n+++ {new_name}\n");
+    for hunk in hunks {
+        let first = &ops[hunk.start];
+        let last = &ops[hunk.end - 1];
+        let old_range = first.old.start.saturating_sub(context)..(last.old.end + context).min(old_lines.len());
+        let new_range = first.new.start.saturating_sub(context)..(last.new.end + context).min(new_lines.len());
+        out.push_str(&format!("@@ -{} +{} @@\n", hunk_range(&old_range), hunk_range(&new_range)));
+
+        let mut push_line = |prefix: char, line: &str| {
+            out.push(prefix);
+            out.push_str(line);
+            if !line.ends_with('\n') {
+                out.push_str("\n\\ No newline at end of file\n");
+            }
+        };
+        for line in &old_lines[old_range.start..first.old.start] {
+            push_line(' ', line);
+        }
+        for op in &ops[hunk.clone()] {
+            match op.kind {
+                DiffKindEnum::Equal => old_lines[op.old.clone()].iter().for_each(|line| push_line(' ', line)),
+                DiffKindEnum::Delete => old_lines[op.old.clone()].iter().for_each(|line| push_line('-', line)),
+                DiffKindEnum::Insert => new_lines[op.new.clone()].iter().for_each(|line| push_line('+', line)),
+            }
+        }
+        for line in &old_lines[last.old.end..old_range.end] {
+            push_line(' ', line);
+        }
+    }
+    out
+}
+
+/// `start,len` of a hunk header; the start of an empty range is the line
+/// before it and a length of 1 is omitted, as GNU diff does.
+fn hunk_range(range: &Range<usize>) -> String {
+    match range.len() {
+        0 => format!("{},0", range.start),
+        1 => format!("{}", range.start + 1),
+        len => format!("{},{}", range.start + 1, len),
+    }
+}
+
+/// Error returned when a unified diff cannot be applied.
+#[derive(Debug, PartialEq)]
+pub enum PatchError {
+    /// The line of the patch (1-based) is not a valid hunk header or hunk line.
+    Malformed { line: usize },
+    /// The hunk (1-based) does not match the text, even with fuzz.
+    HunkFailed { hunk: usize },
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatchError::Malformed { line } => write!(f, "malformed patch at line {line}"),
+            PatchError::HunkFailed { hunk } => write!(f, "hunk {hunk} does not apply"),
+        }
+    }
+}
+
+impl std::error::Error for PatchError {}
+
+/// A parsed hunk: its old start line (1-based) and its lines as
+/// `(prefix, line)` with `' '`, `'-'` or `'+'` prefixes.
+struct Hunk<'a> {
+    old_start: usize,
+    lines: Vec<(char, Cow<'a, str>)>,
+}
+
+/// Applies a unified diff (as produced by [`format_unified_diff`] or
+/// `diff -u`) to `original`, with [`DEFAULT_FUZZ`].
+///
+/// See [`apply_unified_diff_fuzz`].
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::diff::{apply_unified_diff, format_unified_diff};
+/// let old = "one\ntwo\nthree\n";
+/// let new = "one\n2\nthree\nfour";
+/// let patch = format_unified_diff(old, new, "a", "b", 3);
+/// assert_eq!(apply_unified_diff(old, &patch).unwrap(), new);
+///
+/// // The text moved: the hunk is found 2 lines further down
+/// assert_eq!(apply_unified_diff("zero\nzero\none\ntwo\nthree\n", &patch).unwrap(), "zero\nzero\none\n2\nthree\nfour");
+/// ```
+pub fn apply_unified_diff(original: &str, patch: &str) -> Result<String, PatchError> {
+    apply_unified_diff_fuzz(original, patch, DEFAULT_FUZZ)
+}
+
+/// Applies a unified diff to `original`.
+///
+/// ### Rules implemented:
+/// - Lines before the first `@@` header (`---`, `+++`, `diff`...) are ignored.
+/// - Each hunk is searched for at its line number, shifted by the offset of
+///   the previous hunk, then at the nearest positions above and below.
+/// - If a hunk does not match anywhere, up to `max_fuzz` of its leading and
+///   trailing context lines are ignored, one more at each step.
+/// - An empty patch returns the original text.
+///
+/// # Errors
+/// [`PatchError::Malformed`] for an invalid patch and
+/// [`PatchError::HunkFailed`] for a hunk that does not apply.
+pub fn apply_unified_diff_fuzz(original: &str, patch: &str, max_fuzz: usize) -> Result<String, PatchError> {
+    let hunks = parse_hunks(patch)?;
+    let lines = split_lines(original);
+    let mut out = String::with_capacity(original.len());
+    let mut copied = 0;
+    let mut offset: isize = 0;
+
+    for (index, hunk) in hunks.iter().enumerate() {
+        let (position, old_len, new_lines, expected) = (0..=max_fuzz)
+            .find_map(|fuzz| locate_hunk(hunk, fuzz, &lines, copied, offset))
+            .ok_or(PatchError::HunkFailed { hunk: index + 1 })?;
+
+        out.push_str(&lines[copied..position].concat());
+        for line in new_lines {
+            out.push_str(line);
+        }
+        copied = position + old_len;
+        offset = position as isize - expected as isize;
+    }
+
+    out.push_str(&lines[copied..].concat());
+    Ok(out)
+}
+
+/// Finds where a hunk applies with `fuzz` context lines ignored at each end.
+///
+/// # Returns
+/// `(position, old line count, new lines, expected position)`.
+fn locate_hunk<'h>(
+    hunk: &'h Hunk<'_>,
+    fuzz: usize,
+    lines: &[&str],
+    min_position: usize,
+    offset: isize,
+) -> Option<(usize, usize, Vec<&'h str>, usize)> {
+    let leading = hunk.lines.iter().take(fuzz).take_while(|(prefix, _)| *prefix == ' ').count();
+    let trailing = hunk.lines[leading..].iter().rev().take(fuzz).take_while(|(prefix, _)| *prefix == ' ').count();
+    if fuzz > 0 && leading.max(trailing) < fuzz {
+        return None;
+    }
+    let body = &hunk.lines[leading..hunk.lines.len() - trailing];
+    let old: Vec<&str> = body.iter().filter(|(prefix, _)| *prefix != '+').map(|(_, line)| line.as_ref()).collect();
+    let new: Vec<&str> = body.iter().filter(|(prefix, _)| *prefix != '-').map(|(_, line)| line.as_ref()).collect();
+
+    // A hunk without old lines inserts after line `old_start`
+    let start = if hunk.lines.iter().any(|(prefix, _)| *prefix != '+') { hunk.old_start.saturating_sub(1) } else { hunk.old_start };
+    let expected = start + leading;
+    let last = lines.len().checked_sub(old.len())?;
+    if min_position > last {
+        return None;
+    }
+    let wanted = (expected as isize + offset).clamp(min_position as isize, last as isize) as usize;
+
+    let matches = |position: usize| lines[position..position + old.len()] == old[..];
+    let candidates = (0..=last - min_position).flat_map(|distance| {
+        let above = wanted.checked_sub(distance).filter(|p| *p >= min_position && distance > 0);
+        let below = Some(wanted + distance).filter(|p| *p <= last);
+        above.into_iter().chain(below)
+    });
+    candidates.into_iter().find(|p| matches(*p)).map(|position| (position, old.len(), new, expected))
+}
+
+fn parse_hunks(patch: &str) -> Result<Vec<Hunk<'_>>, PatchError> {
+    let mut hunks: Vec<Hunk> = Vec::new();
+    let mut remaining = (0, 0);
+
+    for (number, line) in patch.split_inclusive('\n').enumerate() {
+        let malformed = PatchError::Malformed { line: number + 1 };
+        if let Some(header) = line.strip_prefix("@@ ") {
+            if remaining != (0, 0) {
+                return Err(malformed);
+            }
+            let (old, new) = parse_hunk_header(header).ok_or(malformed)?;
+            hunks.push(Hunk { old_start: old.0, lines: Vec::new() });
+            remaining = (old.1, new.1);
+            continue;
+        }
+        let Some(hunk) = hunks.last_mut() else { continue };
+
+        if line.starts_with('\\') {
+            match hunk.lines.last_mut() {
+                Some((_, last)) if last.ends_with('\n') => {
+                    let trimmed = last.trim_end_matches('\n').trim_end_matches('\r').len();
+                    last.to_mut().truncate(trimmed);
+                }
+                _ => return Err(malformed),
+            }
+            continue;
+        }
+        if remaining == (0, 0) {
+            continue;
+        }
+
+        // Some tools strip the space of empty context lines
+        let (prefix, content) = match line {
+            "\n" | "\r\n" => (' ', line),
+            _ => {
+                let prefix = line.chars().next().unwrap_or(' ');
+                (prefix, &line[prefix.len_utf8()..])
+            }
+        };
+        let counts = match prefix {
+            ' ' => (1, 1),
+            '-' => (1, 0),
+            '+' => (0, 1),
+            _ => return Err(malformed),
+        };
+        if remaining.0 < counts.0 || remaining.1 < counts.1 {
+            return Err(malformed);
+        }
+        remaining = (remaining.0 - counts.0, remaining.1 - counts.1);
+        hunk.lines.push((prefix, Cow::Borrowed(content)));
+    }
+
+    if remaining != (0, 0) {
+        return Err(PatchError::Malformed { line: patch.split_inclusive('\n').count() });
+    }
+    Ok(hunks)
+}
+
+/// Parses `-l,s +l,s @@...` into the old and new `(start, length)`.
+fn parse_hunk_header(header: &str) -> Option<((usize, usize), (usize, usize))> {
+    let (ranges, _) = header.split_once(" @@")?;
+    let (old, new) = ranges.split_once(' ')?;
+    let range = |r: &str| -> Option<(usize, usize)> {
+        match r.split_once(',') {
+            Some((start, len)) => Some((start.parse().ok()?, len.parse().ok()?)),
+            None => Some((r.parse().ok()?, 1)),
+        }
+    };
+    Some((range(old.strip_prefix('-')?)?, range(new.strip_prefix('+')?)?))
+}
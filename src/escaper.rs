@@ -0,0 +1,376 @@
+use std::fmt;
+use std::fmt::Write;
+
+/// Error returned when an escaped string cannot be decoded.
+/// `position` is the byte index of the offending escape in the input.
+#[derive(Debug, PartialEq)]
+pub enum EscapeError {
+    /// The escape does not have the expected hexadecimal digits.
+    InvalidHex { position: usize },
+    /// The escape is not a valid Unicode scalar value (e.g. above `10FFFF`).
+    InvalidCodePoint { position: usize },
+    /// A UTF-16 surrogate is not part of a valid high/low pair.
+    LoneSurrogate { position: usize },
+    /// The backslash is followed by a character that is not a known escape,
+    /// or is the last character of the string.
+    UnknownEscape { position: usize },
+}
+
+impl fmt::Display for EscapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EscapeError::InvalidHex { position } => write!(f, "invalid hexadecimal escape at byte {position}"),
+            EscapeError::InvalidCodePoint { position } => write!(f, "invalid code point at byte {position}"),
+            EscapeError::LoneSurrogate { position } => write!(f, "unpaired UTF-16 surrogate at byte {position}"),
+            EscapeError::UnknownEscape { position } => write!(f, "unknown escape sequence at byte {position}"),
+        }
+    }
+}
+
+impl std::error::Error for EscapeError {}
+
+/// Output style for encode_unicode_escapes function
+pub enum EscapeStyle {
+    /// `\u00e9`, non-BMP characters as UTF-16 surrogate pairs (`\ud83d\ude00`).
+    /// Only non-ASCII characters are escaped.
+    Java,
+    /// Same as `Java` but ASCII control characters are escaped too (`\u000a`).
+    Json,
+    /// `\u{e9}`. Non-ASCII and ASCII control characters are escaped.
+    Rust,
+}
+
+/// Decodes Unicode escapes in a string.
+///
+/// ### Escapes recognized:
+/// - `\uXXXX` (Java, JSON, JavaScript): four hex digits. A high surrogate
+///   followed by a low surrogate escape is combined into one character.
+/// - `\u{X...}` (Rust, JavaScript ES6) and `\x{X...}` (Perl, PCRE): one to six
+///   hex digits.
+/// - `\\` is an escaped backslash and becomes one backslash, so `\\u0041` is
+///   not decoded. `\"` becomes `"`, as produced by [`EscapeStyle::Json`].
+///
+/// Any other backslash sequence is copied unchanged, so the output of
+/// [`encode_unicode_escapes`] decodes back to its input in every style.
+///
+/// # Returns
+/// The decoded `String`, or an [`EscapeError`] for malformed escapes.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::escaper::decode_unicode_escapes;
+/// assert_eq!(decode_unicode_escapes(r"caf\u00e9").unwrap(), "café");
+/// assert_eq!(decode_unicode_escapes(r"\ud83d\ude00 \u{1F600} \x{1f600}").unwrap(), "😀 😀 😀");
+/// assert_eq!(decode_unicode_escapes(r#"keep \n, \"q\" and \\u0041"#).unwrap(), r#"keep \n, "q" and \u0041"#);
+/// assert!(decode_unicode_escapes(r"\ud83d alone").is_err());
+/// ```
+pub fn decode_unicode_escapes(s: &str) -> Result<String, EscapeError> {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(idx) = rest.find('\\') {
+        out.push_str(&rest[..idx]);
+        let position = s.len() - rest.len() + idx;
+        let escape = &rest[idx..];
+
+        let (decoded, used) = if escape.starts_with("\\\\") {
+            ('\\', 2)
+        } else if escape.starts_with("\\\"") {
+            ('"', 2)
+        } else if escape.starts_with("\\u{") || escape.starts_with("\\x{") {
+            decode_braced(escape, position)?
+        } else if escape.starts_with("\\u") {
+            decode_utf16_pair(escape, position)?
+        } else {
+            out.push('\\');
+            rest = &escape[1..];
+            continue;
+        };
+
+        out.push(decoded);
+        rest = &escape[used..];
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// `\u{...}` or `\x{...}` at the start of `s`.
+fn decode_braced(s: &str, position: usize) -> Result<(char, usize), EscapeError> {
+    let close = s.find('}').ok_or(EscapeError::InvalidHex { position })?;
+    let hex = &s[3..close];
+    if hex.is_empty() || hex.len() > 6 {
+        return Err(EscapeError::InvalidHex { position });
+    }
+    let value = u32::from_str_radix(hex, 16).map_err(|_| EscapeError::InvalidHex { position })?;
+    let c = char::from_u32(value).ok_or(EscapeError::InvalidCodePoint { position })?;
+    Ok((c, close + 1))
+}
+
+/// `\uXXXX` at the start of `s`, possibly followed by a low surrogate `\uXXXX`.
+fn decode_utf16_pair(s: &str, position: usize) -> Result<(char, usize), EscapeError> {
+    let unit = |hex: Option<&str>| -> Result<u16, EscapeError> {
+        let hex = hex.filter(|h| h.bytes().all(|b| b.is_ascii_hexdigit())).ok_or(EscapeError::InvalidHex { position })?;
+        Ok(u16::from_str_radix(hex, 16).unwrap())
+    };
+
+    let high = unit(s.get(2..6))?;
+    if let Some(c) = char::from_u32(high as u32) {
+        return Ok((c, 6));
+    }
+    if !(0xD800..=0xDBFF).contains(&high) || !s[6..].starts_with("\\u") {
+        return Err(EscapeError::LoneSurrogate { position });
+    }
+    let low = unit(s.get(8..12))?;
+    match char::decode_utf16([high, low]).next() {
+        Some(Ok(c)) => Ok((c, 12)),
+        _ => Err(EscapeError::LoneSurrogate { position }),
+    }
+}
+
+/// Escapes characters as Unicode escapes in the given [`EscapeStyle`], so the
+/// string can be embedded in Java, JSON or Rust sources, or in ASCII-only
+/// files.
+///
+/// Backslashes are escaped as `\\` in every style, and `"` as `\"` in `Json`
+/// style (so the output is a valid JSON string body); the result decodes back
+/// with [`decode_unicode_escapes`].
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::escaper::{decode_unicode_escapes, encode_unicode_escapes, EscapeStyle};
+/// assert_eq!(encode_unicode_escapes("café 😀", EscapeStyle::Java), r"caf\u00e9 \ud83d\ude00");
+/// assert_eq!(encode_unicode_escapes("a\nb", EscapeStyle::Json), r"a\u000ab");
+/// assert_eq!(encode_unicode_escapes(r#"C:\dir "x""#, EscapeStyle::Json), r#"C:\\dir \"x\""#);
+/// assert_eq!(encode_unicode_escapes("café 😀", EscapeStyle::Rust), r"caf\u{e9} \u{1f600}");
+/// let original = "naïve 你好 🙂";
+/// assert_eq!(decode_unicode_escapes(&encode_unicode_escapes(original, EscapeStyle::Java)).unwrap(), original);
+/// ```
+pub fn encode_unicode_escapes(s: &str, style: EscapeStyle) -> String {
+    let mut out = String::with_capacity(s.len());
+    encode_unicode_escapes_into(s, style, &mut out);
+    out
+}
+
+/// Same as [`encode_unicode_escapes`], appending the result to `out` so its
+/// capacity can be reused.
+pub fn encode_unicode_escapes_into(s: &str, style: EscapeStyle, out: &mut String) {
+    for c in s.chars() {
+        if c == '\\' || (c == '"' && matches!(style, EscapeStyle::Json)) {
+            out.push('\\');
+            out.push(c);
+            continue;
+        }
+        let escape = match style {
+            EscapeStyle::Java => !c.is_ascii(),
+            EscapeStyle::Json | EscapeStyle::Rust => !c.is_ascii() || c.is_ascii_control(),
+        };
+        if !escape {
+            out.push(c);
+        } else if let EscapeStyle::Rust = style {
+            let _ = write!(out, "\\u{{{:x}}}", c as u32);
+        } else {
+            let mut units = [0u16; 2];
+            for unit in c.encode_utf16(&mut units) {
+                let _ = write!(out, "\\u{unit:04x}");
+            }
+        }
+    }
+}
+
+/// Decodes the escape sequences of a C string literal (without the quotes).
+///
+/// ### Escapes recognized:
+/// - `\a \b \f \n \r \t \v \\ \' \" \?`
+/// - `\0` to `\777`: one to three octal digits.
+/// - `\xHH...`: hexadecimal digits (as many as follow, like C).
+/// - `\uXXXX` and `\UXXXXXXXX`: universal character names.
+///
+/// Numeric escapes are interpreted as Unicode code points, so `\xe9` is `é`.
+///
+/// # Returns
+/// The decoded `String`, or an [`EscapeError`] for unknown or malformed escapes.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::escaper::unescape_c_string;
+/// assert_eq!(unescape_c_string(r#"line\n\t\x41\101\"q\""#).unwrap(), "line\n\tAA\"q\"");
+/// assert!(unescape_c_string(r"bad \q").is_err());
+/// ```
+pub fn unescape_c_string(s: &str) -> Result<String, EscapeError> {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((position, c)) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        let Some((_, kind)) = chars.next() else {
+            return Err(EscapeError::UnknownEscape { position });
+        };
+        let decoded = match kind {
+            'a' => '\u{07}',
+            'b' => '\u{08}',
+            'f' => '\u{0C}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'v' => '\u{0B}',
+            '\\' | '\'' | '"' | '?' => kind,
+            '0'..='7' => {
+                let mut value = kind.to_digit(8).unwrap();
+                for _ in 0..2 {
+                    match chars.peek().and_then(|(_, d)| d.to_digit(8)) {
+                        Some(d) => {
+                            value = value * 8 + d;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                char::from_u32(value).ok_or(EscapeError::InvalidCodePoint { position })?
+            }
+            'x' | 'u' | 'U' => {
+                let max_digits = match kind {
+                    'u' => 4,
+                    'U' => 8,
+                    _ => usize::MAX,
+                };
+                let mut value: u32 = 0;
+                let mut digits = 0;
+                while digits < max_digits && let Some(d) = chars.peek().and_then(|(_, d)| d.to_digit(16)) {
+                    value = value.checked_mul(16).ok_or(EscapeError::InvalidCodePoint { position })? + d;
+                    digits += 1;
+                    chars.next();
+                }
+                if digits == 0 || (kind != 'x' && digits != max_digits) {
+                    return Err(EscapeError::InvalidHex { position });
+                }
+                char::from_u32(value).ok_or(EscapeError::InvalidCodePoint { position })?
+            }
+            _ => return Err(EscapeError::UnknownEscape { position }),
+        };
+        out.push(decoded);
+    }
+
+    Ok(out)
+}
+
+/// Returns the short escape of a character in a C string literal
+/// (`\\ \" \n \r \t \a \b \f \v`), or `None` if it has none.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::escaper::c_short_escape;
+/// const NEWLINE: Option<&str> = c_short_escape('\n');
+/// assert_eq!(NEWLINE, Some(r"\n"));
+/// assert_eq!(c_short_escape('a'), None);
+/// ```
+pub const fn c_short_escape(c: char) -> Option<&'static str> {
+    match c {
+        '\\' => Some("\\\\"),
+        '"' => Some("\\\""),
+        '\n' => Some("\\n"),
+        '\r' => Some("\\r"),
+        '\t' => Some("\\t"),
+        '\u{07}' => Some("\\a"),
+        '\u{08}' => Some("\\b"),
+        '\u{0C}' => Some("\\f"),
+        '\u{0B}' => Some("\\v"),
+        _ => None,
+    }
+}
+
+/// Returns the short escape of a character in a Rust string literal
+/// (`\\ \" \n \r \t \0`), or `None` if it has none.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::escaper::rust_short_escape;
+/// const NUL: Option<&str> = rust_short_escape('\0');
+/// assert_eq!(NUL, Some(r"\0"));
+/// assert_eq!(rust_short_escape('\u{07}'), None);
+/// ```
+pub const fn rust_short_escape(c: char) -> Option<&'static str> {
+    match c {
+        '\\' => Some("\\\\"),
+        '"' => Some("\\\""),
+        '\n' => Some("\\n"),
+        '\r' => Some("\\r"),
+        '\t' => Some("\\t"),
+        '\0' => Some("\\0"),
+        _ => None,
+    }
+}
+
+/// Escapes a string so it can be placed between double quotes in C source.
+///
+/// Backslashes, double quotes and the usual control characters get their short
+/// escapes (`\\ \" \n \r \t \a \b \f \v`); other control characters become
+/// octal escapes. Non-ASCII characters are kept as they are (UTF-8 source).
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::escaper::{escape_c_string, unescape_c_string};
+/// assert_eq!(escape_c_string("say \"hi\"\n"), r#"say \"hi\"\n"#);
+/// assert_eq!(escape_c_string("\u{1}"), r"\001");
+/// let original = "tab\there \\ 🙂";
+/// assert_eq!(unescape_c_string(&escape_c_string(original)).unwrap(), original);
+/// ```
+pub fn escape_c_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    escape_c_string_into(s, &mut out);
+    out
+}
+
+/// Same as [`escape_c_string`], appending the result to `out` so its
+/// capacity can be reused.
+pub fn escape_c_string_into(s: &str, out: &mut String) {
+    for c in s.chars() {
+        if let Some(escape) = c_short_escape(c) {
+            out.push_str(escape);
+        } else if c.is_ascii_control() {
+            let _ = write!(out, "\\{:03o}", c as u32);
+        } else {
+            out.push(c);
+        }
+    }
+}
+
+/// Turns a string into a Rust string literal, including the surrounding
+/// double quotes, ready to be written by a code generator.
+///
+/// Backslashes, double quotes, `\n`, `\r`, `\t` and `\0` get their short
+/// escapes; other control characters (including C1 controls and bidi
+/// overrides, which could hide code) become `\u{...}` escapes. Other non-ASCII
+/// characters are kept as they are.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::escaper::escape_rust_string_literal;
+/// assert_eq!(escape_rust_string_literal("a \"quote\"\n"), r#""a \"quote\"\n""#);
+/// assert_eq!(escape_rust_string_literal("café\u{7f}"), r#""café\u{7f}""#);
+/// assert_eq!(escape_rust_string_literal("\u{202e}"), r#""\u{202e}""#);
+/// ```
+pub fn escape_rust_string_literal(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    escape_rust_string_literal_into(s, &mut out);
+    out
+}
+
+/// Same as [`escape_rust_string_literal`], appending the literal to `out` so
+/// its capacity can be reused.
+pub fn escape_rust_string_literal_into(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        if let Some(escape) = rust_short_escape(c) {
+            out.push_str(escape);
+        } else if c.is_control() || matches!(c as u32, 0x200E | 0x200F | 0x202A..=0x202E | 0x2066..=0x2069) {
+            let _ = write!(out, "\\u{{{:x}}}", c as u32);
+        } else {
+            out.push(c);
+        }
+    }
+    out.push('"');
+}
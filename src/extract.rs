@@ -0,0 +1,265 @@
+use crate::analyzer::is_combining_mark;
+
+/// A hashtag or mention found by [`extract_hashtags`] or [`extract_mentions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TagMatch<'a> {
+    /// The tag without its `#` or `@`.
+    pub name: &'a str,
+    /// Byte offset of the `#` or `@` in the text.
+    pub start: usize,
+    /// Byte offset just after the tag.
+    pub end: usize,
+}
+
+/// URL schemes recognized by [`extract_urls`], lowercase.
+const URL_SCHEMES: &[&str] = &["https://", "http://", "ftp://"];
+
+/// Characters removed from the end of a URL or email: sentence punctuation
+/// and quotes that follow a link in prose.
+const TRAILING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?', '\'', '"', '*'];
+
+/// Extracts the URLs found in `text`, in order.
+///
+/// ### Rules implemented:
+/// - A URL starts with `http://`, `https://` or `ftp://` (any case), or with
+///   `www.`, at the start of a word.
+/// - It ends at whitespace, `<`, `>`, `"` or a backtick.
+/// - Brackets must balance: a `)`, `]` or `}` without its opening bracket in
+///   the URL ends it, so `(see https://a.com)` and markdown links
+///   `[text](https://a.com)` give `https://a.com`, while
+///   `https://en.wikipedia.org/wiki/Rust_(language)` is kept whole.
+/// - Trailing sentence punctuation and quotes (`. , ; : ! ? ' " *`) are removed.
+/// - Something must follow the scheme, so a bare `http://` is not a URL.
+///
+/// URLs are the tokens [`word_count`](crate::analyzer::word_count) counts as
+/// one word, with the same boundaries.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::extract::extract_urls;
+/// let text = "Docs at https://docs.rs/regex. Also see [the book](https://doc.rust-lang.org/book/) and (www.rust-lang.org)!";
+/// assert_eq!(extract_urls(text), vec!["https://docs.rs/regex", "https://doc.rust-lang.org/book/", "www.rust-lang.org"]);
+/// assert_eq!(extract_urls("https://en.wikipedia.org/wiki/Rust_(language)."), vec!["https://en.wikipedia.org/wiki/Rust_(language)"]);
+/// ```
+///
+/// # Arguments
+/// * `text` – The text to search.
+///
+/// # Returns
+/// The URLs, as slices of `text`.
+pub fn extract_urls(text: &str) -> Vec<&str> {
+    let mut urls = Vec::new();
+    let mut previous = None;
+    let mut i = 0;
+    while i < text.len() {
+        let rest = &text[i..];
+        if !previous.is_some_and(char::is_alphanumeric) && let Some(len) = url_len(rest) {
+            urls.push(&rest[..len]);
+            previous = rest[..len].chars().next_back();
+            i += len;
+        } else {
+            let c = rest.chars().next().unwrap();
+            previous = Some(c);
+            i += c.len_utf8();
+        }
+    }
+    urls
+}
+
+/// Extracts the email addresses found in `text`, in order.
+///
+/// ### Rules implemented:
+/// - An address is `local@domain`, made of ASCII letters, digits and
+///   `. _ % + -`, starting at the start of a word.
+/// - The local part must not start with `.`; the domain needs a `.` and no
+///   empty labels.
+/// - A trailing `.` or `-` is sentence punctuation, not part of the address.
+/// - Addresses inside a URL (`https://user@host.com`) are not extracted;
+///   `mailto:` links give their address.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::extract::extract_emails;
+/// let text = "Write to <ana@mail.com> or bo.smith+news@example.co.uk. Not: @handle, a@b, https://u@host.com";
+/// assert_eq!(extract_emails(text), vec!["ana@mail.com", "bo.smith+news@example.co.uk"]);
+/// assert_eq!(extract_emails("[mail me](mailto:me@site.org)"), vec!["me@site.org"]);
+/// ```
+///
+/// # Arguments
+/// * `text` – The text to search.
+///
+/// # Returns
+/// The addresses, as slices of `text`.
+pub fn extract_emails(text: &str) -> Vec<&str> {
+    let mut emails = Vec::new();
+    let mut previous = None;
+    let mut i = 0;
+    while i < text.len() {
+        let rest = &text[i..];
+        let at_word_start = !previous.is_some_and(char::is_alphanumeric);
+        let len = if at_word_start && let Some(len) = url_len(rest) {
+            len
+        } else if at_word_start && !previous.is_some_and(is_email_char) && let Some(len) = email_len(rest) {
+            emails.push(&rest[..len]);
+            len
+        } else {
+            rest.chars().next().unwrap().len_utf8()
+        };
+        previous = rest[..len].chars().next_back();
+        i += len;
+    }
+    emails
+}
+
+/// Extracts the hashtags of `text`, in order.
+///
+/// ### Rules implemented:
+/// - A hashtag is `#` (or the fullwidth `＃`) followed by letters, digits,
+///   combining marks, joiners and `_`, in any script (`#café`, `#東京`).
+/// - It needs at least one letter: `#1` and `#2024` are not hashtags.
+/// - The `#` must not follow a letter, digit, `_`, `&` (`&#39;`), `#` or `@`,
+///   and the tag must not be followed by another `#` or `@`.
+/// - Fragments in URLs (`https://a.com/#top`) are not hashtags.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::extract::extract_hashtags;
+/// let tags = extract_hashtags("Loving #RustLang and #東京2024! Not: C#, #1, a#b");
+/// let names: Vec<&str> = tags.iter().map(|tag| tag.name).collect();
+/// assert_eq!(names, vec!["RustLang", "東京2024"]);
+/// assert_eq!((tags[0].start, tags[0].end), (7, 16));
+/// ```
+///
+/// # Arguments
+/// * `text` – The text to search.
+///
+/// # Returns
+/// The hashtags with their byte spans, `#` included.
+pub fn extract_hashtags(text: &str) -> Vec<TagMatch<'_>> {
+    extract_tags(text, ['#', '\u{ff03}'], |name| name.chars().any(char::is_alphabetic))
+}
+
+/// Extracts the @mentions of `text`, in order.
+///
+/// ### Rules implemented:
+/// - A mention is `@` (or the fullwidth `＠`) followed by letters, digits,
+///   combining marks, joiners and `_`, in any script (`@josé_92`).
+/// - The name must not start with a digit: `@3pm` is not a mention.
+/// - The `@` must not follow a letter, digit, `_`, `&`, `#` or `@`, so email
+///   addresses are not mentions, and the name must not be followed by another
+///   `#` or `@`.
+/// - `@` inside URLs (`https://user@host.com`) is not a mention.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::extract::extract_mentions;
+/// let mentions = extract_mentions("cc @ana_b, @José: mail bo@mail.com at @3pm");
+/// let names: Vec<&str> = mentions.iter().map(|mention| mention.name).collect();
+/// assert_eq!(names, vec!["ana_b", "José"]);
+/// assert_eq!(&"cc @ana_b"[mentions[0].start..mentions[0].end], "@ana_b");
+/// ```
+///
+/// # Arguments
+/// * `text` – The text to search.
+///
+/// # Returns
+/// The mentions with their byte spans, `@` included.
+pub fn extract_mentions(text: &str) -> Vec<TagMatch<'_>> {
+    extract_tags(text, ['@', '\u{ff20}'], |name| !name.starts_with(|c: char| c.is_numeric()))
+}
+
+/// Finds the tags introduced by one of `sigils` whose name passes
+/// `is_valid_name`, with the boundary rules of [`extract_hashtags`].
+fn extract_tags(text: &str, sigils: [char; 2], is_valid_name: impl Fn(&str) -> bool) -> Vec<TagMatch<'_>> {
+    let is_tag_char = |c: char| c.is_alphanumeric() || c == '_' || matches!(c, '\u{200c}' | '\u{200d}') || is_combining_mark(c);
+    let is_any_sigil = |c: char| matches!(c, '#' | '@' | '\u{ff03}' | '\u{ff20}');
+
+    let mut tags = Vec::new();
+    let mut previous: Option<char> = None;
+    let mut i = 0;
+    while i < text.len() {
+        let rest = &text[i..];
+        let c = rest.chars().next().unwrap();
+        let at_word_start = !previous.is_some_and(char::is_alphanumeric);
+        let mut len = c.len_utf8();
+        if at_word_start && let Some(url) = url_len(rest) {
+            len = url;
+        } else if sigils.contains(&c) && !previous.is_some_and(|p| is_tag_char(p) || p == '&' || is_any_sigil(p)) {
+            let body = &rest[len..];
+            let name = &body[..body.find(|n: char| !is_tag_char(n)).unwrap_or(body.len())];
+            let followed_by_sigil = body[name.len()..].starts_with(is_any_sigil);
+            if !name.is_empty() && !followed_by_sigil && is_valid_name(name) {
+                len += name.len();
+                tags.push(TagMatch { name, start: i, end: i + len });
+            }
+        }
+        previous = rest[..len].chars().next_back();
+        i += len;
+    }
+    tags
+}
+
+/// Returns the byte length of the URL at the start of `s`, if any, following
+/// the rules of [`extract_urls`]. Word boundaries are the caller's concern.
+pub(crate) fn url_len(s: &str) -> Option<usize> {
+    let scheme = URL_SCHEMES
+        .iter()
+        .find(|scheme| s.get(..scheme.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme)))
+        .map_or(0, |scheme| scheme.len());
+    let prefix = if scheme > 0 {
+        scheme
+    } else if s.get(..4).is_some_and(|prefix| prefix.eq_ignore_ascii_case("www.")) {
+        4
+    } else {
+        return None;
+    };
+    if !s[prefix..].starts_with(|c: char| c.is_alphanumeric()) {
+        return None;
+    }
+
+    let mut open = Vec::new();
+    let mut end = s.len();
+    for (i, c) in s.char_indices() {
+        if c.is_whitespace() || c.is_control() || matches!(c, '<' | '>' | '"' | '`') {
+            end = i;
+            break;
+        }
+        match c {
+            '(' | '[' | '{' => open.push(c),
+            ')' | ']' | '}' => {
+                let opener = match c {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                if open.pop() != Some(opener) {
+                    end = i;
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let url = s[..end].trim_end_matches(TRAILING_PUNCTUATION);
+    (url.len() > prefix).then_some(url.len())
+}
+
+/// Returns the byte length of the email address at the start of `s`, if any.
+pub(crate) fn email_len(s: &str) -> Option<usize> {
+    let len = s.find(|c: char| !is_email_char(c) && c != '@').unwrap_or(s.len());
+    let candidate = s[..len].trim_end_matches(['.', '-']);
+
+    let (local, domain) = candidate.split_once('@')?;
+    let valid = !local.is_empty()
+        && !local.starts_with('.')
+        && !domain.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && domain.split('.').all(|label| !label.is_empty());
+    valid.then_some(candidate.len())
+}
+
+fn is_email_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '%' | '+' | '-')
+}
@@ -0,0 +1,192 @@
+//! C ABI wrappers (feature `ffi`), so services written in other languages
+//! can call the crate.
+//!
+//! Strings are passed as a UTF-8 pointer and a length in bytes (no NUL
+//! terminator needed; the pointer may be null when the length is 0). Every
+//! function returns a [`BtStatus`] and writes its result through an output
+//! pointer. Strings and arrays returned by the crate are owned by the caller,
+//! who must release them with [`bt_string_free`] and [`bt_string_array_free`].
+//!
+//! To build a shared library, compile the crate as a `cdylib`, e.g.
+//! `cargo rustc --release --features ffi --crate-type cdylib`.
+//!
+//! ```c
+//! BtString out;
+//! if (bt_redact(text, strlen(text), 42, &out) == BT_OK) {
+//!     fwrite(out.ptr, 1, out.len, stdout);
+//!     bt_string_free(out);
+//! }
+//! ```
+
+use std::ptr;
+
+use crate::analyzer::word_count;
+use crate::anonymizer::{anonymize, Anonymizer};
+use crate::slugify;
+use crate::splitter::split_into_chunks;
+
+/// Result of an FFI call.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BtStatus {
+    /// Success: the output was written.
+    Ok = 0,
+    /// A required pointer was null.
+    NullPointer = 1,
+    /// The input is not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// Another argument is out of range, such as a non-ASCII separator.
+    InvalidArgument = 3,
+}
+
+/// A UTF-8 string allocated by the crate (not NUL-terminated).
+/// Release it with [`bt_string_free`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct BtString {
+    pub ptr: *mut u8,
+    pub len: usize,
+    pub capacity: usize,
+}
+
+/// An array of strings allocated by the crate. Release it with
+/// [`bt_string_array_free`], which frees the strings too.
+#[repr(C)]
+#[derive(Debug)]
+pub struct BtStringArray {
+    pub ptr: *mut BtString,
+    pub len: usize,
+}
+
+impl From<String> for BtString {
+    fn from(s: String) -> Self {
+        let mut s = std::mem::ManuallyDrop::new(s);
+        BtString { ptr: s.as_mut_ptr(), len: s.len(), capacity: s.capacity() }
+    }
+}
+
+/// Reads the input string, `""` for a null pointer with length 0.
+///
+/// # Safety
+/// `ptr` must be valid for reads of `len` bytes.
+unsafe fn input<'a>(ptr: *const u8, len: usize) -> Result<&'a str, BtStatus> {
+    if ptr.is_null() {
+        return if len == 0 { Ok("") } else { Err(BtStatus::NullPointer) };
+    }
+    // SAFETY: guaranteed by the caller
+    let bytes = unsafe { std::slice::from_raw_parts(ptr, len) };
+    std::str::from_utf8(bytes).map_err(|_| BtStatus::InvalidUtf8)
+}
+
+/// Writes `value` to `out`.
+///
+/// # Safety
+/// `out` must be null or valid for writes.
+unsafe fn output<T>(out: *mut T, value: T) -> BtStatus {
+    if out.is_null() {
+        return BtStatus::NullPointer;
+    }
+    // SAFETY: guaranteed by the caller
+    unsafe { out.write(value) };
+    BtStatus::Ok
+}
+
+/// Counts the words of the text, see [`word_count`].
+///
+/// # Safety
+/// `text` must be valid for reads of `len` bytes and `out_count` valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn bt_word_count(text: *const u8, len: usize, out_count: *mut usize) -> BtStatus {
+    match unsafe { input(text, len) } {
+        Ok(text) => unsafe { output(out_count, word_count(text)) },
+        Err(status) => status,
+    }
+}
+
+/// Splits the text into chunks of at most `chunk_size_bytes` bytes, see
+/// [`split_into_chunks`].
+///
+/// # Safety
+/// `text` must be valid for reads of `len` bytes and `out_chunks` valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn bt_split_into_chunks(text: *const u8, len: usize, chunk_size_bytes: usize, out_chunks: *mut BtStringArray) -> BtStatus {
+    let text = match unsafe { input(text, len) } {
+        Ok(text) => text,
+        Err(status) => return status,
+    };
+    if out_chunks.is_null() {
+        return BtStatus::NullPointer;
+    }
+    let chunks: Box<[BtString]> = split_into_chunks(text, chunk_size_bytes).into_iter().map(BtString::from).collect();
+    let len = chunks.len();
+    let ptr = Box::into_raw(chunks) as *mut BtString;
+    unsafe { output(out_chunks, BtStringArray { ptr, len }) }
+}
+
+/// Redacts the text before it is shared: names, emails and numbers are
+/// replaced with deterministic fakes, see [`anonymize`] (all replacements
+/// enabled).
+///
+/// # Safety
+/// `text` must be valid for reads of `len` bytes and `out_text` valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn bt_redact(text: *const u8, len: usize, seed: u64, out_text: *mut BtString) -> BtStatus {
+    let text = match unsafe { input(text, len) } {
+        Ok(text) => text,
+        Err(status) => return status,
+    };
+    if out_text.is_null() {
+        return BtStatus::NullPointer;
+    }
+    unsafe { output(out_text, BtString::from(anonymize(text, &Anonymizer::new(seed)))) }
+}
+
+/// Converts a title into a URL-safe slug with words joined by `separator`
+/// (an ASCII character, [`BtStatus::InvalidArgument`] otherwise), see
+/// [`slugify`].
+///
+/// # Safety
+/// `text` must be valid for reads of `len` bytes and `out_slug` valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn bt_slugify(text: *const u8, len: usize, separator: u8, out_slug: *mut BtString) -> BtStatus {
+    let text = match unsafe { input(text, len) } {
+        Ok(text) => text,
+        Err(status) => return status,
+    };
+    if out_slug.is_null() {
+        return BtStatus::NullPointer;
+    }
+    if !separator.is_ascii() {
+        return BtStatus::InvalidArgument;
+    }
+    unsafe { output(out_slug, BtString::from(slugify(text, char::from(separator)))) }
+}
+
+/// Frees a string returned by the crate. Null strings are ignored.
+///
+/// # Safety
+/// `s` must come from this crate and not have been freed already.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn bt_string_free(s: BtString) {
+    if !s.ptr.is_null() {
+        // SAFETY: the parts come from a `String` leaked by `BtString::from`
+        drop(unsafe { String::from_raw_parts(s.ptr, s.len, s.capacity) });
+    }
+}
+
+/// Frees an array of strings returned by the crate, and its strings.
+/// Null arrays are ignored.
+///
+/// # Safety
+/// `array` must come from this crate and not have been freed already.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn bt_string_array_free(array: BtStringArray) {
+    if array.ptr.is_null() {
+        return;
+    }
+    // SAFETY: the parts come from a boxed slice leaked by `bt_split_into_chunks`
+    let strings = unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(array.ptr, array.len)) };
+    for s in strings.into_vec() {
+        unsafe { bt_string_free(s) };
+    }
+}
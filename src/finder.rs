@@ -1,5 +1,8 @@
 use regex::Regex;
 
+use crate::locale::case_fold;
+use crate::splitter::Separator;
+
 /// Finds and returns the substring before the first occurrence of a given separator.
 ///
 /// # Arguments
@@ -23,11 +26,69 @@ use regex::Regex;
 /// assert_eq!(result, "");
 /// ```
 pub fn get_first_occurrance(s: &str, separator: &str) -> String{
-    if let Some(position) = s.find(separator){
-        s[..position].to_owned()
-    }else{
-        "".to_owned()
-    }
+    get_first_occurrance_ref(s, separator).unwrap_or_default().to_owned()
+}
+
+/// Same as [`get_first_occurrance`], returning a slice of `s`, or `None` if
+/// the separator is not found (instead of an empty string).
+///
+/// The separator can be a `char`, a string, a set of chars or a predicate
+/// (see [`Separator`](crate::splitter::Separator)).
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::finder::get_first_occurrance_ref;
+/// assert_eq!(get_first_occurrance_ref("Hello, world!", ", "), Some("Hello"));
+/// assert_eq!(get_first_occurrance_ref(",leading", ','), Some(""));
+/// assert_eq!(get_first_occurrance_ref("No separator here", ','), None);
+/// ```
+pub fn get_first_occurrance_ref<P: Separator>(s: &str, separator: P) -> Option<&str>{
+    separator.find_in(s).map(|(start, _)| &s[..start])
+}
+
+/// Finds and returns the substring after the last occurrence of a given separator.
+///
+/// # Arguments
+///
+/// * `s` - A string slice that holds the text to search within.
+/// * `separator` - A string slice that specifies the character(s) to look for as a separator.
+///
+/// # Returns
+///
+/// Returns a new `String` containing the substring after the last occurrence of the separator.
+/// If the separator is not found, an empty `String` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::finder::get_last_occurrance;
+/// let result = get_last_occurrance("config.db.host", ".");
+/// assert_eq!(result, "host");
+///
+/// let result = get_last_occurrance("No separator here", ",");
+/// assert_eq!(result, "");
+/// ```
+pub fn get_last_occurrance(s: &str, separator: &str) -> String{
+    get_last_occurrance_ref(s, separator).unwrap_or_default().to_owned()
+}
+
+/// Same as [`get_last_occurrance`], returning a slice of `s`, or `None` if
+/// the separator is not found (instead of an empty string).
+///
+/// The separator can be a `char`, a string, a set of chars or a predicate
+/// (see [`Separator`](crate::splitter::Separator)).
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::finder::get_last_occurrance_ref;
+/// assert_eq!(get_last_occurrance_ref("config.db.host", '.'), Some("host"));
+/// assert_eq!(get_last_occurrance_ref("trailing.", '.'), Some(""));
+/// assert_eq!(get_last_occurrance_ref("No separator here", ','), None);
+/// ```
+pub fn get_last_occurrance_ref<P: Separator>(s: &str, separator: P) -> Option<&str>{
+    separator.rfind_in(s).map(|(_, end)| &s[end..])
 }
 
 /// Checks whether a given string contains the specified `word`
@@ -179,5 +240,202 @@ pub fn find_value_by_key(kv_pairs: &Vec<String>, key_to_find: &str) -> Option<St
         }
 
     }
+    trace_event!(debug, key = key_to_find, pairs = kv_pairs.len(),
+        without_delimiter = kv_pairs.iter().filter(|item| !item.contains('=')).count(), "key not found");
     None
-}
\ No newline at end of file
+}
+
+/// Same as [`find_value_by_key`], comparing keys case-insensitively with
+/// Unicode case folding (`"Content-Type"` matches `"content-type"`, `"STRASSE"`
+/// matches `"Straße"`).
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::finder::find_value_by_key_ci;
+/// let headers = vec!["Content-Type=text/html".to_owned(), "X-Request-Id=42".to_owned()];
+/// assert_eq!(find_value_by_key_ci(&headers, "content-type"), Some("text/html".to_string()));
+/// assert_eq!(find_value_by_key_ci(&headers, "x-request-id"), Some("42".to_string()));
+/// assert_eq!(find_value_by_key_ci(&headers, "accept"), None);
+/// ```
+pub fn find_value_by_key_ci(kv_pairs: &[String], key_to_find: &str) -> Option<String> {
+    let folded = case_fold(key_to_find);
+    kv_pairs
+        .iter()
+        .filter_map(|item| item.split_once('='))
+        .find(|(key, _)| case_fold(key) == folded)
+        .map(|(_, value)| value.to_owned())
+}
+
+/// Finds all the key-value pairs whose key starts with `prefix`, in order.
+///
+/// Pairs are separated by `'='` like in [`find_value_by_key`]; items without
+/// `'='` are skipped.
+///
+/// # Returns
+///
+/// A `Vec` of `(key, value)` for every matching item (empty if none match).
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::finder::find_values_by_key_prefix;
+/// let pairs = vec!["db.host=x".to_owned(), "app.name=y".to_owned(), "db.port=5432".to_owned()];
+/// assert_eq!(find_values_by_key_prefix(&pairs, "db."), vec![
+///     ("db.host".to_string(), "x".to_string()),
+///     ("db.port".to_string(), "5432".to_string()),
+/// ]);
+/// assert!(find_values_by_key_prefix(&pairs, "cache.").is_empty());
+/// ```
+pub fn find_values_by_key_prefix(kv_pairs: &[String], prefix: &str) -> Vec<(String, String)> {
+    kv_pairs
+        .iter()
+        .filter_map(|item| item.split_once('='))
+        .filter(|(key, _)| key.starts_with(prefix))
+        .map(|(key, value)| (key.to_owned(), value.to_owned()))
+        .collect()
+}
+
+/// Finds the first occurrence of `needle` in `s` at or after byte position `start`.
+///
+/// Equivalent to `s[start..].find(needle).map(|i| i + start)` but returns
+/// positions relative to the whole string and never panics: `start` may be
+/// past the end or inside a multi-byte character. An empty needle matches at
+/// `start`, moved forward to the next character boundary.
+///
+/// # Arguments
+/// * `s` - The string to search.
+/// * `needle` - The substring to look for.
+/// * `start` - Byte position where the search begins.
+///
+/// # Returns
+/// `Some(byte_index)` of the match in `s`, or `None`.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::finder::find_from;
+/// assert_eq!(find_from("a-b-c", "-", 0), Some(1));
+/// assert_eq!(find_from("a-b-c", "-", 2), Some(3));
+/// assert_eq!(find_from("a-b-c", "-", 4), None);
+/// assert_eq!(find_from("a-b-c", "-", 99), None);
+/// ```
+pub fn find_from(s: &str, needle: &str, start: usize) -> Option<usize> {
+    Searcher::new(needle).find_from(s, start)
+}
+
+/// Finds the last occurrence of `needle` that ends at or before byte position `end`.
+///
+/// An empty needle matches at `end`, moved back to the previous character
+/// boundary.
+///
+/// # Arguments
+/// * `s` - The string to search.
+/// * `needle` - The substring to look for.
+/// * `end` - Byte position where the backward search begins (clamped to the
+///   length of `s`).
+///
+/// # Returns
+/// `Some(byte_index)` of the start of the match in `s`, or `None`.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::finder::rfind_before;
+/// assert_eq!(rfind_before("a-b-c", "-", 5), Some(3));
+/// assert_eq!(rfind_before("a-b-c", "-", 3), Some(1));
+/// assert_eq!(rfind_before("a-b-c", "-", 1), None);
+/// ```
+pub fn rfind_before(s: &str, needle: &str, end: usize) -> Option<usize> {
+    Searcher::new(needle).rfind_before(s, end)
+}
+
+/// A substring searcher that preprocesses the needle once (Boyer-Moore-Horspool
+/// shift tables) so it can be reused for many searches, forwards and backwards,
+/// without re-slicing the haystack.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::finder::Searcher;
+/// let searcher = Searcher::new("needle");
+/// let haystack = "hay needle hay needle";
+/// assert_eq!(searcher.find_from(haystack, 0), Some(4));
+/// assert_eq!(searcher.find_from(haystack, 5), Some(15));
+/// assert_eq!(searcher.rfind_before(haystack, 14), Some(4));
+/// ```
+pub struct Searcher {
+    needle: Vec<u8>,
+    forward_shift: [usize; 256],
+    backward_shift: [usize; 256],
+}
+
+impl Searcher {
+    /// Creates a searcher for `needle`.
+    pub fn new(needle: &str) -> Self {
+        let needle = needle.as_bytes().to_vec();
+        let len = needle.len();
+        let mut forward_shift = [len.max(1); 256];
+        let mut backward_shift = [len.max(1); 256];
+
+        for (i, b) in needle.iter().enumerate().take(len.saturating_sub(1)) {
+            forward_shift[*b as usize] = len - 1 - i;
+        }
+        for (i, b) in needle.iter().enumerate().skip(1).rev() {
+            backward_shift[*b as usize] = i;
+        }
+
+        Searcher { needle, forward_shift, backward_shift }
+    }
+
+    /// Returns the needle this searcher looks for.
+    pub fn needle(&self) -> &str {
+        // Built from a &str, so always valid UTF-8
+        std::str::from_utf8(&self.needle).unwrap()
+    }
+
+    /// Finds the first match in `haystack` at or after byte position `start`.
+    /// See [`find_from`].
+    pub fn find_from(&self, haystack: &str, start: usize) -> Option<usize> {
+        let hay = haystack.as_bytes();
+        let len = self.needle.len();
+        if start > hay.len() || hay.len() - start < len {
+            return None;
+        }
+        if len == 0 {
+            return Some(haystack.ceil_char_boundary(start));
+        }
+
+        let mut pos = start;
+        while pos + len <= hay.len() {
+            if hay[pos..pos + len] == self.needle[..] {
+                return Some(pos);
+            }
+            pos += self.forward_shift[hay[pos + len - 1] as usize];
+        }
+        None
+    }
+
+    /// Finds the last match that ends at or before byte position `end`.
+    /// See [`rfind_before`].
+    pub fn rfind_before(&self, haystack: &str, end: usize) -> Option<usize> {
+        let hay = haystack.as_bytes();
+        let end = end.min(hay.len());
+        let len = self.needle.len();
+        if end < len {
+            return None;
+        }
+        if len == 0 {
+            return Some(haystack.floor_char_boundary(end));
+        }
+
+        let mut pos = end - len;
+        loop {
+            if hay[pos..pos + len] == self.needle[..] {
+                return Some(pos);
+            }
+            let shift = self.backward_shift[hay[pos] as usize];
+            if pos < shift {
+                return None;
+            }
+            pos -= shift;
+        }
+    }
+}
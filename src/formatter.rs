@@ -0,0 +1,479 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::analyzer::{is_cjk, is_combining_mark};
+
+/// Returns the number of terminal columns a character occupies.
+///
+/// ### Rules implemented:
+/// - Combining marks, zero-width characters, variation selectors and control
+///   characters take **0** columns.
+/// - CJK ideographs, Hangul, Kana, fullwidth forms and pictographic emoji
+///   take **2** columns.
+/// - Everything else takes **1** column.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::formatter::char_width;
+/// assert_eq!(char_width('a'), 1);
+/// assert_eq!(char_width('你'), 2);
+/// assert_eq!(char_width('🙂'), 2);
+/// assert_eq!(char_width('\u{301}'), 0);
+/// ```
+pub fn char_width(c: char) -> usize {
+    if c.is_control() || is_combining_mark(c) {
+        return 0;
+    }
+
+    let u = c as u32;
+    if matches!(u,
+        0x200B..=0x200F | // zero-width space, joiners, direction marks
+        0x2060..=0x2064 | // word joiner and invisible operators
+        0xFE00..=0xFE0F | // variation selectors
+        0xFEFF            // zero-width no-break space (BOM)
+    ) {
+        return 0;
+    }
+
+    if is_cjk(c) || matches!(u,
+        0x1100..=0x115F   | // Hangul Jamo
+        0x2E80..=0x303E   | // CJK radicals, symbols and punctuation
+        0x3041..=0x33FF   | // Kana, Bopomofo, CJK compatibility
+        0xA960..=0xA97F   | // Hangul Jamo Extended-A
+        0xAC00..=0xD7A3   | // Hangul Syllables
+        0xFE30..=0xFE4F   | // CJK Compatibility Forms
+        0xFF00..=0xFF60   | // Fullwidth Forms
+        0xFFE0..=0xFFE6   | // Fullwidth signs
+        0x1F300..=0x1F64F | // Misc Symbols and Pictographs, Emoticons
+        0x1F680..=0x1F6FF | // Transport and Map Symbols
+        0x1F900..=0x1F9FF   // Supplemental Symbols and Pictographs
+    ) {
+        return 2;
+    }
+
+    1
+}
+
+/// Alignment for pad_to_width function
+pub enum AlignmentEnum {
+    Left,
+    Right,
+    Center,
+}
+
+/// Returns the number of terminal columns needed to display `s`.
+///
+/// This is the sum of [`char_width`] over every character of the string.
+/// Terminal escape sequences (colors/styles and OSC 8 hyperlinks, see
+/// [`osc8_link`]) are not displayed and take **0** columns.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::formatter::{display_width, osc8_link};
+/// assert_eq!(display_width("hello"), 5);
+/// assert_eq!(display_width("你好"), 4);
+/// assert_eq!(display_width("e\u{301}clair"), 6);
+/// assert_eq!(display_width("\x1b[1mbold\x1b[0m"), 4);
+/// assert_eq!(display_width(&osc8_link("docs", "https://example.com")), 4);
+/// ```
+pub fn display_width(s: &str) -> usize {
+    units(s).map(|(_, w)| w).sum()
+}
+
+/// Wraps `text` in an OSC 8 terminal hyperlink pointing to `url`.
+///
+/// Terminals that support OSC 8 render `text` as a clickable link; other
+/// terminals simply show `text`. The escape sequences have no display width,
+/// so [`display_width`], [`pad_to_width`] and [`elide_middle`] keep tables aligned.
+///
+/// # Arguments
+/// * `text` - The visible text of the link.
+/// * `url` - The link target.
+///
+/// # Returns
+/// `ESC ] 8 ; ; url ESC \ text ESC ] 8 ; ; ESC \`
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::formatter::osc8_link;
+/// assert_eq!(osc8_link("docs", "https://example.com"),
+///            "\x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\");
+/// ```
+pub fn osc8_link(text: &str, url: &str) -> String {
+    format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\")
+}
+
+/// Wraps the byte `ranges` of `text` in bold red ANSI styling, as `grep
+/// --color` shows matches.
+///
+/// Ranges may be unsorted and overlap; empty ranges and ranges that are out
+/// of bounds or not on character boundaries are ignored.
+///
+/// # Arguments
+/// * `text` - The text to highlight.
+/// * `ranges` - Byte ranges of `text` to highlight.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::formatter::highlight;
+/// assert_eq!(highlight("disk full", &[5..9]), "disk \x1b[1;31mfull\x1b[0m");
+/// assert_eq!(highlight("abc", &[]), "abc");
+/// ```
+pub fn highlight(text: &str, ranges: &[Range<usize>]) -> String {
+    let mut ranges: Vec<Range<usize>> = ranges
+        .iter()
+        .filter(|r| r.start < r.end && text.get(r.start..r.end).is_some())
+        .cloned()
+        .collect();
+    ranges.sort_by_key(|r| r.start);
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+
+    let mut out = String::with_capacity(text.len() + merged.len() * 11);
+    let mut position = 0;
+    for range in merged {
+        out.push_str(&text[position..range.start]);
+        out.push_str("\x1b[1;31m");
+        out.push_str(&text[range.clone()]);
+        out.push_str("\x1b[0m");
+        position = range.end;
+    }
+    out.push_str(&text[position..]);
+    out
+}
+
+/// Pads `s` with spaces up to `width` display columns.
+///
+/// Widths are measured with [`display_width`], so wide characters, combining
+/// marks, styles and OSC 8 hyperlinks do not break the alignment of tables.
+/// Strings already `width` columns wide or wider are returned unchanged.
+///
+/// # Arguments
+/// * `s` - The string to pad.
+/// * `width` - The target width in display columns.
+/// * `align` - Where the text goes: `Left` pads on the right, `Right` pads on
+///   the left, and `Center` splits the padding (extra space on the right).
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::formatter::{pad_to_width, AlignmentEnum};
+/// assert_eq!(pad_to_width("ab", 5, AlignmentEnum::Left), "ab   ");
+/// assert_eq!(pad_to_width("ab", 5, AlignmentEnum::Right), "   ab");
+/// assert_eq!(pad_to_width("ab", 5, AlignmentEnum::Center), " ab  ");
+/// assert_eq!(pad_to_width("你好", 5, AlignmentEnum::Left), "你好 ");
+/// ```
+pub fn pad_to_width(s: &str, width: usize, align: AlignmentEnum) -> String {
+    let padding = width.saturating_sub(display_width(s));
+    let (left, right) = match align {
+        AlignmentEnum::Left => (0, padding),
+        AlignmentEnum::Right => (padding, 0),
+        AlignmentEnum::Center => (padding / 2, padding - padding / 2),
+    };
+
+    let mut out = String::with_capacity(s.len() + padding);
+    out.extend(std::iter::repeat_n(' ', left));
+    out.push_str(s);
+    out.extend(std::iter::repeat_n(' ', right));
+    out
+}
+
+/// Shortens a string to at most `max_width` display columns by replacing the
+/// middle with `ellipsis`, keeping the start and the end visible.
+///
+/// This is useful when rendering long IDs and paths in tables, where both
+/// the beginning (root, prefix) and the end (file name, suffix) carry meaning.
+///
+/// # Arguments
+/// * `s` - The string to shorten.
+/// * `max_width` - The maximum number of display columns of the result.
+/// * `ellipsis` - The marker inserted in place of the removed middle (e.g. `"…"`).
+///
+/// # Returns
+/// A new `String`:
+/// - `s` unchanged if it already fits in `max_width` columns.
+/// - head + `ellipsis` + tail otherwise. When the space cannot be split evenly
+///   the head keeps the extra column; columns the head cannot use (because of a
+///   wide character at the boundary) are given to the tail.
+/// - A prefix of `ellipsis` if `max_width` is narrower than the ellipsis itself.
+///
+/// Widths are measured with [`display_width`], so CJK characters and emoji
+/// count as two columns, and combining marks stay attached to their base
+/// character.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::formatter::elide_middle;
+/// assert_eq!(elide_middle("a1b2c3d4e5f6g7h8", 9, "…"), "a1b2…g7h8");
+/// assert_eq!(elide_middle("/very/long/path/file.txt", 15, "…"), "/very/l…ile.txt");
+/// assert_eq!(elide_middle("short", 10, "…"), "short");
+/// assert_eq!(elide_middle("abcdefghij", 5, "..."), "a...j");
+/// assert_eq!(elide_middle("你好世界你好", 7, "…"), "你…你好");
+/// ```
+pub fn elide_middle(s: &str, max_width: usize, ellipsis: &str) -> String {
+    if display_width(s) <= max_width {
+        return s.to_owned();
+    }
+
+    let ellipsis_width = display_width(ellipsis);
+    if ellipsis_width >= max_width {
+        return take_width(ellipsis, max_width).into_owned();
+    }
+
+    let available = max_width - ellipsis_width;
+    let head = take_width(s, available - available / 2);
+    // Columns the head could not use (e.g. a wide char at the boundary) go to the tail
+    let tail = take_width_from_end(s, available - display_width(&head));
+
+    let mut out = String::with_capacity(head.len() + ellipsis.len() + tail.len());
+    out.push_str(&head);
+    out.push_str(ellipsis);
+    out.push_str(tail);
+    out
+}
+
+/// Truncates the text to `cols` terminal columns, ending with an ellipsis
+/// (`…`) when it does not fit.
+///
+/// For right-to-left text (see [`base_direction`](crate::analyzer::base_direction))
+/// the ellipsis is followed by a right-to-left mark, so it stays on the left,
+/// visual end of the text even inside a left-to-right line.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::formatter::{display_width, truncate_display};
+/// assert_eq!(truncate_display("Hello, world", 8), "Hello, …");
+/// assert_eq!(truncate_display("short", 8), "short");
+/// assert_eq!(truncate_display("שלום עולם", 5), "שלום…\u{200F}");
+/// assert_eq!(display_width(&truncate_display("שלום עולם", 5)), 5);
+/// ```
+pub fn truncate_display(text: &str, cols: usize) -> String {
+    if display_width(text) <= cols {
+        return text.to_owned();
+    }
+    if cols == 0 {
+        return String::new();
+    }
+
+    let mut out = take_width(text, cols - 1).into_owned();
+    out.push('…');
+    if crate::analyzer::base_direction(text) == crate::analyzer::Direction::Rtl {
+        out.push('\u{200F}');
+    }
+    out
+}
+
+/// Renumbers ordered list items so each list counts up from its first item,
+/// fixing the numbering after items were added, removed or moved.
+///
+/// Lists and nesting levels are found with
+/// [`detect_list_items`](crate::analyzer::detect_list_items). Nested lists
+/// are numbered on their own, and a bullet item at the same level restarts
+/// the count. The `.` or `)` delimiter of each item is kept, and numbers
+/// stop at `u64::MAX` instead of overflowing.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::formatter::renumber_ordered_list;
+/// assert_eq!(renumber_ordered_list("1. a\n5. b\n   1. b1\n   1. b2\n2. c\n"),
+///            "1. a\n2. b\n   1. b1\n   2. b2\n3. c\n");
+/// assert_eq!(renumber_ordered_list("3) x\n3) y"), "3) x\n4) y");
+/// ```
+pub fn renumber_ordered_list(text: &str) -> String {
+    let mut items = crate::analyzer::detect_list_items(text).into_iter().peekable();
+    let mut next_numbers: Vec<Option<u64>> = Vec::new();
+    let mut list = 0;
+    let mut out = String::with_capacity(text.len());
+
+    for (line_index, line) in text.split_inclusive('\n').enumerate() {
+        let Some(item) = items.next_if(|item| item.line == line_index) else {
+            out.push_str(line);
+            continue;
+        };
+        if item.list != list {
+            list = item.list;
+            next_numbers.clear();
+        }
+        next_numbers.resize(item.level + 1, None);
+
+        let Some(number) = item.number else {
+            next_numbers[item.level] = None;
+            out.push_str(line);
+            continue;
+        };
+        let number = next_numbers[item.level].unwrap_or(number);
+        next_numbers[item.level] = Some(number.saturating_add(1));
+
+        let body = line.trim_start();
+        out.push_str(&line[..line.len() - body.len()]);
+        out.push_str(&number.to_string());
+        out.push_str(&body[item.marker.len() - 1..]);
+    }
+
+    out
+}
+
+/// Removes terminal escape sequences (CSI colors and styles, OSC hyperlinks
+/// and titles) from `s`, e.g. from captured terminal logs before counting
+/// words or columns.
+///
+/// An `ESC` that does not start a CSI or OSC sequence is kept.
+///
+/// # Returns
+/// `Cow::Borrowed(s)` without allocating when there is no escape sequence,
+/// otherwise the stripped `String`.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::formatter::{osc8_link, strip_ansi};
+/// assert_eq!(strip_ansi("\x1b[1;31mError:\x1b[0m disk full"), "Error: disk full");
+/// assert_eq!(strip_ansi(&osc8_link("docs", "https://example.com")), "docs");
+/// assert!(matches!(strip_ansi("plain"), std::borrow::Cow::Borrowed("plain")));
+/// ```
+pub fn strip_ansi(s: &str) -> Cow<'_, str> {
+    let mut out = String::new();
+    let mut copied = 0;
+    for (i, _) in s.match_indices('\x1b') {
+        if i < copied {
+            continue;
+        }
+        if let Some(len) = escape_sequence_len(&s[i..]) {
+            out.push_str(&s[copied..i]);
+            copied = i + len;
+        }
+    }
+    if copied == 0 {
+        return Cow::Borrowed(s);
+    }
+    out.push_str(&s[copied..]);
+    Cow::Owned(out)
+}
+
+/// Returns the length in bytes of the terminal escape sequence at the start
+/// of `s`, or `None` if `s` does not start with one.
+///
+/// Recognizes CSI sequences (`ESC [ ... final`) used for colors and styles,
+/// and OSC sequences (`ESC ] ... BEL` or `ESC ] ... ESC \`) used for
+/// hyperlinks and window titles. Unterminated sequences extend to the end.
+pub(crate) fn escape_sequence_len(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    if bytes.len() < 2 || bytes[0] != 0x1b {
+        return None;
+    }
+
+    match bytes[1] {
+        b'[' => {
+            let end = bytes[2..].iter().position(|b| (0x40..=0x7e).contains(b));
+            Some(end.map_or(bytes.len(), |i| i + 3))
+        }
+        b']' => {
+            let mut i = 2;
+            while i < bytes.len() {
+                match bytes[i] {
+                    0x07 => return Some(i + 1),
+                    0x1b if bytes.get(i + 1) == Some(&b'\\') => return Some(i + 2),
+                    _ => i += 1,
+                }
+            }
+            Some(bytes.len())
+        }
+        _ => None,
+    }
+}
+
+/// Iterates over the display units of `s`: either a whole escape sequence or a
+/// single character, as `(byte_index, width)`.
+fn units(s: &str) -> impl DoubleEndedIterator<Item = (usize, usize)> {
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < s.len() {
+        let (len, width) = match escape_sequence_len(&s[i..]) {
+            Some(len) => (len, 0),
+            None => {
+                let c = s[i..].chars().next().unwrap();
+                (c.len_utf8(), char_width(c))
+            }
+        };
+        out.push((i, width));
+        i += len;
+    }
+    out.into_iter()
+}
+
+/// Returns the longest prefix of `s` that fits in `width` columns, see
+/// [`take_width`].
+pub(crate) fn fitting_prefix(s: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (i, w) in units(s) {
+        if used + w > width {
+            return &s[..i];
+        }
+        used += w;
+    }
+    s
+}
+
+/// Returns the longest prefix of `s` that fits in `width` columns.
+/// Zero-width characters following the last included character are kept.
+///
+/// A hyperlink or a style opened in the prefix but closed after the cut is
+/// closed at its end (`ESC ] 8 ; ; ESC \` and `ESC [ 0 m`), so it does not
+/// leak into the text that follows.
+pub(crate) fn take_width(s: &str, width: usize) -> Cow<'_, str> {
+    let head = fitting_prefix(s, width);
+    match closing_sequences(head) {
+        "" => Cow::Borrowed(head),
+        closing => Cow::Owned(format!("{head}{closing}")),
+    }
+}
+
+/// Returns the escape sequences closing the OSC 8 hyperlink and the SGR
+/// styles still open at the end of `s`.
+pub(crate) fn closing_sequences(s: &str) -> &'static str {
+    let (mut link_open, mut style_open) = (false, false);
+    let mut next = 0;
+    for (i, _) in s.match_indices('\x1b') {
+        if i < next {
+            continue;
+        }
+        let Some(len) = escape_sequence_len(&s[i..]) else { continue };
+        next = i + len;
+        let sequence = &s[i + 2..next];
+        if s[i + 1..].starts_with(']') {
+            // `8 ; params ; uri` and the terminator; an empty URI ends the link
+            if let Some((_, uri)) = sequence.strip_prefix("8;").and_then(|link| link.split_once(';')) {
+                link_open = !uri.trim_end_matches(['\x07', '\x1b', '\\']).is_empty();
+            }
+        } else if let Some(params) = sequence.strip_suffix('m') {
+            // The last parameter decides: an empty one or `0` resets the styles
+            style_open = !matches!(params.rsplit(';').next(), Some("" | "0"));
+        }
+    }
+    match (link_open, style_open) {
+        (true, true) => "\x1b]8;;\x1b\\\x1b[0m",
+        (true, false) => "\x1b]8;;\x1b\\",
+        (false, true) => "\x1b[0m",
+        (false, false) => "",
+    }
+}
+
+/// Returns the longest suffix of `s` that fits in `width` columns, without
+/// separating combining marks from their base character.
+fn take_width_from_end(s: &str, width: usize) -> &str {
+    let mut used = 0;
+    let mut start = s.len();
+    for (i, w) in units(s).rev() {
+        if used + w > width {
+            break;
+        }
+        used += w;
+        if w > 0 {
+            start = i;
+        }
+    }
+    &s[start..]
+}
@@ -0,0 +1,171 @@
+/// Computes the 64-bit FNV-1a hash of a byte slice.
+///
+/// Unlike `std::collections::hash_map::DefaultHasher`, the result is stable
+/// across runs, platforms and Rust versions, so it can be stored or used to
+/// derive deterministic values.
+///
+/// # Arguments
+/// * `bytes` - The data to hash.
+///
+/// # Returns
+/// The 64-bit hash value.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::hasher::fnv1a_64;
+/// assert_eq!(fnv1a_64(b""), 0xcbf29ce484222325);
+/// assert_eq!(fnv1a_64(b"a"), 0xaf63dc4c8601ec8c);
+/// ```
+pub fn fnv1a_64(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, b| (hash ^ *b as u64).wrapping_mul(PRIME))
+}
+
+/// Computes the SipHash-2-4 keyed hash of a byte slice.
+///
+/// SipHash is a keyed pseudo-random function: without the 128-bit `key` the
+/// output cannot be predicted or reversed, and the same key and input always
+/// give the same result across runs and platforms.
+///
+/// # Arguments
+/// * `key` - The 16-byte secret key.
+/// * `bytes` - The data to hash.
+///
+/// # Returns
+/// The 64-bit hash value.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::hasher::siphash24;
+/// let key: [u8; 16] = core::array::from_fn(|i| i as u8);
+/// // Reference vector from the SipHash paper
+/// assert_eq!(siphash24(&key, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]), 0xa129ca6149be45e5);
+/// ```
+pub fn siphash24(key: &[u8; 16], bytes: &[u8]) -> u64 {
+    let k0 = u64::from_le_bytes(key[..8].try_into().unwrap());
+    let k1 = u64::from_le_bytes(key[8..].try_into().unwrap());
+    let mut v = [
+        k0 ^ 0x736f6d6570736575,
+        k1 ^ 0x646f72616e646f6d,
+        k0 ^ 0x6c7967656e657261,
+        k1 ^ 0x7465646279746573,
+    ];
+
+    let chunks = bytes.chunks_exact(8);
+    let tail = chunks.remainder();
+    for chunk in chunks {
+        let m = u64::from_le_bytes(chunk.try_into().unwrap());
+        v[3] ^= m;
+        sip_round(&mut v);
+        sip_round(&mut v);
+        v[0] ^= m;
+    }
+
+    let mut last = (bytes.len() as u64 & 0xff) << 56;
+    for (i, b) in tail.iter().enumerate() {
+        last |= (*b as u64) << (8 * i);
+    }
+    v[3] ^= last;
+    sip_round(&mut v);
+    sip_round(&mut v);
+    v[0] ^= last;
+
+    v[2] ^= 0xff;
+    for _ in 0..4 {
+        sip_round(&mut v);
+    }
+    v[0] ^ v[1] ^ v[2] ^ v[3]
+}
+
+fn sip_round(v: &mut [u64; 4]) {
+    v[0] = v[0].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(13) ^ v[0];
+    v[0] = v[0].rotate_left(32);
+    v[2] = v[2].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(16) ^ v[2];
+    v[0] = v[0].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(21) ^ v[0];
+    v[2] = v[2].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(17) ^ v[2];
+    v[2] = v[2].rotate_left(32);
+}
+
+/// Computes the 64-bit SimHash of a text from its character 3-shingles.
+///
+/// Similar texts get hashes that differ in few bits, so the Hamming distance
+/// between two SimHashes (`(a ^ b).count_ones()`) estimates how different the
+/// texts are. Each shingle is hashed with [`fnv1a_64`].
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::hasher::simhash;
+/// let a = simhash("user 1234 logged in from 10.0.0.1");
+/// let b = simhash("user 1235 logged in from 10.0.0.1");
+/// let c = simhash("disk /dev/sda1 is almost full");
+/// assert!((a ^ b).count_ones() < (a ^ c).count_ones());
+/// assert_eq!(simhash(""), 0);
+/// ```
+pub fn simhash(text: &str) -> u64 {
+    let chars: Vec<char> = text.chars().collect();
+    let mut weights = [0i32; 64];
+    let mut buf = [0u8; 12];
+
+    let mut add = |shingle: &[char]| {
+        let mut len = 0;
+        for c in shingle {
+            len += c.encode_utf8(&mut buf[len..]).len();
+        }
+        let h = fnv1a_64(&buf[..len]);
+        for (bit, w) in weights.iter_mut().enumerate() {
+            *w += if h >> bit & 1 == 1 { 1 } else { -1 };
+        }
+    };
+
+    if chars.len() < 3 {
+        if !chars.is_empty() {
+            add(&chars);
+        }
+    } else {
+        chars.windows(3).for_each(&mut add);
+    }
+
+    weights
+        .iter()
+        .enumerate()
+        .filter(|(_, w)| **w > 0)
+        .fold(0, |hash, (bit, _)| hash | 1 << bit)
+}
+
+/// Computes a fingerprint of a text that ignores formatting differences, so
+/// trivially reformatted duplicates of a document get the same value.
+///
+/// Before hashing with [`fnv1a_64`]:
+/// - Punctuation is removed (see [`crate::analyzer::is_punctuation`]).
+/// - The text is lowercased (Unicode-aware).
+/// - Runs of whitespace (spaces, tabs, newlines, Unicode spaces) become a
+///   single space, and leading/trailing whitespace is ignored.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::hasher::canonical_fingerprint;
+/// assert_eq!(canonical_fingerprint("Hello, World!"), canonical_fingerprint("hello   world"));
+/// assert_eq!(canonical_fingerprint("  Line one.\nLine two "), canonical_fingerprint("line one line two"));
+/// assert_ne!(canonical_fingerprint("hello world"), canonical_fingerprint("hello there"));
+/// ```
+pub fn canonical_fingerprint(text: &str) -> u64 {
+    let mut canonical = String::with_capacity(text.len());
+    for word in text.split_whitespace() {
+        let start = canonical.len();
+        if start > 0 {
+            canonical.push(' ');
+        }
+        canonical.extend(word.chars().filter(|c| !crate::analyzer::is_punctuation(*c)).flat_map(char::to_lowercase));
+        // A word made only of punctuation adds nothing, not even a space
+        if canonical.len() == start + usize::from(start > 0) {
+            canonical.truncate(start);
+        }
+    }
+    fnv1a_64(canonical.as_bytes())
+}
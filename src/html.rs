@@ -0,0 +1,199 @@
+/// Elements whose content is not text and is removed with them.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "template", "noscript"];
+
+/// Elements that start a new block: replaced with a newline so words on both
+/// sides stay apart.
+const BLOCK_ELEMENTS: &[&str] = &[
+    "address", "article", "aside", "blockquote", "br", "dd", "div", "dl", "dt", "figcaption", "figure", "footer",
+    "form", "h1", "h2", "h3", "h4", "h5", "h6", "header", "hr", "li", "main", "nav", "ol", "p", "pre", "section",
+    "table", "td", "th", "tr", "ul",
+];
+
+/// Named character references, sorted by name: the Latin-1 set, the markup
+/// characters and common typographic symbols.
+const NAMED_ENTITIES: &[(&str, char)] = &[
+    ("AElig", '\u{c6}'), ("Aacute", '\u{c1}'), ("Acirc", '\u{c2}'), ("Agrave", '\u{c0}'), ("Aring", '\u{c5}'),
+    ("Atilde", '\u{c3}'), ("Auml", '\u{c4}'), ("Ccedil", '\u{c7}'), ("Dagger", '\u{2021}'), ("ETH", '\u{d0}'),
+    ("Eacute", '\u{c9}'), ("Ecirc", '\u{ca}'), ("Egrave", '\u{c8}'), ("Euml", '\u{cb}'), ("Iacute", '\u{cd}'),
+    ("Icirc", '\u{ce}'), ("Igrave", '\u{cc}'), ("Iuml", '\u{cf}'), ("Ntilde", '\u{d1}'), ("OElig", '\u{152}'),
+    ("Oacute", '\u{d3}'), ("Ocirc", '\u{d4}'), ("Ograve", '\u{d2}'), ("Oslash", '\u{d8}'),
+    ("Otilde", '\u{d5}'), ("Ouml", '\u{d6}'), ("Prime", '\u{2033}'), ("Scaron", '\u{160}'),
+    ("THORN", '\u{de}'), ("Uacute", '\u{da}'), ("Ucirc", '\u{db}'), ("Ugrave", '\u{d9}'), ("Uuml", '\u{dc}'),
+    ("Yacute", '\u{dd}'), ("Yuml", '\u{178}'), ("aacute", '\u{e1}'), ("acirc", '\u{e2}'), ("acute", '\u{b4}'),
+    ("aelig", '\u{e6}'), ("agrave", '\u{e0}'), ("amp", '\u{26}'), ("apos", '\u{27}'), ("aring", '\u{e5}'),
+    ("atilde", '\u{e3}'), ("auml", '\u{e4}'), ("bdquo", '\u{201e}'), ("brvbar", '\u{a6}'),
+    ("bull", '\u{2022}'), ("ccedil", '\u{e7}'), ("cedil", '\u{b8}'), ("cent", '\u{a2}'), ("circ", '\u{2c6}'),
+    ("copy", '\u{a9}'), ("curren", '\u{a4}'), ("dagger", '\u{2020}'), ("darr", '\u{2193}'), ("deg", '\u{b0}'),
+    ("divide", '\u{f7}'), ("eacute", '\u{e9}'), ("ecirc", '\u{ea}'), ("egrave", '\u{e8}'),
+    ("emsp", '\u{2003}'), ("ensp", '\u{2002}'), ("eth", '\u{f0}'), ("euml", '\u{eb}'), ("euro", '\u{20ac}'),
+    ("fnof", '\u{192}'), ("frac12", '\u{bd}'), ("frac14", '\u{bc}'), ("frac34", '\u{be}'), ("ge", '\u{2265}'),
+    ("gt", '\u{3e}'), ("harr", '\u{2194}'), ("hellip", '\u{2026}'), ("iacute", '\u{ed}'), ("icirc", '\u{ee}'),
+    ("iexcl", '\u{a1}'), ("igrave", '\u{ec}'), ("infin", '\u{221e}'), ("iquest", '\u{bf}'),
+    ("iuml", '\u{ef}'), ("laquo", '\u{ab}'), ("larr", '\u{2190}'), ("ldquo", '\u{201c}'), ("le", '\u{2264}'),
+    ("lrm", '\u{200e}'), ("lsaquo", '\u{2039}'), ("lsquo", '\u{2018}'), ("lt", '\u{3c}'), ("macr", '\u{af}'),
+    ("mdash", '\u{2014}'), ("micro", '\u{b5}'), ("middot", '\u{b7}'), ("minus", '\u{2212}'),
+    ("nbsp", '\u{a0}'), ("ndash", '\u{2013}'), ("ne", '\u{2260}'), ("not", '\u{ac}'), ("ntilde", '\u{f1}'),
+    ("oacute", '\u{f3}'), ("ocirc", '\u{f4}'), ("oelig", '\u{153}'), ("ograve", '\u{f2}'), ("ordf", '\u{aa}'),
+    ("ordm", '\u{ba}'), ("oslash", '\u{f8}'), ("otilde", '\u{f5}'), ("ouml", '\u{f6}'), ("para", '\u{b6}'),
+    ("permil", '\u{2030}'), ("plusmn", '\u{b1}'), ("pound", '\u{a3}'), ("prime", '\u{2032}'),
+    ("quot", '\u{22}'), ("raquo", '\u{bb}'), ("rarr", '\u{2192}'), ("rdquo", '\u{201d}'), ("reg", '\u{ae}'),
+    ("rlm", '\u{200f}'), ("rsaquo", '\u{203a}'), ("rsquo", '\u{2019}'), ("sbquo", '\u{201a}'),
+    ("scaron", '\u{161}'), ("sect", '\u{a7}'), ("shy", '\u{ad}'), ("sup1", '\u{b9}'), ("sup2", '\u{b2}'),
+    ("sup3", '\u{b3}'), ("szlig", '\u{df}'), ("thinsp", '\u{2009}'), ("thorn", '\u{fe}'),
+    ("tilde", '\u{2dc}'), ("times", '\u{d7}'), ("trade", '\u{2122}'), ("uacute", '\u{fa}'),
+    ("uarr", '\u{2191}'), ("ucirc", '\u{fb}'), ("ugrave", '\u{f9}'), ("uml", '\u{a8}'), ("uuml", '\u{fc}'),
+    ("yacute", '\u{fd}'), ("yen", '\u{a5}'), ("yuml", '\u{ff}'), ("zwj", '\u{200d}'), ("zwnj", '\u{200c}'),
+];
+
+/// Removes HTML markup from `html`, keeping its text.
+///
+/// ### Rules implemented:
+/// - Tags are removed; quoted attribute values may contain `>`.
+/// - Block elements (`p`, `div`, `br`, `li`, headings, table cells...) are
+///   replaced with `\n`, inline elements (`b`, `a`, `span`...) with nothing.
+/// - Comments, `<!DOCTYPE>` and processing instructions are removed.
+/// - The content of `script`, `style`, `template` and `noscript` is removed.
+/// - A `<` that does not start a tag (`a < b`) is kept.
+/// - Entities are not decoded; see [`decode_html_entities`].
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::html::strip_html_tags;
+/// let html = "<!-- nav --><h1>Title</h1><p>Some <b>bold</b> text &amp; <a href=\"/x?a>b\">a link</a>.</p><script>var x = '<p>';</script>";
+/// assert_eq!(strip_html_tags(html), "\nTitle\n\nSome bold text &amp; a link.\n");
+/// assert_eq!(strip_html_tags("1 < 2"), "1 < 2");
+/// ```
+pub fn strip_html_tags(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        out.push_str(&rest[..start]);
+        let tag = &rest[start..];
+
+        if let Some(comment) = tag.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = tag_end(tag) else {
+            out.push('<');
+            rest = &tag[1..];
+            continue;
+        };
+        rest = &tag[end..];
+
+        let name = tag_name(tag);
+        if name.is_empty() {
+            continue;
+        }
+        if !tag.starts_with("</") && RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+            let close = format!("</{name}");
+            rest = find_ci(rest, &close).map_or("", |i| &rest[i..]);
+            continue;
+        }
+        if BLOCK_ELEMENTS.contains(&name.as_str()) {
+            out.push('\n');
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Returns the length of the tag at the start of `tag`, or `None` if the `<`
+/// does not start a tag.
+fn tag_end(tag: &str) -> Option<usize> {
+    let after = tag[1..].chars().next()?;
+    if !(after.is_ascii_alphabetic() || matches!(after, '/' | '!' | '?')) {
+        return None;
+    }
+    let mut quote = None;
+    for (i, c) in tag.char_indices().skip(1) {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, '>') => return Some(i + 1),
+            _ => {}
+        }
+    }
+    Some(tag.len())
+}
+
+/// Lowercase element name of a start or end tag, empty for `<!...>` and `<?...>`.
+fn tag_name(tag: &str) -> String {
+    tag.trim_start_matches('<')
+        .trim_start_matches('/')
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+fn find_ci(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .char_indices()
+        .map(|(i, _)| i)
+        .find(|&i| haystack[i..].get(..needle.len()).is_some_and(|s| s.eq_ignore_ascii_case(needle)))
+}
+
+/// Longest reference decoded, in bytes between `&` and `;`. Longer
+/// candidates are not references, so the search for `;` stops there.
+const MAX_REFERENCE_LEN: usize = 32;
+
+/// Decodes HTML character references: named entities (`&amp;`, `&eacute;`,
+/// `&hellip;`...) and numeric ones (`&#233;`, `&#xE9;`).
+///
+/// References must end with `;`; unknown names are kept as written. Numeric
+/// references to `0`, surrogates or values beyond Unicode decode to `U+FFFD`.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::html::decode_html_entities;
+/// assert_eq!(decode_html_entities("caf&eacute; &amp; cr&#xE8;me &#8212; &lt;b&gt;"), "café & crème — <b>");
+/// assert_eq!(decode_html_entities("AT&T &unknown; &#0;"), "AT&T &unknown; \u{fffd}");
+/// ```
+pub fn decode_html_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        let reference = &rest[start + 1..];
+        let end = reference.bytes().take(MAX_REFERENCE_LEN + 1).position(|b| b == b';');
+        let decoded = end.filter(|end| *end > 0).and_then(|end| {
+            let name = &reference[..end];
+            let c = match name.strip_prefix('#') {
+                Some(number) => decode_numeric(number),
+                None => NAMED_ENTITIES.binary_search_by(|(k, _)| k.cmp(&name)).ok().map(|i| NAMED_ENTITIES[i].1),
+            };
+            c.map(|c| (c, end + 1))
+        });
+
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &reference[len..];
+            }
+            None => {
+                out.push('&');
+                rest = reference;
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+fn decode_numeric(number: &str) -> Option<char> {
+    let (digits, radix) = match number.strip_prefix(['x', 'X']) {
+        Some(hex) => (hex, 16),
+        None => (number, 10),
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+    let value = u32::from_str_radix(digits, radix).unwrap_or(u32::MAX);
+    Some(char::from_u32(value).filter(|c| *c != '\0').unwrap_or(char::REPLACEMENT_CHARACTER))
+}
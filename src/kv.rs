@@ -0,0 +1,763 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Parses a Java-style `.properties` text into a map.
+///
+/// ### Rules implemented:
+/// - One `key=value` or `key:value` (or `key value`) entry per logical line.
+///   Whitespace around the separator is ignored.
+/// - Lines starting with `#` or `!` (after optional whitespace) are comments;
+///   blank lines are ignored.
+/// - A line ending with an odd number of backslashes continues on the next
+///   line; the leading whitespace of the continuation line is dropped.
+/// - Escapes in keys and values: `\t`, `\n`, `\r`, `\f`, `\uXXXX` (UTF-16,
+///   surrogate pairs are combined) and `\` followed by any other character
+///   (e.g. `\=`, `\:`, `\ `, `\\`) meaning that character. An invalid `\u`
+///   escape is kept as written.
+/// - When a key appears several times the last value wins.
+///
+/// # Arguments
+/// * `text` - The content of the properties file.
+///
+/// # Returns
+/// A `HashMap` with the unescaped keys and values.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::kv::parse_properties;
+/// let text = "# comment\nname = caf\\u00e9\npath: C:\\\\temp\nlist = a, \\\n       b\nempty=";
+/// let props = parse_properties(text);
+/// assert_eq!(props["name"], "café");
+/// assert_eq!(props["path"], "C:\\temp");
+/// assert_eq!(props["list"], "a, b");
+/// assert_eq!(props["empty"], "");
+/// ```
+pub fn parse_properties(text: &str) -> HashMap<String, String> {
+    trace_span!("parse_properties", bytes = text.len());
+    let map: HashMap<String, String> = property_entries(text)
+        .map(|(key, value)| {
+            trace_event!(trace, key = %key, "property found");
+            (key.into_owned(), value.into_owned())
+        })
+        .collect();
+    trace_event!(debug, keys = map.len(), "properties parsed");
+    map
+}
+
+/// The unescaped `(key, value)` entries of a `.properties` text, in order.
+/// Entries without escapes or line continuations borrow from the text.
+pub(crate) fn property_entries(text: &str) -> impl Iterator<Item = (Cow<'_, str>, Cow<'_, str>)> {
+    let mut lines = text.lines();
+    std::iter::from_fn(move || {
+        loop {
+            let line = lines.next()?.trim_start();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+                continue;
+            }
+            if !ends_with_continuation(line) {
+                let (key, value) = split_property(line);
+                return Some((unescape_property(key), unescape_property(value)));
+            }
+
+            let mut logical = line.to_owned();
+            while ends_with_continuation(&logical) {
+                logical.pop();
+                match lines.next() {
+                    Some(next) => logical.push_str(next.trim_start()),
+                    None => break,
+                }
+            }
+            let (key, value) = split_property(&logical);
+            return Some((
+                Cow::Owned(unescape_property(key).into_owned()),
+                Cow::Owned(unescape_property(value).into_owned()),
+            ));
+        }
+    })
+}
+
+/// Builds a lookup map from `key=value` strings in one pass, with the same
+/// rules as [`find_value_by_key`](crate::finder::find_value_by_key): the key
+/// is everything before the first `=` (no trimming), items without `=` are
+/// skipped, and the first value of a repeated key wins.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::kv::parse_kv_pairs;
+/// let map = parse_kv_pairs(["name=John", "age=30", "name=Jane", "flag"]);
+/// assert_eq!(map["name"], "John");
+/// assert_eq!(map.len(), 2);
+///
+/// let owned = vec!["url=http://x/?a=b".to_owned()];
+/// assert_eq!(parse_kv_pairs(&owned)["url"], "http://x/?a=b");
+/// ```
+pub fn parse_kv_pairs<I>(lines: I) -> HashMap<String, String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut map = HashMap::new();
+    for line in lines {
+        if let Some((key, value)) = line.as_ref().split_once('=') {
+            map.entry(key.to_owned()).or_insert_with(|| value.to_owned());
+        }
+    }
+    map
+}
+
+/// Same as [`parse_kv_pairs`], keeping every pair in input order, including
+/// repeated keys (like a multimap).
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::kv::parse_kv_pairs_ordered;
+/// let pairs = parse_kv_pairs_ordered("tag=a\ntag=b\nid=1".lines());
+/// assert_eq!(pairs, vec![
+///     ("tag".to_owned(), "a".to_owned()),
+///     ("tag".to_owned(), "b".to_owned()),
+///     ("id".to_owned(), "1".to_owned()),
+/// ]);
+/// ```
+pub fn parse_kv_pairs_ordered<I>(lines: I) -> Vec<(String, String)>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    lines
+        .into_iter()
+        .filter_map(|line| line.as_ref().split_once('=').map(|(key, value)| (key.to_owned(), value.to_owned())))
+        .collect()
+}
+
+/// Kind of error found by the parse_env_block function
+#[derive(Debug, PartialEq)]
+pub enum EnvParseErrorKind {
+    /// The line has no `=`.
+    MissingEquals,
+    /// The key is empty or has characters other than ASCII letters, digits,
+    /// `_` and `.`, or starts with a digit.
+    InvalidKey,
+    /// A quoted value is never closed.
+    UnclosedQuote,
+    /// Text other than a comment follows the closing quote.
+    TrailingText,
+}
+
+/// Error found by the parse_env_block function, with its 1-based line number.
+#[derive(Debug, PartialEq)]
+pub struct EnvParseError {
+    pub line: usize,
+    pub kind: EnvParseErrorKind,
+}
+
+impl std::fmt::Display for EnvParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self.kind {
+            EnvParseErrorKind::MissingEquals => "missing '='",
+            EnvParseErrorKind::InvalidKey => "invalid key",
+            EnvParseErrorKind::UnclosedQuote => "unclosed quote",
+            EnvParseErrorKind::TrailingText => "text after the closing quote",
+        };
+        write!(f, "line {}: {message}", self.line)
+    }
+}
+
+impl std::error::Error for EnvParseError {}
+
+/// Parses a dotenv-style block of `KEY=value` lines.
+///
+/// ### Rules implemented:
+/// - Blank lines and lines starting with `#` are ignored. An optional
+///   `export ` prefix is ignored.
+/// - Whitespace around the key and the unquoted value is trimmed. In an
+///   unquoted value, ` #` starts a comment, and a `\` at the end of the line
+///   continues the value on the next line.
+/// - Double-quoted values can span several lines and support the escapes
+///   `\n`, `\r`, `\t`, `\"`, `\\` and `\$`. Single-quoted values can span
+///   several lines and are taken literally. A comment may follow the quotes;
+///   any other text after them is an error.
+/// - When a key appears several times the last value wins, at the position
+///   of the first one.
+/// - Invalid lines are reported and skipped; parsing continues on the next line.
+///
+/// # Returns
+/// The variables in order of first appearance, and the errors found.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::kv::{parse_env_block, EnvParseErrorKind};
+/// let text = "# database\nexport DB_HOST=localhost # local\nDB_PASS='p#ss'\nMOTD=\"Hello\\nWorld\"\nBROKEN\n";
+/// let (vars, errors) = parse_env_block(text);
+/// assert_eq!(vars, vec![
+///     ("DB_HOST".to_owned(), "localhost".to_owned()),
+///     ("DB_PASS".to_owned(), "p#ss".to_owned()),
+///     ("MOTD".to_owned(), "Hello\nWorld".to_owned()),
+/// ]);
+/// assert_eq!(errors[0].line, 5);
+/// assert_eq!(errors[0].kind, EnvParseErrorKind::MissingEquals);
+/// ```
+pub fn parse_env_block(text: &str) -> (Vec<(String, String)>, Vec<EnvParseError>) {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut vars: Vec<(String, String)> = Vec::new();
+    // Position of each key in vars
+    let mut positions: HashMap<&str, usize> = HashMap::new();
+    let mut errors = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line_number = i + 1;
+        // Trailing whitespace may be inside a quoted value spanning several lines
+        let line = lines[i].trim_start();
+        i += 1;
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").map_or(line, str::trim_start);
+        let Some((key, rest)) = line.split_once('=') else {
+            errors.push(EnvParseError { line: line_number, kind: EnvParseErrorKind::MissingEquals });
+            continue;
+        };
+        let key = key.trim();
+        if !is_env_key(key) {
+            errors.push(EnvParseError { line: line_number, kind: EnvParseErrorKind::InvalidKey });
+            continue;
+        }
+
+        let rest = rest.trim_start();
+        let value = match rest.chars().next() {
+            Some(quote @ ('"' | '\'')) => match quoted_env_value(&rest[1..], &lines[i..], quote) {
+                Some((value, extra_lines, after)) => {
+                    i += extra_lines;
+                    let after = after.trim();
+                    if !after.is_empty() && !after.starts_with('#') {
+                        errors.push(EnvParseError { line: line_number, kind: EnvParseErrorKind::TrailingText });
+                        continue;
+                    }
+                    value
+                }
+                None => {
+                    errors.push(EnvParseError { line: line_number, kind: EnvParseErrorKind::UnclosedQuote });
+                    continue;
+                }
+            },
+            _ => {
+                let mut value = strip_env_comment(rest).trim_end().to_owned();
+                while value.ends_with('\\') && i < lines.len() {
+                    value.pop();
+                    value.push('\n');
+                    value.push_str(strip_env_comment(lines[i].trim()).trim_end());
+                    i += 1;
+                }
+                value
+            }
+        };
+
+        let position = *positions.entry(key).or_insert(vars.len());
+        if position == vars.len() {
+            vars.push((key.to_owned(), value));
+        } else {
+            vars[position].1 = value;
+        }
+    }
+
+    (vars, errors)
+}
+
+fn is_env_key(key: &str) -> bool {
+    key.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
+
+/// Removes a ` #` comment from an unquoted value.
+fn strip_env_comment(value: &str) -> &str {
+    if value.starts_with('#') {
+        return "";
+    }
+    match value.find(" #").or_else(|| value.find("\t#")) {
+        Some(position) => &value[..position],
+        None => value,
+    }
+}
+
+/// Reads a quoted value starting after the opening quote, possibly continuing
+/// on the following lines. Returns the value, the number of extra lines used
+/// and the text after the closing quote.
+fn quoted_env_value<'a>(first: &'a str, following: &[&'a str], quote: char) -> Option<(String, usize, &'a str)> {
+    let mut value = String::new();
+    let mut text = first;
+    let mut extra_lines = 0;
+    loop {
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c == quote {
+                return Some((value, extra_lines, chars.as_str()));
+            }
+            if c == '\\' && quote == '"' {
+                match chars.next() {
+                    Some('n') => value.push('\n'),
+                    Some('r') => value.push('\r'),
+                    Some('t') => value.push('\t'),
+                    Some(escaped @ ('"' | '\\' | '$')) => value.push(escaped),
+                    Some(other) => {
+                        value.push('\\');
+                        value.push(other);
+                    }
+                    None => value.push('\\'),
+                }
+            } else {
+                value.push(c);
+            }
+        }
+        text = following.get(extra_lines)?;
+        extra_lines += 1;
+        value.push('\n');
+    }
+}
+
+/// Differences between two key/value maps, returned by the diff_kv_maps function.
+///
+/// Entries are sorted by key. `Display` renders a compact report with one
+/// line per key, prefixed with `+` (added), `-` (removed) or `~` (changed).
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct KvDiff {
+    /// Keys only in the second map, with their value.
+    pub added: Vec<(String, String)>,
+    /// Keys only in the first map, with their value.
+    pub removed: Vec<(String, String)>,
+    /// Keys in both maps with different values, as `(key, old, new)`.
+    pub changed: Vec<(String, String, String)>,
+}
+
+impl KvDiff {
+    /// Returns `true` if both maps were equal.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl std::fmt::Display for KvDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (key, value) in &self.removed {
+            writeln!(f, "- {key}={value}")?;
+        }
+        for (key, value) in &self.added {
+            writeln!(f, "+ {key}={value}")?;
+        }
+        for (key, old, new) in &self.changed {
+            writeln!(f, "~ {key}: {old} -> {new}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares two key/value maps (for example two parsed configuration files)
+/// and returns the added, removed and changed keys of `b` relative to `a`.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::kv::{diff_kv_maps, parse_properties};
+/// let old = parse_properties("host=db1\nport=5432\ndebug=true");
+/// let new = parse_properties("host=db2\nport=5432\ntimeout=30");
+/// let diff = diff_kv_maps(&old, &new);
+/// assert_eq!(diff.added, vec![("timeout".to_owned(), "30".to_owned())]);
+/// assert_eq!(diff.to_string(), "- debug=true\n+ timeout=30\n~ host: db1 -> db2\n");
+/// ```
+pub fn diff_kv_maps(a: &HashMap<String, String>, b: &HashMap<String, String>) -> KvDiff {
+    let mut diff = KvDiff::default();
+
+    for (key, old) in a {
+        match b.get(key) {
+            None => diff.removed.push((key.clone(), old.clone())),
+            Some(new) if new != old => diff.changed.push((key.clone(), old.clone(), new.clone())),
+            Some(_) => {}
+        }
+    }
+    for (key, new) in b {
+        if !a.contains_key(key) {
+            diff.added.push((key.clone(), new.clone()));
+        }
+    }
+
+    diff.added.sort();
+    diff.removed.sort();
+    diff.changed.sort();
+    diff
+}
+
+/// Conflict strategy for the merge_kv function
+pub enum MergeStrategy {
+    /// The overlay value replaces the base value.
+    Override,
+    /// The base value is kept and the overlay value ignored.
+    KeepBase,
+    /// Both values are kept as a list: `base`, the separator, then `overlay`.
+    ConcatList(String),
+    /// The merge fails on the first conflicting key.
+    ErrorOnConflict,
+}
+
+/// Error returned by merge_kv with [`MergeStrategy::ErrorOnConflict`].
+#[derive(Debug, PartialEq)]
+pub struct MergeConflictError {
+    /// The key with different values in both maps (the smallest one if
+    /// there are several).
+    pub key: String,
+}
+
+impl std::fmt::Display for MergeConflictError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "conflicting values for key {}", self.key)
+    }
+}
+
+impl std::error::Error for MergeConflictError {}
+
+/// Merges an `overlay` map into a `base` map, as when loading layered
+/// configuration (defaults, then file, then environment).
+///
+/// Keys present in only one map are copied. A key present in both with
+/// different values is a conflict, resolved with `strategy`. Equal values
+/// are not conflicts.
+///
+/// # Returns
+/// The merged map and the sorted list of conflicting keys (for
+/// [`MergeStrategy::Override`], the keys that were overridden), or a
+/// [`MergeConflictError`] with [`MergeStrategy::ErrorOnConflict`].
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::kv::{merge_kv, parse_properties, MergeStrategy};
+/// let defaults = parse_properties("host=localhost\nport=80\ntags=web");
+/// let overlay = parse_properties("port=8080\ntags=api");
+///
+/// let (merged, overridden) = merge_kv(&defaults, &overlay, MergeStrategy::Override).unwrap();
+/// assert_eq!(merged["port"], "8080");
+/// assert_eq!(overridden, vec!["port", "tags"]);
+///
+/// let (merged, _) = merge_kv(&defaults, &overlay, MergeStrategy::ConcatList(",".to_owned())).unwrap();
+/// assert_eq!(merged["tags"], "web,api");
+///
+/// let error = merge_kv(&defaults, &overlay, MergeStrategy::ErrorOnConflict).unwrap_err();
+/// assert_eq!(error.key, "port");
+/// ```
+pub fn merge_kv(base: &HashMap<String, String>, overlay: &HashMap<String, String>, strategy: MergeStrategy)
+    -> Result<(HashMap<String, String>, Vec<String>), MergeConflictError> {
+    let mut conflicts: Vec<&String> = overlay.iter()
+        .filter(|(key, value)| base.get(*key).is_some_and(|old| old != *value))
+        .map(|(key, _)| key)
+        .collect();
+    conflicts.sort();
+
+    if let (MergeStrategy::ErrorOnConflict, Some(key)) = (&strategy, conflicts.first()) {
+        return Err(MergeConflictError { key: (*key).clone() });
+    }
+
+    let mut merged = base.clone();
+    for (key, value) in overlay {
+        match merged.get_mut(key) {
+            None => {
+                merged.insert(key.clone(), value.clone());
+            }
+            Some(old) if old != value => match &strategy {
+                MergeStrategy::Override => *old = value.clone(),
+                MergeStrategy::ConcatList(separator) => {
+                    old.push_str(separator);
+                    old.push_str(value);
+                }
+                MergeStrategy::KeepBase | MergeStrategy::ErrorOnConflict => {}
+            },
+            Some(_) => {}
+        }
+    }
+
+    Ok((merged, conflicts.into_iter().cloned().collect()))
+}
+
+/// `true` if the line ends with an odd number of backslashes.
+fn ends_with_continuation(line: &str) -> bool {
+    line.bytes().rev().take_while(|b| *b == b'\\').count() % 2 == 1
+}
+
+/// Splits a logical line into its raw (still escaped) key and value.
+fn split_property(line: &str) -> (&str, &str) {
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '=' || c == ':' {
+            return (&line[..i], line[i + 1..].trim_start());
+        } else if c.is_whitespace() {
+            let rest = line[i..].trim_start();
+            let rest = match rest.strip_prefix(['=', ':']) {
+                Some(value) => value.trim_start(),
+                None => rest,
+            };
+            return (&line[..i], rest);
+        }
+    }
+    (line, "")
+}
+
+fn unescape_property(s: &str) -> Cow<'_, str> {
+    if !s.contains('\\') {
+        return Cow::Borrowed(s);
+    }
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('f') => out.push('\u{0C}'),
+            Some('u') => {
+                let rest = chars.as_str();
+                match decode_utf16_escape(rest) {
+                    Some((decoded, used)) => {
+                        out.push(decoded);
+                        chars = rest[used..].chars();
+                    }
+                    None => out.push_str("\\u"),
+                }
+            }
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+
+    Cow::Owned(out)
+}
+
+/// Decodes the 4 hex digits after `\u`, combining a following `\uXXXX` low
+/// surrogate when the first one is a high surrogate. Returns the character and
+/// the number of bytes consumed from `s`.
+fn decode_utf16_escape(s: &str) -> Option<(char, usize)> {
+    let high = u16::from_str_radix(s.get(..4)?, 16).ok()?;
+    if let Some(c) = char::from_u32(high as u32) {
+        return Some((c, 4));
+    }
+    let low = s.get(4..6).filter(|p| *p == "\\u").and_then(|_| s.get(6..10))?;
+    let low = u16::from_str_radix(low, 16).ok()?;
+    let c = char::decode_utf16([high, low]).next()?.ok()?;
+    Some((c, 10))
+}
+
+/// Error returned by [`KvParser::parse`]. Positions are byte offsets in the
+/// parsed text.
+#[derive(Debug, PartialEq)]
+pub enum KvParseError {
+    /// A quote opened at `position` is never closed.
+    UnclosedQuote { position: usize },
+    /// The pair starting at `position` has no key-value delimiter.
+    MissingDelimiter { position: usize },
+}
+
+impl std::fmt::Display for KvParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KvParseError::UnclosedQuote { position } => write!(f, "unclosed quote at position {position}"),
+            KvParseError::MissingDelimiter { position } => write!(f, "missing key-value delimiter in pair at position {position}"),
+        }
+    }
+}
+
+impl std::error::Error for KvParseError {}
+
+/// Configurable parser for lists of key-value pairs such as
+/// `"a=1; b='x;y'"`, built with chained setters.
+///
+/// ### Rules implemented:
+/// - Pairs are separated by the pair delimiter (`;` by default) and keys
+///   from values by the key-value delimiter (`=` by default). Only the first
+///   key-value delimiter of a pair counts; later ones are part of the value.
+/// - A key or value starting with a quote (`"` or `'` by default) runs until
+///   the matching quote, delimiters included. The quotes are removed.
+/// - The escape character (`\` by default) makes the next character literal,
+///   e.g. `\;` or `\"`.
+/// - Unquoted, unescaped whitespace around keys and values is trimmed
+///   (can be disabled).
+/// - Empty pairs (`a=1;;b=2`, a trailing `;`) are skipped. For repeated keys
+///   the last value wins.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::kv::KvParser;
+/// let map = KvParser::new().parse("a=1; b='x;y'; c = \"quoted \\\" \" ; d=e=f").unwrap();
+/// assert_eq!(map["a"], "1");
+/// assert_eq!(map["b"], "x;y");
+/// assert_eq!(map["c"], "quoted \" ");
+/// assert_eq!(map["d"], "e=f");
+///
+/// let map = KvParser::new()
+///     .key_value_delimiter(':')
+///     .pair_delimiter('\n')
+///     .parse("host: db1\nport: 5432\n")
+///     .unwrap();
+/// assert_eq!(map["port"], "5432");
+/// ```
+#[derive(Debug, Clone)]
+pub struct KvParser {
+    key_value_delimiter: char,
+    pair_delimiter: char,
+    quotes: Vec<char>,
+    escape: Option<char>,
+    trim: bool,
+}
+
+impl Default for KvParser {
+    fn default() -> Self {
+        KvParser {
+            key_value_delimiter: '=',
+            pair_delimiter: ';',
+            quotes: vec!['"', '\''],
+            escape: Some('\\'),
+            trim: true,
+        }
+    }
+}
+
+/// A key or value being parsed.
+#[derive(Default)]
+struct KvField {
+    text: String,
+    /// Length of the text that must survive trimming (quoted or escaped).
+    protected: usize,
+}
+
+impl KvField {
+    fn push(&mut self, c: char, protect: bool) {
+        self.text.push(c);
+        if protect {
+            self.protected = self.text.len();
+        }
+    }
+
+    fn is_blank(&self) -> bool {
+        self.protected == 0 && self.text.trim().is_empty()
+    }
+
+    fn finish(mut self, trim: bool) -> String {
+        if trim {
+            let end = self.protected.max(self.text.trim_end().len());
+            self.text.truncate(end);
+        }
+        self.text
+    }
+}
+
+impl KvParser {
+    /// Creates a parser for `key=value` pairs separated by `;`, with `"` and
+    /// `'` quotes, `\` escapes and whitespace trimming.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the delimiter between a key and its value.
+    pub fn key_value_delimiter(mut self, delimiter: char) -> Self {
+        self.key_value_delimiter = delimiter;
+        self
+    }
+
+    /// Sets the delimiter between pairs.
+    pub fn pair_delimiter(mut self, delimiter: char) -> Self {
+        self.pair_delimiter = delimiter;
+        self
+    }
+
+    /// Sets the quote characters (an empty slice disables quoting).
+    pub fn quotes(mut self, quotes: &[char]) -> Self {
+        self.quotes = quotes.to_vec();
+        self
+    }
+
+    /// Sets the escape character, or disables escaping with `None`.
+    pub fn escape(mut self, escape: Option<char>) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    /// Enables or disables trimming of whitespace around keys and values.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Parses the whole text into a map.
+    ///
+    /// # Returns
+    /// The map of keys to values, or a [`KvParseError`] for an unclosed
+    /// quote or a non-empty pair without key-value delimiter.
+    pub fn parse(&self, text: &str) -> Result<HashMap<String, String>, KvParseError> {
+        trace_span!("kv_parse", bytes = text.len());
+        let mut map = HashMap::new();
+        let mut key = KvField::default();
+        let mut value: Option<KvField> = None;
+        let mut pair_start = 0;
+        let mut quote: Option<(char, usize)> = None;
+        let mut chars = text.char_indices();
+
+        while let Some((i, c)) = chars.next() {
+            let in_value = value.is_some();
+            let field = match value.as_mut() {
+                Some(value) => value,
+                None => &mut key,
+            };
+            if Some(c) == self.escape {
+                match chars.next() {
+                    Some((_, escaped)) => field.push(escaped, true),
+                    None => field.push(c, true),
+                }
+            } else if let Some((open, _)) = quote {
+                if c == open {
+                    quote = None;
+                } else {
+                    field.push(c, true);
+                }
+            } else if self.quotes.contains(&c) && field.is_blank() {
+                if self.trim {
+                    field.text.clear();
+                }
+                quote = Some((c, i));
+            } else if c == self.pair_delimiter {
+                self.insert_pair(&mut map, std::mem::take(&mut key), value.take(), pair_start)?;
+                pair_start = i + c.len_utf8();
+            } else if c == self.key_value_delimiter && !in_value {
+                value = Some(KvField::default());
+            } else if !(self.trim && c.is_whitespace() && field.text.is_empty()) {
+                field.push(c, false);
+            }
+        }
+
+        if let Some((_, position)) = quote {
+            trace_event!(debug, position, "unclosed quote");
+            return Err(KvParseError::UnclosedQuote { position });
+        }
+        self.insert_pair(&mut map, key, value, pair_start)?;
+        trace_event!(debug, keys = map.len(), "key-value pairs parsed");
+        Ok(map)
+    }
+
+    fn insert_pair(&self, map: &mut HashMap<String, String>, key: KvField, value: Option<KvField>, position: usize)
+        -> Result<(), KvParseError> {
+        match value {
+            Some(value) => {
+                let key = key.finish(self.trim);
+                trace_event!(trace, key = %key, position, "key found");
+                map.insert(key, value.finish(self.trim));
+                Ok(())
+            }
+            None if key.is_blank() => Ok(()),
+            None => {
+                trace_event!(debug, position, "pair without key-value delimiter");
+                Err(KvParseError::MissingDelimiter { position })
+            }
+        }
+    }
+}
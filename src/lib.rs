@@ -1,8 +1,67 @@
 //! Multiple String related functions
+
+/// Emits a `tracing` event (`trace_event!(debug, ...)`) when the `tracing`
+/// feature is enabled; expands to nothing otherwise.
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        tracing::$level!($($arg)+);
+    };
+}
+
+/// Enters a `tracing` debug span until the end of the current block when the
+/// `tracing` feature is enabled; expands to nothing otherwise.
+macro_rules! trace_span {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!($($arg)+).entered();
+    };
+}
+
 pub mod finder;
 pub mod cleanser;
 pub mod splitter;
 pub mod analyzer;
+pub mod formatter;
+pub mod hasher;
+pub mod anonymizer;
+pub mod matcher;
+pub mod compressor;
+pub mod log_analyzer;
+pub mod segmenter;
+pub mod builder;
+pub mod kv;
+pub mod escaper;
+pub mod transcoder;
+pub mod stylize;
+pub mod wrap;
+pub mod template;
+pub mod locale;
+pub mod watermark;
+pub mod random;
+pub mod checkdigit;
+pub mod pipe;
+pub mod batch;
+pub mod case;
+pub mod v2;
+pub mod query;
+pub mod cache;
+pub mod sections;
+pub mod translit;
+pub mod moderation;
+pub mod normalize;
+pub mod html;
+pub mod diff;
+pub mod extract;
+pub mod script;
+#[cfg(feature = "arena")]
+pub mod arena;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "testkit")]
+pub mod testkit;
 
 use rand::distr::SampleString;
 use rand::distr::Alphanumeric;
@@ -35,17 +94,58 @@ pub fn generate_url_safe_string(n: usize) -> String {
     Alphanumeric.sample_string(&mut rand::rng(), n)       
 }
 
-
-
-
-
-
-
-
-
-
-
-
-
-
-
+/// Turns a title into a deterministic URL- and filename-safe slug.
+///
+/// # Arguments
+///
+/// * `s` - The text to convert, e.g. an article title.
+/// * `separator` - The character placed between words, usually `-` or `_`.
+///
+/// # Returns
+///
+/// A lowercase `String` made of ASCII letters and digits joined by `separator`.
+///
+/// # Rules implemented
+///
+/// - Diacritics are removed and Latin ligatures expanded (`é` → `e`, `æ` → `ae`, `ß` → `ss`).
+/// - Cyrillic and Greek are romanized with [`translit::transliterate`] (`Привет` → `privet`).
+/// - Apostrophes are dropped inside words (`Don't` → `dont`).
+/// - Any other character that is not an ASCII letter or digit separates
+///   words; separators never repeat and never start or end the slug.
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::slugify;
+/// assert_eq!(slugify("Crème Brûlée: the 10 best recipes!", '-'), "creme-brulee-the-10-best-recipes");
+/// assert_eq!(slugify("  Don't Panic -- Straße  ", '_'), "dont_panic_strasse");
+/// assert_eq!(slugify("Привет мир", '-'), "privet-mir");
+/// ```
+///
+/// # Notes
+///
+/// - Complements [`generate_url_safe_string`]: one makes random safe strings,
+///   the other deterministic safe strings from titles.
+pub fn slugify(s: &str, separator: char) -> String {
+    let mut slug = String::with_capacity(s.len());
+    let mut pending_separator = false;
+    for c in cleanser::remove_diacritics(&translit::transliterate(s)).chars() {
+        if matches!(c, '\'' | '\u{2019}') {
+            continue;
+        }
+        let folded = locale::fold_latin(c);
+        let letters = folded.map_or_else(|| vec![c], |f| f.chars().filter(|m| !analyzer::is_combining_mark(*m)).collect());
+        for letter in letters {
+            if letter.is_ascii_alphanumeric() {
+                if pending_separator && !slug.is_empty() {
+                    slug.push(separator);
+                }
+                pending_separator = false;
+                slug.push(letter.to_ascii_lowercase());
+            } else {
+                pending_separator = true;
+            }
+        }
+    }
+    slug
+}
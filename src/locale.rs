@@ -0,0 +1,412 @@
+use std::cmp::Ordering;
+
+use crate::analyzer::is_combining_mark;
+
+/// Canonical decompositions (NFD) of the precomposed Latin letters of the
+/// Latin-1 Supplement, Latin Extended-A/B and Latin Extended Additional
+/// blocks, sorted by character: base ASCII letter followed by combining marks.
+const LATIN_DECOMPOSITIONS: &[(char, &str)] = &[
+    ('À', "A\u{300}"), ('Á', "A\u{301}"), ('Â', "A\u{302}"), ('Ã', "A\u{303}"), ('Ä', "A\u{308}"), ('Å', "A\u{30a}"),
+    ('Ç', "C\u{327}"), ('È', "E\u{300}"), ('É', "E\u{301}"), ('Ê', "E\u{302}"), ('Ë', "E\u{308}"), ('Ì', "I\u{300}"),
+    ('Í', "I\u{301}"), ('Î', "I\u{302}"), ('Ï', "I\u{308}"), ('Ñ', "N\u{303}"), ('Ò', "O\u{300}"), ('Ó', "O\u{301}"),
+    ('Ô', "O\u{302}"), ('Õ', "O\u{303}"), ('Ö', "O\u{308}"), ('Ù', "U\u{300}"), ('Ú', "U\u{301}"), ('Û', "U\u{302}"),
+    ('Ü', "U\u{308}"), ('Ý', "Y\u{301}"), ('à', "a\u{300}"), ('á', "a\u{301}"), ('â', "a\u{302}"), ('ã', "a\u{303}"),
+    ('ä', "a\u{308}"), ('å', "a\u{30a}"), ('ç', "c\u{327}"), ('è', "e\u{300}"), ('é', "e\u{301}"), ('ê', "e\u{302}"),
+    ('ë', "e\u{308}"), ('ì', "i\u{300}"), ('í', "i\u{301}"), ('î', "i\u{302}"), ('ï', "i\u{308}"), ('ñ', "n\u{303}"),
+    ('ò', "o\u{300}"), ('ó', "o\u{301}"), ('ô', "o\u{302}"), ('õ', "o\u{303}"), ('ö', "o\u{308}"), ('ù', "u\u{300}"),
+    ('ú', "u\u{301}"), ('û', "u\u{302}"), ('ü', "u\u{308}"), ('ý', "y\u{301}"), ('ÿ', "y\u{308}"), ('Ā', "A\u{304}"),
+    ('ā', "a\u{304}"), ('Ă', "A\u{306}"), ('ă', "a\u{306}"), ('Ą', "A\u{328}"), ('ą', "a\u{328}"), ('Ć', "C\u{301}"),
+    ('ć', "c\u{301}"), ('Ĉ', "C\u{302}"), ('ĉ', "c\u{302}"), ('Ċ', "C\u{307}"), ('ċ', "c\u{307}"), ('Č', "C\u{30c}"),
+    ('č', "c\u{30c}"), ('Ď', "D\u{30c}"), ('ď', "d\u{30c}"), ('Ē', "E\u{304}"), ('ē', "e\u{304}"), ('Ĕ', "E\u{306}"),
+    ('ĕ', "e\u{306}"), ('Ė', "E\u{307}"), ('ė', "e\u{307}"), ('Ę', "E\u{328}"), ('ę', "e\u{328}"), ('Ě', "E\u{30c}"),
+    ('ě', "e\u{30c}"), ('Ĝ', "G\u{302}"), ('ĝ', "g\u{302}"), ('Ğ', "G\u{306}"), ('ğ', "g\u{306}"), ('Ġ', "G\u{307}"),
+    ('ġ', "g\u{307}"), ('Ģ', "G\u{327}"), ('ģ', "g\u{327}"), ('Ĥ', "H\u{302}"), ('ĥ', "h\u{302}"), ('Ĩ', "I\u{303}"),
+    ('ĩ', "i\u{303}"), ('Ī', "I\u{304}"), ('ī', "i\u{304}"), ('Ĭ', "I\u{306}"), ('ĭ', "i\u{306}"), ('Į', "I\u{328}"),
+    ('į', "i\u{328}"), ('İ', "I\u{307}"), ('Ĵ', "J\u{302}"), ('ĵ', "j\u{302}"), ('Ķ', "K\u{327}"), ('ķ', "k\u{327}"),
+    ('Ĺ', "L\u{301}"), ('ĺ', "l\u{301}"), ('Ļ', "L\u{327}"), ('ļ', "l\u{327}"), ('Ľ', "L\u{30c}"), ('ľ', "l\u{30c}"),
+    ('Ń', "N\u{301}"), ('ń', "n\u{301}"), ('Ņ', "N\u{327}"), ('ņ', "n\u{327}"), ('Ň', "N\u{30c}"), ('ň', "n\u{30c}"),
+    ('Ō', "O\u{304}"), ('ō', "o\u{304}"), ('Ŏ', "O\u{306}"), ('ŏ', "o\u{306}"), ('Ő', "O\u{30b}"), ('ő', "o\u{30b}"),
+    ('Ŕ', "R\u{301}"), ('ŕ', "r\u{301}"), ('Ŗ', "R\u{327}"), ('ŗ', "r\u{327}"), ('Ř', "R\u{30c}"), ('ř', "r\u{30c}"),
+    ('Ś', "S\u{301}"), ('ś', "s\u{301}"), ('Ŝ', "S\u{302}"), ('ŝ', "s\u{302}"), ('Ş', "S\u{327}"), ('ş', "s\u{327}"),
+    ('Š', "S\u{30c}"), ('š', "s\u{30c}"), ('Ţ', "T\u{327}"), ('ţ', "t\u{327}"), ('Ť', "T\u{30c}"), ('ť', "t\u{30c}"),
+    ('Ũ', "U\u{303}"), ('ũ', "u\u{303}"), ('Ū', "U\u{304}"), ('ū', "u\u{304}"), ('Ŭ', "U\u{306}"), ('ŭ', "u\u{306}"),
+    ('Ů', "U\u{30a}"), ('ů', "u\u{30a}"), ('Ű', "U\u{30b}"), ('ű', "u\u{30b}"), ('Ų', "U\u{328}"), ('ų', "u\u{328}"),
+    ('Ŵ', "W\u{302}"), ('ŵ', "w\u{302}"), ('Ŷ', "Y\u{302}"), ('ŷ', "y\u{302}"), ('Ÿ', "Y\u{308}"), ('Ź', "Z\u{301}"),
+    ('ź', "z\u{301}"), ('Ż', "Z\u{307}"), ('ż', "z\u{307}"), ('Ž', "Z\u{30c}"), ('ž', "z\u{30c}"), ('Ơ', "O\u{31b}"),
+    ('ơ', "o\u{31b}"), ('Ư', "U\u{31b}"), ('ư', "u\u{31b}"), ('Ǎ', "A\u{30c}"), ('ǎ', "a\u{30c}"), ('Ǐ', "I\u{30c}"),
+    ('ǐ', "i\u{30c}"), ('Ǒ', "O\u{30c}"), ('ǒ', "o\u{30c}"), ('Ǔ', "U\u{30c}"), ('ǔ', "u\u{30c}"), ('Ǖ', "U\u{308}\u{304}"),
+    ('ǖ', "u\u{308}\u{304}"), ('Ǘ', "U\u{308}\u{301}"), ('ǘ', "u\u{308}\u{301}"), ('Ǚ', "U\u{308}\u{30c}"), ('ǚ', "u\u{308}\u{30c}"), ('Ǜ', "U\u{308}\u{300}"),
+    ('ǜ', "u\u{308}\u{300}"), ('Ǟ', "A\u{308}\u{304}"), ('ǟ', "a\u{308}\u{304}"), ('Ǡ', "A\u{307}\u{304}"), ('ǡ', "a\u{307}\u{304}"), ('Ǧ', "G\u{30c}"),
+    ('ǧ', "g\u{30c}"), ('Ǩ', "K\u{30c}"), ('ǩ', "k\u{30c}"), ('Ǫ', "O\u{328}"), ('ǫ', "o\u{328}"), ('Ǭ', "O\u{328}\u{304}"),
+    ('ǭ', "o\u{328}\u{304}"), ('ǰ', "j\u{30c}"), ('Ǵ', "G\u{301}"), ('ǵ', "g\u{301}"), ('Ǹ', "N\u{300}"), ('ǹ', "n\u{300}"),
+    ('Ǻ', "A\u{30a}\u{301}"), ('ǻ', "a\u{30a}\u{301}"), ('Ȁ', "A\u{30f}"), ('ȁ', "a\u{30f}"), ('Ȃ', "A\u{311}"), ('ȃ', "a\u{311}"),
+    ('Ȅ', "E\u{30f}"), ('ȅ', "e\u{30f}"), ('Ȇ', "E\u{311}"), ('ȇ', "e\u{311}"), ('Ȉ', "I\u{30f}"), ('ȉ', "i\u{30f}"),
+    ('Ȋ', "I\u{311}"), ('ȋ', "i\u{311}"), ('Ȍ', "O\u{30f}"), ('ȍ', "o\u{30f}"), ('Ȏ', "O\u{311}"), ('ȏ', "o\u{311}"),
+    ('Ȑ', "R\u{30f}"), ('ȑ', "r\u{30f}"), ('Ȓ', "R\u{311}"), ('ȓ', "r\u{311}"), ('Ȕ', "U\u{30f}"), ('ȕ', "u\u{30f}"),
+    ('Ȗ', "U\u{311}"), ('ȗ', "u\u{311}"), ('Ș', "S\u{326}"), ('ș', "s\u{326}"), ('Ț', "T\u{326}"), ('ț', "t\u{326}"),
+    ('Ȟ', "H\u{30c}"), ('ȟ', "h\u{30c}"), ('Ȧ', "A\u{307}"), ('ȧ', "a\u{307}"), ('Ȩ', "E\u{327}"), ('ȩ', "e\u{327}"),
+    ('Ȫ', "O\u{308}\u{304}"), ('ȫ', "o\u{308}\u{304}"), ('Ȭ', "O\u{303}\u{304}"), ('ȭ', "o\u{303}\u{304}"), ('Ȯ', "O\u{307}"), ('ȯ', "o\u{307}"),
+    ('Ȱ', "O\u{307}\u{304}"), ('ȱ', "o\u{307}\u{304}"), ('Ȳ', "Y\u{304}"), ('ȳ', "y\u{304}"), ('Ḁ', "A\u{325}"), ('ḁ', "a\u{325}"),
+    ('Ḃ', "B\u{307}"), ('ḃ', "b\u{307}"), ('Ḅ', "B\u{323}"), ('ḅ', "b\u{323}"), ('Ḇ', "B\u{331}"), ('ḇ', "b\u{331}"),
+    ('Ḉ', "C\u{327}\u{301}"), ('ḉ', "c\u{327}\u{301}"), ('Ḋ', "D\u{307}"), ('ḋ', "d\u{307}"), ('Ḍ', "D\u{323}"), ('ḍ', "d\u{323}"),
+    ('Ḏ', "D\u{331}"), ('ḏ', "d\u{331}"), ('Ḑ', "D\u{327}"), ('ḑ', "d\u{327}"), ('Ḓ', "D\u{32d}"), ('ḓ', "d\u{32d}"),
+    ('Ḕ', "E\u{304}\u{300}"), ('ḕ', "e\u{304}\u{300}"), ('Ḗ', "E\u{304}\u{301}"), ('ḗ', "e\u{304}\u{301}"), ('Ḙ', "E\u{32d}"), ('ḙ', "e\u{32d}"),
+    ('Ḛ', "E\u{330}"), ('ḛ', "e\u{330}"), ('Ḝ', "E\u{327}\u{306}"), ('ḝ', "e\u{327}\u{306}"), ('Ḟ', "F\u{307}"), ('ḟ', "f\u{307}"),
+    ('Ḡ', "G\u{304}"), ('ḡ', "g\u{304}"), ('Ḣ', "H\u{307}"), ('ḣ', "h\u{307}"), ('Ḥ', "H\u{323}"), ('ḥ', "h\u{323}"),
+    ('Ḧ', "H\u{308}"), ('ḧ', "h\u{308}"), ('Ḩ', "H\u{327}"), ('ḩ', "h\u{327}"), ('Ḫ', "H\u{32e}"), ('ḫ', "h\u{32e}"),
+    ('Ḭ', "I\u{330}"), ('ḭ', "i\u{330}"), ('Ḯ', "I\u{308}\u{301}"), ('ḯ', "i\u{308}\u{301}"), ('Ḱ', "K\u{301}"), ('ḱ', "k\u{301}"),
+    ('Ḳ', "K\u{323}"), ('ḳ', "k\u{323}"), ('Ḵ', "K\u{331}"), ('ḵ', "k\u{331}"), ('Ḷ', "L\u{323}"), ('ḷ', "l\u{323}"),
+    ('Ḹ', "L\u{323}\u{304}"), ('ḹ', "l\u{323}\u{304}"), ('Ḻ', "L\u{331}"), ('ḻ', "l\u{331}"), ('Ḽ', "L\u{32d}"), ('ḽ', "l\u{32d}"),
+    ('Ḿ', "M\u{301}"), ('ḿ', "m\u{301}"), ('Ṁ', "M\u{307}"), ('ṁ', "m\u{307}"), ('Ṃ', "M\u{323}"), ('ṃ', "m\u{323}"),
+    ('Ṅ', "N\u{307}"), ('ṅ', "n\u{307}"), ('Ṇ', "N\u{323}"), ('ṇ', "n\u{323}"), ('Ṉ', "N\u{331}"), ('ṉ', "n\u{331}"),
+    ('Ṋ', "N\u{32d}"), ('ṋ', "n\u{32d}"), ('Ṍ', "O\u{303}\u{301}"), ('ṍ', "o\u{303}\u{301}"), ('Ṏ', "O\u{303}\u{308}"), ('ṏ', "o\u{303}\u{308}"),
+    ('Ṑ', "O\u{304}\u{300}"), ('ṑ', "o\u{304}\u{300}"), ('Ṓ', "O\u{304}\u{301}"), ('ṓ', "o\u{304}\u{301}"), ('Ṕ', "P\u{301}"), ('ṕ', "p\u{301}"),
+    ('Ṗ', "P\u{307}"), ('ṗ', "p\u{307}"), ('Ṙ', "R\u{307}"), ('ṙ', "r\u{307}"), ('Ṛ', "R\u{323}"), ('ṛ', "r\u{323}"),
+    ('Ṝ', "R\u{323}\u{304}"), ('ṝ', "r\u{323}\u{304}"), ('Ṟ', "R\u{331}"), ('ṟ', "r\u{331}"), ('Ṡ', "S\u{307}"), ('ṡ', "s\u{307}"),
+    ('Ṣ', "S\u{323}"), ('ṣ', "s\u{323}"), ('Ṥ', "S\u{301}\u{307}"), ('ṥ', "s\u{301}\u{307}"), ('Ṧ', "S\u{30c}\u{307}"), ('ṧ', "s\u{30c}\u{307}"),
+    ('Ṩ', "S\u{323}\u{307}"), ('ṩ', "s\u{323}\u{307}"), ('Ṫ', "T\u{307}"), ('ṫ', "t\u{307}"), ('Ṭ', "T\u{323}"), ('ṭ', "t\u{323}"),
+    ('Ṯ', "T\u{331}"), ('ṯ', "t\u{331}"), ('Ṱ', "T\u{32d}"), ('ṱ', "t\u{32d}"), ('Ṳ', "U\u{324}"), ('ṳ', "u\u{324}"),
+    ('Ṵ', "U\u{330}"), ('ṵ', "u\u{330}"), ('Ṷ', "U\u{32d}"), ('ṷ', "u\u{32d}"), ('Ṹ', "U\u{303}\u{301}"), ('ṹ', "u\u{303}\u{301}"),
+    ('Ṻ', "U\u{304}\u{308}"), ('ṻ', "u\u{304}\u{308}"), ('Ṽ', "V\u{303}"), ('ṽ', "v\u{303}"), ('Ṿ', "V\u{323}"), ('ṿ', "v\u{323}"),
+    ('Ẁ', "W\u{300}"), ('ẁ', "w\u{300}"), ('Ẃ', "W\u{301}"), ('ẃ', "w\u{301}"), ('Ẅ', "W\u{308}"), ('ẅ', "w\u{308}"),
+    ('Ẇ', "W\u{307}"), ('ẇ', "w\u{307}"), ('Ẉ', "W\u{323}"), ('ẉ', "w\u{323}"), ('Ẋ', "X\u{307}"), ('ẋ', "x\u{307}"),
+    ('Ẍ', "X\u{308}"), ('ẍ', "x\u{308}"), ('Ẏ', "Y\u{307}"), ('ẏ', "y\u{307}"), ('Ẑ', "Z\u{302}"), ('ẑ', "z\u{302}"),
+    ('Ẓ', "Z\u{323}"), ('ẓ', "z\u{323}"), ('Ẕ', "Z\u{331}"), ('ẕ', "z\u{331}"), ('ẖ', "h\u{331}"), ('ẗ', "t\u{308}"),
+    ('ẘ', "w\u{30a}"), ('ẙ', "y\u{30a}"), ('Ạ', "A\u{323}"), ('ạ', "a\u{323}"), ('Ả', "A\u{309}"), ('ả', "a\u{309}"),
+    ('Ấ', "A\u{302}\u{301}"), ('ấ', "a\u{302}\u{301}"), ('Ầ', "A\u{302}\u{300}"), ('ầ', "a\u{302}\u{300}"), ('Ẩ', "A\u{302}\u{309}"), ('ẩ', "a\u{302}\u{309}"),
+    ('Ẫ', "A\u{302}\u{303}"), ('ẫ', "a\u{302}\u{303}"), ('Ậ', "A\u{323}\u{302}"), ('ậ', "a\u{323}\u{302}"), ('Ắ', "A\u{306}\u{301}"), ('ắ', "a\u{306}\u{301}"),
+    ('Ằ', "A\u{306}\u{300}"), ('ằ', "a\u{306}\u{300}"), ('Ẳ', "A\u{306}\u{309}"), ('ẳ', "a\u{306}\u{309}"), ('Ẵ', "A\u{306}\u{303}"), ('ẵ', "a\u{306}\u{303}"),
+    ('Ặ', "A\u{323}\u{306}"), ('ặ', "a\u{323}\u{306}"), ('Ẹ', "E\u{323}"), ('ẹ', "e\u{323}"), ('Ẻ', "E\u{309}"), ('ẻ', "e\u{309}"),
+    ('Ẽ', "E\u{303}"), ('ẽ', "e\u{303}"), ('Ế', "E\u{302}\u{301}"), ('ế', "e\u{302}\u{301}"), ('Ề', "E\u{302}\u{300}"), ('ề', "e\u{302}\u{300}"),
+    ('Ể', "E\u{302}\u{309}"), ('ể', "e\u{302}\u{309}"), ('Ễ', "E\u{302}\u{303}"), ('ễ', "e\u{302}\u{303}"), ('Ệ', "E\u{323}\u{302}"), ('ệ', "e\u{323}\u{302}"),
+    ('Ỉ', "I\u{309}"), ('ỉ', "i\u{309}"), ('Ị', "I\u{323}"), ('ị', "i\u{323}"), ('Ọ', "O\u{323}"), ('ọ', "o\u{323}"),
+    ('Ỏ', "O\u{309}"), ('ỏ', "o\u{309}"), ('Ố', "O\u{302}\u{301}"), ('ố', "o\u{302}\u{301}"), ('Ồ', "O\u{302}\u{300}"), ('ồ', "o\u{302}\u{300}"),
+    ('Ổ', "O\u{302}\u{309}"), ('ổ', "o\u{302}\u{309}"), ('Ỗ', "O\u{302}\u{303}"), ('ỗ', "o\u{302}\u{303}"), ('Ộ', "O\u{323}\u{302}"), ('ộ', "o\u{323}\u{302}"),
+    ('Ớ', "O\u{31b}\u{301}"), ('ớ', "o\u{31b}\u{301}"), ('Ờ', "O\u{31b}\u{300}"), ('ờ', "o\u{31b}\u{300}"), ('Ở', "O\u{31b}\u{309}"), ('ở', "o\u{31b}\u{309}"),
+    ('Ỡ', "O\u{31b}\u{303}"), ('ỡ', "o\u{31b}\u{303}"), ('Ợ', "O\u{31b}\u{323}"), ('ợ', "o\u{31b}\u{323}"), ('Ụ', "U\u{323}"), ('ụ', "u\u{323}"),
+    ('Ủ', "U\u{309}"), ('ủ', "u\u{309}"), ('Ứ', "U\u{31b}\u{301}"), ('ứ', "u\u{31b}\u{301}"), ('Ừ', "U\u{31b}\u{300}"), ('ừ', "u\u{31b}\u{300}"),
+    ('Ử', "U\u{31b}\u{309}"), ('ử', "u\u{31b}\u{309}"), ('Ữ', "U\u{31b}\u{303}"), ('ữ', "u\u{31b}\u{303}"), ('Ự', "U\u{31b}\u{323}"), ('ự', "u\u{31b}\u{323}"),
+    ('Ỳ', "Y\u{300}"), ('ỳ', "y\u{300}"), ('Ỵ', "Y\u{323}"), ('ỵ', "y\u{323}"), ('Ỷ', "Y\u{309}"), ('ỷ', "y\u{309}"),
+    ('Ỹ', "Y\u{303}"), ('ỹ', "y\u{303}"),
+];
+
+/// Latin letters without a canonical decomposition, folded to ASCII letters.
+/// Letters with a stroke carry `U+0338` so they still sort after the plain letter.
+const LATIN_SPECIAL_FOLDS: &[(char, &str)] = &[
+    ('Æ', "AE"), ('Ð', "D\u{338}"), ('Ø', "O\u{338}"), ('Þ', "TH"), ('ß', "ss"), ('æ', "ae"),
+    ('ð', "d\u{338}"), ('ø', "o\u{338}"), ('þ', "th"), ('Đ', "D\u{338}"), ('đ', "d\u{338}"), ('Ħ', "H\u{338}"),
+    ('ħ', "h\u{338}"), ('ı', "i"), ('Ł', "L\u{338}"), ('ł', "l\u{338}"), ('Œ', "OE"), ('œ', "oe"),
+    ('Ŧ', "T\u{338}"), ('ŧ', "t\u{338}"), ('ẞ', "SS"),
+];
+
+/// Returns the canonical decomposition (NFD) of a precomposed Latin letter,
+/// e.g. `é` → `e` + `U+0301`, or `None` for other characters.
+pub(crate) fn decompose_latin(c: char) -> Option<&'static str> {
+    LATIN_DECOMPOSITIONS
+        .binary_search_by(|(k, _)| k.cmp(&c))
+        .ok()
+        .map(|i| LATIN_DECOMPOSITIONS[i].1)
+}
+
+/// Decomposes a Latin letter like [`decompose_latin`], also folding letters
+/// without a decomposition (`ß` → `ss`, `ø` → `o` + stroke, `æ` → `ae`).
+pub(crate) fn fold_latin(c: char) -> Option<&'static str> {
+    decompose_latin(c).or_else(|| LATIN_SPECIAL_FOLDS.iter().find(|(k, _)| *k == c).map(|(_, v)| *v))
+}
+
+/// Composes Latin base letters followed by combining marks into their
+/// precomposed form where [`LATIN_DECOMPOSITIONS`] has one (NFC for Latin
+/// text): `e` + `U+0301` → `é`. Other characters are kept as is.
+pub(crate) fn compose_latin(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = String::with_capacity(s.len());
+    let mut i = 0;
+    let mut candidate = String::new();
+    while i < chars.len() {
+        let marks = chars[i + 1..].iter().take_while(|c| is_combining_mark(**c)).count();
+        let composed = (1..=marks).rev().find_map(|n| {
+            candidate.clear();
+            candidate.extend(&chars[i..=i + n]);
+            LATIN_DECOMPOSITIONS.iter().find(|(_, d)| *d == candidate).map(|(k, _)| (*k, n))
+        });
+        match composed {
+            Some((c, n)) => {
+                out.push(c);
+                i += n + 1;
+            }
+            None => {
+                out.push(chars[i]);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Characters whose full case folding differs from their lowercase form.
+const CASE_FOLD_EXCEPTIONS: &[(char, &str)] = &[
+    ('ß', "ss"), ('ς', "σ"), ('ϐ', "β"), ('ϑ', "θ"), ('ϕ', "φ"), ('ϖ', "π"), ('ϰ', "κ"), ('ϱ', "ρ"),
+    ('ϵ', "ε"), ('ſ', "s"), ('ẛ', "ṡ"), ('ι', "ι"), ('ﬀ', "ff"), ('ﬁ', "fi"), ('ﬂ', "fl"), ('ﬃ', "ffi"),
+    ('ﬄ', "ffl"), ('ﬅ', "st"), ('ﬆ', "st"),
+];
+
+/// Returns the Unicode full case folding of the text, for case-insensitive
+/// comparisons: `"Straße"`, `"STRASSE"` and `"strasse"` all fold to
+/// `"strasse"`, and the Greek final sigma folds like the other sigmas.
+pub(crate) fn case_fold(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars().flat_map(char::to_lowercase) {
+        match CASE_FOLD_EXCEPTIONS.iter().find(|(k, _)| *k == c) {
+            Some((_, folded)) => out.push_str(folded),
+            None => out.push(c),
+        }
+    }
+    out
+}
+
+/// Options for the collation_key function
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CollationOptions {
+    /// Accented letters compare equal to their base letter (`é` = `e`).
+    pub ignore_accents: bool,
+    /// Uppercase and lowercase letters compare equal (`A` = `a`).
+    pub ignore_case: bool,
+}
+
+/// Returns a sort key for `s`: comparing the keys of two strings as bytes
+/// orders them the way people expect in Latin-script languages, instead of
+/// the code point order of `str` (where `Z` < `a` < `é`).
+///
+/// ### Rules implemented (a simplified Unicode Collation Algorithm):
+/// - Letters compare first by their base letter, ignoring accents and case:
+///   `a` < `B` < `é` < `f`. `ß` sorts as `ss`, `æ` as `ae`, `ø` as `o`.
+/// - Only when the base letters are equal, unaccented letters go before
+///   accented ones (`cote` < `coté`), unless `ignore_accents` is set.
+/// - Then lowercase goes before uppercase (`a` < `A`), unless `ignore_case`
+///   is set.
+/// - Other characters compare by code point after being lowercased.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::locale::{collation_key, CollationOptions};
+/// let mut names = vec!["Zoë", "zoe", "Émile", "adam", "Eva", "Ærø"];
+/// names.sort_by_key(|name| collation_key(name, CollationOptions::default()));
+/// assert_eq!(names, vec!["adam", "Ærø", "Émile", "Eva", "zoe", "Zoë"]);
+///
+/// let options = CollationOptions { ignore_accents: true, ignore_case: true };
+/// assert_eq!(collation_key("Zoë", options), collation_key("zoe", options));
+/// ```
+pub fn collation_key(s: &str, options: CollationOptions) -> Vec<u8> {
+    let mut primary = Vec::with_capacity(s.len());
+    let mut secondary = Vec::new();
+    let mut tertiary = Vec::new();
+
+    for c in s.chars() {
+        if is_combining_mark(c) && !secondary.is_empty() {
+            // Decomposed input: the mark weighs on the previous letter
+            *secondary.last_mut().unwrap() = accent_weight(c);
+            continue;
+        }
+        let (bases, accent) = match fold_latin(c) {
+            Some(folded) => (folded, folded.chars().find(|u| !u.is_ascii_alphabetic()).map_or(1, accent_weight)),
+            None => ("", 1),
+        };
+
+        let mut push = |base: char| {
+            for lower in base.to_lowercase() {
+                let mut buf = [0; 4];
+                // Shift the bytes by one so that 0 only separates the levels
+                primary.extend(lower.encode_utf8(&mut buf).bytes().map(|b| b + 1));
+            }
+            secondary.push(accent);
+            tertiary.push(if base.is_uppercase() { 2 } else { 1 });
+        };
+        if bases.is_empty() {
+            push(c);
+        } else {
+            bases.chars().filter(char::is_ascii_alphabetic).for_each(push);
+        }
+    }
+
+    let mut key = primary;
+    if !options.ignore_accents {
+        key.push(0);
+        key.extend(secondary);
+    }
+    if !options.ignore_case {
+        key.push(0);
+        key.extend(tertiary);
+    }
+    key
+}
+
+/// Secondary weight of a combining mark; 1 means no accent.
+fn accent_weight(mark: char) -> u8 {
+    (mark as u32).saturating_sub(0x2FF).min(0xFE) as u8 + 1
+}
+
+/// Compares two strings with [`collation_key`] ordering.
+///
+/// # Examples
+/// ```
+/// use std::cmp::Ordering;
+/// use bt_string_utils::locale::{compare_collated, CollationOptions};
+/// assert_eq!(compare_collated("éclair", "Eclipse", CollationOptions::default()), Ordering::Less);
+/// ```
+pub fn compare_collated(a: &str, b: &str, options: CollationOptions) -> Ordering {
+    collation_key(a, options).cmp(&collation_key(b, options))
+}
+
+/// Languages with case mapping exceptions, for the locale casing functions
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// Language-independent Unicode case mapping (what `str` methods do).
+    #[default]
+    Root,
+    /// Turkish: dotted `İ`/`i` and dotless `I`/`ı` are separate letters.
+    Turkish,
+    /// Azerbaijani: same `i` rules as Turkish.
+    Azerbaijani,
+    /// German: `ß` uppercases to `SS` (as in `Root`); `ẞ` lowercases to `ß`.
+    German,
+    /// Greek: accents (tonos) are dropped in uppercase text.
+    Greek,
+}
+
+/// Lowercases the text with the case mapping exceptions of `locale`.
+///
+/// Like `str::to_lowercase`, a capital sigma at the end of a word becomes
+/// the final form `ς`. In Turkish and Azerbaijani, `I` lowercases to
+/// dotless `ı` and `İ` to `i`.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::locale::{to_lower_locale, Locale};
+/// assert_eq!(to_lower_locale("DİYARBAKIR", Locale::Turkish), "diyarbakır");
+/// assert_eq!(to_lower_locale("DIYARBAKIR", Locale::Root), "diyarbakir");
+/// assert_eq!(to_lower_locale("ΟΔΟΣ", Locale::Greek), "οδος");
+/// ```
+pub fn to_lower_locale(s: &str, locale: Locale) -> String {
+    match locale {
+        Locale::Turkish | Locale::Azerbaijani => s
+            .replace("I\u{307}", "i")
+            .replace('İ', "i")
+            .replace('I', "ı")
+            .to_lowercase(),
+        Locale::Root | Locale::German | Locale::Greek => s.to_lowercase(),
+    }
+}
+
+/// Uppercases the text with the case mapping exceptions of `locale`.
+///
+/// Like `str::to_uppercase`, `ß` becomes `SS`. In Turkish and Azerbaijani,
+/// `i` uppercases to dotted `İ` (and `ı` to `I`). In Greek, the accents of
+/// vowels are removed, as Greek is written without them in capitals
+/// (diaeresis is kept).
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::locale::{to_upper_locale, Locale};
+/// assert_eq!(to_upper_locale("istanbul", Locale::Turkish), "İSTANBUL");
+/// assert_eq!(to_upper_locale("straße", Locale::German), "STRASSE");
+/// assert_eq!(to_upper_locale("Αθήνα", Locale::Greek), "ΑΘΗΝΑ");
+/// assert_eq!(to_upper_locale("Αθήνα", Locale::Root), "ΑΘΉΝΑ");
+/// ```
+pub fn to_upper_locale(s: &str, locale: Locale) -> String {
+    match locale {
+        Locale::Turkish | Locale::Azerbaijani => s.replace('i', "İ").to_uppercase(),
+        Locale::Greek => strip_greek_accents(&s.to_uppercase()),
+        Locale::Root | Locale::German => s.to_uppercase(),
+    }
+}
+
+/// Removes tonos (and the combining acute or perispomeni) from Greek capitals.
+fn strip_greek_accents(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut after_greek = false;
+    for c in s.chars() {
+        if after_greek && matches!(c, '\u{301}' | '\u{342}') {
+            continue;
+        }
+        if after_greek && matches!(c, '\u{308}' | '\u{344}') {
+            // Keep the diaeresis, precomposed when possible
+            match out.pop() {
+                Some('Ι') => out.push('Ϊ'),
+                Some('Υ') => out.push('Ϋ'),
+                Some(last) => {
+                    out.push(last);
+                    out.push('\u{308}');
+                }
+                None => out.push('\u{308}'),
+            }
+            continue;
+        }
+        let plain = match c {
+            'Ά' => 'Α', 'Έ' => 'Ε', 'Ή' => 'Η', 'Ί' => 'Ι', 'Ό' => 'Ο', 'Ύ' => 'Υ', 'Ώ' => 'Ω',
+            _ => c,
+        };
+        if !is_combining_mark(plain) {
+            after_greek = ('\u{370}'..='\u{3FF}').contains(&plain);
+        }
+        out.push(plain);
+    }
+    out
+}
+
+/// Letters whose titlecase differs from their uppercase: digraphs and
+/// ligatures capitalize only their first part.
+const TITLECASE_EXCEPTIONS: &[(char, &str)] = &[
+    ('ß', "Ss"), ('Ǆ', "ǅ"), ('ǅ', "ǅ"), ('ǆ', "ǅ"), ('Ǉ', "ǈ"), ('ǈ', "ǈ"), ('ǉ', "ǈ"),
+    ('Ǌ', "ǋ"), ('ǋ', "ǋ"), ('ǌ', "ǋ"), ('Ǳ', "ǲ"), ('ǲ', "ǲ"), ('ǳ', "ǲ"), ('ﬀ', "Ff"),
+    ('ﬁ', "Fi"), ('ﬂ', "Fl"), ('ﬃ', "Ffi"), ('ﬄ', "Ffl"), ('ﬅ', "St"), ('ﬆ', "St"),
+];
+
+/// Returns the titlecase form of a character (the form of a capitalized
+/// word's first letter) with the exceptions of `locale`.
+pub(crate) fn to_titlecase_locale(c: char, locale: Locale) -> String {
+    if c == 'i' && matches!(locale, Locale::Turkish | Locale::Azerbaijani) {
+        return "İ".to_owned();
+    }
+    match TITLECASE_EXCEPTIONS.iter().find(|(k, _)| *k == c) {
+        Some((_, title)) => (*title).to_owned(),
+        None => c.to_uppercase().collect(),
+    }
+}
+
+/// Capitalizes the first letter of the text with the casing rules of
+/// `locale`, leaving the rest unchanged.
+///
+/// ### Rules implemented:
+/// - Leading spaces and punctuation (quotes, brackets...) are skipped; text
+///   starting with a digit is returned unchanged.
+/// - The whole first grapheme is kept together, so a letter followed by
+///   combining accents (`e` + `◌́`) capitalizes correctly.
+/// - Digraphs and ligatures use their titlecase form: `ǆ` → `ǅ`,
+///   `ﬁ` → `Fi`, `ß` → `Ss`.
+/// - Turkish and Azerbaijani `i` becomes `İ`. Greek keeps the accent of an
+///   initial capital (`ά` → `Ά`).
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::locale::{capitalize_locale, Locale};
+/// assert_eq!(capitalize_locale("e\u{301}clair", Locale::Root), "E\u{301}clair");
+/// assert_eq!(capitalize_locale("izmir", Locale::Turkish), "İzmir");
+/// assert_eq!(capitalize_locale("«ﬁnal»", Locale::Root), "«Final»");
+/// assert_eq!(capitalize_locale("1st place", Locale::Root), "1st place");
+/// ```
+pub fn capitalize_locale(s: &str, locale: Locale) -> String {
+    let Some((start, first)) = s.char_indices().find(|(_, c)| c.is_alphanumeric()) else {
+        return s.to_owned();
+    };
+    if !first.is_alphabetic() {
+        return s.to_owned();
+    }
+
+    let end = crate::segmenter::next_grapheme_end(s, start);
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push_str(&s[..start]);
+    out.push_str(&to_titlecase_locale(first, locale));
+    out.push_str(&s[start + first.len_utf8()..end]);
+    out.push_str(&s[end..]);
+    out
+}
@@ -0,0 +1,387 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+use crate::formatter::highlight;
+use crate::hasher::{canonical_fingerprint, simhash};
+use crate::matcher::levenshtein_similarity;
+
+/// SimHashes of lines further apart than this many bits are never compared
+/// with the (much slower) edit distance, at thresholds of 0.7 and above.
+const MAX_SIMHASH_DISTANCE: u32 = 20;
+
+/// Groups near-identical lines (e.g. repetitive log output) together.
+///
+/// Each line is compared with the first line of every existing cluster: a
+/// cheap [`simhash`] comparison discards clearly different lines, then the
+/// [`levenshtein_similarity`] decides. A line joins the first cluster it is
+/// similar enough to, or starts a new one.
+///
+/// The SimHash cutoff loosens as the threshold goes down (from 20 differing
+/// bits at 0.7 and above to all 64 at 0.0), so that low thresholds still
+/// group lines that share few words.
+///
+/// # Arguments
+/// * `lines` - The lines to group.
+/// * `threshold` - Minimum Levenshtein similarity (`0.0` to `1.0`) with the
+///   first line of a cluster to join it.
+///
+/// # Returns
+/// A `Vec` of clusters, each a `Vec` of line indices in ascending order.
+/// Clusters are ordered by their first line.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::log_analyzer::cluster_similar_lines;
+/// let lines = [
+///     "connection from 10.0.0.1 accepted",
+///     "disk usage at 91%",
+///     "connection from 10.0.0.2 accepted",
+///     "connection from 10.0.0.3 accepted",
+/// ];
+/// assert_eq!(cluster_similar_lines(&lines, 0.9), vec![vec![0, 2, 3], vec![1]]);
+/// ```
+pub fn cluster_similar_lines<S: AsRef<str>>(lines: &[S], threshold: f64) -> Vec<Vec<usize>> {
+    let mut clusters: Vec<Vec<usize>> = Vec::new();
+    let mut representatives: Vec<(u64, &str)> = Vec::new();
+    let max_distance = MAX_SIMHASH_DISTANCE.max(((1.0 - threshold.clamp(0.0, 1.0)) * 64.0).ceil() as u32);
+
+    for (i, line) in lines.iter().enumerate() {
+        let line = line.as_ref();
+        let hash = simhash(line);
+
+        let found = representatives.iter().position(|(rep_hash, rep)| {
+            (rep_hash ^ hash).count_ones() <= max_distance
+                && levenshtein_similarity(rep, line) >= threshold
+        });
+
+        match found {
+            Some(c) => clusters[c].push(i),
+            None => {
+                representatives.push((hash, line));
+                clusters.push(vec![i]);
+            }
+        }
+    }
+
+    clusters
+}
+
+/// Placeholder used for the variable parts of a log template.
+pub const TEMPLATE_WILDCARD: &str = "<*>";
+
+/// Minimum fraction of matching tokens for a line to join a template.
+const TEMPLATE_SIMILARITY: f64 = 0.5;
+
+/// Result of the extract_log_template function.
+#[derive(Debug, PartialEq)]
+pub struct Template {
+    /// The learned patterns, e.g. `"user <*> logged in from <*>"`.
+    pub patterns: Vec<String>,
+    /// One binding per input line, in input order.
+    pub bindings: Vec<TemplateBinding>,
+}
+
+/// The pattern a line belongs to and the values of its variable parts.
+#[derive(Debug, PartialEq)]
+pub struct TemplateBinding {
+    /// Index into [`Template::patterns`].
+    pub pattern: usize,
+    /// The tokens of the line found at each [`TEMPLATE_WILDCARD`] position.
+    pub variables: Vec<String>,
+}
+
+/// Learns message templates from log lines (Drain-style) and replaces their
+/// variable parts with [`TEMPLATE_WILDCARD`].
+///
+/// ### Rules implemented:
+/// - Lines are split into tokens on whitespace; only lines with the same
+///   number of tokens can share a template.
+/// - Tokens containing digits (ids, counters, IPs, durations) are variables.
+/// - A line joins the most similar template of its length if at least half of
+///   its tokens are equal to the template's (a wildcard matches any token);
+///   the positions that differ become wildcards. Otherwise it starts a new
+///   template.
+/// - Once every line is assigned, the variables of each line are read from
+///   the final version of its template.
+///
+/// # Arguments
+/// * `lines` - The log lines.
+///
+/// # Returns
+/// A [`Template`] with the learned patterns and the per-line bindings.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::log_analyzer::extract_log_template;
+/// let lines = [
+///     "user alice logged in from 10.0.0.1",
+///     "user bob logged in from 10.0.0.2",
+///     "cache cleared",
+/// ];
+/// let template = extract_log_template(&lines);
+/// assert_eq!(template.patterns, vec!["user <*> logged in from <*>", "cache cleared"]);
+/// assert_eq!(template.bindings[1].pattern, 0);
+/// assert_eq!(template.bindings[1].variables, vec!["bob", "10.0.0.2"]);
+/// assert!(template.bindings[2].variables.is_empty());
+/// ```
+pub fn extract_log_template<S: AsRef<str>>(lines: &[S]) -> Template {
+    let tokenized: Vec<Vec<&str>> = lines.iter().map(|l| l.as_ref().split_whitespace().collect()).collect();
+    let mut patterns: Vec<Vec<&str>> = Vec::new();
+    let mut assigned = Vec::with_capacity(lines.len());
+
+    for tokens in &tokenized {
+        let masked: Vec<&str> = tokens
+            .iter()
+            .map(|t| if t.chars().any(|c| c.is_ascii_digit()) { TEMPLATE_WILDCARD } else { *t })
+            .collect();
+
+        let best = patterns
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.len() == masked.len())
+            .map(|(i, p)| (i, token_similarity(p, &masked)))
+            .filter(|(_, sim)| *sim >= TEMPLATE_SIMILARITY)
+            .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)));
+
+        match best {
+            Some((i, _)) => {
+                for (p, t) in patterns[i].iter_mut().zip(&masked) {
+                    if p != t {
+                        *p = TEMPLATE_WILDCARD;
+                    }
+                }
+                assigned.push(i);
+            }
+            None => {
+                assigned.push(patterns.len());
+                patterns.push(masked);
+            }
+        }
+    }
+
+    let bindings = tokenized
+        .iter()
+        .zip(assigned)
+        .map(|(tokens, pattern)| TemplateBinding {
+            pattern,
+            variables: patterns[pattern]
+                .iter()
+                .zip(tokens)
+                .filter(|(p, _)| **p == TEMPLATE_WILDCARD)
+                .map(|(_, t)| (*t).to_owned())
+                .collect(),
+        })
+        .collect();
+
+    Template {
+        patterns: patterns.iter().map(|p| p.join(" ")).collect(),
+        bindings,
+    }
+}
+
+fn token_similarity(pattern: &[&str], tokens: &[&str]) -> f64 {
+    if pattern.is_empty() {
+        return 1.0;
+    }
+    let equal = pattern.iter().zip(tokens).filter(|(p, t)| **p == TEMPLATE_WILDCARD || p == t).count();
+    equal as f64 / pattern.len() as f64
+}
+
+/// Decision of the dedupe_log_message function for one message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogDecision {
+    /// Log the message.
+    Emit,
+    /// Drop the message: it is the n-th repetition within the window.
+    Suppressed(usize),
+}
+
+/// A message that was suppressed, reported once its window is over.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepeatSummary {
+    /// The first occurrence of the message.
+    pub message: String,
+    /// The number of suppressed repetitions.
+    pub count: usize,
+}
+
+impl fmt::Display for RepeatSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(last message repeated {} times)", self.count)
+    }
+}
+
+/// Repetition window of one canonical message.
+struct SeenMessage {
+    message: String,
+    since: Instant,
+    suppressed: usize,
+}
+
+/// The state of the dedupe_log_message function: which messages were emitted
+/// recently and how many repetitions were suppressed since.
+///
+/// Messages are compared by [`canonical_fingerprint`], so repetitions that only
+/// differ in case, punctuation or whitespace are suppressed too.
+pub struct DedupeWindow {
+    window: Duration,
+    seen: HashMap<u64, SeenMessage>,
+    pending: Vec<RepeatSummary>,
+}
+
+impl DedupeWindow {
+    /// Creates a window suppressing repetitions for `window` after a message
+    /// is emitted.
+    pub fn new(window: Duration) -> Self {
+        DedupeWindow { window, seen: HashMap::new(), pending: Vec::new() }
+    }
+
+    /// Same as [`dedupe_log_message`], at the given time instead of now.
+    pub fn check_at(&mut self, msg: &str, now: Instant) -> LogDecision {
+        let key = canonical_fingerprint(msg);
+        if let Some(seen) = self.seen.get_mut(&key) {
+            if now.saturating_duration_since(seen.since) < self.window {
+                seen.suppressed += 1;
+                return LogDecision::Suppressed(seen.suppressed);
+            }
+            if seen.suppressed > 0 {
+                self.pending.push(RepeatSummary { message: seen.message.clone(), count: seen.suppressed });
+            }
+        }
+        self.seen.insert(key, SeenMessage { message: msg.to_owned(), since: now, suppressed: 0 });
+        LogDecision::Emit
+    }
+
+    /// Returns the summaries of the windows over at `now` that suppressed
+    /// messages, oldest first, and forgets the expired messages.
+    ///
+    /// Call it periodically (or before logging) and log each summary after
+    /// its message, e.g. `"{message} {summary}"`.
+    pub fn flush_at(&mut self, now: Instant) -> Vec<RepeatSummary> {
+        let window = self.window;
+        let mut expired: Vec<(Instant, RepeatSummary)> = Vec::new();
+        self.seen.retain(|_, seen| {
+            if now.saturating_duration_since(seen.since) < window {
+                return true;
+            }
+            if seen.suppressed > 0 {
+                expired.push((seen.since, RepeatSummary { message: std::mem::take(&mut seen.message), count: seen.suppressed }));
+            }
+            false
+        });
+        expired.sort_by_key(|(since, _)| *since);
+
+        let mut summaries = std::mem::take(&mut self.pending);
+        summaries.extend(expired.into_iter().map(|(_, summary)| summary));
+        summaries
+    }
+
+    /// Same as [`DedupeWindow::flush_at`], now.
+    pub fn flush(&mut self) -> Vec<RepeatSummary> {
+        self.flush_at(Instant::now())
+    }
+}
+
+/// Decides whether a log message should be emitted or suppressed as a
+/// repetition of a message emitted less than the window's duration ago.
+///
+/// Suppressed repetitions are counted; [`DedupeWindow::flush`] reports them
+/// as `"(last message repeated N times)"` once the window is over.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bt_string_utils::log_analyzer::{dedupe_log_message, DedupeWindow, LogDecision};
+/// let mut window = DedupeWindow::new(Duration::from_secs(60));
+/// assert_eq!(dedupe_log_message("Disk full!", &mut window), LogDecision::Emit);
+/// assert_eq!(dedupe_log_message("disk  full", &mut window), LogDecision::Suppressed(1));
+/// assert_eq!(dedupe_log_message("Disk full.", &mut window), LogDecision::Suppressed(2));
+/// assert_eq!(dedupe_log_message("Disk almost full", &mut window), LogDecision::Emit);
+/// ```
+pub fn dedupe_log_message(msg: &str, window: &mut DedupeWindow) -> LogDecision {
+    window.check_at(msg, Instant::now())
+}
+
+/// A line found by the grep_lines function, with its context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrepHit<'a> {
+    /// Line number of the match, starting at 1.
+    pub line_number: usize,
+    /// The matching line, without its line ending.
+    pub line: &'a str,
+    /// Byte ranges of the pattern in `line`.
+    pub matches: Vec<Range<usize>>,
+    /// `(line_number, line)` of the context lines before the match.
+    pub before: Vec<(usize, &'a str)>,
+    /// `(line_number, line)` of the context lines after the match.
+    pub after: Vec<(usize, &'a str)>,
+}
+
+impl GrepHit<'_> {
+    /// Returns the line with its matches highlighted in ANSI bold red, as
+    /// `grep --color` prints them.
+    pub fn highlighted(&self) -> String {
+        highlight(self.line, &self.matches)
+    }
+}
+
+/// Finds the lines of `text` containing `pattern`, with up to `before` and
+/// `after` lines of context, like `grep -B before -A after -F pattern`.
+///
+/// As with grep, every line is reported once: a matching line is always a
+/// hit, never context, and context lines already shown after a previous hit
+/// are not repeated before the next one. A gap between the line numbers of
+/// consecutive hits and their context is where grep prints `--`.
+///
+/// # Arguments
+/// * `text` - The text to search, usually a log; `\n` and `\r\n` end lines.
+/// * `pattern` - The text to find, case-sensitive. An empty pattern matches
+///   every line.
+/// * `before` - The number of context lines before each match.
+/// * `after` - The number of context lines after each match.
+///
+/// # Returns
+/// The hits in line order.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::log_analyzer::grep_lines;
+/// let log = "start\nload config\nERROR: disk full\nretry\nok\nERROR: disk full\n";
+/// let hits = grep_lines(log, "ERROR", 1, 1);
+/// assert_eq!(hits.len(), 2);
+/// assert_eq!(hits[0].line_number, 3);
+/// assert_eq!(hits[0].before, vec![(2, "load config")]);
+/// assert_eq!(hits[0].after, vec![(4, "retry")]);
+/// // Line 4 was already shown after the first hit
+/// assert_eq!(hits[1].before, vec![(5, "ok")]);
+/// assert_eq!(hits[1].highlighted(), "\x1b[1;31mERROR\x1b[0m: disk full");
+/// ```
+pub fn grep_lines<'a>(text: &'a str, pattern: &str, before: usize, after: usize) -> Vec<GrepHit<'a>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let matches: Vec<Vec<Range<usize>>> = lines
+        .iter()
+        .map(|line| line.match_indices(pattern).filter(|_| !pattern.is_empty()).map(|(i, m)| i..i + m.len()).collect())
+        .collect();
+    let is_hit = |i: usize| pattern.is_empty() || !matches[i].is_empty();
+
+    let mut hits: Vec<GrepHit<'a>> = Vec::new();
+    // Index of the first line not printed yet
+    let mut shown = 0;
+    for i in (0..lines.len()).filter(|&i| is_hit(i)) {
+        let context = |range: Range<usize>| range.map(|j| (j + 1, lines[j])).collect::<Vec<_>>();
+        let before_lines = context(i.saturating_sub(before).max(shown)..i);
+        let context_end = lines.len().min((i + 1).saturating_add(after));
+        let after_end = (i + 1..context_end).find(|&j| is_hit(j)).unwrap_or(context_end);
+        hits.push(GrepHit {
+            line_number: i + 1,
+            line: lines[i],
+            matches: matches[i].clone(),
+            before: before_lines,
+            after: context(i + 1..after_end),
+        });
+        shown = after_end;
+    }
+    hits
+}
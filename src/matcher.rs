@@ -0,0 +1,750 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::case::identifier_words;
+use crate::hasher::simhash;
+
+/// Returns the set of trigrams of a string, sorted and without duplicates.
+///
+/// Like PostgreSQL's `pg_trgm`, the text is lowercased and split into words
+/// on non-alphanumeric characters; each word is padded with two spaces in
+/// front and one at the end before taking every run of three characters.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::matcher::trigrams;
+/// let t = trigrams("Cat");
+/// assert_eq!(t, vec![[' ', ' ', 'c'], [' ', 'c', 'a'], ['a', 't', ' '], ['c', 'a', 't']]);
+/// ```
+pub fn trigrams(s: &str) -> Vec<[char; 3]> {
+    let mut out = Vec::new();
+    for word in s.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()) {
+        let mut padded = vec![' ', ' '];
+        padded.extend(word.chars().flat_map(char::to_lowercase));
+        padded.push(' ');
+        out.extend(padded.windows(3).map(|w| [w[0], w[1], w[2]]));
+    }
+    out.sort_unstable();
+    out.dedup();
+    out
+}
+
+/// Computes the trigram similarity of two strings: the number of shared
+/// trigrams divided by the number of distinct trigrams in both (Jaccard index).
+///
+/// # Returns
+/// A value between `0.0` (nothing in common) and `1.0` (same trigrams).
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::matcher::trigram_similarity;
+/// assert_eq!(trigram_similarity("word", "word"), 1.0);
+/// assert_eq!(trigram_similarity("word", "xyz"), 0.0);
+/// assert!(trigram_similarity("serde_json", "serde-json") == 1.0);
+/// assert!(trigram_similarity("tokio", "toki") > 0.4);
+/// ```
+pub fn trigram_similarity(a: &str, b: &str) -> f64 {
+    let ta = trigrams(a);
+    let tb = trigrams(b);
+    let shared = ta.iter().filter(|t| tb.binary_search(t).is_ok()).count();
+    jaccard(shared, ta.len(), tb.len())
+}
+
+fn jaccard(shared: usize, len_a: usize, len_b: usize) -> f64 {
+    let union = len_a + len_b - shared;
+    if union == 0 { 0.0 } else { shared as f64 / union as f64 }
+}
+
+/// An inverted index of trigrams for fast fuzzy search over many strings.
+///
+/// Building the index is done once; each search only looks at the items that
+/// share at least one trigram with the query instead of comparing the query
+/// against every item.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::matcher::TrigramIndex;
+/// let index = TrigramIndex::build(["serde", "serde_json", "tokio", "rand"]);
+/// let hits = index.search("serde-jsn", 0.3);
+/// assert_eq!(hits[0].0, 1);
+/// assert!(index.search("zzz", 0.1).is_empty());
+/// ```
+pub struct TrigramIndex {
+    postings: HashMap<[char; 3], Vec<usize>>,
+    trigram_counts: Vec<usize>,
+}
+
+impl TrigramIndex {
+    /// Builds the index. The position of each item in `items` is the index
+    /// returned by [`TrigramIndex::search`].
+    pub fn build<I, S>(items: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut postings: HashMap<[char; 3], Vec<usize>> = HashMap::new();
+        let mut trigram_counts = Vec::new();
+
+        for (idx, item) in items.into_iter().enumerate() {
+            let item_trigrams = trigrams(item.as_ref());
+            trigram_counts.push(item_trigrams.len());
+            for t in item_trigrams {
+                postings.entry(t).or_default().push(idx);
+            }
+        }
+
+        TrigramIndex { postings, trigram_counts }
+    }
+
+    /// Returns the number of indexed items.
+    pub fn len(&self) -> usize {
+        self.trigram_counts.len()
+    }
+
+    /// Returns `true` if the index has no items.
+    pub fn is_empty(&self) -> bool {
+        self.trigram_counts.is_empty()
+    }
+
+    /// Finds the items whose [`trigram_similarity`] with `query` is at least
+    /// `min_similarity`.
+    ///
+    /// # Returns
+    /// A `Vec` of `(item_index, score)` sorted by descending score, then by
+    /// ascending index.
+    pub fn search(&self, query: &str, min_similarity: f64) -> Vec<(usize, f64)> {
+        let query_trigrams = trigrams(query);
+        let mut shared: HashMap<usize, usize> = HashMap::new();
+
+        for t in &query_trigrams {
+            if let Some(ids) = self.postings.get(t) {
+                for id in ids {
+                    *shared.entry(*id).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let mut hits: Vec<(usize, f64)> = shared
+            .into_iter()
+            .map(|(id, count)| (id, jaccard(count, query_trigrams.len(), self.trigram_counts[id])))
+            .filter(|(_, score)| *score >= min_similarity)
+            .collect();
+
+        hits.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        hits
+    }
+}
+
+/// Computes the Levenshtein edit distance between two strings: the minimum
+/// number of single-character insertions, deletions and substitutions needed
+/// to turn `a` into `b`. Characters are Unicode scalar values.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::matcher::levenshtein;
+/// assert_eq!(levenshtein("kitten", "sitting"), 3);
+/// assert_eq!(levenshtein("", "abc"), 3);
+/// assert_eq!(levenshtein("naïve", "naive"), 1);
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Returns the Levenshtein similarity of two strings:
+/// `1 - distance / max(length)`, between `0.0` and `1.0`.
+/// Two empty strings have a similarity of `1.0`.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::matcher::levenshtein_similarity;
+/// assert_eq!(levenshtein_similarity("abcd", "abcf"), 0.75);
+/// assert_eq!(levenshtein_similarity("", ""), 1.0);
+/// ```
+pub fn levenshtein_similarity(a: &str, b: &str) -> f64 {
+    let longest = a.chars().count().max(b.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(a, b) as f64 / longest as f64
+}
+
+/// Returns the Jaro-Winkler similarity of two strings, between `0.0` and
+/// `1.0`, comparing Unicode scalar values.
+///
+/// The Jaro similarity counts the characters the strings share within a
+/// window and their transpositions; the Winkler variant raises it for strings
+/// sharing a prefix (up to 4 characters, scale 0.1). Well suited to short
+/// strings such as names. Two empty strings have a similarity of `1.0`.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::matcher::jaro_winkler_similarity;
+/// assert!((jaro_winkler_similarity("MARTHA", "MARHTA") - 0.9611).abs() < 1e-4);
+/// assert!((jaro_winkler_similarity("DIXON", "DICKSONX") - 0.8133).abs() < 1e-4);
+/// assert_eq!(jaro_winkler_similarity("abc", "xyz"), 0.0);
+/// ```
+pub fn jaro_winkler_similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::new();
+    for (i, ca) in a.iter().enumerate() {
+        let end = (i + window + 1).min(b.len());
+        if let Some(j) = (i.saturating_sub(window)..end).find(|&j| !b_matched[j] && b[j] == *ca) {
+            b_matched[j] = true;
+            a_matches.push(*ca);
+        }
+    }
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+
+    let b_matches = b.iter().zip(&b_matched).filter(|(_, matched)| **matched).map(|(c, _)| c);
+    let transpositions = a_matches.iter().zip(b_matches).filter(|(x, y)| x != y).count() / 2;
+    let m = a_matches.len() as f64;
+    let jaro = (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0;
+
+    let prefix = a.iter().zip(&b).take(4).take_while(|(x, y)| x == y).count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+/// A Levenshtein automaton: accepts the strings within `max_edits` edits
+/// (insertions, deletions, substitutions) of a query.
+///
+/// The automaton is fed one character at a time, so a walk over a prefix
+/// structure can stop descending as soon as [`LevenshteinAutomaton::can_match`]
+/// is `false`. [`LevenshteinAutomaton::search`] does exactly that over a
+/// sorted word list, skipping every entry that shares a dead prefix.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::matcher::LevenshteinAutomaton;
+/// let automaton = LevenshteinAutomaton::new("serde", 1);
+/// assert_eq!(automaton.distance("sered"), None);
+/// assert_eq!(automaton.distance("sarde"), Some(1));
+///
+/// let words = ["rand", "serde", "serde_json", "sered", "serve"];
+/// let hits: Vec<_> = automaton.search(&words).collect();
+/// assert_eq!(hits, vec![("serde", 0), ("serve", 1)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LevenshteinAutomaton {
+    query: Vec<char>,
+    max_edits: usize,
+}
+
+/// A state of a [`LevenshteinAutomaton`]: the edit distances between the
+/// characters fed so far and each prefix of the query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevenshteinState {
+    row: Vec<usize>,
+}
+
+impl LevenshteinAutomaton {
+    /// Creates an automaton accepting strings within `max_edits` of `query`.
+    pub fn new(query: &str, max_edits: usize) -> Self {
+        LevenshteinAutomaton { query: query.chars().collect(), max_edits }
+    }
+
+    /// Returns the state before any character has been fed.
+    pub fn start(&self) -> LevenshteinState {
+        LevenshteinState { row: (0..=self.query.len()).map(|d| d.min(self.max_edits + 1)).collect() }
+    }
+
+    /// Returns the state after feeding `c` in `state`.
+    pub fn step(&self, state: &LevenshteinState, c: char) -> LevenshteinState {
+        let limit = self.max_edits + 1;
+        let mut row = Vec::with_capacity(state.row.len());
+        row.push((state.row[0] + 1).min(limit));
+        for (j, q) in self.query.iter().enumerate() {
+            let substitution = state.row[j] + usize::from(*q != c);
+            let value = substitution.min(state.row[j + 1] + 1).min(row[j] + 1);
+            row.push(value.min(limit));
+        }
+        LevenshteinState { row }
+    }
+
+    /// Returns `true` if the characters fed so far are within `max_edits` of
+    /// the query.
+    pub fn is_match(&self, state: &LevenshteinState) -> bool {
+        state.row[self.query.len()] <= self.max_edits
+    }
+
+    /// Returns `true` if some continuation of the characters fed so far can
+    /// still match.
+    pub fn can_match(&self, state: &LevenshteinState) -> bool {
+        state.row.iter().any(|d| *d <= self.max_edits)
+    }
+
+    /// Returns the edit distance of `word` to the query if it is within
+    /// `max_edits`, stopping early once no match is possible.
+    pub fn distance(&self, word: &str) -> Option<usize> {
+        let mut state = self.start();
+        for c in word.chars() {
+            state = self.step(&state, c);
+            if !self.can_match(&state) {
+                return None;
+            }
+        }
+        self.is_match(&state).then(|| state.row[self.query.len()])
+    }
+
+    /// Streams the entries of `sorted` (sorted ascending) within `max_edits`
+    /// of the query, as `(entry, distance)` in list order.
+    ///
+    /// States are shared between entries with a common prefix, and once a
+    /// prefix cannot match, every entry starting with it is skipped without
+    /// being examined.
+    pub fn search<'w, S: AsRef<str>>(&self, sorted: &'w [S]) -> LevenshteinMatches<'_, 'w, S> {
+        LevenshteinMatches { automaton: self, words: sorted, index: 0, prefix: Vec::new(), states: vec![self.start()] }
+    }
+}
+
+/// Iterator over the matches of a [`LevenshteinAutomaton`] in a sorted word
+/// list. Created by [`LevenshteinAutomaton::search`].
+pub struct LevenshteinMatches<'a, 'w, S> {
+    automaton: &'a LevenshteinAutomaton,
+    words: &'w [S],
+    index: usize,
+    prefix: Vec<char>,
+    states: Vec<LevenshteinState>,
+}
+
+impl<'w, S: AsRef<str>> Iterator for LevenshteinMatches<'_, 'w, S> {
+    type Item = (&'w str, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let word = self.words.get(self.index)?.as_ref();
+            self.index += 1;
+
+            let common = self.prefix.iter().zip(word.chars()).take_while(|(a, b)| **a == *b).count();
+            self.prefix.truncate(common);
+            self.states.truncate(common + 1);
+
+            let mut alive = true;
+            for c in word.chars().skip(common) {
+                let state = self.automaton.step(&self.states[self.states.len() - 1], c);
+                alive = self.automaton.can_match(&state);
+                self.prefix.push(c);
+                self.states.push(state);
+                if !alive {
+                    break;
+                }
+            }
+
+            if !alive {
+                let dead: String = self.prefix.iter().collect();
+                self.index += self.words[self.index..].partition_point(|w| w.as_ref().starts_with(&dead));
+                continue;
+            }
+
+            let state = &self.states[self.states.len() - 1];
+            if self.automaton.is_match(state) {
+                return Some((word, state.row[self.automaton.query.len()]));
+            }
+        }
+    }
+}
+
+/// A match found by the find_approx function: the byte span in the haystack
+/// and the number of edits between it and the needle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApproxMatch {
+    pub start: usize,
+    pub end: usize,
+    pub edits: usize,
+}
+
+/// Finds the first approximate occurrence of `needle` in `haystack`: a
+/// substring within `max_edits` insertions, deletions or substitutions of
+/// the needle (Unicode scalar values), e.g. to locate words in OCR'd text.
+///
+/// Among the matches overlapping the first one, the one with the fewest edits
+/// wins (on a tie, the first as long as the needle), and it starts where the
+/// fewest edits are needed (the shortest such span). Needles of up to 64
+/// characters are searched with Myers' bit-parallel algorithm, longer ones
+/// with the classic dynamic programming algorithm.
+///
+/// # Returns
+/// An [`ApproxMatch`], or `None` if no substring is close enough.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::matcher::find_approx;
+/// let ocr = "Tota1 amount: 1,234.00 EUR";
+/// let found = find_approx(ocr, "Total", 1).unwrap();
+/// assert_eq!((found.start, found.end, found.edits), (0, 5, 1));
+/// assert_eq!(&ocr[found.start..found.end], "Tota1");
+/// assert!(find_approx(ocr, "Subtotal", 2).is_none());
+/// ```
+pub fn find_approx(haystack: &str, needle: &str, max_edits: usize) -> Option<ApproxMatch> {
+    let pattern: Vec<char> = needle.chars().collect();
+    if pattern.is_empty() {
+        return Some(ApproxMatch { start: 0, end: 0, edits: 0 });
+    }
+
+    let scores: Box<dyn Iterator<Item = (usize, usize)>> = if pattern.len() <= 64 {
+        Box::new(myers_end_scores(haystack, &pattern))
+    } else {
+        Box::new(sellers_end_scores(haystack, &pattern))
+    };
+    let scores = std::iter::once((0, pattern.len())).chain(scores);
+
+    let mut best: Option<ApproxMatch> = None;
+    for (end, edits) in scores {
+        if let Some(found) = best
+            && haystack[found.start..end].chars().count() > pattern.len() + max_edits
+        {
+            break;
+        }
+        if edits > max_edits {
+            continue;
+        }
+        // Prefer fewer edits, then a span as long as the needle (`Tota1` over `Tota` for `Total`)
+        let better = best.is_none_or(|found| {
+            edits < found.edits || (edits == found.edits && haystack[found.start..found.end].chars().count() < pattern.len())
+        });
+        if better {
+            best = Some(ApproxMatch { start: match_start(&haystack[..end], &pattern, edits), end, edits });
+        }
+    }
+    best
+}
+
+/// Myers' bit-vector search: for every character of `text`, the byte offset
+/// after it and the smallest edit distance of `pattern` (at most 64
+/// characters) to a substring ending there.
+fn myers_end_scores<'a>(text: &'a str, pattern: &[char]) -> impl Iterator<Item = (usize, usize)> + 'a {
+    let m = pattern.len();
+    let mut peq: HashMap<char, u64> = HashMap::new();
+    for (i, c) in pattern.iter().enumerate() {
+        *peq.entry(*c).or_insert(0) |= 1 << i;
+    }
+    let last_bit = 1u64 << (m - 1);
+    let (mut pv, mut mv, mut score) = (u64::MAX, 0u64, m);
+
+    text.char_indices().map(move |(i, c)| {
+        let eq = peq.get(&c).copied().unwrap_or(0);
+        let xv = eq | mv;
+        let xh = ((eq & pv).wrapping_add(pv) ^ pv) | eq;
+        let mut ph = mv | !(xh | pv);
+        let mut mh = pv & xh;
+        if ph & last_bit != 0 {
+            score += 1;
+        } else if mh & last_bit != 0 {
+            score -= 1;
+        }
+        ph <<= 1;
+        mh <<= 1;
+        pv = mh | !(xv | ph);
+        mv = ph & xv;
+        (i + c.len_utf8(), score)
+    })
+}
+
+/// Same as [`myers_end_scores`] with Sellers' dynamic programming, for
+/// patterns of any length.
+fn sellers_end_scores<'a>(text: &'a str, pattern: &'a [char]) -> impl Iterator<Item = (usize, usize)> + 'a {
+    let mut column: Vec<usize> = (0..=pattern.len()).collect();
+    text.char_indices().map(move |(i, c)| {
+        let mut diagonal = column[0];
+        for (j, p) in pattern.iter().enumerate() {
+            let substitution = diagonal + usize::from(*p != c);
+            diagonal = column[j + 1];
+            column[j + 1] = substitution.min(column[j] + 1).min(diagonal + 1);
+        }
+        (i + c.len_utf8(), column[pattern.len()])
+    })
+}
+
+/// Returns the largest start of a substring of `text` ending at its end and
+/// within `edits` of `pattern`.
+fn match_start(text: &str, pattern: &[char], edits: usize) -> usize {
+    // Dynamic programming on the reversed strings, anchored at the end
+    let mut column: Vec<usize> = (0..=pattern.len()).collect();
+    if column[pattern.len()] <= edits {
+        return text.len();
+    }
+    for (i, c) in text.char_indices().rev().take(pattern.len() + edits) {
+        let mut diagonal = column[0];
+        column[0] += 1;
+        for (j, p) in pattern.iter().rev().enumerate() {
+            let substitution = diagonal + usize::from(*p != c);
+            diagonal = column[j + 1];
+            column[j + 1] = substitution.min(column[j] + 1).min(diagonal + 1);
+        }
+        if column[pattern.len()] <= edits {
+            return i;
+        }
+    }
+    0
+}
+
+/// A match found by [`abbrev_match`]: how well the abbreviation fits and
+/// which parts of the candidate it matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbbrevScore {
+    /// Higher is better; only comparable between matches of the same pattern.
+    pub score: u32,
+    /// Byte ranges of the candidate matched by the pattern, one per hump, for
+    /// highlighting.
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// Matches an IDE-style camel-hump abbreviation against an identifier, as
+/// symbol pickers do (`FiRe` finds `FileReader`).
+///
+/// The candidate is split into words like [`to_snake_case`](crate::case::to_snake_case)
+/// does (`HTTPServer` → `HTTP` `Server`, `file_reader` → `file` `reader`).
+/// The pattern must then be a sequence of fragments, each the prefix of a
+/// word, in order; words may be skipped.
+///
+/// ### Rules implemented:
+/// - Matching is case-insensitive, but an uppercase pattern letter starts a
+///   new hump unless the candidate letter is uppercase too (`FiRe` does not
+///   match `Fire`).
+/// - Non-alphanumeric pattern characters are ignored (`file_r` is `filer`).
+/// - The score favors matches starting at the first word, longer fragments,
+///   letters of the same case and fewer skipped words.
+///
+/// # Returns
+/// An [`AbbrevScore`], or `None` if the pattern does not match.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::matcher::abbrev_match;
+/// let found = abbrev_match("FiRe", "FileReader").unwrap();
+/// assert_eq!(found.ranges, vec![0..2, 4..6]);
+/// assert!(abbrev_match("fr", "file_reader").is_some());
+/// assert!(abbrev_match("FiRe", "Firewall").is_none());
+/// // A match at the start ranks above one in the middle
+/// let prefix = abbrev_match("BuRe", "BufferedReader").unwrap();
+/// let middle = abbrev_match("BuRe", "InputBufferedReader").unwrap();
+/// assert!(prefix.score > middle.score);
+/// ```
+pub fn abbrev_match(pattern: &str, candidate: &str) -> Option<AbbrevScore> {
+    const FIRST_WORD_BONUS: u32 = 16;
+    const HUMP_SCORE: u32 = 6;
+    const CONTINUATION_SCORE: u32 = 5;
+    const WHOLE_WORD_BONUS: u32 = 2;
+    const SKIPPED_WORD_PENALTY: u32 = 2;
+
+    let pattern: Vec<char> = pattern.chars().filter(|c| c.is_alphanumeric()).collect();
+    if pattern.is_empty() {
+        return Some(AbbrevScore { score: 0, ranges: Vec::new() });
+    }
+    let words: Vec<Vec<(usize, char)>> = identifier_words(candidate)
+        .into_iter()
+        .map(|range| candidate[range.clone()].char_indices().map(|(i, c)| (range.start + i, c)).collect())
+        .collect();
+
+    // best[p][w]: best score matching pattern[p..] with a fragment starting at
+    // word w, with the fragment length and the word of the next fragment
+    let mut best: Vec<Vec<Option<(u32, usize, usize)>>> = vec![vec![None; words.len()]; pattern.len() + 1];
+    for p in (0..pattern.len()).rev() {
+        for (w, word) in words.iter().enumerate() {
+            let mut fragment_score = HUMP_SCORE - CONTINUATION_SCORE;
+            for (k, &(_, c)) in word.iter().enumerate().take(pattern.len() - p) {
+                let wanted = pattern[p + k];
+                let same_letter = wanted == c || wanted.to_lowercase().eq(c.to_lowercase());
+                if !same_letter || (k > 0 && wanted.is_uppercase() && !c.is_uppercase()) {
+                    break;
+                }
+                fragment_score += CONTINUATION_SCORE + u32::from(wanted == c);
+                let rest = p + k + 1;
+                let candidate_score = if rest == pattern.len() {
+                    Some((fragment_score + if k + 1 == word.len() { WHOLE_WORD_BONUS } else { 0 }, w))
+                } else {
+                    (w + 1..words.len())
+                        .filter_map(|next| {
+                            let (score, _, _) = best[rest][next]?;
+                            let skipped = (next - w - 1) as u32;
+                            Some(((fragment_score + score).saturating_sub(SKIPPED_WORD_PENALTY * skipped), next))
+                        })
+                        .max_by_key(|&(score, next)| (score, std::cmp::Reverse(next)))
+                };
+                if let Some((score, next)) = candidate_score
+                    && best[p][w].is_none_or(|(current, _, _)| score > current)
+                {
+                    best[p][w] = Some((score, k + 1, next));
+                }
+            }
+        }
+    }
+
+    let (mut w, score) = (0..words.len())
+        .filter_map(|w| best[0][w].map(|(score, _, _)| (w, score + if w == 0 { FIRST_WORD_BONUS } else { 0 })))
+        .max_by_key(|&(w, score)| (score, std::cmp::Reverse(w)))?;
+    let mut ranges = Vec::new();
+    let mut p = 0;
+    while p < pattern.len() {
+        let (_, length, next) = best[p][w].expect("path recorded by the table");
+        let word = &words[w];
+        let end = word.get(length).map_or_else(|| word[length - 1].0 + word[length - 1].1.len_utf8(), |&(i, _)| i);
+        ranges.push(word[0].0..end);
+        p += length;
+        w = next;
+    }
+    Some(AbbrevScore { score, ranges })
+}
+
+/// A string distance usable by [`most_similar`] and [`fuzzy_filter`], so the
+/// metric can be swapped without changing the search code.
+///
+/// Implemented by [`Levenshtein`], [`JaroWinkler`], [`Trigram`] and
+/// [`SimHash`]; implement it for a custom metric.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::matcher::{JaroWinkler, Levenshtein, StringMetric};
+/// let metrics: [&dyn StringMetric; 2] = [&Levenshtein, &JaroWinkler];
+/// for metric in metrics {
+///     assert_eq!(metric.normalized_similarity("same", "same"), 1.0);
+/// }
+/// assert_eq!(Levenshtein.distance("kitten", "sitting"), 3.0);
+/// ```
+pub trait StringMetric {
+    /// Returns the distance between `a` and `b`: `0.0` for equal strings, in
+    /// the unit of the metric.
+    fn distance(&self, a: &str, b: &str) -> f64;
+
+    /// Returns the similarity of `a` and `b`, between `0.0` (nothing in
+    /// common) and `1.0` (equal).
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64;
+}
+
+/// The Levenshtein metric: the distance is the number of edits
+/// ([`levenshtein`]), the similarity [`levenshtein_similarity`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Levenshtein;
+
+/// The Jaro-Winkler metric: the similarity is [`jaro_winkler_similarity`],
+/// the distance `1 - similarity`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JaroWinkler;
+
+/// The trigram metric: the similarity is [`trigram_similarity`], the distance
+/// `1 - similarity`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Trigram;
+
+/// The SimHash metric: the distance is the number of differing bits of the
+/// [`simhash`] of both strings, the similarity `1 - bits / 64`. Cheap to
+/// compare once hashed, and meant for longer texts such as log lines.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SimHash;
+
+impl StringMetric for Levenshtein {
+    fn distance(&self, a: &str, b: &str) -> f64 {
+        levenshtein(a, b) as f64
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        levenshtein_similarity(a, b)
+    }
+}
+
+impl StringMetric for JaroWinkler {
+    fn distance(&self, a: &str, b: &str) -> f64 {
+        1.0 - jaro_winkler_similarity(a, b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        jaro_winkler_similarity(a, b)
+    }
+}
+
+impl StringMetric for Trigram {
+    fn distance(&self, a: &str, b: &str) -> f64 {
+        1.0 - self.normalized_similarity(a, b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        if a == b { 1.0 } else { trigram_similarity(a, b) }
+    }
+}
+
+impl StringMetric for SimHash {
+    fn distance(&self, a: &str, b: &str) -> f64 {
+        (simhash(a) ^ simhash(b)).count_ones() as f64
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        1.0 - self.distance(a, b) / 64.0
+    }
+}
+
+/// Returns the candidate most similar to `query` according to `metric`, with
+/// its similarity. On a tie, the first candidate wins.
+///
+/// # Returns
+/// `None` if there are no candidates.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::matcher::{most_similar, JaroWinkler, Levenshtein};
+/// let commands = ["status", "stash", "commit", "checkout"];
+/// assert_eq!(most_similar("stsh", &commands, &Levenshtein).map(|(c, _)| c), Some("stash"));
+/// assert_eq!(most_similar("chekout", &commands, &JaroWinkler).map(|(c, _)| c), Some("checkout"));
+/// ```
+pub fn most_similar<'a, S: AsRef<str>, M: StringMetric + ?Sized>(
+    query: &str,
+    candidates: &'a [S],
+    metric: &M,
+) -> Option<(&'a str, f64)> {
+    candidates
+        .iter()
+        .map(|candidate| (candidate.as_ref(), metric.normalized_similarity(query, candidate.as_ref())))
+        .fold(None, |best, (candidate, similarity)| match best {
+            Some((_, best_similarity)) if best_similarity >= similarity => best,
+            _ => Some((candidate, similarity)),
+        })
+}
+
+/// Returns the candidates whose similarity to `query` according to `metric`
+/// is at least `min_similarity`, most similar first (candidates with the same
+/// similarity keep their order).
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::matcher::{fuzzy_filter, Trigram};
+/// let crates = ["serde", "serde_json", "tokio", "serde_yaml"];
+/// let hits: Vec<&str> = fuzzy_filter("serde-json", &crates, &Trigram, 0.4).into_iter().map(|(c, _)| c).collect();
+/// assert_eq!(hits, vec!["serde_json", "serde"]);
+/// ```
+pub fn fuzzy_filter<'a, S: AsRef<str>, M: StringMetric + ?Sized>(
+    query: &str,
+    candidates: &'a [S],
+    metric: &M,
+    min_similarity: f64,
+) -> Vec<(&'a str, f64)> {
+    let mut hits: Vec<(&'a str, f64)> = candidates
+        .iter()
+        .map(|candidate| (candidate.as_ref(), metric.normalized_similarity(query, candidate.as_ref())))
+        .filter(|(_, similarity)| *similarity >= min_similarity)
+        .collect();
+    hits.sort_by(|a, b| b.1.total_cmp(&a.1));
+    hits
+}
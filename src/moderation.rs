@@ -0,0 +1,163 @@
+/// Severity of a [`ContentFilter`] term, from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SeverityEnum {
+    Low,
+    Medium,
+    High,
+}
+
+/// A term found by [`ContentFilter::classify`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterMatch {
+    /// The term as it was added to the filter.
+    pub term: String,
+    /// Byte offset of the match in the text.
+    pub start: usize,
+    /// Byte offset just after the match.
+    pub end: usize,
+    pub severity: SeverityEnum,
+}
+
+/// The result of [`ContentFilter::classify`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FilterVerdict {
+    /// The matches, sorted by position.
+    pub matches: Vec<FilterMatch>,
+    /// The highest severity among the matches, or `None` if the text is clean.
+    pub max_severity: Option<SeverityEnum>,
+}
+
+impl FilterVerdict {
+    /// Returns `true` if no term was found.
+    pub fn is_clean(&self) -> bool {
+        self.matches.is_empty()
+    }
+}
+
+/// A configurable word filter for moderation: terms with a severity, an
+/// allowlist of words that must never be flagged, and an optional
+/// word-boundary-only mode.
+///
+/// Matching is case-insensitive. By default terms are also found inside
+/// words; a match is ignored when it falls inside an allowlisted word, which
+/// avoids the classic false positive of "Scunthorpe".
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::moderation::{ContentFilter, SeverityEnum};
+/// let filter = ContentFilter::new()
+///     .term("darn", SeverityEnum::Low)
+///     .term("heck", SeverityEnum::Medium)
+///     .allow("Checkout");
+/// let verdict = filter.classify("Darn it, what the HECK! Go to checkout.");
+/// assert_eq!(verdict.matches.len(), 2);
+/// assert_eq!(verdict.matches[1].start, 18);
+/// assert_eq!(verdict.max_severity, Some(SeverityEnum::Medium));
+///
+/// let strict = filter.word_boundary_only(true);
+/// assert!(strict.classify("darned").is_clean());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ContentFilter {
+    terms: Vec<(String, SeverityEnum)>,
+    allowlist: Vec<String>,
+    word_boundary_only: bool,
+}
+
+impl ContentFilter {
+    /// Creates an empty filter matching terms anywhere in the text.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a term with its severity. Adding a term again replaces its
+    /// severity.
+    pub fn term(mut self, term: &str, severity: SeverityEnum) -> Self {
+        let term = term.to_lowercase();
+        match self.terms.iter_mut().find(|(t, _)| *t == term) {
+            Some(existing) => existing.1 = severity,
+            None if !term.is_empty() => self.terms.push((term, severity)),
+            None => {}
+        }
+        self
+    }
+
+    /// Adds a word (or phrase) that is never flagged, even when it contains a
+    /// term.
+    pub fn allow(mut self, word: &str) -> Self {
+        if !word.is_empty() {
+            self.allowlist.push(word.to_lowercase());
+        }
+        self
+    }
+
+    /// Only matches terms standing as whole words when `enabled`.
+    pub fn word_boundary_only(mut self, enabled: bool) -> Self {
+        self.word_boundary_only = enabled;
+        self
+    }
+
+    /// Finds the terms in `text`.
+    ///
+    /// # Returns
+    /// A [`FilterVerdict`] with the matches sorted by start offset (then by
+    /// end offset) and their maximum severity.
+    pub fn classify(&self, text: &str) -> FilterVerdict {
+        let allowed: Vec<(usize, usize)> = self
+            .allowlist
+            .iter()
+            .flat_map(|word| find_all_ci(text, word))
+            .filter(|&(start, end)| is_word_bounded(text, start, end))
+            .collect();
+
+        let mut matches: Vec<FilterMatch> = Vec::new();
+        for (term, severity) in &self.terms {
+            for (start, end) in find_all_ci(text, term) {
+                if self.word_boundary_only && !is_word_bounded(text, start, end) {
+                    continue;
+                }
+                if allowed.iter().any(|&(a, b)| a <= start && end <= b) {
+                    continue;
+                }
+                matches.push(FilterMatch { term: term.clone(), start, end, severity: *severity });
+            }
+        }
+        matches.sort_by_key(|m| (m.start, m.end));
+
+        let max_severity = matches.iter().map(|m| m.severity).max();
+        FilterVerdict { matches, max_severity }
+    }
+}
+
+/// Byte ranges of the case-insensitive occurrences of `needle` (lowercase)
+/// in `text`, overlapping ones included.
+fn find_all_ci(text: &str, needle: &str) -> Vec<(usize, usize)> {
+    text.char_indices()
+        .filter_map(|(start, _)| starts_with_ci(&text[start..], needle).map(|len| (start, start + len)))
+        .collect()
+}
+
+/// Returns the byte length of the prefix of `haystack` matching `needle`
+/// (lowercase) case-insensitively.
+fn starts_with_ci(haystack: &str, needle: &str) -> Option<usize> {
+    let mut needle = needle.chars().peekable();
+    let mut len = 0;
+    for c in haystack.chars() {
+        if needle.peek().is_none() {
+            break;
+        }
+        for lower in c.to_lowercase() {
+            if needle.next() != Some(lower) {
+                return None;
+            }
+        }
+        len += c.len_utf8();
+    }
+    needle.peek().is_none().then_some(len)
+}
+
+fn is_word_bounded(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].chars().next_back().is_none_or(|c| !c.is_alphanumeric());
+    let after = text[end..].chars().next().is_none_or(|c| !c.is_alphanumeric());
+    before && after
+}
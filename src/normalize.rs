@@ -0,0 +1,76 @@
+use crate::analyzer::is_combining_mark;
+use crate::cleanser::{collapse_whitespace, strip_excess_combining_marks};
+use crate::locale::{case_fold, compose_latin, fold_latin};
+
+/// What a text is normalized for by the normalize_for function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Purpose {
+    /// Matching and indexing: case folded, without accents, fullwidth forms
+    /// narrowed and whitespace collapsed. Lossy.
+    Search,
+    /// Showing to users: composed accents, `\n` newlines, no trailing
+    /// whitespace on lines and at most two combining marks per letter.
+    Display,
+    /// Persisting: composed accents and `\n` newlines. Nothing visible is lost.
+    Storage,
+}
+
+/// Normalizes text with a fixed, opinionated set of steps for a [`Purpose`],
+/// applied in this order:
+///
+/// | Step | Search | Display | Storage |
+/// |------|:------:|:-------:|:-------:|
+/// | `\r\n` and `\r` become `\n` | ✓ | ✓ | ✓ |
+/// | Fullwidth ASCII and ideographic space become ASCII | ✓ | | |
+/// | Accents and other combining marks removed (`é` → `e`, `ø` → `o`) | ✓ | | |
+/// | Full case folding (`Straße` → `strasse`) | ✓ | | |
+/// | Whitespace runs become one space, ends trimmed | ✓ | | |
+/// | More than two combining marks per letter removed | | ✓ | |
+/// | Latin letters composed (NFC: `e` + `◌́` → `é`) | | ✓ | ✓ |
+/// | Trailing whitespace removed from every line | | ✓ | |
+///
+/// Composition covers the precomposed Latin letters only.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::normalize::{normalize_for, Purpose};
+/// assert_eq!(normalize_for("  Crème\u{a0}BRÛLÉE\r\n", Purpose::Search), "creme brulee");
+/// assert_eq!(normalize_for("ＡＢＣ　１２３", Purpose::Search), "abc 123");
+/// assert_eq!(normalize_for("Cre\u{300}me  \r\nbru\u{302}le\u{301}e", Purpose::Display), "Crème\nbrûlée");
+/// assert_eq!(normalize_for("Cre\u{300}me  \r\n", Purpose::Storage), "Crème  \n");
+/// ```
+pub fn normalize_for(text: &str, purpose: Purpose) -> String {
+    let text = normalize_newlines(text);
+    match purpose {
+        Purpose::Search => {
+            let mut folded = String::with_capacity(text.len());
+            for c in text.chars().map(narrow_width) {
+                match fold_latin(c) {
+                    Some(decomposed) => folded.extend(decomposed.chars().filter(|m| !is_combining_mark(*m))),
+                    None if is_combining_mark(c) => {}
+                    None => folded.push(c),
+                }
+            }
+            collapse_whitespace(&case_fold(&folded)).trim().to_string()
+        }
+        Purpose::Display => {
+            let composed = compose_latin(&strip_excess_combining_marks(&text, 2));
+            composed.split('\n').map(str::trim_end).collect::<Vec<_>>().join("\n")
+        }
+        Purpose::Storage => compose_latin(&text),
+    }
+}
+
+fn normalize_newlines(text: &str) -> String {
+    text.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// Maps fullwidth ASCII forms (`Ａ`, `１`, `！`) and the ideographic space to
+/// their ASCII counterparts.
+fn narrow_width(c: char) -> char {
+    match c {
+        '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+        '\u{3000}' => ' ',
+        _ => c,
+    }
+}
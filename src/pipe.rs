@@ -0,0 +1,144 @@
+use std::borrow::Cow;
+
+use crate::case::to_snake_case;
+use crate::cleanser::collapse_whitespace;
+
+/// A step of a [`Pipe`].
+enum Step {
+    Trim,
+    TrimStart,
+    TrimEnd,
+    CollapseWhitespace,
+    Lowercase,
+    Uppercase,
+    SnakeCase,
+    TakeChars(usize),
+}
+
+/// Chains string transformations and runs them when [`Pipe::finish`] is
+/// called.
+///
+/// Steps that only shorten the text (`trim`, `take_chars`) slice it instead
+/// of copying it, so a pipeline of such steps allocates only the final
+/// `String`. Consecutive case changes are fused into one pass.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::pipe::Pipe;
+/// let key = Pipe::from("   Monthly  Sales\tReport (2024)  ")
+///     .trim()
+///     .collapse_whitespace()
+///     .to_snake_case()
+///     .take_chars(30)
+///     .finish();
+/// assert_eq!(key, "monthly_sales_report_2024");
+/// ```
+pub struct Pipe<'a> {
+    input: &'a str,
+    steps: Vec<Step>,
+}
+
+impl<'a> From<&'a str> for Pipe<'a> {
+    fn from(input: &'a str) -> Self {
+        Pipe { input, steps: Vec::new() }
+    }
+}
+
+impl<'a> Pipe<'a> {
+    /// Removes leading and trailing whitespace.
+    pub fn trim(mut self) -> Self {
+        self.steps.push(Step::Trim);
+        self
+    }
+
+    /// Removes leading whitespace.
+    pub fn trim_start(mut self) -> Self {
+        self.steps.push(Step::TrimStart);
+        self
+    }
+
+    /// Removes trailing whitespace.
+    pub fn trim_end(mut self) -> Self {
+        self.steps.push(Step::TrimEnd);
+        self
+    }
+
+    /// Replaces each run of whitespace with a single ASCII space (see
+    /// [`collapse_whitespace`]).
+    pub fn collapse_whitespace(mut self) -> Self {
+        self.steps.push(Step::CollapseWhitespace);
+        self
+    }
+
+    /// Converts the text to lowercase.
+    pub fn to_lowercase(mut self) -> Self {
+        self.steps.push(Step::Lowercase);
+        self
+    }
+
+    /// Converts the text to uppercase.
+    pub fn to_uppercase(mut self) -> Self {
+        self.steps.push(Step::Uppercase);
+        self
+    }
+
+    /// Converts the text to `snake_case` (see [`to_snake_case`]).
+    pub fn to_snake_case(mut self) -> Self {
+        self.steps.push(Step::SnakeCase);
+        self
+    }
+
+    /// Keeps at most the first `n` characters.
+    pub fn take_chars(mut self, n: usize) -> Self {
+        self.steps.push(Step::TakeChars(n));
+        self
+    }
+
+    /// Runs the steps and returns the result.
+    pub fn finish(self) -> String {
+        self.run().into_owned()
+    }
+
+    /// Runs the steps, returning the input borrowed if it was only sliced.
+    pub fn finish_cow(self) -> Cow<'a, str> {
+        self.run()
+    }
+
+    fn run(self) -> Cow<'a, str> {
+        let mut text = Cow::Borrowed(self.input);
+        let mut steps = self.steps.iter().peekable();
+
+        while let Some(step) = steps.next() {
+            text = match step {
+                Step::Trim => slice(text, str::trim),
+                Step::TrimStart => slice(text, str::trim_start),
+                Step::TrimEnd => slice(text, str::trim_end),
+                Step::TakeChars(n) => slice(text, |s| {
+                    &s[..s.char_indices().nth(*n).map_or(s.len(), |(i, _)| i)]
+                }),
+                Step::CollapseWhitespace => Cow::Owned(collapse_whitespace(&text)),
+                Step::SnakeCase => Cow::Owned(to_snake_case(&text)),
+                Step::Lowercase | Step::Uppercase => {
+                    // Only the last of consecutive case changes matters
+                    let mut last = step;
+                    while let Some(next) = steps.next_if(|s| matches!(s, Step::Lowercase | Step::Uppercase)) {
+                        last = next;
+                    }
+                    match last {
+                        Step::Uppercase => Cow::Owned(text.to_uppercase()),
+                        _ => Cow::Owned(text.to_lowercase()),
+                    }
+                }
+            };
+        }
+        text
+    }
+}
+
+/// Applies a slicing function, without copying borrowed text.
+fn slice<'a>(text: Cow<'a, str>, f: impl Fn(&str) -> &str) -> Cow<'a, str> {
+    match text {
+        Cow::Borrowed(s) => Cow::Borrowed(f(s)),
+        Cow::Owned(s) => Cow::Owned(f(&s).to_owned()),
+    }
+}
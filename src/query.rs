@@ -0,0 +1,97 @@
+/// Decodes `%XX` percent escapes. Escapes that do not form valid UTF-8 are
+/// replaced with `U+FFFD`; a `%` not followed by two hexadecimal digits is
+/// kept as written. `+` is not changed (see [`parse_query`]).
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::query::percent_decode;
+/// assert_eq!(percent_decode("hello%20world%21"), "hello world!");
+/// assert_eq!(percent_decode("caf%C3%A9"), "café");
+/// assert_eq!(percent_decode("100%"), "100%");
+/// ```
+pub fn percent_decode(s: &str) -> String {
+    if !s.contains('%') {
+        return s.to_owned();
+    }
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let Some(hex) = s.get(i + 1..i + 3)
+            && hex.bytes().all(|b| b.is_ascii_hexdigit())
+            && let Ok(byte) = u8::from_str_radix(hex, 16)
+        {
+            out.push(byte);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Decodes a query string key or value: `+` is a space, then percent escapes.
+fn decode_component(s: &str) -> String {
+    if s.contains('+') {
+        percent_decode(&s.replace('+', " "))
+    } else {
+        percent_decode(s)
+    }
+}
+
+/// Parses a URL query string into its `(key, value)` parameters, in order
+/// and including repeated keys.
+///
+/// ### Rules implemented:
+/// - Anything up to the first `?` (a full URL or path) is skipped, as is a
+///   `#fragment`.
+/// - Parameters are separated by `&` or `;`; empty ones are skipped.
+/// - A parameter without `=` has an empty value.
+/// - Keys and values are percent-decoded, with `+` meaning a space (`%2B`
+///   is a literal `+`).
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::query::parse_query;
+/// let params = parse_query("/search?q=hello%20world&tag=a+b;tag=c&debug");
+/// assert_eq!(params, vec![
+///     ("q".to_owned(), "hello world".to_owned()),
+///     ("tag".to_owned(), "a b".to_owned()),
+///     ("tag".to_owned(), "c".to_owned()),
+///     ("debug".to_owned(), "".to_owned()),
+/// ]);
+/// ```
+pub fn parse_query(query: &str) -> Vec<(String, String)> {
+    query_params(query)
+        .map(|(key, value)| (decode_component(key), decode_component(value)))
+        .collect()
+}
+
+/// Returns the decoded value of the first parameter named `key` (compared
+/// after decoding), or `None` if there is none.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::query::find_query_param;
+/// let url = "https://example.com/?name=J%C3%BCrgen&page=2#top";
+/// assert_eq!(find_query_param(url, "name"), Some("Jürgen".to_owned()));
+/// assert_eq!(find_query_param(url, "page"), Some("2".to_owned()));
+/// assert_eq!(find_query_param(url, "top"), None);
+/// ```
+pub fn find_query_param(query: &str, key: &str) -> Option<String> {
+    query_params(query)
+        .find(|(k, _)| decode_component(k) == key)
+        .map(|(_, value)| decode_component(value))
+}
+
+/// The raw `(key, value)` parameters of a query string.
+fn query_params(query: &str) -> impl Iterator<Item = (&str, &str)> {
+    let query = query.split_once('#').map_or(query, |(q, _)| q);
+    let query = query.split_once('?').map_or(query, |(_, q)| q);
+    query
+        .split(['&', ';'])
+        .filter(|param| !param.is_empty())
+        .map(|param| param.split_once('=').unwrap_or((param, "")))
+}
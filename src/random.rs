@@ -0,0 +1,251 @@
+use rand::RngExt;
+
+/// Alphabet of readable codes: Crockford's Base32, without the letters
+/// `I`, `L`, `O` (confused with `1` and `0`) and `U`.
+pub const READABLE_ALPHABET: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Generates a random code of `len` characters that is easy to read aloud
+/// and type, such as a license key or a 2FA backup code.
+///
+/// Characters come from [`READABLE_ALPHABET`], which never uses the letters
+/// `I`, `L` and `O`, so a code has no look-alike pairs; the digits `0` and
+/// `1` are the only forms of those shapes. With `grouping` > 0 the code is
+/// split in groups of that many characters joined by `-`.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::random::{generate_readable_code, normalize_entered_code};
+/// let code = generate_readable_code(8, 4);
+/// assert_eq!(code.len(), 9);
+/// assert_eq!(&code[4..5], "-");
+/// assert_eq!(normalize_entered_code(&code.to_lowercase(), 4), Some(code));
+/// ```
+pub fn generate_readable_code(len: usize, grouping: usize) -> String {
+    let alphabet = READABLE_ALPHABET.as_bytes();
+    let mut rng = rand::rng();
+    let code: String = (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())] as char)
+        .collect();
+    group_code(&code, grouping)
+}
+
+/// Normalizes a code typed by a user into the form returned by
+/// [`generate_readable_code`], so it can be compared with the stored code.
+///
+/// ### Rules implemented:
+/// - Letters are uppercased; spaces, `-` and `_` are ignored.
+/// - Look-alike typos are corrected: `O` → `0`, `I` and `L` → `1`, `U` → `V`.
+/// - The code is regrouped with `grouping` like the generated code.
+///
+/// # Returns
+/// `None` if the input contains a character that cannot be part of a code.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::random::normalize_entered_code;
+/// assert_eq!(normalize_entered_code("ab1o 2l3i", 4), Some("AB10-2131".to_owned()));
+/// assert_eq!(normalize_entered_code("AB10-2131", 0), Some("AB102131".to_owned()));
+/// assert_eq!(normalize_entered_code("AB!0", 4), None);
+/// ```
+pub fn normalize_entered_code(input: &str, grouping: usize) -> Option<String> {
+    let mut code = String::with_capacity(input.len());
+    for c in input.chars() {
+        let c = match c.to_ascii_uppercase() {
+            ' ' | '-' | '_' => continue,
+            'O' => '0',
+            'I' | 'L' => '1',
+            'U' => 'V',
+            c if READABLE_ALPHABET.contains(c) => c,
+            _ => return None,
+        };
+        code.push(c);
+    }
+    Some(group_code(&code, grouping))
+}
+
+/// Picks one of the items at random, with a probability proportional to its
+/// weight, e.g. to generate test data with a realistic distribution.
+///
+/// Items with a weight that is zero, negative or not finite are never
+/// picked. Returns `""` if no item can be picked.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::random::sample_weighted;
+/// let status = sample_weighted(&[("ok", 0.9), ("error", 0.1), ("never", 0.0)]);
+/// assert!(status == "ok" || status == "error");
+/// assert_eq!(sample_weighted(&[]), "");
+/// ```
+pub fn sample_weighted<'a>(items: &[(&'a str, f64)]) -> &'a str {
+    let valid = |w: f64| w.is_finite() && w > 0.0;
+    let total: f64 = items.iter().map(|(_, w)| *w).filter(|w| valid(*w)).sum();
+    if total <= 0.0 {
+        return "";
+    }
+
+    let mut target = rand::rng().random_range(0.0..total);
+    let mut last = "";
+    for (item, weight) in items.iter().filter(|(_, w)| valid(*w)) {
+        if target < *weight {
+            return item;
+        }
+        target -= weight;
+        last = item;
+    }
+    // Rounding errors can leave a tiny remainder: it belongs to the last item
+    last
+}
+
+/// Shuffles the words of the text (split on whitespace and joined with single
+/// spaces) in an order determined by `seed`, for text augmentation and
+/// reproducible test data. The same text and seed always give the same result.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::random::shuffle_words;
+/// let shuffled = shuffle_words("the quick brown fox jumps", 42);
+/// assert_eq!(shuffled, shuffle_words("the  quick brown\nfox jumps", 42));
+/// let mut words: Vec<&str> = shuffled.split(' ').collect();
+/// words.sort();
+/// assert_eq!(words, vec!["brown", "fox", "jumps", "quick", "the"]);
+/// ```
+pub fn shuffle_words(text: &str, seed: u64) -> String {
+    let mut words: Vec<&str> = text.split_whitespace().collect();
+    let mut rng = SplitMix64(seed);
+    // Fisher-Yates
+    for i in (1..words.len()).rev() {
+        words.swap(i, rng.below(i as u64 + 1) as usize);
+    }
+    words.join(" ")
+}
+
+const LOREM_WORDS: &[&str] = &[
+    "lorem", "ipsum", "dolor", "sit", "amet", "consectetur", "adipiscing", "elit", "sed", "do",
+    "eiusmod", "tempor", "incididunt", "ut", "labore", "et", "dolore", "magna", "aliqua", "enim",
+    "ad", "minim", "veniam", "quis", "nostrud", "exercitation", "ullamco", "laboris", "nisi", "aliquip",
+    "ex", "ea", "commodo", "consequat", "duis", "aute", "irure", "in", "reprehenderit", "voluptate",
+    "velit", "esse", "cillum", "fugiat", "nulla", "pariatur", "excepteur", "sint", "occaecat", "cupidatat",
+    "non", "proident", "sunt", "culpa", "qui", "officia", "deserunt", "mollit", "anim", "id", "est", "laborum",
+];
+
+/// The classic opening of lorem ipsum text, in words.
+const LOREM_OPENING: usize = 8;
+
+/// Seed of lorem_words and lorem_paragraphs, so their output never changes.
+const LOREM_SEED: u64 = 0x10E3_1950;
+
+/// Returns `n` lorem ipsum words separated by spaces, starting with the
+/// classic "lorem ipsum dolor sit amet...". The output only depends on `n`.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::random::lorem_words;
+/// assert_eq!(lorem_words(5), "lorem ipsum dolor sit amet");
+/// assert_eq!(lorem_words(100).split(' ').count(), 100);
+/// assert_eq!(lorem_words(0), "");
+/// ```
+pub fn lorem_words(n: usize) -> String {
+    let mut rng = SplitMix64(LOREM_SEED);
+    let words: Vec<&str> = (0..n)
+        .map(|i| if i < LOREM_OPENING { LOREM_WORDS[i] } else { LOREM_WORDS[rng.below(LOREM_WORDS.len() as u64) as usize] })
+        .collect();
+    words.join(" ")
+}
+
+/// Returns `n` paragraphs of lorem ipsum sentences separated by blank lines
+/// (`\n\n`), the first one starting with "Lorem ipsum dolor sit amet,
+/// consectetur adipiscing elit.". The output only depends on `n`.
+///
+/// Paragraphs have 4 to 8 sentences, which makes this a quick way to build
+/// large documents for tests.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::random::lorem_paragraphs;
+/// let text = lorem_paragraphs(3);
+/// assert!(text.starts_with("Lorem ipsum dolor sit amet, consectetur adipiscing elit. "));
+/// assert_eq!(text.split("\n\n").count(), 3);
+/// assert_eq!(text, lorem_paragraphs(3));
+/// ```
+pub fn lorem_paragraphs(n: usize) -> String {
+    let mut rng = SplitMix64(LOREM_SEED);
+    let paragraphs: Vec<String> = (0..n)
+        .map(|p| {
+            let count = 4 + rng.below(5) as usize;
+            let mut sentences: Vec<String> = (0..count).map(|_| sentence(&mut rng)).collect();
+            if p == 0 {
+                sentences[0] = "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_owned();
+            }
+            sentences.join(" ")
+        })
+        .collect();
+    paragraphs.join("\n\n")
+}
+
+/// Returns a lorem ipsum sentence of 6 to 14 words, capitalized and ending
+/// with a period, determined by `seed`.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::random::fake_sentence;
+/// let sentence = fake_sentence(7);
+/// assert_eq!(sentence, fake_sentence(7));
+/// assert!(sentence.ends_with('.'));
+/// assert!(sentence.starts_with(|c: char| c.is_uppercase()));
+/// ```
+pub fn fake_sentence(seed: u64) -> String {
+    sentence(&mut SplitMix64(seed))
+}
+
+fn sentence(rng: &mut SplitMix64) -> String {
+    let count = 6 + rng.below(9) as usize;
+    // Long sentences get a comma after a word in their first half
+    let comma = if count > 9 { Some(2 + rng.below(count as u64 / 2) as usize) } else { None };
+
+    let mut out = String::new();
+    for i in 0..count {
+        let word = LOREM_WORDS[rng.below(LOREM_WORDS.len() as u64) as usize];
+        if i == 0 {
+            out.push_str(&crate::locale::capitalize_locale(word, crate::locale::Locale::Root));
+        } else {
+            out.push(' ');
+            out.push_str(word);
+        }
+        if comma == Some(i) {
+            out.push(',');
+        }
+    }
+    out.push('.');
+    out
+}
+
+/// splitmix64: a small, fast generator whose output only depends on its
+/// seed, for results that must be reproducible across versions.
+pub(crate) struct SplitMix64(pub(crate) u64);
+
+impl SplitMix64 {
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// A value below `n` (`n` > 0).
+    pub(crate) fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+}
+
+/// Joins groups of `grouping` characters of an ASCII code with `-`.
+fn group_code(code: &str, grouping: usize) -> String {
+    if grouping == 0 {
+        return code.to_owned();
+    }
+    code.as_bytes()
+        .chunks(grouping)
+        .map(|group| std::str::from_utf8(group).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("-")
+}
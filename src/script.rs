@@ -0,0 +1,185 @@
+use crate::analyzer::is_cjk;
+
+/// Writing system of a letter, as used by [`tag_chunks`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScriptEnum {
+    Latin,
+    Cyrillic,
+    Greek,
+    Arabic,
+    Hebrew,
+    Devanagari,
+    Thai,
+    /// Chinese characters (also Japanese kanji and Korean hanja).
+    Han,
+    /// Japanese hiragana and katakana.
+    Kana,
+    /// Korean.
+    Hangul,
+    /// Letters of any other script.
+    Other,
+    /// No letters at all: digits, punctuation, symbols and emoji only.
+    Common,
+}
+
+/// Script and language of one chunk, returned by [`tag_chunks`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChunkTag {
+    /// The script of most of the letters of the chunk.
+    pub dominant_script: ScriptEnum,
+    /// ISO 639-1 code of the guessed language (`"en"`, `"ja"`...), or `None`
+    /// when the chunk is too short or ambiguous.
+    pub detected_lang: Option<&'static str>,
+    /// Share of the letters that are Han, kana or Hangul, from 0.0 to 1.0.
+    pub cjk_ratio: f64,
+}
+
+/// Frequent short words of the Latin-script languages recognized by
+/// [`tag_chunks`]. Words shared by several languages only count when the
+/// other words decide.
+const LATIN_STOPWORDS: &[(&str, &[&str])] = &[
+    ("en", &["and", "are", "be", "by", "for", "from", "have", "is", "it", "not", "of", "that", "the", "this", "to", "was", "with", "you"]),
+    ("es", &["al", "como", "con", "de", "del", "el", "en", "es", "está", "la", "las", "los", "para", "pero", "por", "que", "se", "su", "una", "y"]),
+    ("fr", &["au", "avec", "ce", "dans", "de", "des", "du", "elle", "est", "et", "il", "je", "la", "le", "les", "mais", "ne", "nous", "pas", "pour", "qui", "sur", "une", "vous"]),
+    ("de", &["auch", "auf", "das", "dem", "den", "der", "die", "ein", "eine", "für", "ich", "ist", "mit", "nicht", "sich", "sie", "und", "von", "wir", "zu"]),
+    ("it", &["che", "con", "del", "della", "di", "gli", "il", "in", "la", "le", "ma", "non", "per", "sono", "una", "è"]),
+    ("pt", &["ao", "com", "da", "das", "de", "do", "dos", "em", "mas", "na", "não", "no", "os", "para", "por", "que", "se", "um", "uma", "é"]),
+    ("nl", &["dat", "de", "die", "een", "en", "het", "ik", "is", "met", "niet", "ook", "op", "te", "van", "voor", "zijn"]),
+];
+
+/// The scripts counted by [`tag_chunk`], in the order of their counts.
+const SCRIPTS: [ScriptEnum; ScriptEnum::Common as usize] = [
+    ScriptEnum::Latin, ScriptEnum::Cyrillic, ScriptEnum::Greek, ScriptEnum::Arabic, ScriptEnum::Hebrew, ScriptEnum::Devanagari,
+    ScriptEnum::Thai, ScriptEnum::Han, ScriptEnum::Kana, ScriptEnum::Hangul, ScriptEnum::Other,
+];
+
+/// Minimum number of stopwords needed to name the language of Latin text.
+const MIN_STOPWORDS: usize = 2;
+
+/// Tags each chunk with its dominant script, a guessed language and its
+/// share of CJK letters, so multilingual documents split with
+/// [`split_into_chunks`](crate::splitter::split_into_chunks) can be routed to
+/// per-language pipelines. Each chunk is read once.
+///
+/// ### Rules implemented:
+/// - Only letters count: digits, punctuation, spaces and emoji are ignored.
+///   A chunk without letters is [`ScriptEnum::Common`], with no language.
+/// - Scripts with one main language give it directly: Greek `el`, Hebrew
+///   `he`, Devanagari `hi`, Thai `th`, Hangul `ko`, kana `ja`.
+/// - Han text is Japanese (`ja`) if it has any kana, Chinese (`zh`) otherwise.
+/// - Cyrillic is Ukrainian (`uk`) with `і ї є ґ`, Serbian (`sr`) with
+///   `ђ ј љ њ ћ џ`, Belarusian (`be`) with `ў`, Russian (`ru`) otherwise.
+/// - Arabic script is Urdu (`ur`) with `ٹ ڈ ڑ ں ے`, Persian (`fa`) with
+///   `پ چ ژ گ`, Arabic (`ar`) otherwise.
+/// - Latin text is English, Spanish, French, German, Italian, Portuguese or
+///   Dutch, from the most frequent stopwords; fewer than two stopwords or a
+///   tie gives `None`.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::script::{tag_chunks, ScriptEnum};
+/// let tags = tag_chunks(&["The cat is on the mat.", "東京は日本の首都です。", "Привет, как дела?", "42!"]);
+/// assert_eq!((tags[0].dominant_script, tags[0].detected_lang), (ScriptEnum::Latin, Some("en")));
+/// assert_eq!((tags[1].dominant_script, tags[1].detected_lang, tags[1].cjk_ratio), (ScriptEnum::Han, Some("ja"), 1.0));
+/// assert_eq!((tags[2].dominant_script, tags[2].detected_lang), (ScriptEnum::Cyrillic, Some("ru")));
+/// assert_eq!((tags[3].dominant_script, tags[3].detected_lang), (ScriptEnum::Common, None));
+/// ```
+///
+/// # Arguments
+/// * `chunks` – The chunks to tag, such as the output of `split_into_chunks`.
+///
+/// # Returns
+/// One tag per chunk, in order.
+pub fn tag_chunks<S: AsRef<str>>(chunks: &[S]) -> Vec<ChunkTag> {
+    chunks.iter().map(|chunk| tag_chunk(chunk.as_ref())).collect()
+}
+
+fn tag_chunk(text: &str) -> ChunkTag {
+    let mut counts = [0usize; ScriptEnum::Common as usize];
+    let mut hits = [0usize; LATIN_STOPWORDS.len()];
+    // Languages given away by letters of their own
+    let (mut cyrillic_lang, mut arabic_lang) = ("ru", "ar");
+    let mut word = String::new();
+
+    for c in text.chars().chain(std::iter::once(' ')) {
+        let script = script_of(c);
+        if script == ScriptEnum::Latin {
+            word.extend(c.to_lowercase());
+        } else if !word.is_empty() {
+            for (hit, (_, words)) in hits.iter_mut().zip(LATIN_STOPWORDS) {
+                *hit += usize::from(words.contains(&word.as_str()));
+            }
+            word.clear();
+        }
+        if script != ScriptEnum::Common {
+            counts[script as usize] += 1;
+        }
+        match c {
+            'і' | 'ї' | 'є' | 'ґ' | 'І' | 'Ї' | 'Є' | 'Ґ' => cyrillic_lang = "uk",
+            'ђ' | 'ј' | 'љ' | 'њ' | 'ћ' | 'џ' | 'Ђ' | 'Ј' | 'Љ' | 'Њ' | 'Ћ' | 'Џ' => cyrillic_lang = "sr",
+            'ў' | 'Ў' => cyrillic_lang = "be",
+            'ٹ' | 'ڈ' | 'ڑ' | 'ں' | 'ے' => arabic_lang = "ur",
+            // Urdu also uses the Persian letters
+            'پ' | 'چ' | 'ژ' | 'گ' if arabic_lang != "ur" => arabic_lang = "fa",
+            _ => {}
+        }
+    }
+
+    let letters: usize = counts.iter().sum();
+    let cjk = [ScriptEnum::Han, ScriptEnum::Kana, ScriptEnum::Hangul].iter().map(|&s| counts[s as usize]).sum::<usize>();
+    let cjk_ratio = if letters == 0 { 0.0 } else { cjk as f64 / letters as f64 };
+    // The first script with the most letters
+    let dominant_script = SCRIPTS
+        .iter()
+        .copied()
+        .filter(|&s| counts[s as usize] > 0)
+        .max_by(|&a, &b| counts[a as usize].cmp(&counts[b as usize]).then((b as usize).cmp(&(a as usize))))
+        .unwrap_or(ScriptEnum::Common);
+
+    let detected_lang = match dominant_script {
+        ScriptEnum::Latin => latin_language(&hits),
+        ScriptEnum::Cyrillic => Some(cyrillic_lang),
+        ScriptEnum::Arabic => Some(arabic_lang),
+        ScriptEnum::Greek => Some("el"),
+        ScriptEnum::Hebrew => Some("he"),
+        ScriptEnum::Devanagari => Some("hi"),
+        ScriptEnum::Thai => Some("th"),
+        ScriptEnum::Han if counts[ScriptEnum::Kana as usize] > 0 => Some("ja"),
+        ScriptEnum::Han => Some("zh"),
+        ScriptEnum::Kana => Some("ja"),
+        ScriptEnum::Hangul => Some("ko"),
+        ScriptEnum::Other | ScriptEnum::Common => None,
+    };
+    ChunkTag { dominant_script, detected_lang, cjk_ratio }
+}
+
+/// Picks the Latin-script language with the most stopword hits, if it has
+/// enough of them and no other language has as many.
+fn latin_language(hits: &[usize]) -> Option<&'static str> {
+    let best = *hits.iter().max()?;
+    let mut leaders = hits.iter().zip(LATIN_STOPWORDS).filter(|(hit, _)| **hit == best);
+    let (_, (lang, _)) = leaders.next()?;
+    (best >= MIN_STOPWORDS && leaders.next().is_none()).then_some(*lang)
+}
+
+/// Returns the script of a letter, or [`ScriptEnum::Common`] for other characters.
+fn script_of(c: char) -> ScriptEnum {
+    if !c.is_alphabetic() {
+        return ScriptEnum::Common;
+    }
+    if is_cjk(c) {
+        return ScriptEnum::Han;
+    }
+    match c as u32 {
+        0x0000..=0x024F | 0x1E00..=0x1EFF | 0x2C60..=0x2C7F | 0xA720..=0xA7FF | 0xFF21..=0xFF3A | 0xFF41..=0xFF5A => ScriptEnum::Latin,
+        0x0370..=0x03FF | 0x1F00..=0x1FFF => ScriptEnum::Greek,
+        0x0400..=0x052F | 0x2DE0..=0x2DFF | 0xA640..=0xA69F => ScriptEnum::Cyrillic,
+        0x0590..=0x05FF | 0xFB1D..=0xFB4F => ScriptEnum::Hebrew,
+        0x0600..=0x06FF | 0x0750..=0x077F | 0x08A0..=0x08FF | 0xFB50..=0xFDFF | 0xFE70..=0xFEFF => ScriptEnum::Arabic,
+        0x0900..=0x097F => ScriptEnum::Devanagari,
+        0x0E00..=0x0E7F => ScriptEnum::Thai,
+        0x3040..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F => ScriptEnum::Kana,
+        0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7AF => ScriptEnum::Hangul,
+        _ => ScriptEnum::Other,
+    }
+}
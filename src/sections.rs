@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+
+/// Parses INI-style text into its sections, each with its `(key, value)`
+/// entries in order.
+///
+/// ### Rules implemented:
+/// - `[name]` starts a section (the name is trimmed). Entries before the
+///   first header belong to the section `""`. A section appearing several
+///   times gets the entries of all its occurrences.
+/// - Entries are `key = value` or `key: value`, split at the first `=` or
+///   `:`; whitespace around keys and values is trimmed. Repeated keys are
+///   all kept.
+/// - Lines starting with `;` or `#` (after optional whitespace) are comments;
+///   other lines without a delimiter are ignored.
+/// - An indented line right after an entry continues its value on a new
+///   line (as in Python's configparser). A blank line or a comment ends the
+///   value.
+///
+/// # Returns
+/// A `BTreeMap` from section names to entries. Sections without entries are
+/// included.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::sections::parse_sections;
+/// let text = "name = demo\n[server]\nhost=db1 ; not a comment\nmotd = Hello\n   World\n; comment\n[empty]\n";
+/// let sections = parse_sections(text);
+/// assert_eq!(sections[""], vec![("name".to_owned(), "demo".to_owned())]);
+/// assert_eq!(sections["server"], vec![
+///     ("host".to_owned(), "db1 ; not a comment".to_owned()),
+///     ("motd".to_owned(), "Hello\nWorld".to_owned()),
+/// ]);
+/// assert!(sections["empty"].is_empty());
+/// ```
+pub fn parse_sections(text: &str) -> BTreeMap<String, Vec<(String, String)>> {
+    let mut sections: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+    let mut current = String::new();
+    let mut can_continue = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with(';') || trimmed.starts_with('#') {
+            can_continue = false;
+            continue;
+        }
+
+        if can_continue && line.starts_with(char::is_whitespace) {
+            if let Some((_, value)) = sections.get_mut(&current).and_then(|entries| entries.last_mut()) {
+                value.push('\n');
+                value.push_str(trimmed);
+            }
+            continue;
+        }
+
+        if let Some(name) = trimmed.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+            current = name.trim().to_owned();
+            sections.entry(current.clone()).or_default();
+            can_continue = false;
+        } else if let Some(position) = trimmed.find(['=', ':']) {
+            let (key, value) = (&trimmed[..position], &trimmed[position + 1..]);
+            sections.entry(current.clone()).or_default().push((key.trim_end().to_owned(), value.trim_start().to_owned()));
+            can_continue = true;
+        } else {
+            can_continue = false;
+        }
+    }
+
+    sections
+}
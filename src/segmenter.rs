@@ -0,0 +1,407 @@
+use crate::analyzer::is_combining_mark;
+
+const ZWJ: char = '\u{200D}';
+
+/// Returns `true` for characters that never start a grapheme cluster and
+/// extend the previous one: combining marks, the zero-width joiner, variation
+/// selectors, emoji skin-tone modifiers and tag characters.
+const fn is_grapheme_extend(c: char) -> bool {
+    is_combining_mark(c)
+        || matches!(c as u32,
+            0x200D            | // zero-width joiner
+            0xFE00..=0xFE0F   | // variation selectors
+            0x1F3FB..=0x1F3FF | // emoji skin-tone modifiers
+            0xE0020..=0xE007F | // tag characters (flag sequences)
+            0xE0100..=0xE01EF   // variation selectors supplement
+        )
+}
+
+const fn is_regional_indicator(c: char) -> bool {
+    matches!(c, '\u{1F1E6}'..='\u{1F1FF}')
+}
+
+/// Byte index where the grapheme cluster starting at `start` ends.
+pub(crate) fn next_grapheme_end(s: &str, start: usize) -> usize {
+    let mut chars = s[start..].chars();
+    let Some(first) = chars.next() else {
+        return start;
+    };
+    let mut end = start + first.len_utf8();
+    let mut previous = first;
+
+    if first == '\r' && s[end..].starts_with('\n') {
+        return end + 1;
+    }
+    if first == '\r' || first == '\n' {
+        return end;
+    }
+    if is_regional_indicator(first) && let Some(second) = s[end..].chars().next()
+        && is_regional_indicator(second) {
+        end += second.len_utf8();
+    }
+
+    for c in s[end..].chars() {
+        if is_grapheme_extend(c) || previous == ZWJ {
+            end += c.len_utf8();
+            previous = c;
+        } else {
+            break;
+        }
+    }
+    end
+}
+
+/// Byte index where the grapheme cluster ending at `end` starts.
+pub(crate) fn prev_grapheme_start(s: &str, end: usize) -> usize {
+    let before = &s[..end];
+    let mut chars = before.char_indices().rev().peekable();
+    let Some((mut start, mut c)) = chars.next() else {
+        return end;
+    };
+
+    if c == '\n' && before[..start].ends_with('\r') {
+        return start - 1;
+    }
+
+    loop {
+        let extends = is_grapheme_extend(c);
+        match chars.peek() {
+            Some(&(i, p)) if extends || p == ZWJ => {
+                start = i;
+                c = p;
+                chars.next();
+            }
+            _ => break,
+        }
+    }
+
+    if is_regional_indicator(c) {
+        // Regional indicators pair up from the start of the run
+        let preceding = before[..start].chars().rev().take_while(|p| is_regional_indicator(*p)).count();
+        if preceding % 2 == 1 {
+            start -= 4;
+        }
+    }
+    start
+}
+
+/// Splits a string into grapheme clusters: what users perceive as a single
+/// character.
+///
+/// A cluster is a base character followed by its combining marks, variation
+/// selectors and skin-tone modifiers; emoji joined with zero-width joiners,
+/// pairs of regional indicators (flags) and `"\r\n"` are also kept together.
+/// This is a simplified version of the Unicode extended grapheme cluster
+/// rules that covers Latin, Cyrillic, Greek, CJK and emoji text.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::segmenter::graphemes;
+/// let g: Vec<&str> = graphemes("e\u{301}a👍🏽🇫🇷").collect();
+/// assert_eq!(g, vec!["e\u{301}", "a", "👍🏽", "🇫🇷"]);
+/// let family: Vec<&str> = graphemes("👨‍👩‍👧").collect();
+/// assert_eq!(family.len(), 1);
+/// ```
+pub fn graphemes(s: &str) -> impl Iterator<Item = &str> {
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        if pos >= s.len() {
+            return None;
+        }
+        let end = next_grapheme_end(s, pos);
+        let g = &s[pos..end];
+        pos = end;
+        Some(g)
+    })
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || is_combining_mark(c) || c == '\'' || c == '-' || c == '_'
+}
+
+pub(crate) const fn is_sentence_terminator(c: char) -> bool {
+    matches!(c, '.' | '!' | '?' | '…' | '。' | '！' | '？')
+}
+
+pub(crate) const fn is_closing_punctuation(c: char) -> bool {
+    matches!(c, '"' | '\'' | ')' | ']' | '”' | '’' | '»' | '」')
+}
+
+/// Words followed by a period that does not end the sentence, lowercase and
+/// without their final period. `No.` is only an abbreviation before a number
+/// (`No. 5`), see [`is_sentence_end`].
+const ABBREVIATIONS: &[&str] = &[
+    "al", "approx", "cf", "dept", "dr", "e.g", "est", "fig", "i.e", "jr", "mr", "mrs", "ms", "mt", "pp", "prof",
+    "sr", "st", "vol", "vs",
+];
+
+/// Returns `true` if the run of terminators and closing punctuation
+/// `text[terminator..end]`, ending with the terminator `last`, ends a
+/// sentence, with the rules of [`sentences`].
+pub(crate) fn is_sentence_end(text: &str, terminator: usize, end: usize, last: char) -> bool {
+    let rest = &text[end..];
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        // Full-width terminators need no space; "3.14" or "a.m" go on
+        return matches!(last, '。' | '！' | '？');
+    }
+    // A lowercase word goes on: "Wait... what", "\"Stop!\" he said"
+    if rest.trim_start().starts_with(char::is_lowercase) {
+        return false;
+    }
+    if text[terminator..].starts_with('.') && !text[terminator + 1..end].starts_with(is_sentence_terminator) {
+        let word = text[..terminator]
+            .rsplit(char::is_whitespace)
+            .next()
+            .unwrap_or("")
+            .trim_start_matches(|c: char| !c.is_alphanumeric());
+        // A lone capital is an initial, except the pronoun "I"
+        let mut letters = word.chars();
+        let is_initial = letters.next().is_some_and(|c| c.is_uppercase() && c != 'I') && letters.next().is_none();
+        let is_number_sign = word.eq_ignore_ascii_case("no") && rest.trim_start().starts_with(|c: char| c.is_ascii_digit());
+        if is_initial || is_number_sign || ABBREVIATIONS.iter().any(|abbreviation| word.eq_ignore_ascii_case(abbreviation)) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Byte index just after the sentence starting at `start`: after its
+/// terminators and closing punctuation, or the end of the text.
+fn next_sentence_end(text: &str, start: usize) -> usize {
+    let mut chars = text[start..].char_indices().map(|(i, c)| (start + i, c)).peekable();
+    while let Some((i, c)) = chars.next() {
+        if !is_sentence_terminator(c) {
+            continue;
+        }
+        let mut end = i + c.len_utf8();
+        let mut last = c;
+        while let Some(&(j, n)) = chars.peek().filter(|(_, n)| is_sentence_terminator(*n) || is_closing_punctuation(*n)) {
+            if is_sentence_terminator(n) {
+                last = n;
+            }
+            end = j + n.len_utf8();
+            chars.next();
+        }
+        if is_sentence_end(text, i, end, last) {
+            return end;
+        }
+    }
+    text.len()
+}
+
+/// Splits a text into sentences, trimmed of surrounding whitespace.
+///
+/// ### Rules implemented:
+/// - A sentence ends with `.`, `!`, `?` or `…` (possibly repeated and
+///   followed by closing quotes or brackets) and whitespace or the end of the
+///   text. Full-width `。`, `！` and `？` need no whitespace.
+/// - Common abbreviations (`e.g.`, `i.e.`, `Dr.`, `Mr.`, `vs.`, `Fig.`...)
+///   and initials (`J. R. R. Tolkien`) do not end a sentence; neither does
+///   `No.` before a number (`No. 5`). The pronoun `I` is not an initial.
+/// - Decimal numbers (`3.14`) and dotted words (`example.com`) do not either,
+///   since no whitespace follows the period.
+/// - Nothing ends before a lowercase word: `Wait... what?` and
+///   `"Stop!" he said.` are one sentence each.
+/// - Runs of punctuation without letters or digits are not sentences.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::segmenter::sentences;
+/// let text = "Dr. Smith paid $3.50, e.g. for coffee. \"Is it late?\" she asked. Yes!";
+/// let found: Vec<&str> = sentences(text).collect();
+/// assert_eq!(found, vec!["Dr. Smith paid $3.50, e.g. for coffee.", "\"Is it late?\" she asked.", "Yes!"]);
+/// ```
+pub fn sentences(text: &str) -> impl Iterator<Item = &str> {
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        while pos < text.len() {
+            let end = next_sentence_end(text, pos);
+            let sentence = text[pos..end].trim();
+            pos = end;
+            if sentence.chars().any(char::is_alphanumeric) {
+                return Some(sentence);
+            }
+        }
+        None
+    })
+}
+
+/// A position inside a string that moves by graphemes, words, sentences and
+/// lines, for editor-like tools.
+///
+/// The position is a byte index that is always on a grapheme boundary.
+/// A word is a run of letters, digits, combining marks, apostrophes, hyphens
+/// and underscores; any other character separates words. Unlike
+/// [`crate::analyzer::word_count`], punctuation inside a token (as in a URL or
+/// `a.b`) starts a new word, and a CJK run is a single word.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::segmenter::TextCursor;
+/// let mut cursor = TextCursor::new("Hello wörld. Bye!");
+/// assert_eq!(cursor.next_grapheme(), Some("H"));
+/// cursor.next_word();
+/// assert_eq!(cursor.rest(), "wörld. Bye!");
+/// cursor.next_sentence();
+/// assert_eq!(cursor.rest(), "Bye!");
+/// cursor.prev_word();
+/// assert_eq!(cursor.rest(), "wörld. Bye!");
+/// ```
+pub struct TextCursor<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> TextCursor<'a> {
+    /// Creates a cursor at the start of `text`.
+    pub fn new(text: &'a str) -> Self {
+        TextCursor { text, pos: 0 }
+    }
+
+    /// Returns the current byte position.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Moves to the start of the text.
+    pub fn move_to_start(&mut self) {
+        self.pos = 0;
+    }
+
+    /// Moves to the end of the text.
+    pub fn move_to_end(&mut self) {
+        self.pos = self.text.len();
+    }
+
+    /// Returns the text from the current position to the end.
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    /// Returns the text from the start to the current position.
+    pub fn before(&self) -> &'a str {
+        &self.text[..self.pos]
+    }
+
+    /// Returns the next `n` grapheme clusters from the current position
+    /// without moving.
+    pub fn peek_graphemes(&self, n: usize) -> &'a str {
+        let mut end = self.pos;
+        for _ in 0..n {
+            end = next_grapheme_end(self.text, end);
+        }
+        &self.text[self.pos..end]
+    }
+
+    /// Returns the grapheme cluster after the cursor and moves past it.
+    /// Returns `None` at the end of the text.
+    pub fn next_grapheme(&mut self) -> Option<&'a str> {
+        if self.pos >= self.text.len() {
+            return None;
+        }
+        let start = self.pos;
+        self.pos = next_grapheme_end(self.text, start);
+        Some(&self.text[start..self.pos])
+    }
+
+    /// Returns the grapheme cluster before the cursor and moves before it.
+    /// Returns `None` at the start of the text.
+    pub fn prev_grapheme(&mut self) -> Option<&'a str> {
+        if self.pos == 0 {
+            return None;
+        }
+        let end = self.pos;
+        self.pos = prev_grapheme_start(self.text, end);
+        Some(&self.text[self.pos..end])
+    }
+
+    /// Moves to the start of the next word, or to the end of the text.
+    /// Returns `false` if the cursor did not move.
+    pub fn next_word(&mut self) -> bool {
+        let start = self.pos;
+        let rest = self.rest();
+        let skip_word = rest.find(|c: char| !is_word_char(c)).unwrap_or(rest.len());
+        let after = &rest[skip_word..];
+        let skip_gap = after.find(is_word_char).unwrap_or(after.len());
+        self.pos += skip_word + skip_gap;
+        self.pos != start
+    }
+
+    /// Moves to the start of the current word, or of the previous word if
+    /// already at the start of a word. Returns `false` if the cursor did not move.
+    pub fn prev_word(&mut self) -> bool {
+        let start = self.pos;
+        let before = self.before();
+        let gap = before.trim_end_matches(|c: char| !is_word_char(c));
+        let word_start = gap.trim_end_matches(is_word_char);
+        self.pos = word_start.len();
+        self.pos != start
+    }
+
+    /// Moves to the start of the next sentence, or to the end of the text.
+    ///
+    /// A sentence ends with `.`, `!`, `?` or `…` (possibly repeated and
+    /// followed by closing quotes or brackets) and whitespace.
+    /// Returns `false` if the cursor did not move.
+    pub fn next_sentence(&mut self) -> bool {
+        let start = self.pos;
+        let text = self.text;
+        let mut iter = text[start..].char_indices().map(|(i, c)| (start + i, c)).peekable();
+
+        while let Some((_, c)) = iter.next() {
+            if !is_sentence_terminator(c) {
+                continue;
+            }
+            while iter.peek().is_some_and(|(_, n)| is_sentence_terminator(*n) || is_closing_punctuation(*n)) {
+                iter.next();
+            }
+            match iter.peek() {
+                Some((_, n)) if n.is_whitespace() => {
+                    let after = iter.find(|(_, n)| !n.is_whitespace());
+                    self.pos = after.map_or(text.len(), |(i, _)| i);
+                    return true;
+                }
+                None => break,
+                _ => {}
+            }
+        }
+
+        self.pos = text.len();
+        self.pos != start
+    }
+
+    /// Returns the `(line, column)` of the cursor, both starting at 0.
+    /// The column is counted in grapheme clusters.
+    pub fn line_col(&self) -> (usize, usize) {
+        let before = self.before();
+        let line = before.matches('\n').count();
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (line, graphemes(&before[line_start..]).count())
+    }
+
+    /// Moves to `line` and `col` (both starting at 0, column in grapheme
+    /// clusters). A column past the end of the line moves to the end of the
+    /// line. Returns `false`, without moving, if the line does not exist.
+    pub fn move_to_line_col(&mut self, line: usize, col: usize) -> bool {
+        let line_start = if line == 0 {
+            0
+        } else {
+            match self.text.match_indices('\n').nth(line - 1) {
+                Some((i, _)) => i + 1,
+                None => return false,
+            }
+        };
+        let line_end = self.text[line_start..].find('\n').map_or(self.text.len(), |i| line_start + i);
+        let line_end = if self.text[..line_end].ends_with('\r') { line_end - 1 } else { line_end };
+
+        let mut pos = line_start;
+        for _ in 0..col {
+            if pos >= line_end {
+                break;
+            }
+            pos = next_grapheme_end(self.text, pos);
+        }
+        self.pos = pos.min(line_end);
+        true
+    }
+}
@@ -1,9 +1,102 @@
+use std::ops::Range;
+
+/// A separator for the split functions, like the standard library's
+/// (unstable) `Pattern`: a `char`, a string, a set of chars or a predicate
+/// `Fn(char) -> bool`.
+///
+/// Matches are byte ranges of the haystack, so the split functions continue
+/// after the separator's actual length, whatever its UTF-8 length. An empty
+/// string separator never matches.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::splitter::Separator;
+/// assert_eq!('→'.find_in("a→b→c"), Some((1, 4)));
+/// assert_eq!("→".rfind_in("a→b→c"), Some((5, 8)));
+/// assert_eq!((|c: char| c.is_ascii_digit()).find_in("ab12"), Some((2, 3)));
+/// assert_eq!([',', ';'].find_in("a;b,c"), Some((1, 2)));
+/// ```
+pub trait Separator {
+    /// Returns the byte range `(start, end)` of the first match in `haystack`.
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Returns the byte range `(start, end)` of the last match in `haystack`.
+    fn rfind_in(&self, haystack: &str) -> Option<(usize, usize)>;
+}
+
+impl Separator for char {
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.find(*self).map(|i| (i, i + self.len_utf8()))
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.rfind(*self).map(|i| (i, i + self.len_utf8()))
+    }
+}
+
+impl Separator for &str {
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        if self.is_empty() {
+            return None;
+        }
+        haystack.find(*self).map(|i| (i, i + self.len()))
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        if self.is_empty() {
+            return None;
+        }
+        haystack.rfind(*self).map(|i| (i, i + self.len()))
+    }
+}
+
+impl Separator for &String {
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_str().find_in(haystack)
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_str().rfind_in(haystack)
+    }
+}
+
+impl Separator for &[char] {
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        (|c: char| self.contains(&c)).find_in(haystack)
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        (|c: char| self.contains(&c)).rfind_in(haystack)
+    }
+}
+
+impl<const N: usize> Separator for [char; N] {
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_slice().find_in(haystack)
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_slice().rfind_in(haystack)
+    }
+}
+
+impl<F: Fn(char) -> bool> Separator for F {
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.char_indices().find(|(_, c)| self(*c)).map(|(i, c)| (i, i + c.len_utf8()))
+    }
+
+    fn rfind_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.char_indices().rev().find(|(_, c)| self(*c)).map(|(i, c)| (i, i + c.len_utf8()))
+    }
+}
+
 /// Splits the given string at the first occurrence of the specified separator.
 ///
 /// # Arguments
 ///
 /// * `s` - A string slice to be split.
-/// * `separator` - The substring used as a separator.
+/// * `separator` - The separator: a `char`, a string, a set of chars or a
+///   predicate (see [`Separator`]).
 ///
 /// # Returns
 ///
@@ -21,24 +114,155 @@
 /// assert_eq!(part1, "hello");
 /// assert_eq!(part2, "world");
 ///
-/// let (part1, part2) = get_first_of_split("key:value", ":");
+/// let (part1, part2) = get_first_of_split("key:value", ':');
+/// assert_eq!(part1, "key");
+/// assert_eq!(part2, "value");
+///
+/// let (part1, part2) = get_first_of_split("a→b→c", "→");
+/// assert_eq!(part1, "a");
+/// assert_eq!(part2, "b→c");
+///
+/// let (part1, part2) = get_first_of_split("key := value", " := ");
 /// assert_eq!(part1, "key");
 /// assert_eq!(part2, "value");
 ///
+/// let (part1, part2) = get_first_of_split("abc123", |c: char| c.is_ascii_digit());
+/// assert_eq!(part1, "abc");
+/// assert_eq!(part2, "23");
+///
 /// let (part1, part2) = get_first_of_split("no=separator", " ");
 /// assert_eq!(part1, "no=separator");
 /// assert_eq!(part2, "");
 /// ```
-pub fn get_first_of_split(s: &str, separator: &str) -> (String, String){
-    if let Some(position) = s.find(separator){
-        let str1 = s[..position].to_owned();
-        let str2 = s[position + 1..].to_owned();
-        (str1, str2)
-    }else{
-        (s.to_owned(),"".to_owned())
+pub fn get_first_of_split<P: Separator>(s: &str, separator: P) -> (String, String){
+    let (str1, str2) = get_first_of_split_ref(s, separator);
+    (str1.to_owned(), str2.to_owned())
+}
+
+/// Same as [`get_first_of_split`], returning slices of `s` instead of new strings.
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::splitter::get_first_of_split_ref;
+/// assert_eq!(get_first_of_split_ref("key=value=x", '='), ("key", "value=x"));
+/// assert_eq!(get_first_of_split_ref("no separator", '='), ("no separator", ""));
+/// ```
+pub fn get_first_of_split_ref<P: Separator>(s: &str, separator: P) -> (&str, &str){
+    match separator.find_in(s){
+        Some((start, end)) => (&s[..start], &s[end..]),
+        None => (s, ""),
     }
 }
 
+/// Splits the given string at the last occurrence of the specified separator.
+///
+/// # Arguments
+///
+/// * `s` - A string slice to be split.
+/// * `separator` - The separator: a `char`, a string, a set of chars or a
+///   predicate (see [`Separator`]).
+///
+/// # Returns
+///
+/// A tuple containing two strings:
+/// - The part of the string before the last separator.
+/// - The part of the string after the last separator.
+///
+/// If the separator is not found, returns the original string and an empty string.
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::splitter::get_last_of_split;
+/// let (stem, extension) = get_last_of_split("archive.tar.gz", '.');
+/// assert_eq!(stem, "archive.tar");
+/// assert_eq!(extension, "gz");
+///
+/// let (part1, part2) = get_last_of_split("a→b→c", "→");
+/// assert_eq!(part1, "a→b");
+/// assert_eq!(part2, "c");
+///
+/// let (part1, part2) = get_last_of_split("C:\\dir/file", ['/', '\\']);
+/// assert_eq!(part1, "C:\\dir");
+/// assert_eq!(part2, "file");
+///
+/// let (part1, part2) = get_last_of_split("README", ".");
+/// assert_eq!(part1, "README");
+/// assert_eq!(part2, "");
+/// ```
+pub fn get_last_of_split<P: Separator>(s: &str, separator: P) -> (String, String){
+    let (str1, str2) = get_last_of_split_ref(s, separator);
+    (str1.to_owned(), str2.to_owned())
+}
+
+/// Same as [`get_last_of_split`], returning slices of `s` instead of new strings.
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::splitter::get_last_of_split_ref;
+/// assert_eq!(get_last_of_split_ref("photo.final.jpeg", '.'), ("photo.final", "jpeg"));
+/// assert_eq!(get_last_of_split_ref("README", '.'), ("README", ""));
+/// ```
+pub fn get_last_of_split_ref<P: Separator>(s: &str, separator: P) -> (&str, &str){
+    match separator.rfind_in(s){
+        Some((start, end)) => (&s[..start], &s[end..]),
+        None => (s, ""),
+    }
+}
+
+/// Splits the given string at every occurrence of the specified separator,
+/// like Python's `str.split(sep, maxsplit)`.
+///
+/// # Arguments
+///
+/// * `s` - A string slice to be split.
+/// * `separator` - The separator: a `char`, a string, a set of chars or a
+///   predicate (see [`Separator`]).
+/// * `max_splits` - The maximum number of splits to do, `None` for no limit.
+///   The last piece holds the rest of the string.
+///
+/// # Returns
+///
+/// The pieces of the string, in order. Adjacent separators produce empty pieces.
+/// If the separator is not found (or is empty), returns the original string as the only piece.
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::splitter::split_all;
+/// assert_eq!(split_all("a:b::c", ':', None), vec!["a", "b", "", "c"]);
+/// assert_eq!(split_all("a:b::c", ":", Some(1)), vec!["a", "b::c"]);
+/// assert_eq!(split_all("a→b→c", "→", Some(5)), vec!["a", "b", "c"]);
+/// assert_eq!(split_all("a1b22c", |c: char| c.is_ascii_digit(), None), vec!["a", "b", "", "c"]);
+/// assert_eq!(split_all("no separator", ":", None), vec!["no separator"]);
+/// ```
+pub fn split_all<P: Separator>(s: &str, separator: P, max_splits: Option<usize>) -> Vec<String> {
+    split_all_ref(s, separator, max_splits).into_iter().map(str::to_owned).collect()
+}
+
+/// Same as [`split_all`], returning slices of `s` instead of new strings.
+///
+/// # Examples
+///
+/// ```
+/// use bt_string_utils::splitter::split_all_ref;
+/// assert_eq!(split_all_ref("GET /index.html HTTP/1.1", ' ', Some(1)), vec!["GET", "/index.html HTTP/1.1"]);
+/// ```
+pub fn split_all_ref<P: Separator>(s: &str, separator: P, max_splits: Option<usize>) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut rest = s;
+    while max_splits.is_none_or(|max| pieces.len() < max)
+        && let Some((start, end)) = separator.find_in(rest)
+    {
+        pieces.push(&rest[..start]);
+        rest = &rest[end..];
+    }
+    pieces.push(rest);
+    pieces
+}
+
 /// Splits a string into at most `n` substrings, grouped by whole words.
 ///
 /// This function performs **word‑based splitting**, never character‑based.
@@ -190,7 +414,9 @@ pub fn split_upto_n_by_word(s: &str, n: usize) -> Vec<&str> {
 /// - The function will step backwards within the byte array if necessary to ensure that chunks don't break in the middle of a multi-byte character.
 /// - It is optimized to handle **UTF-8** encoded data correctly. 
 /// - If the input string is extremely short, only a single chunk will be returned.
+/// - A character longer than `chunk_size_bytes` (including any character when it is `0`) gets a chunk of its own.
 pub fn split_into_chunks(content: &str, chunk_size_bytes: usize) -> Vec<String> {
+    trace_span!("split_into_chunks", bytes = content.len(), chunk_size_bytes);
     let mut chunks = Vec::new();
     let bytes = content.as_bytes();
     let mut offset = 0;
@@ -203,6 +429,11 @@ pub fn split_into_chunks(content: &str, chunk_size_bytes: usize) -> Vec<String>
         while std::str::from_utf8(&bytes[offset..valid_end]).is_err() {
             valid_end -= 1; // Step back to avoid splitting a multi-byte character
         }
+        if valid_end == offset {
+            // The next character is larger than the chunk size: it gets its own chunk
+            valid_end += content[offset..].chars().next().map_or(1, char::len_utf8);
+        }
+        trace_event!(trace, offset, bytes = valid_end - offset, stepped_back = end.saturating_sub(valid_end), "chunk produced");
 
         let chunk = String::from_utf8_lossy(&bytes[offset..valid_end]).to_string();
         chunks.push(chunk);
@@ -210,5 +441,150 @@ pub fn split_into_chunks(content: &str, chunk_size_bytes: usize) -> Vec<String>
         offset = valid_end; // Move to the next chunk start position
     }
 
+    trace_event!(debug, chunks = chunks.len(), "content split into chunks");
     chunks
-}
\ No newline at end of file
+}
+
+/// Splits a text into paragraphs, following the same rules as
+/// [`crate::analyzer::count_paragraphs`].
+///
+/// Each newline sequence (`\r\n`, `\n` or `\r`) ends a paragraph, so
+/// consecutive newlines produce empty paragraphs. A text starting with a
+/// newline does not get an extra empty paragraph before it, and an empty text
+/// has no paragraphs. The newlines themselves are not included.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::splitter::split_paragraphs;
+/// use bt_string_utils::analyzer::count_paragraphs;
+/// assert_eq!(split_paragraphs("One\r\nTwo\n\nFour"), vec!["One", "Two", "", "Four"]);
+/// assert_eq!(split_paragraphs("Hello\n"), vec!["Hello", ""]);
+/// assert!(split_paragraphs("").is_empty());
+/// let text = "\nA\rB\n";
+/// assert_eq!(split_paragraphs(text).len(), count_paragraphs(text));
+/// ```
+pub fn split_paragraphs(text: &str) -> Vec<&str> {
+    if text.is_empty() {
+        return Vec::new();
+    }
+
+    let mut paragraphs = Vec::new();
+    let mut start = 0;
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\r' | b'\n' => {
+                paragraphs.push(&text[start..i]);
+                i += if bytes[i] == b'\r' && bytes.get(i + 1) == Some(&b'\n') { 2 } else { 1 };
+                start = i;
+            }
+            _ => i += 1,
+        }
+    }
+    paragraphs.push(&text[start..]);
+
+    if text.starts_with(['\n', '\r']) {
+        paragraphs.remove(0);
+    }
+    paragraphs
+}
+
+/// Recognizes the heading lines of [`split_sections`]: a line prefix such as
+/// `"## "` or `"Chapter "`, or a predicate `Fn(&str) -> bool` on the line.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::splitter::HeadingMatcher;
+/// assert!("## ".is_heading("## Usage"));
+/// assert!(!"## ".is_heading("### Usage"));
+/// let numbered = |line: &str| line.split_once(". ").is_some_and(|(n, _)| n.parse::<u32>().is_ok());
+/// assert!(numbered.is_heading("12. Results"));
+/// ```
+pub trait HeadingMatcher {
+    /// Returns `true` if `line` (without its line ending) is a heading.
+    fn is_heading(&self, line: &str) -> bool;
+}
+
+impl HeadingMatcher for &str {
+    fn is_heading(&self, line: &str) -> bool {
+        !self.is_empty() && line.starts_with(*self)
+    }
+}
+
+impl HeadingMatcher for &String {
+    fn is_heading(&self, line: &str) -> bool {
+        self.as_str().is_heading(line)
+    }
+}
+
+impl<F: Fn(&str) -> bool> HeadingMatcher for F {
+    fn is_heading(&self, line: &str) -> bool {
+        self(line)
+    }
+}
+
+/// A part of a document found by [`split_sections`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// The heading line, without its line ending; `None` for the text before
+    /// the first heading.
+    pub heading: Option<&'a str>,
+    /// The text after the heading line, up to the next heading, line endings
+    /// included.
+    pub body: &'a str,
+    /// Byte range of the whole section (heading and body) in the text.
+    pub span: Range<usize>,
+}
+
+/// Splits a document into sections, each starting at a line recognized by
+/// `heading_matcher`, e.g. `"## "` for Markdown or a closure matching
+/// `"Chapter N"`.
+///
+/// Text before the first heading becomes a section without heading, unless it
+/// is only whitespace. Lines end with `\n` or `\r\n`. The sections cover the
+/// text from the first one on, so their spans can be used to edit it.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::splitter::split_sections;
+/// let report = "Summary\n## Intro\nHello\n## Results\nAll good\n";
+/// let sections = split_sections(report, "## ");
+/// assert_eq!(sections.len(), 3);
+/// assert_eq!(sections[0].heading, None);
+/// assert_eq!(sections[1].heading, Some("## Intro"));
+/// assert_eq!(sections[1].body, "Hello\n");
+/// assert_eq!(&report[sections[2].span.clone()], "## Results\nAll good\n");
+///
+/// let book = "Chapter 1\nIt begins.\nChapter Two\nChapter 2\nIt ends.";
+/// let chapters = split_sections(book, |line: &str| {
+///     line.strip_prefix("Chapter ").is_some_and(|n| n.parse::<u32>().is_ok())
+/// });
+/// assert_eq!(chapters[0].body, "It begins.\nChapter Two\n");
+/// assert_eq!(chapters[1].heading, Some("Chapter 2"));
+/// ```
+pub fn split_sections<M: HeadingMatcher>(text: &str, heading_matcher: M) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    // Start and heading of the section being read
+    let mut current: (usize, Option<&str>) = (0, None);
+    let mut body_start = 0;
+    let mut line_start = 0;
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches('\n').trim_end_matches('\r');
+        if heading_matcher.is_heading(content) {
+            let (start, heading) = current;
+            if heading.is_some() || !text[start..line_start].trim().is_empty() {
+                sections.push(Section { heading, body: &text[body_start..line_start], span: start..line_start });
+            }
+            current = (line_start, Some(content));
+            body_start = line_start + line.len();
+        }
+        line_start += line.len();
+    }
+
+    let (start, heading) = current;
+    if heading.is_some() || !text[start..].trim().is_empty() {
+        sections.push(Section { heading, body: &text[body_start..], span: start..text.len() });
+    }
+    sections
+}
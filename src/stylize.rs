@@ -0,0 +1,138 @@
+/// Unicode text styles for the stylize function
+#[derive(Clone, Copy)]
+pub enum StyleEnum {
+    /// Mathematical bold letters and digits: `𝐀𝐛𝐜𝟏`
+    Bold,
+    /// Mathematical italic letters: `𝐴𝑏𝑐` (digits unchanged)
+    Italic,
+    /// Mathematical bold italic letters: `𝑨𝒃𝒄` (digits unchanged)
+    BoldItalic,
+    /// Mathematical monospace letters and digits: `𝙰𝚋𝚌𝟷`
+    Monospace,
+    /// Small capitals for lowercase letters: `ᴀʙᴄ`
+    SmallCaps,
+    /// Fullwidth forms of ASCII: `Ａｂｃ１`
+    Fullwidth,
+    /// Circled letters and digits: `Ⓐⓑⓒ①`
+    Circled,
+    /// Grade 1 Braille letters (case-insensitive): `⠁⠃⠉`
+    Braille,
+    /// Leet speak (`e` → `3`, `o` → `0`...). Not reversed by [`unstylize`].
+    Leet,
+}
+
+const SMALL_CAPS: [char; 26] = [
+    'ᴀ', 'ʙ', 'ᴄ', 'ᴅ', 'ᴇ', 'ꜰ', 'ɢ', 'ʜ', 'ɪ', 'ᴊ', 'ᴋ', 'ʟ', 'ᴍ',
+    'ɴ', 'ᴏ', 'ᴘ', 'ꞯ', 'ʀ', 'ꜱ', 'ᴛ', 'ᴜ', 'ᴠ', 'ᴡ', 'x', 'ʏ', 'ᴢ',
+];
+
+const BRAILLE: [char; 26] = [
+    '⠁', '⠃', '⠉', '⠙', '⠑', '⠋', '⠛', '⠓', '⠊', '⠚', '⠅', '⠇', '⠍',
+    '⠝', '⠕', '⠏', '⠟', '⠗', '⠎', '⠞', '⠥', '⠧', '⠺', '⠭', '⠽', '⠵',
+];
+
+const LEET: &[(char, char)] = &[('a', '4'), ('e', '3'), ('g', '9'), ('i', '1'), ('l', '1'), ('o', '0'), ('s', '5'), ('t', '7'), ('z', '2')];
+
+/// Letterlike symbols used by Unicode in place of the reserved code points of
+/// the mathematical alphanumeric block (e.g. italic `h` is `ℎ`).
+const LETTERLIKE: &[(char, char)] = &[
+    ('ℎ', 'h'), ('ℬ', 'B'), ('ℰ', 'E'), ('ℱ', 'F'), ('ℋ', 'H'), ('ℐ', 'I'), ('ℒ', 'L'),
+    ('ℳ', 'M'), ('ℛ', 'R'), ('ℯ', 'e'), ('ℊ', 'g'), ('ℴ', 'o'), ('ℭ', 'C'), ('ℌ', 'H'),
+    ('ℑ', 'I'), ('ℜ', 'R'), ('ℨ', 'Z'), ('ℂ', 'C'), ('ℍ', 'H'), ('ℕ', 'N'), ('ℙ', 'P'),
+    ('ℚ', 'Q'), ('ℝ', 'R'), ('ℤ', 'Z'),
+];
+
+/// Maps ASCII letters and digits to a Unicode [`StyleEnum`], the fancy text
+/// seen in social-media bios and usernames.
+///
+/// Characters without a styled form in the chosen style are kept unchanged.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::stylize::{stylize, StyleEnum};
+/// assert_eq!(stylize("Bold 1", StyleEnum::Bold), "𝐁𝐨𝐥𝐝 𝟏");
+/// assert_eq!(stylize("hi", StyleEnum::Italic), "ℎ𝑖");
+/// assert_eq!(stylize("Wide!", StyleEnum::Fullwidth), "Ｗｉｄｅ！");
+/// assert_eq!(stylize("ab1", StyleEnum::Circled), "ⓐⓑ①");
+/// assert_eq!(stylize("small", StyleEnum::SmallCaps), "ꜱᴍᴀʟʟ");
+/// assert_eq!(stylize("leet", StyleEnum::Leet), "1337");
+/// ```
+pub fn stylize(text: &str, style: StyleEnum) -> String {
+    text.chars().map(|c| stylize_char(c, style)).collect()
+}
+
+fn stylize_char(c: char, style: StyleEnum) -> char {
+    let offset = |base: u32| -> Option<u32> {
+        match c {
+            'A'..='Z' => Some(base + (c as u32 - 'A' as u32)),
+            'a'..='z' => Some(base + 26 + (c as u32 - 'a' as u32)),
+            _ => None,
+        }
+    };
+    let digit = |base: u32| c.to_digit(10).map(|d| base + d);
+    let from = |u: Option<u32>| u.and_then(char::from_u32).unwrap_or(c);
+
+    match style {
+        StyleEnum::Bold => from(offset(0x1D400).or_else(|| digit(0x1D7CE))),
+        StyleEnum::Italic if c == 'h' => 'ℎ',
+        StyleEnum::Italic => from(offset(0x1D434)),
+        StyleEnum::BoldItalic => from(offset(0x1D468)),
+        StyleEnum::Monospace => from(offset(0x1D670).or_else(|| digit(0x1D7F6))),
+        StyleEnum::SmallCaps if c.is_ascii_lowercase() => SMALL_CAPS[(c as u8 - b'a') as usize],
+        StyleEnum::SmallCaps => c,
+        StyleEnum::Fullwidth if c == ' ' => '\u{3000}',
+        StyleEnum::Fullwidth if ('!'..='~').contains(&c) => from(Some(c as u32 + 0xFEE0)),
+        StyleEnum::Fullwidth => c,
+        StyleEnum::Circled => match c {
+            'A'..='Z' => from(Some(0x24B6 + (c as u32 - 'A' as u32))),
+            'a'..='z' => from(Some(0x24D0 + (c as u32 - 'a' as u32))),
+            '0' => '⓪',
+            '1'..='9' => from(Some(0x2460 + (c as u32 - '1' as u32))),
+            _ => c,
+        },
+        StyleEnum::Braille if c.is_ascii_alphabetic() => BRAILLE[(c.to_ascii_lowercase() as u8 - b'a') as usize],
+        StyleEnum::Braille => c,
+        StyleEnum::Leet => LEET.iter().find(|(k, _)| *k == c.to_ascii_lowercase()).map_or(c, |(_, v)| *v),
+    }
+}
+
+/// Maps styled Unicode text back to plain ASCII letters and digits.
+///
+/// Reverses every style of [`stylize`] except `Leet` and, beyond those, all
+/// mathematical alphanumeric styles (script, fraktur, double-struck,
+/// sans-serif...). This normalizes "fancy" social-media text before counting
+/// or searching it. Braille letters come back in lowercase.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::stylize::unstylize;
+/// assert_eq!(unstylize("𝐁𝐨𝐥𝐝 ℎ𝑖 Ｗｉｄｅ！ ⓐ① ꜱᴍᴀʟʟ ⠁⠃"), "Bold hi Wide! a1 small ab");
+/// assert_eq!(unstylize("𝔉𝔯𝔞𝔨𝔱𝔲𝔯 ℝ𝕖𝕒𝕝"), "Fraktur Real");
+/// ```
+pub fn unstylize(text: &str) -> String {
+    text.chars().map(unstylize_char).collect()
+}
+
+fn unstylize_char(c: char) -> char {
+    let u = c as u32;
+    let plain = match u {
+        0x1D400..=0x1D6A3 => {
+            let i = ((u - 0x1D400) % 52) as u8;
+            Some(if i < 26 { b'A' + i } else { b'a' + i - 26 } as char)
+        }
+        0x1D7CE..=0x1D7FF => Some((b'0' + ((u - 0x1D7CE) % 10) as u8) as char),
+        0xFF01..=0xFF5E => char::from_u32(u - 0xFEE0),
+        0x3000 => Some(' '),
+        0x24B6..=0x24CF => Some((b'A' + (u - 0x24B6) as u8) as char),
+        0x24D0..=0x24E9 => Some((b'a' + (u - 0x24D0) as u8) as char),
+        0x2460..=0x2468 => Some((b'1' + (u - 0x2460) as u8) as char),
+        0x24EA => Some('0'),
+        _ => None,
+    };
+
+    plain
+        .or_else(|| LETTERLIKE.iter().find(|(k, _)| *k == c).map(|(_, v)| *v))
+        .or_else(|| SMALL_CAPS.iter().position(|s| *s == c && c != 'x').map(|i| (b'a' + i as u8) as char))
+        .or_else(|| BRAILLE.iter().position(|b| *b == c).map(|i| (b'a' + i as u8) as char))
+        .unwrap_or(c)
+}
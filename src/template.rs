@@ -0,0 +1,372 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::formatter::{display_width, take_width};
+
+/// Error returned when a template cannot be rendered.
+/// `position` is the byte index of the offending brace or spec in the template.
+#[derive(Debug, PartialEq)]
+pub enum TemplateError {
+    /// A `{` without its closing `}`.
+    UnclosedBrace { position: usize },
+    /// A `}` that does not close a placeholder (write `}}` for a literal brace).
+    UnmatchedBrace { position: usize },
+    /// The placeholder refers to an argument that was not given.
+    MissingArgument { index: usize },
+    /// The placeholder or its format spec cannot be parsed.
+    InvalidSpec { position: usize },
+    /// The placeholder refers to a named argument that was not given.
+    MissingNamedArgument { name: String },
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::UnclosedBrace { position } => write!(f, "unclosed brace at byte {position}"),
+            TemplateError::UnmatchedBrace { position } => write!(f, "unmatched closing brace at byte {position}"),
+            TemplateError::MissingArgument { index } => write!(f, "missing argument {index}"),
+            TemplateError::InvalidSpec { position } => write!(f, "invalid format spec at byte {position}"),
+            TemplateError::MissingNamedArgument { name } => write!(f, "missing argument {name}"),
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+/// Renders a template with positional arguments, like `format!` but with the
+/// template read at runtime (e.g. user-editable messages).
+///
+/// ### Placeholders:
+/// - `{0}`, `{1}`... insert the argument at that index; `{}` takes the next
+///   argument after the previous implicit one.
+/// - `{0:spec}` with `spec` = `[[fill]align][width][.max]`:
+///   - `align` is `<` (left, the default), `>` (right) or `^` (center),
+///     optionally preceded by a `fill` character (space by default).
+///   - `width` pads the value to that many display columns.
+///   - `.max` truncates the value to at most `max` display columns.
+/// - `{{` and `}}` are literal braces.
+///
+/// Widths are measured with [`display_width`], so CJK text stays aligned.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::template::format_positional;
+/// let out = format_positional("Hello {0}, you have {1:>5} points", &["Ana", "42"]).unwrap();
+/// assert_eq!(out, "Hello Ana, you have    42 points");
+/// assert_eq!(format_positional("[{:*^7}] [{:.3}]", &["ab", "truncated"]).unwrap(), "[**ab***] [tru]");
+/// assert_eq!(format_positional("{{{0}}}", &["x"]).unwrap(), "{x}");
+/// assert!(format_positional("{2}", &["x"]).is_err());
+/// ```
+pub fn format_positional<S: AsRef<str>>(template: &str, args: &[S]) -> Result<String, TemplateError> {
+    let mut out = String::with_capacity(template.len());
+    let mut next_implicit = 0;
+    let mut rest = template;
+
+    while let Some(i) = rest.find(['{', '}']) {
+        let position = template.len() - rest.len() + i;
+        out.push_str(&rest[..i]);
+        let after = &rest[i + 1..];
+
+        if rest[i..].starts_with("{{") || rest[i..].starts_with("}}") {
+            out.push_str(&rest[i..i + 1]);
+            rest = &after[1..];
+            continue;
+        }
+        if rest.as_bytes()[i] == b'}' {
+            return Err(TemplateError::UnmatchedBrace { position });
+        }
+
+        let end = after.find('}').ok_or(TemplateError::UnclosedBrace { position })?;
+        let (index, spec) = after[..end].split_once(':').unwrap_or((&after[..end], ""));
+        let index = if index.is_empty() {
+            next_implicit += 1;
+            next_implicit - 1
+        } else {
+            index.trim().parse().map_err(|_| TemplateError::InvalidSpec { position })?
+        };
+
+        let value = args.get(index).ok_or(TemplateError::MissingArgument { index })?;
+        let spec = FormatSpec::parse(spec).ok_or(TemplateError::InvalidSpec { position })?;
+        spec.apply(value.as_ref(), &mut out);
+        rest = &after[end + 1..];
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// A parsed `[[fill]align][width][.max]` spec.
+pub(crate) struct FormatSpec {
+    fill: char,
+    align: char,
+    width: usize,
+    max: Option<usize>,
+}
+
+impl FormatSpec {
+    pub(crate) fn parse(spec: &str) -> Option<Self> {
+        let mut chars = spec.chars();
+        let (fill, align, rest) = match (chars.next(), chars.next()) {
+            (Some(fill), Some(align @ ('<' | '>' | '^'))) => (fill, align, chars.as_str()),
+            (Some(align @ ('<' | '>' | '^')), _) => (' ', align, &spec[1..]),
+            _ => (' ', '<', spec),
+        };
+
+        let (width, max) = match rest.split_once('.') {
+            Some((width, max)) => (width, Some(max.parse().ok()?)),
+            None => (rest, None),
+        };
+        let width = if width.is_empty() { 0 } else { width.parse().ok()? };
+
+        Some(FormatSpec { fill, align, width, max })
+    }
+
+    pub(crate) fn apply(&self, value: &str, out: &mut String) {
+        let value = match self.max {
+            Some(max) => take_width(value, max),
+            None => Cow::Borrowed(value),
+        };
+        let padding = self.width.saturating_sub(display_width(&value));
+        let (left, right) = match self.align {
+            '>' => (padding, 0),
+            '^' => (padding / 2, padding - padding / 2),
+            _ => (0, padding),
+        };
+        out.extend(std::iter::repeat_n(self.fill, left));
+        out.push_str(&value);
+        out.extend(std::iter::repeat_n(self.fill, right));
+    }
+}
+
+/// CLDR plural categories, as used in `plural` clauses of format_message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// The keyword of the category in a message (`"one"`, `"other"`...).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+/// Plural rules of a language: maps a number to its plural category.
+///
+/// Implement it to render messages in languages with other rules than
+/// English (e.g. Polish `few`/`many`) with format_message_with_rules.
+pub trait PluralRules {
+    fn category(&self, n: f64) -> PluralCategory;
+}
+
+/// English plural rules: `one` for 1, `other` for everything else.
+pub struct EnglishPluralRules;
+
+impl PluralRules for EnglishPluralRules {
+    fn category(&self, n: f64) -> PluralCategory {
+        if n == 1.0 { PluralCategory::One } else { PluralCategory::Other }
+    }
+}
+
+/// Renders an ICU MessageFormat-style message with English plural rules.
+///
+/// ### Syntax supported:
+/// - `{name}` inserts the argument `name`.
+/// - `{name, plural, =0 {...} one {...} other {...}}` picks the branch of an
+///   exact `=N` match first, then of the plural category of the number, then
+///   `other` (which is required). Inside a branch `#` is the number.
+/// - `{name, select, male {...} female {...} other {...}}` picks the branch
+///   matching the value, or `other`.
+/// - Branches can be nested. `'{'` quotes special characters and `''` is an
+///   apostrophe.
+///
+/// See [`format_message_with_rules`] for other languages.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::template::format_message;
+/// let message = "You have {count, plural, =0 {no files} one {# file} other {# files}}";
+/// assert_eq!(format_message(message, &[("count", "1")]).unwrap(), "You have 1 file");
+/// assert_eq!(format_message(message, &[("count", "12")]).unwrap(), "You have 12 files");
+/// assert_eq!(format_message(message, &[("count", "0")]).unwrap(), "You have no files");
+///
+/// let message = "{name} updated {gender, select, female {her} male {his} other {their}} profile";
+/// assert_eq!(format_message(message, &[("name", "Ana"), ("gender", "female")]).unwrap(), "Ana updated her profile");
+/// ```
+pub fn format_message(template: &str, args: &[(&str, &str)]) -> Result<String, TemplateError> {
+    format_message_with_rules(template, args, &EnglishPluralRules)
+}
+
+/// Same as [`format_message`] with the plural rules of another language.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::template::{format_message_with_rules, PluralCategory, PluralRules};
+/// struct French;
+/// impl PluralRules for French {
+///     fn category(&self, n: f64) -> PluralCategory {
+///         if n < 2.0 { PluralCategory::One } else { PluralCategory::Other }
+///     }
+/// }
+/// let message = "{n, plural, one {# fichier} other {# fichiers}}";
+/// assert_eq!(format_message_with_rules(message, &[("n", "0")], &French).unwrap(), "0 fichier");
+/// ```
+pub fn format_message_with_rules(template: &str, args: &[(&str, &str)], rules: &dyn PluralRules) -> Result<String, TemplateError> {
+    let mut parser = MessageParser { template, pos: 0, args, rules };
+    let out = parser.message(None)?;
+    if parser.pos < template.len() {
+        return Err(TemplateError::UnmatchedBrace { position: parser.pos });
+    }
+    Ok(out)
+}
+
+struct MessageParser<'a> {
+    template: &'a str,
+    pos: usize,
+    args: &'a [(&'a str, &'a str)],
+    rules: &'a dyn PluralRules,
+}
+
+impl<'a> MessageParser<'a> {
+    /// Renders text up to an unmatched `}` or the end. `number` replaces `#`
+    /// inside plural branches.
+    fn message(&mut self, number: Option<&'a str>) -> Result<String, TemplateError> {
+        let mut out = String::new();
+        while let Some(c) = self.template[self.pos..].chars().next() {
+            match c {
+                '}' => break,
+                '{' => {
+                    let part = self.placeholder(number)?;
+                    out.push_str(&part);
+                }
+                '#' if number.is_some() => {
+                    out.push_str(number.unwrap_or_default());
+                    self.pos += 1;
+                }
+                '\'' => self.quoted(&mut out),
+                _ => {
+                    out.push(c);
+                    self.pos += c.len_utf8();
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Copies an apostrophe-quoted literal (`''` or `'{...}'`) to `out`.
+    fn quoted(&mut self, out: &mut String) {
+        let rest = &self.template[self.pos + 1..];
+        if rest.starts_with('\'') {
+            out.push('\'');
+            self.pos += 2;
+        } else if rest.starts_with(['{', '}', '#']) {
+            let end = rest.find('\'').unwrap_or(rest.len());
+            out.push_str(&rest[..end]);
+            self.pos += 1 + (end + 1).min(rest.len());
+        } else {
+            out.push('\'');
+            self.pos += 1;
+        }
+    }
+
+    fn placeholder(&mut self, number: Option<&'a str>) -> Result<String, TemplateError> {
+        let start = self.pos;
+        self.pos += 1;
+        let name = self.word(start)?;
+        let value = self.args.iter().find(|(key, _)| *key == name).map(|(_, value)| *value)
+            .ok_or_else(|| TemplateError::MissingNamedArgument { name: name.to_owned() })?;
+
+        if self.eat('}') {
+            return Ok(value.to_owned());
+        }
+        if !self.eat(',') {
+            return Err(self.invalid(start));
+        }
+        let kind = self.word(start)?;
+        if !self.eat(',') {
+            return Err(self.invalid(start));
+        }
+
+        let (selectors, number): (Vec<String>, Option<&'a str>) = match kind {
+            "plural" => {
+                let n: f64 = value.trim().parse().map_err(|_| TemplateError::InvalidSpec { position: start })?;
+                (vec![format!("={value}"), self.rules.category(n).as_str().to_owned()], Some(value))
+            }
+            "select" => (vec![value.to_owned()], number),
+            _ => return Err(TemplateError::InvalidSpec { position: start }),
+        };
+
+        let mut branches = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.eat('}') {
+                break;
+            }
+            let selector = self.word(start)?;
+            self.skip_whitespace();
+            if !self.eat('{') {
+                return Err(self.invalid(start));
+            }
+            let text = self.message(number)?;
+            if !self.eat('}') {
+                return Err(TemplateError::UnclosedBrace { position: start });
+            }
+            branches.push((selector, text));
+        }
+
+        selectors.iter().map(String::as_str).chain(["other"])
+            .find_map(|selector| branches.iter().find(|(s, _)| *s == selector))
+            .map(|(_, text)| text.clone())
+            .ok_or(TemplateError::InvalidSpec { position: start })
+    }
+
+    /// Reads a name, type or selector, skipping whitespace around it.
+    fn word(&mut self, start: usize) -> Result<&'a str, TemplateError> {
+        self.skip_whitespace();
+        let template = self.template;
+        let rest = &template[self.pos..];
+        let len = rest.find(|c: char| c.is_whitespace() || matches!(c, ',' | '{' | '}')).unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.invalid(start));
+        }
+        self.pos += len;
+        let word = &template[self.pos - len..self.pos];
+        self.skip_whitespace();
+        Ok(word)
+    }
+
+    /// The error for an unexpected character in the placeholder at `start`.
+    fn invalid(&self, start: usize) -> TemplateError {
+        if self.pos == self.template.len() {
+            TemplateError::UnclosedBrace { position: start }
+        } else {
+            TemplateError::InvalidSpec { position: start }
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.template[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.template[self.pos..].starts_with(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+}
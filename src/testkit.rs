@@ -0,0 +1,132 @@
+//! Generators of edge-case strings for property tests (feature `testkit`).
+//!
+//! Text code often works on ASCII test data and breaks on real input:
+//! combining marks, emoji joined with ZWJ, bidirectional controls, invisible
+//! characters or very long runs of wide characters. These helpers produce
+//! such strings so code calling this crate can be tested against them.
+
+use crate::random::SplitMix64;
+
+/// Hand-picked strings that commonly break text handling code.
+const EDGE_CASES: &[&str] = &[
+    "",
+    " ",
+    "\t\n\r\n\r",
+    "\u{0}",
+    "a\u{301}",
+    "e\u{301}\u{302}\u{303}\u{304}\u{305}\u{306}\u{307}\u{308}",
+    "Z\u{351}\u{357}\u{30e}\u{33e}a\u{36b}\u{346}l\u{30a}g\u{35b}o\u{312}",
+    "👩\u{200D}👩\u{200D}👧\u{200D}👦",
+    "👍🏽",
+    "🇯🇵🇫🇷",
+    "🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+    "\u{202E}txt.exe",
+    "\u{2067}שלום\u{2069} world",
+    "مرحبا بالعالم",
+    "\u{200B}\u{200C}\u{200D}\u{2060}\u{FEFF}",
+    "\u{AD}soft\u{AD}hyphen",
+    "\u{A0}\u{2003}\u{3000}",
+    "ǅ ﬁ ß İ ı",
+    "ΣΟΦΟΣ",
+    "𝐁𝐨𝐥𝐝 ℎ𝑖",
+    "\u{1F600}",
+    "\u{10FFFF}",
+    "\u{E000}\u{F8FF}",
+    "a\r\nb\rc\nd",
+    "\x1b[31mred\x1b[0m",
+    "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\",
+    "'; DROP TABLE users; --",
+    "<script>alert(1)</script>",
+    "../../etc/passwd",
+    "%s%n%x{0}{}",
+];
+
+/// Returns a catalog of strings that commonly break text handling code:
+/// empty and whitespace-only strings, stacked combining marks, ZWJ emoji
+/// sequences, flags, skin tones, bidirectional overrides, RTL text,
+/// zero-width and invisible characters, special casing, the last code point,
+/// private use characters, mixed line endings, terminal escapes, and
+/// injection-looking text.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::testkit::edge_case_strings;
+/// use bt_string_utils::segmenter::graphemes;
+/// for s in edge_case_strings() {
+///     assert_eq!(graphemes(&s).collect::<String>(), s);
+/// }
+/// ```
+pub fn edge_case_strings() -> Vec<String> {
+    EDGE_CASES.iter().map(|s| (*s).to_owned()).collect()
+}
+
+/// Returns byte sequences that are not valid UTF-8 and must be rejected by
+/// `std::str::from_utf8`: UTF-16 surrogates encoded as in WTF-8 (lone and
+/// paired), overlong encodings, truncated sequences, stray continuation
+/// bytes and code points above `U+10FFFF`.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::testkit::invalid_utf8_cases;
+/// for bytes in invalid_utf8_cases() {
+///     assert!(std::str::from_utf8(&bytes).is_err());
+/// }
+/// ```
+pub fn invalid_utf8_cases() -> Vec<Vec<u8>> {
+    vec![
+        vec![0xED, 0xA0, 0x80],                         // lone high surrogate U+D800
+        vec![0xED, 0xBF, 0xBF],                         // lone low surrogate U+DFFF
+        vec![0xED, 0xA0, 0xBD, 0xED, 0xB8, 0x80],       // surrogate pair for U+1F600
+        b"abc\xED\xA0\x80def".to_vec(),                 // surrogate inside text
+        vec![0xC0, 0xAF],                               // overlong '/'
+        vec![0xE0, 0x80, 0xAF],                         // overlong '/' on 3 bytes
+        vec![0xE2, 0x82],                               // truncated '€'
+        vec![0xF0, 0x9F, 0x98],                         // truncated emoji
+        vec![0x80],                                     // stray continuation byte
+        vec![0xFF, 0xFE],                               // bytes never used in UTF-8
+        vec![0xF4, 0x90, 0x80, 0x80],                   // U+110000
+    ]
+}
+
+/// Returns a run of `n` CJK ideographs (two columns wide each, three bytes
+/// in UTF-8) without any whitespace, to test chunking and wrapping limits.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::testkit::cjk_run;
+/// let run = cjk_run(1000);
+/// assert_eq!(run.chars().count(), 1000);
+/// assert_eq!(run.len(), 3000);
+/// ```
+pub fn cjk_run(n: usize) -> String {
+    (0..n).map(|i| char::from_u32(0x4E00 + (i as u32 * 7919) % 0x5000).unwrap_or('字')).collect()
+}
+
+/// Returns a random string of up to `max_parts` pieces taken from
+/// [`edge_case_strings`], ASCII words and CJK runs, determined by `seed`.
+/// Use it to run a property over many seeds.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::testkit::nasty_string;
+/// use bt_string_utils::cleanser::strip_excess_combining_marks;
+/// for seed in 0..100 {
+///     let s = nasty_string(seed, 8);
+///     assert_eq!(s, nasty_string(seed, 8));
+///     let cleaned = strip_excess_combining_marks(&s, 100);
+///     assert_eq!(cleaned, s);
+/// }
+/// ```
+pub fn nasty_string(seed: u64, max_parts: usize) -> String {
+    let mut rng = SplitMix64(seed);
+    let parts = if max_parts == 0 { 0 } else { 1 + rng.below(max_parts as u64) as usize };
+    let mut out = String::new();
+    for _ in 0..parts {
+        match rng.below(4) {
+            0 => out.push_str(["word", "Hello", "x", "123", " "][rng.below(5) as usize]),
+            1 => out.push_str(&cjk_run(1 + rng.below(16) as usize)),
+            _ => out.push_str(EDGE_CASES[rng.below(EDGE_CASES.len() as u64) as usize]),
+        }
+    }
+    out
+}
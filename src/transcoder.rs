@@ -0,0 +1,100 @@
+/// International Morse code for letters, digits and common punctuation.
+const MORSE_TABLE: &[(char, &str)] = &[
+    ('A', ".-"), ('B', "-..."), ('C', "-.-."), ('D', "-.."), ('E', "."), ('F', "..-."),
+    ('G', "--."), ('H', "...."), ('I', ".."), ('J', ".---"), ('K', "-.-"), ('L', ".-.."),
+    ('M', "--"), ('N', "-."), ('O', "---"), ('P', ".--."), ('Q', "--.-"), ('R', ".-."),
+    ('S', "..."), ('T', "-"), ('U', "..-"), ('V', "...-"), ('W', ".--"), ('X', "-..-"),
+    ('Y', "-.--"), ('Z', "--.."),
+    ('0', "-----"), ('1', ".----"), ('2', "..---"), ('3', "...--"), ('4', "....-"),
+    ('5', "....."), ('6', "-...."), ('7', "--..."), ('8', "---.."), ('9', "----."),
+    ('.', ".-.-.-"), (',', "--..--"), ('?', "..--.."), ('\'', ".----."), ('!', "-.-.--"),
+    ('/', "-..-."), ('(', "-.--."), (')', "-.--.-"), ('&', ".-..."), (':', "---..."),
+    (';', "-.-.-."), ('=', "-...-"), ('+', ".-.-."), ('-', "-....-"), ('_', "..--.-"),
+    ('"', ".-..-."), ('$', "...-..-"), ('@', ".--.-."),
+];
+
+/// NATO/ICAO phonetic alphabet.
+const NATO_TABLE: &[(char, &str)] = &[
+    ('A', "Alfa"), ('B', "Bravo"), ('C', "Charlie"), ('D', "Delta"), ('E', "Echo"),
+    ('F', "Foxtrot"), ('G', "Golf"), ('H', "Hotel"), ('I', "India"), ('J', "Juliett"),
+    ('K', "Kilo"), ('L', "Lima"), ('M', "Mike"), ('N', "November"), ('O', "Oscar"),
+    ('P', "Papa"), ('Q', "Quebec"), ('R', "Romeo"), ('S', "Sierra"), ('T', "Tango"),
+    ('U', "Uniform"), ('V', "Victor"), ('W', "Whiskey"), ('X', "X-ray"), ('Y', "Yankee"),
+    ('Z', "Zulu"),
+    ('0', "Zero"), ('1', "One"), ('2', "Two"), ('3', "Three"), ('4', "Four"),
+    ('5', "Five"), ('6', "Six"), ('7', "Seven"), ('8', "Eight"), ('9', "Nine"),
+];
+
+fn lookup(table: &'static [(char, &'static str)], c: char) -> Option<&'static str> {
+    let c = c.to_ascii_uppercase();
+    table.iter().find(|(k, _)| *k == c).map(|(_, v)| *v)
+}
+
+/// Converts text to International Morse code.
+///
+/// Letters are case-insensitive. Characters are separated by a space and
+/// words by `" / "`. Characters without a Morse code (accented letters,
+/// emoji...) are skipped.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::transcoder::to_morse;
+/// assert_eq!(to_morse("SOS"), "... --- ...");
+/// assert_eq!(to_morse("Hi there"), ".... .. / - .... . .-. .");
+/// ```
+pub fn to_morse(s: &str) -> String {
+    s.split_whitespace()
+        .map(|word| word.chars().filter_map(|c| lookup(MORSE_TABLE, c)).collect::<Vec<_>>().join(" "))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+/// Decodes International Morse code produced by [`to_morse`].
+///
+/// Codes are separated by whitespace and words by `/`. The result is in
+/// uppercase.
+///
+/// # Returns
+/// `Some(text)`, or `None` if a code is not valid Morse.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::transcoder::from_morse;
+/// assert_eq!(from_morse("... --- ..."), Some("SOS".to_owned()));
+/// assert_eq!(from_morse(".... .. / - .... . .-. ."), Some("HI THERE".to_owned()));
+/// assert_eq!(from_morse("......."), None);
+/// ```
+pub fn from_morse(s: &str) -> Option<String> {
+    let mut words = Vec::new();
+    for word in s.split('/') {
+        let decoded = word
+            .split_whitespace()
+            .map(|code| MORSE_TABLE.iter().find(|(_, m)| *m == code).map(|(c, _)| *c))
+            .collect::<Option<String>>()?;
+        if !decoded.is_empty() {
+            words.push(decoded);
+        }
+    }
+    Some(words.join(" "))
+}
+
+/// Spells text with the NATO phonetic alphabet (`"abc"` → `"Alfa Bravo Charlie"`),
+/// for voice read-back of codes and identifiers.
+///
+/// Letters are case-insensitive and digits are spelled as English numbers.
+/// Other characters, except whitespace, are kept as they are.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::transcoder::to_nato_phonetic;
+/// assert_eq!(to_nato_phonetic("abc"), "Alfa Bravo Charlie");
+/// assert_eq!(to_nato_phonetic("A1-b"), "Alfa One - Bravo");
+/// ```
+pub fn to_nato_phonetic(s: &str) -> String {
+    s.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| lookup(NATO_TABLE, c).map_or_else(|| c.to_string(), str::to_owned))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,219 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+/// Built-in romanization of Cyrillic lowercase letters (Russian, Ukrainian,
+/// Belarusian, Serbian and Macedonian), sorted by character.
+const CYRILLIC: &[(char, &str)] = &[
+    ('\u{430}', "a"), ('\u{431}', "b"), ('\u{432}', "v"), ('\u{433}', "g"), ('\u{434}', "d"), ('\u{435}', "e"),
+    ('\u{436}', "zh"), ('\u{437}', "z"), ('\u{438}', "i"), ('\u{439}', "y"), ('\u{43a}', "k"), ('\u{43b}', "l"),
+    ('\u{43c}', "m"), ('\u{43d}', "n"), ('\u{43e}', "o"), ('\u{43f}', "p"), ('\u{440}', "r"), ('\u{441}', "s"),
+    ('\u{442}', "t"), ('\u{443}', "u"), ('\u{444}', "f"), ('\u{445}', "kh"), ('\u{446}', "ts"), ('\u{447}', "ch"),
+    ('\u{448}', "sh"), ('\u{449}', "shch"), ('\u{44a}', ""), ('\u{44b}', "y"), ('\u{44c}', ""), ('\u{44d}', "e"),
+    ('\u{44e}', "yu"), ('\u{44f}', "ya"), ('\u{450}', "e"), ('\u{451}', "yo"), ('\u{452}', "dj"),
+    ('\u{453}', "gj"), ('\u{454}', "ye"), ('\u{455}', "dz"), ('\u{456}', "i"), ('\u{457}', "yi"), ('\u{458}', "j"),
+    ('\u{459}', "lj"), ('\u{45a}', "nj"), ('\u{45b}', "c"), ('\u{45c}', "kj"), ('\u{45d}', "i"), ('\u{45e}', "u"),
+    ('\u{45f}', "dz"), ('\u{491}', "g"),
+];
+
+/// Built-in romanization of Greek lowercase letters, with and without tonos,
+/// sorted by character.
+const GREEK: &[(char, &str)] = &[
+    ('\u{390}', "i"), ('\u{3ac}', "a"), ('\u{3ad}', "e"), ('\u{3ae}', "i"), ('\u{3af}', "i"), ('\u{3b0}', "y"),
+    ('\u{3b1}', "a"), ('\u{3b2}', "v"), ('\u{3b3}', "g"), ('\u{3b4}', "d"), ('\u{3b5}', "e"), ('\u{3b6}', "z"),
+    ('\u{3b7}', "i"), ('\u{3b8}', "th"), ('\u{3b9}', "i"), ('\u{3ba}', "k"), ('\u{3bb}', "l"), ('\u{3bc}', "m"),
+    ('\u{3bd}', "n"), ('\u{3be}', "x"), ('\u{3bf}', "o"), ('\u{3c0}', "p"), ('\u{3c1}', "r"), ('\u{3c2}', "s"),
+    ('\u{3c3}', "s"), ('\u{3c4}', "t"), ('\u{3c5}', "y"), ('\u{3c6}', "f"), ('\u{3c7}', "ch"), ('\u{3c8}', "ps"),
+    ('\u{3c9}', "o"), ('\u{3ca}', "i"), ('\u{3cb}', "y"), ('\u{3cc}', "o"), ('\u{3cd}', "y"), ('\u{3ce}', "o"),
+];
+
+/// A table of custom character-to-string replacements, such as corporate
+/// romanization rules (`ä` → `ae`, `ß` → `ss`...).
+///
+/// Create it with [`TransliterationMap::builder`]. Single-character mappings
+/// take precedence over ranges; among overlapping ranges, the one added last
+/// wins.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::translit::TransliterationMap;
+/// let map = TransliterationMap::builder()
+///     .map('ä', "ae")
+///     .map('ö', "oe")
+///     .map('ü', "ue")
+///     .map('ß', "ss")
+///     .map_range('\u{300}'..='\u{36F}', "")
+///     .build();
+/// assert_eq!(map.apply("Größe über"), "Groesse ueber");
+/// assert_eq!(map.apply("Cafe\u{301}"), "Cafe");
+/// assert!(matches!(map.apply("plain"), std::borrow::Cow::Borrowed(_)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TransliterationMap {
+    chars: HashMap<char, String>,
+    ranges: Vec<(RangeInclusive<char>, String)>,
+}
+
+/// Builder for a [`TransliterationMap`]. Created by
+/// [`TransliterationMap::builder`].
+#[derive(Debug, Clone, Default)]
+pub struct TransliterationMapBuilder {
+    map: TransliterationMap,
+}
+
+impl TransliterationMap {
+    /// Starts building an empty map.
+    pub fn builder() -> TransliterationMapBuilder {
+        TransliterationMapBuilder::default()
+    }
+
+    /// Returns the replacement of `c`, or `None` if it is kept as is.
+    pub fn get(&self, c: char) -> Option<&str> {
+        if let Some(replacement) = self.chars.get(&c) {
+            return Some(replacement);
+        }
+        self.ranges.iter().rev().find(|(range, _)| range.contains(&c)).map(|(_, replacement)| replacement.as_str())
+    }
+
+    /// Returns `true` if the map has no mappings.
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty() && self.ranges.is_empty()
+    }
+
+    /// Replaces every mapped character of `text`.
+    ///
+    /// # Returns
+    /// `Cow::Borrowed(text)` if no character is mapped, otherwise the
+    /// transliterated `String`.
+    pub fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut chars = text.char_indices();
+        let (start, replacement) = loop {
+            match chars.next() {
+                None => return Cow::Borrowed(text),
+                Some((i, c)) => {
+                    if let Some(replacement) = self.get(c) {
+                        break (i, replacement);
+                    }
+                }
+            }
+        };
+
+        let mut out = String::with_capacity(text.len() + replacement.len());
+        out.push_str(&text[..start]);
+        out.push_str(replacement);
+        for (_, c) in chars {
+            match self.get(c) {
+                Some(replacement) => out.push_str(replacement),
+                None => out.push(c),
+            }
+        }
+        Cow::Owned(out)
+    }
+}
+
+impl TransliterationMapBuilder {
+    /// Maps `c` to `replacement`, replacing any previous mapping of `c`.
+    /// An empty replacement removes the character.
+    pub fn map(mut self, c: char, replacement: &str) -> Self {
+        self.map.chars.insert(c, replacement.to_owned());
+        self
+    }
+
+    /// Maps every character of `range` to `replacement`.
+    pub fn map_range(mut self, range: RangeInclusive<char>, replacement: &str) -> Self {
+        self.map.ranges.push((range, replacement.to_owned()));
+        self
+    }
+
+    /// Adds every `(char, replacement)` pair of `table`, as with
+    /// [`TransliterationMapBuilder::map`].
+    pub fn map_all(mut self, table: &[(char, &str)]) -> Self {
+        for (c, replacement) in table {
+            self.map.chars.insert(*c, (*replacement).to_owned());
+        }
+        self
+    }
+
+    /// Builds the map.
+    pub fn build(self) -> TransliterationMap {
+        self.map
+    }
+}
+
+/// Transliterates Cyrillic and Greek letters of `text` to Latin with the
+/// built-in tables; other characters are kept.
+///
+/// This is a best-effort romanization for slugs, search keys and file names,
+/// not a reversible standard:
+/// - Cyrillic follows the common "passport" style (`ж` → `zh`, `щ` → `shch`,
+///   `ю` → `yu`); the hard and soft signs are removed.
+/// - Greek maps each letter on its own (`θ` → `th`, `ψ` → `ps`), except `ου`
+///   which gives `ou`; accents are dropped.
+/// - An uppercase letter gives a capitalized replacement (`Ж` → `Zh`), or an
+///   uppercase one inside an uppercase word (`ЖУК` → `ZHUK`).
+///
+/// For custom rules, see [`TransliterationMap`].
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::translit::transliterate;
+/// assert_eq!(transliterate("Привет, мир"), "Privet, mir");
+/// assert_eq!(transliterate("Щука и ЖУК"), "Shchuka i ZHUK");
+/// assert_eq!(transliterate("Αθήνα, Μουσείο"), "Athina, Mouseio");
+/// assert!(matches!(transliterate("plain"), std::borrow::Cow::Borrowed(_)));
+/// ```
+///
+/// # Arguments
+/// * `text` – The text to transliterate.
+///
+/// # Returns
+/// `Cow::Borrowed(text)` if it has no Cyrillic or Greek letter, otherwise the
+/// transliterated `String`.
+pub fn transliterate(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(|c| builtin_latin(c).is_some()) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut previous_upper = false;
+    while let Some(c) = chars.next() {
+        let Some(mut latin) = builtin_latin(c) else {
+            out.push(c);
+            previous_upper = false;
+            continue;
+        };
+        if matches!(c, '\u{3bf}' | '\u{39f}') && chars.peek().is_some_and(|next| matches!(next, '\u{3c5}' | '\u{3cd}' | '\u{3a5}' | '\u{38e}')) {
+            chars.next();
+            latin = "ou";
+        }
+
+        let upper = c.is_uppercase();
+        let next_upper = chars.peek().is_some_and(|next| next.is_uppercase());
+        if upper && (previous_upper || next_upper) {
+            out.push_str(&latin.to_ascii_uppercase());
+        } else if upper {
+            let mut letters = latin.chars();
+            if let Some(first) = letters.next() {
+                out.push(first.to_ascii_uppercase());
+                out.push_str(letters.as_str());
+            }
+        } else {
+            out.push_str(latin);
+        }
+        previous_upper = upper;
+    }
+    Cow::Owned(out)
+}
+
+/// Returns the built-in Latin replacement of a Cyrillic or Greek letter, in
+/// lowercase.
+fn builtin_latin(c: char) -> Option<&'static str> {
+    let lower = c.to_lowercase().next()?;
+    let table = match lower {
+        '\u{370}'..='\u{3ff}' => GREEK,
+        '\u{400}'..='\u{4ff}' => CYRILLIC,
+        _ => return None,
+    };
+    table.binary_search_by_key(&lower, |(c, _)| *c).ok().map(|i| table[i].1)
+}
@@ -0,0 +1,109 @@
+//! Version 2 of the core splitting, lookup and trimming API.
+//!
+//! The functions of this module take the input first, borrow it, return
+//! slices of it, and return `None` when there is nothing to find instead of
+//! an empty or unchanged string. Separators can be a `char`, a string, a set
+//! of chars or a predicate (see [`Separator`]).
+//!
+//! The original functions are kept unchanged, so code can migrate one call
+//! at a time:
+//!
+//! | Before | v2 |
+//! |---|---|
+//! | `finder::get_first_occurrance(s, sep)` | [`before_first`]`(s, sep)` |
+//! | `finder::get_last_occurrance(s, sep)` | [`after_last`]`(s, sep)` |
+//! | `splitter::get_first_of_split(s, sep)` | [`split_first`]`(s, sep)` |
+//! | `splitter::get_last_of_split(s, sep)` | [`split_last`]`(s, sep)` |
+//! | `splitter::split_all(s, sep, max)` | [`split_all`]`(s, sep, max)` |
+//! | `finder::find_value_by_key(&pairs, key)` | [`find_value`]`(&pairs, key)` |
+//! | `cleanser::remove_char(location, &input, c)` | [`strip_char`]`(s, location, c)` |
+//! | `finder::get_first_n_chars(s, n)` | [`first_chars`]`(s, n)` |
+//! | `finder::get_last_n_chars(s, n)` | [`last_chars`]`(s, n)` |
+//! | `cleanser::remove_first_n_characters(s, n)` | [`skip_chars`]`(s, n)` |
+//!
+//! # Examples
+//! ```
+//! use bt_string_utils::v2;
+//! assert_eq!(v2::split_first("key=value", '='), Some(("key", "value")));
+//! assert_eq!(v2::split_first("no separator", '='), None);
+//! assert_eq!(v2::after_last("archive.tar.gz", '.'), Some("gz"));
+//! ```
+
+pub use crate::cleanser::remove_first_n_characters as skip_chars;
+pub use crate::cleanser::RemoveLocationEnum;
+pub use crate::finder::get_first_n_chars as first_chars;
+pub use crate::finder::get_last_n_chars as last_chars;
+pub use crate::finder::get_first_occurrance_ref as before_first;
+pub use crate::finder::get_last_occurrance_ref as after_last;
+pub use crate::splitter::split_all_ref as split_all;
+pub use crate::splitter::Separator;
+
+/// Splits `s` at the first occurrence of `separator`, or returns `None` if
+/// it does not occur.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::v2::split_first;
+/// assert_eq!(split_first("a→b→c", "→"), Some(("a", "b→c")));
+/// assert_eq!(split_first("abc", ':'), None);
+/// ```
+pub fn split_first<P: Separator>(s: &str, separator: P) -> Option<(&str, &str)> {
+    separator.find_in(s).map(|(start, end)| (&s[..start], &s[end..]))
+}
+
+/// Splits `s` at the last occurrence of `separator`, or returns `None` if
+/// it does not occur.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::v2::split_last;
+/// assert_eq!(split_last("a.b.c", '.'), Some(("a.b", "c")));
+/// assert_eq!(split_last("abc", '.'), None);
+/// ```
+pub fn split_last<P: Separator>(s: &str, separator: P) -> Option<(&str, &str)> {
+    separator.rfind_in(s).map(|(start, end)| (&s[..start], &s[end..]))
+}
+
+/// Returns the value of the first `key=value` item whose key is `key`,
+/// borrowed from the items.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::v2::find_value;
+/// let pairs = ["name=John", "age=30"];
+/// assert_eq!(find_value(&pairs, "age"), Some("30"));
+/// assert_eq!(find_value(&pairs, "city"), None);
+/// ```
+pub fn find_value<'a, S: AsRef<str>>(pairs: &'a [S], key: &str) -> Option<&'a str> {
+    pairs
+        .iter()
+        .filter_map(|item| item.as_ref().split_once('='))
+        .find(|(k, _)| *k == key)
+        .map(|(_, value)| value)
+}
+
+/// Removes one `target` character at the start, the end or both ends of `s`.
+///
+/// Returns `None` when `target` is not at the requested end. With
+/// [`RemoveLocationEnum::Both`] it is removed where present, and `None` means
+/// it is at neither end.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::v2::{strip_char, RemoveLocationEnum};
+/// assert_eq!(strip_char("\"quoted\"", RemoveLocationEnum::Begin, '"'), Some("quoted\""));
+/// assert_eq!(strip_char("café!", RemoveLocationEnum::End, '!'), Some("café"));
+/// assert_eq!(strip_char("rust", RemoveLocationEnum::End, '!'), None);
+/// assert_eq!(strip_char("\"quoted\"", RemoveLocationEnum::Both, '"'), Some("quoted"));
+/// assert_eq!(strip_char("\"quoted", RemoveLocationEnum::Both, '"'), Some("quoted"));
+/// ```
+pub fn strip_char(s: &str, location: RemoveLocationEnum, target: char) -> Option<&str> {
+    match location {
+        RemoveLocationEnum::Begin => s.strip_prefix(target),
+        RemoveLocationEnum::End => s.strip_suffix(target),
+        RemoveLocationEnum::Both => match s.strip_prefix(target) {
+            Some(rest) => Some(rest.strip_suffix(target).unwrap_or(rest)),
+            None => s.strip_suffix(target),
+        },
+    }
+}
@@ -0,0 +1,66 @@
+//! wasm-bindgen exports (feature `wasm`), so a browser editor counts, chunks
+//! and sanitizes text with exactly the same rules as the server.
+//!
+//! Functions are exported with camelCase names. Build with
+//! `wasm-pack build --features wasm`, then:
+//!
+//! ```js
+//! import { wordCount, splitIntoChunks } from "bt_string_utils";
+//! wordCount("Hello, wide world!"); // 3
+//! ```
+
+use wasm_bindgen::prelude::*;
+
+/// See [`crate::analyzer::word_count`].
+#[wasm_bindgen(js_name = wordCount)]
+pub fn word_count(text: &str) -> usize {
+    crate::analyzer::word_count(text)
+}
+
+/// See [`crate::analyzer::count_paragraphs`].
+#[wasm_bindgen(js_name = countParagraphs)]
+pub fn count_paragraphs(text: &str) -> usize {
+    crate::analyzer::count_paragraphs(text)
+}
+
+/// See [`crate::formatter::display_width`].
+#[wasm_bindgen(js_name = displayWidth)]
+pub fn display_width(text: &str) -> usize {
+    crate::formatter::display_width(text)
+}
+
+/// See [`crate::splitter::split_into_chunks`].
+#[wasm_bindgen(js_name = splitIntoChunks)]
+pub fn split_into_chunks(text: &str, chunk_size_bytes: usize) -> Vec<String> {
+    crate::splitter::split_into_chunks(text, chunk_size_bytes)
+}
+
+/// See [`crate::cleanser::remove_whitespace`].
+#[wasm_bindgen(js_name = removeWhitespace)]
+pub fn remove_whitespace(text: &str) -> String {
+    crate::cleanser::remove_whitespace(text)
+}
+
+/// See [`crate::cleanser::collapse_whitespace`].
+#[wasm_bindgen(js_name = collapseWhitespace)]
+pub fn collapse_whitespace(text: &str) -> String {
+    crate::cleanser::collapse_whitespace(text)
+}
+
+/// See [`crate::cleanser::remove_tags`].
+#[wasm_bindgen(js_name = removeTags)]
+pub fn remove_tags(text: &str, open_tag: &str, close_tag: &str) -> String {
+    crate::cleanser::remove_tags(text, open_tag, close_tag)
+}
+
+/// See [`crate::cleanser::strip_excess_combining_marks`].
+#[wasm_bindgen(js_name = stripExcessCombiningMarks)]
+pub fn strip_excess_combining_marks(text: &str, max_per_base: usize) -> String {
+    crate::cleanser::strip_excess_combining_marks(text, max_per_base)
+}
+
+/// See [`crate::cleanser::fix_whitespace`] (tabs kept, report dropped).
+#[wasm_bindgen(js_name = fixWhitespace)]
+pub fn fix_whitespace(text: &str) -> String {
+    crate::cleanser::fix_whitespace(text, Default::default()).0
+}
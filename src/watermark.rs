@@ -0,0 +1,88 @@
+/// Zero-width non-joiner, encodes a `0` bit.
+const ZERO_BIT: char = '\u{200C}';
+/// Zero-width joiner, encodes a `1` bit.
+const ONE_BIT: char = '\u{200D}';
+
+/// Hides `payload_bits` in the text as invisible zero-width characters: a
+/// zero-width non-joiner (`U+200C`) for `0` and a zero-width joiner
+/// (`U+200D`) for `1`.
+///
+/// The bits are inserted as one block right after the first whitespace
+/// character (or at the start of a text without whitespace), where they
+/// cannot join emoji or change the shaping of letters. A watermark already
+/// in the text is replaced.
+///
+/// The text looks the same when displayed; the watermark survives copy and
+/// paste but not normalization that removes invisible characters.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::watermark::{embed_watermark, extract_watermark, strip_watermark};
+/// let marked = embed_watermark("Hello world", &[true, false, true]);
+/// assert_eq!(marked, "Hello \u{200D}\u{200C}\u{200D}world");
+/// assert_eq!(extract_watermark(&marked), Some(vec![true, false, true]));
+/// assert_eq!(strip_watermark(&marked), "Hello world");
+/// ```
+pub fn embed_watermark(text: &str, payload_bits: &[bool]) -> String {
+    let text = strip_watermark(text);
+    let at = text.find(char::is_whitespace)
+        .map_or(0, |i| i + text[i..].chars().next().map_or(0, char::len_utf8));
+
+    let mut out = String::with_capacity(text.len() + payload_bits.len() * 3);
+    out.push_str(&text[..at]);
+    out.extend(payload_bits.iter().map(|bit| if *bit { ONE_BIT } else { ZERO_BIT }));
+    out.push_str(&text[at..]);
+    out
+}
+
+/// Returns the bits hidden with [`embed_watermark`], or `None` if the text
+/// has no watermark.
+///
+/// Zero-width joiners inside emoji sequences (`👩‍💻`) are not mistaken for a
+/// watermark: only zero-width characters at the start of the text or right
+/// after whitespace are read.
+///
+/// # Examples
+/// ```
+/// use bt_string_utils::watermark::extract_watermark;
+/// assert_eq!(extract_watermark("no mark 👩\u{200D}💻"), None);
+/// ```
+pub fn extract_watermark(text: &str) -> Option<Vec<bool>> {
+    watermark_ranges(text)
+        .into_iter()
+        .next()
+        .map(|(start, end)| text[start..end].chars().map(|c| c == ONE_BIT).collect())
+}
+
+/// Removes a watermark added by [`embed_watermark`], leaving zero-width
+/// joiners of emoji sequences in place.
+pub fn strip_watermark(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for (start, end) in watermark_ranges(text) {
+        out.push_str(&text[last..start]);
+        last = end;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+/// Byte ranges of the runs of zero-width (non-)joiners at the start of the
+/// text or right after whitespace.
+fn watermark_ranges(text: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut run_start = Some(0);
+    for (i, c) in text.char_indices() {
+        if c == ZERO_BIT || c == ONE_BIT {
+            continue;
+        }
+        if let Some(start) = run_start && start < i {
+            ranges.push((start, i));
+        }
+        run_start = c.is_whitespace().then_some(i + c.len_utf8());
+    }
+    if let Some(start) = run_start && start < text.len() {
+        ranges.push((start, text.len()));
+    }
+    ranges
+}
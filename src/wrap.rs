@@ -0,0 +1,347 @@
+use crate::analyzer::list_marker;
+use crate::formatter::{closing_sequences, display_width, fitting_prefix};
+
+/// Wraps the text into lines of at most `width` terminal columns.
+///
+/// ### Rules implemented:
+/// - Any whitespace, including newlines, separates words; lines are filled
+///   greedily and words are joined by a single space.
+/// - Widths are measured with [`display_width`], so CJK characters count as
+///   two columns and terminal styles or hyperlinks as zero.
+/// - A word wider than `width` (such as a long URL or a run of CJK text) is
+///   broken across lines.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::wrap::wrap;
+/// assert_eq!(wrap("The quick brown fox jumps over the lazy dog", 10),
+///            vec!["The quick", "brown fox", "jumps over", "the lazy", "dog"]);
+/// assert_eq!(wrap("你好世界你好", 5), vec!["你好", "世界", "你好"]);
+/// assert!(wrap("   ", 10).is_empty());
+/// ```
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+
+    for word in text.split_whitespace() {
+        let mut word = word;
+        let mut word_width = display_width(word);
+
+        if line_width > 0 && line_width + 1 + word_width <= width {
+            line.push(' ');
+            line.push_str(word);
+            line_width += 1 + word_width;
+            continue;
+        }
+        if line_width > 0 {
+            lines.push(std::mem::take(&mut line));
+        }
+
+        while word_width > width {
+            let mut head = fitting_prefix(word, width);
+            if head.is_empty() {
+                // A single character wider than the line
+                head = &word[..word.chars().next().map_or(0, char::len_utf8)];
+            }
+            // Hyperlinks and styles cut by the break are closed on the line
+            lines.push(format!("{head}{}", closing_sequences(head)));
+            word = &word[head.len()..];
+            word_width = display_width(word);
+        }
+        line.push_str(word);
+        line_width = word_width;
+    }
+
+    if line_width > 0 {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Same as [`wrap`] but returns the lines joined with `\n`.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::wrap::fill;
+/// assert_eq!(fill("one two three", 7), "one two\nthree");
+/// ```
+pub fn fill(text: &str, width: usize) -> String {
+    wrap(text, width).join("\n")
+}
+
+/// Wraps the text to `width` columns and justifies it: spaces are added
+/// between words so every line but the last of each paragraph ends exactly
+/// at `width` (flush left and right margins).
+///
+/// Paragraphs are separated by blank lines and stay separated by one blank
+/// line. Extra spaces go to the leftmost gaps first. Lines with a single
+/// word are left as they are. Widths are measured with [`display_width`].
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::wrap::justify;
+/// assert_eq!(justify("The quick brown fox jumps over the lazy dog", 16),
+///            "The  quick brown\nfox  jumps  over\nthe lazy dog");
+/// assert_eq!(justify("你好 世界 朋友 再见", 11), "你好   世界\n朋友 再见");
+/// ```
+pub fn justify(text: &str, width: usize) -> String {
+    let mut paragraphs: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for line in text.lines().chain([""]) {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push_str(line);
+            current.push(' ');
+        }
+    }
+
+    let mut out = Vec::with_capacity(paragraphs.len());
+    for paragraph in paragraphs {
+        let lines = wrap(&paragraph, width);
+        let last = lines.len() - 1;
+        let justified: Vec<String> = lines.into_iter().enumerate()
+            .map(|(i, line)| if i == last { line } else { justify_line(&line, width) })
+            .collect();
+        out.push(justified.join("\n"));
+    }
+
+    out.join("\n\n")
+}
+
+/// Pads the gaps between the words of a wrapped line to reach `width` columns.
+fn justify_line(line: &str, width: usize) -> String {
+    let words: Vec<&str> = line.split(' ').collect();
+    let gaps = words.len() - 1;
+    if gaps == 0 {
+        return line.to_owned();
+    }
+
+    let extra = width.saturating_sub(display_width(line));
+    let mut out = String::with_capacity(line.len() + extra);
+    for (i, word) in words.iter().enumerate() {
+        if i > 0 {
+            let spaces = 1 + extra / gaps + usize::from(i - 1 < extra % gaps);
+            out.extend(std::iter::repeat_n(' ', spaces));
+        }
+        out.push_str(word);
+    }
+    out
+}
+
+/// Reflows a block of line comments to `width` columns, prefix included.
+///
+/// The comment `prefix` (e.g. `"// "` or `"# "`) is removed from each line,
+/// the prose is rewrapped with [`wrap`] and the prefix is applied again.
+///
+/// ### Rules implemented:
+/// - Blank comment lines separate paragraphs and are kept (as the prefix
+///   without its trailing space).
+/// - List items starting with `-`, `*`, `+`, `1.` or `1)` start a new
+///   paragraph; their continuation lines are indented under the item text.
+/// - Indentation before the prefix is not kept.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::wrap::reflow_comment_block;
+/// let comment = "// This comment was written with\n// very short lines.\n//\n// - first item that is long\n// - second";
+/// assert_eq!(reflow_comment_block(comment, "// ", 24),
+///            "// This comment was\n// written with very\n// short lines.\n//\n// - first item that is\n//   long\n// - second");
+/// ```
+pub fn reflow_comment_block(text: &str, prefix: &str, width: usize) -> String {
+    let bare_prefix = prefix.trim_end();
+    // Paragraphs as (list marker, prose); `None` is a blank line
+    let mut blocks: Vec<Option<(&str, String)>> = Vec::new();
+
+    for line in text.lines() {
+        let line = line.trim_start();
+        let body = line.strip_prefix(prefix)
+            .or_else(|| line.strip_prefix(bare_prefix))
+            .unwrap_or(line)
+            .trim();
+
+        if body.is_empty() {
+            blocks.push(None);
+        } else if let Some(marker) = list_marker(body) {
+            blocks.push(Some((marker, body[marker.len()..].trim_start().to_owned())));
+        } else if let Some(Some((_, prose))) = blocks.last_mut() {
+            prose.push(' ');
+            prose.push_str(body);
+        } else {
+            blocks.push(Some(("", body.to_owned())));
+        }
+    }
+
+    let available = width.saturating_sub(display_width(prefix));
+    let mut out = Vec::new();
+    for block in blocks {
+        let Some((marker, prose)) = block else {
+            out.push(bare_prefix.to_owned());
+            continue;
+        };
+        let marker_width = display_width(marker) + (!marker.is_empty()) as usize;
+        let indent = " ".repeat(marker_width);
+        for (i, line) in wrap(&prose, available.saturating_sub(marker_width)).into_iter().enumerate() {
+            if i == 0 && !marker.is_empty() {
+                out.push(format!("{prefix}{marker} {line}"));
+            } else if i == 0 {
+                out.push(format!("{prefix}{line}"));
+            } else {
+                out.push(format!("{prefix}{indent}{line}"));
+            }
+        }
+    }
+
+    let mut result = out.join("\n");
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// Joins the hard-wrapped lines of each paragraph into one line, the inverse
+/// of [`fill`]. Useful to clean up emails and plain-text documents before
+/// counting words or summarizing them.
+///
+/// ### Rules implemented:
+/// - Consecutive non-blank lines are joined with a single space; blank lines
+///   separate paragraphs and are kept.
+/// - A line ending with a hyphen after a letter is joined without a space,
+///   so words split across lines (`well-` / `known`) are rejoined.
+/// - List items starting with `-`, `*`, `+`, `1.` or `1)` start a new line;
+///   the lines that follow are joined to the item.
+/// - `>` quote prefixes are kept: lines are only joined with lines of the
+///   same quote depth, and blank quoted lines stay `>`.
+/// - Indentation of the first line of a paragraph is kept, trailing
+///   whitespace is removed and a final line break is kept.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::wrap::unwrap_lines;
+/// let email = "Hi Ana,\n\nThe build is\nbroken again:\n- tests fail on\n  Windows\n- docs\n\n> Can you take a\n> look today?\n>\n> Bo";
+/// assert_eq!(unwrap_lines(email),
+///            "Hi Ana,\n\nThe build is broken again:\n- tests fail on Windows\n- docs\n\n> Can you take a look today?\n>\n> Bo");
+/// assert_eq!(unwrap_lines("a well-\nknown fix"), "a well-known fix");
+/// ```
+pub fn unwrap_lines(text: &str) -> String {
+    let mut out: Vec<String> = Vec::new();
+    // Quote depth of the last output line, if the next line may be joined to it
+    let mut open: Option<usize> = None;
+
+    for line in text.lines() {
+        let (prefix, body) = split_quote_prefix(line);
+        let depth = prefix.matches('>').count();
+        let content = body.trim();
+
+        if content.is_empty() {
+            out.push(line.trim_end().to_owned());
+            open = None;
+            continue;
+        }
+        match out.last_mut() {
+            Some(last) if open == Some(depth) && list_marker(content).is_none() => {
+                last.truncate(last.trim_end().len());
+                let mut chars = last.chars().rev();
+                if !(chars.next() == Some('-') && chars.next().is_some_and(char::is_alphabetic)) {
+                    last.push(' ');
+                }
+                last.push_str(content);
+            }
+            _ => {
+                out.push(format!("{prefix}{}", body.trim_end()));
+                open = Some(depth);
+            }
+        }
+    }
+
+    let mut result = out.join("\n");
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// Splits a line into its `>` quote prefix (with the space after each `>`)
+/// and the rest. Lines that are not quoted have an empty prefix.
+fn split_quote_prefix(line: &str) -> (&str, &str) {
+    let mut end = 0;
+    loop {
+        let rest = line[end..].trim_start_matches([' ', '\t']);
+        let Some(after) = rest.strip_prefix('>') else {
+            break;
+        };
+        end = line.len() - after.strip_prefix(' ').unwrap_or(after).len();
+    }
+    line.split_at(end)
+}
+
+/// Removes the margin of a multi-line string literal, Scala-style: on every
+/// line whose first non-blank character is `margin`, everything up to and
+/// including it is removed. Other lines are kept as they are.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::wrap::strip_margin;
+/// let sql = "SELECT *
+///            |  FROM users
+///            |  WHERE id = 1";
+/// assert_eq!(strip_margin(sql, '|'), "SELECT *\n  FROM users\n  WHERE id = 1");
+/// ```
+pub fn strip_margin(text: &str, margin: char) -> String {
+    let mut out = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim_start_matches([' ', '\t']);
+        out.push_str(trimmed.strip_prefix(margin).unwrap_or(line));
+    }
+    out
+}
+
+/// Normalizes an indented multi-line string literal into the block it
+/// represents (like the `indoc` crate or Python's `textwrap.dedent`).
+///
+/// ### Rules implemented:
+/// - A line break right at the start (after the opening quote) is removed.
+/// - The indentation common to all non-blank lines is removed; blank lines
+///   become empty.
+/// - A last line made only of spaces and tabs (before the closing quote) is
+///   removed, keeping the line break before it.
+///
+/// ### Examples
+/// ```
+/// use bt_string_utils::wrap::to_raw_block;
+/// let block = "
+///     fn main() {
+///         println!();
+///     }
+///     ";
+/// assert_eq!(to_raw_block(block), "fn main() {\n    println!();\n}\n");
+/// ```
+pub fn to_raw_block(text: &str) -> String {
+    let text = text.strip_prefix("\r\n").or_else(|| text.strip_prefix('\n')).unwrap_or(text);
+    let text = match text.rfind('\n') {
+        Some(i) if text[i + 1..].trim_matches([' ', '\t']).is_empty() => &text[..=i],
+        _ => text,
+    };
+
+    let indent_of = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+    let common = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| &line[..indent_of(line)])
+        .reduce(|a, b| &a[..a.bytes().zip(b.bytes()).take_while(|(x, y)| x == y).count()])
+        .map_or(0, str::len);
+
+    let mut out = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            out.push_str(&line[line.trim_end_matches(['\r', '\n']).len()..]);
+        } else {
+            out.push_str(&line[common..]);
+        }
+    }
+    out
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod concat_within_limit_tests {
+    use bt_string_utils::builder::{concat_within_char_limit, concat_within_limit};
+
+
+    #[test]
+    fn exact_budget_is_allowed() {
+        assert_eq!(concat_within_limit(&["ab", "cd"], 4), ("abcd".to_owned(), 2));
+    }
+
+    #[test]
+    fn stops_at_first_part_that_does_not_fit() {
+        // "c" would fit but parts are consumed in order
+        assert_eq!(concat_within_limit(&["ab", "cdef", "c"], 4), ("ab".to_owned(), 1));
+    }
+
+    #[test]
+    fn multibyte_parts_are_measured_in_bytes() {
+        assert_eq!(concat_within_limit(&["🦄", "🚀"], 7), ("🦄".to_owned(), 1));
+        assert_eq!(concat_within_char_limit(&["🦄", "🚀"], 2), ("🦄🚀".to_owned(), 2));
+    }
+
+    #[test]
+    fn empty_inputs() {
+        assert_eq!(concat_within_limit::<&str>(&[], 10), ("".to_owned(), 0));
+        assert_eq!(concat_within_limit(&["", ""], 0), ("".to_owned(), 2));
+    }
+
+    #[test]
+    fn accepts_owned_strings() {
+        let parts = vec!["one ".to_owned(), "two".to_owned()];
+        assert_eq!(concat_within_limit(&parts, 4), ("one ".to_owned(), 1));
+    }
+}
+
+#[cfg(test)]
+mod prompt_builder_tests {
+    use bt_string_utils::builder::PromptBuilder;
+
+
+    #[test]
+    fn fitting_prompt_is_unchanged() {
+        let prompt = PromptBuilder::new(100).section("a", 1).section("b", 2).separator(" | ").build();
+        assert_eq!(prompt, "a | b");
+    }
+
+    #[test]
+    fn lowest_priority_truncated_first() {
+        let prompt = PromptBuilder::new(20)
+            .separator("\n")
+            .section("low priority text here", 1)
+            .section("high priority", 5)
+            .build();
+        assert_eq!(prompt, "low\nhigh priority");
+        assert!(prompt.len() <= 20);
+    }
+
+    #[test]
+    fn drops_section_then_truncates_next() {
+        let prompt = PromptBuilder::new(8)
+            .separator(" ")
+            .section("keep this safe", 3)
+            .section("drop me", 1)
+            .build();
+        assert_eq!(prompt, "keep");
+    }
+
+    #[test]
+    fn ties_truncate_last_added() {
+        let prompt = PromptBuilder::new(11).separator(" ").section("first one", 1).section("second one", 1).build();
+        assert_eq!(prompt, "first one");
+    }
+
+    #[test]
+    fn falls_back_to_grapheme_boundaries() {
+        // No whitespace in CJK text: cut between characters, never inside one
+        let prompt = PromptBuilder::new(9).section("你好世界", 1).build();
+        assert_eq!(prompt, "你好世");
+        let prompt = PromptBuilder::new(5).section("e\u{301}e\u{301}", 1).build();
+        assert_eq!(prompt, "e\u{301}");
+    }
+
+    #[test]
+    fn empty_budget() {
+        assert_eq!(PromptBuilder::new(0).section("anything", 1).build(), "");
+        assert_eq!(PromptBuilder::new(10).build(), "");
+    }
+}
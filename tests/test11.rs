@@ -0,0 +1,303 @@
+#[cfg(test)]
+mod parse_properties_tests {
+    use bt_string_utils::kv::parse_properties;
+
+
+    #[test]
+    fn separators() {
+        let props = parse_properties("a=1\nb:2\nc 3\nd = 4\ne : 5\nf   =   6");
+        for (k, v) in [("a", "1"), ("b", "2"), ("c", "3"), ("d", "4"), ("e", "5"), ("f", "6")] {
+            assert_eq!(props[k], v);
+        }
+    }
+
+    #[test]
+    fn comments_and_blank_lines() {
+        let props = parse_properties("# comment\n  ! other comment\n\n   \nkey=value");
+        assert_eq!(props.len(), 1);
+        assert_eq!(props["key"], "value");
+    }
+
+    #[test]
+    fn escaped_separators_in_key() {
+        let props = parse_properties("my\\ key\\=x = v=w:z");
+        assert_eq!(props["my key=x"], "v=w:z");
+    }
+
+    #[test]
+    fn line_continuations() {
+        let props = parse_properties("fruits = apple, \\\n    banana, \\\n    cherry\nnext=1");
+        assert_eq!(props["fruits"], "apple, banana, cherry");
+        assert_eq!(props["next"], "1");
+    }
+
+    #[test]
+    fn escaped_backslash_is_not_continuation() {
+        let props = parse_properties("dir = C:\\\\\nnext = 1");
+        assert_eq!(props["dir"], "C:\\");
+        assert_eq!(props["next"], "1");
+    }
+
+    #[test]
+    fn unicode_escapes_and_surrogates() {
+        let props = parse_properties("a=\\u00e9\\u4f60\nb=\\ud83d\\ude00\nc=\\u12");
+        assert_eq!(props["a"], "é你");
+        assert_eq!(props["b"], "😀");
+        assert_eq!(props["c"], "\\u12");
+    }
+
+    #[test]
+    fn control_escapes() {
+        let props = parse_properties("k=a\\tb\\nc");
+        assert_eq!(props["k"], "a\tb\nc");
+    }
+
+    #[test]
+    fn last_value_wins_and_key_only() {
+        let props = parse_properties("k=1\nk=2\nflag");
+        assert_eq!(props["k"], "2");
+        assert_eq!(props["flag"], "");
+    }
+
+    #[test]
+    fn windows_newlines() {
+        let props = parse_properties("a=1\r\nb=2\r\n");
+        assert_eq!(props["a"], "1");
+        assert_eq!(props["b"], "2");
+    }
+}
+
+#[cfg(test)]
+mod diff_kv_maps_tests {
+    use std::collections::HashMap;
+    use bt_string_utils::kv::{diff_kv_maps, parse_properties};
+
+
+    #[test]
+    fn equal_maps() {
+        let a = parse_properties("a=1\nb=2");
+        let diff = diff_kv_maps(&a, &a.clone());
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "");
+    }
+
+    #[test]
+    fn from_and_to_empty() {
+        let a = parse_properties("b=2\na=1");
+        let empty = HashMap::new();
+        let diff = diff_kv_maps(&empty, &a);
+        assert_eq!(diff.to_string(), "+ a=1\n+ b=2\n");
+        let diff = diff_kv_maps(&a, &empty);
+        assert_eq!(diff.to_string(), "- a=1\n- b=2\n");
+    }
+
+    #[test]
+    fn changed_values_sorted_by_key() {
+        let a = parse_properties("z=1\ny=1\nx=1");
+        let b = parse_properties("z=2\ny=1\nx=3");
+        let diff = diff_kv_maps(&a, &b);
+        assert_eq!(diff.changed, vec![
+            ("x".to_owned(), "1".to_owned(), "3".to_owned()),
+            ("z".to_owned(), "1".to_owned(), "2".to_owned()),
+        ]);
+        assert!(diff.added.is_empty() && diff.removed.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod merge_kv_tests {
+    use bt_string_utils::kv::{merge_kv, parse_properties, MergeStrategy};
+
+
+    #[test]
+    fn no_conflicts() {
+        let base = parse_properties("a=1\nb=2");
+        let overlay = parse_properties("b=2\nc=3");
+        let (merged, conflicts) = merge_kv(&base, &overlay, MergeStrategy::ErrorOnConflict).unwrap();
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged["c"], "3");
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn keep_base() {
+        let base = parse_properties("a=1\nb=2");
+        let overlay = parse_properties("a=9\nc=3");
+        let (merged, conflicts) = merge_kv(&base, &overlay, MergeStrategy::KeepBase).unwrap();
+        assert_eq!(merged["a"], "1");
+        assert_eq!(merged["c"], "3");
+        assert_eq!(conflicts, vec!["a"]);
+    }
+
+    #[test]
+    fn conflict_error_message() {
+        let base = parse_properties("b=1\na=1");
+        let overlay = parse_properties("b=2\na=2");
+        let error = merge_kv(&base, &overlay, MergeStrategy::ErrorOnConflict).unwrap_err();
+        assert_eq!(error.to_string(), "conflicting values for key a");
+    }
+
+    #[test]
+    fn concat_only_conflicts() {
+        let base = parse_properties("paths=/usr/bin\nsame=x");
+        let overlay = parse_properties("paths=/opt/bin\nsame=x");
+        let (merged, _) = merge_kv(&base, &overlay, MergeStrategy::ConcatList(":".to_owned())).unwrap();
+        assert_eq!(merged["paths"], "/usr/bin:/opt/bin");
+        assert_eq!(merged["same"], "x");
+    }
+}
+
+#[cfg(test)]
+mod kv_parser_tests {
+    use bt_string_utils::kv::{KvParseError, KvParser};
+
+
+    #[test]
+    fn quotes_and_escapes() {
+        let map = KvParser::new().parse(r#"a=1; b='x;y' ; c="it's"; d=a\;b; e=' padded '; f="""#).unwrap();
+        assert_eq!(map.len(), 6);
+        assert_eq!(map["a"], "1");
+        assert_eq!(map["b"], "x;y");
+        assert_eq!(map["c"], "it's");
+        assert_eq!(map["d"], "a;b");
+        assert_eq!(map["e"], " padded ");
+        assert_eq!(map["f"], "");
+    }
+
+    #[test]
+    fn quotes_only_at_field_start() {
+        let map = KvParser::new().parse("name=O'Brien; 'spaced key' = v").unwrap();
+        assert_eq!(map["name"], "O'Brien");
+        assert_eq!(map["spaced key"], "v");
+    }
+
+    #[test]
+    fn custom_delimiters() {
+        let parser = KvParser::new().key_value_delimiter(':').pair_delimiter(',').quotes(&[]).escape(None).trim(false);
+        let map = parser.parse("a:1, b:'q',c:x\\y").unwrap();
+        assert_eq!(map["a"], "1");
+        assert_eq!(map[" b"], "'q'");
+        assert_eq!(map["c"], "x\\y");
+    }
+
+    #[test]
+    fn empty_pairs_and_repeated_keys() {
+        let map = KvParser::new().parse(";a=1;; a=2 ;").unwrap();
+        assert_eq!(map.len(), 1);
+        assert_eq!(map["a"], "2");
+        assert!(KvParser::new().parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(KvParser::new().parse("a=1; b='open"), Err(KvParseError::UnclosedQuote { position: 7 }));
+        assert_eq!(KvParser::new().parse("a=1;flag;c=3"), Err(KvParseError::MissingDelimiter { position: 4 }));
+        assert_eq!(KvParseError::UnclosedQuote { position: 7 }.to_string(), "unclosed quote at position 7");
+    }
+}
+
+#[cfg(test)]
+mod parse_kv_pairs_tests {
+    use bt_string_utils::finder::find_value_by_key;
+    use bt_string_utils::kv::{parse_kv_pairs, parse_kv_pairs_ordered};
+
+
+    #[test]
+    fn same_lookups_as_find_value_by_key() {
+        let v = vec!["k1=a".to_owned(), "k2=".to_owned(), "k1=b".to_owned(), "bad".to_owned(), " k3 = c".to_owned(), "=x".to_owned()];
+        let map = parse_kv_pairs(&v);
+        for key in ["k1", "k2", "k3", " k3 ", "", "bad", "missing"] {
+            assert_eq!(map.get(key).cloned(), find_value_by_key(&v, key), "{key:?}");
+        }
+        assert_eq!(map.len(), 4);
+    }
+
+    #[test]
+    fn ordered_keeps_duplicates() {
+        let pairs = parse_kv_pairs_ordered(vec!["b=2", "a=1", "b=3", "noise"]);
+        let keys: Vec<&str> = pairs.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, ["b", "a", "b"]);
+        assert_eq!(pairs[2].1, "3");
+        assert!(parse_kv_pairs_ordered(Vec::<String>::new()).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod parse_env_block_tests {
+    use bt_string_utils::kv::{parse_env_block, EnvParseError, EnvParseErrorKind};
+
+    fn vars(text: &str) -> Vec<(String, String)> {
+        let (vars, errors) = parse_env_block(text);
+        assert!(errors.is_empty(), "{errors:?}");
+        vars
+    }
+
+
+    #[test]
+    fn unquoted_values() {
+        assert_eq!(vars("A=1\n  B = two words  \nC=\nD=x#y\nE=x # comment\nexport   F=f"), vec![
+            ("A".to_owned(), "1".to_owned()),
+            ("B".to_owned(), "two words".to_owned()),
+            ("C".to_owned(), "".to_owned()),
+            ("D".to_owned(), "x#y".to_owned()),
+            ("E".to_owned(), "x".to_owned()),
+            ("F".to_owned(), "f".to_owned()),
+        ]);
+        assert_eq!(vars("LIST=a,\\\n  b,\\\n  c\nNEXT=1")[0].1, "a,\nb,\nc");
+    }
+
+    #[test]
+    fn quoted_values() {
+        let text = "D=\"say \\\"hi\\\" \\$HOME\\tx\" # note\nS='raw \\n #'\nM=\"line 1\nline 2\"\nAFTER=ok";
+        let parsed = vars(text);
+        assert_eq!(parsed[0].1, "say \"hi\" $HOME\tx");
+        assert_eq!(parsed[1].1, "raw \\n #");
+        assert_eq!(parsed[2].1, "line 1\nline 2");
+        assert_eq!(parsed[3], ("AFTER".to_owned(), "ok".to_owned()));
+    }
+
+    #[test]
+    fn multi_line_quoted_values_keep_inner_whitespace() {
+        let parsed = vars("D=\"first  \n  second \"  \nS='a \t\nb'\t\nU=x  ");
+        assert_eq!(parsed[0].1, "first  \n  second ");
+        assert_eq!(parsed[1].1, "a \t\nb");
+        assert_eq!(parsed[2].1, "x");
+    }
+
+    #[test]
+    fn repeated_keys_keep_first_position() {
+        assert_eq!(vars("A=1\nB=2\nA=3"), vec![("A".to_owned(), "3".to_owned()), ("B".to_owned(), "2".to_owned())]);
+    }
+
+    #[test]
+    fn errors_with_line_numbers() {
+        let (vars, errors) = parse_env_block("OK=1\nno equals\n1BAD=x\nQ=\"never closed\n\nLAST=2");
+        assert_eq!(vars, vec![("OK".to_owned(), "1".to_owned()), ("LAST".to_owned(), "2".to_owned())]);
+        assert_eq!(errors, vec![
+            EnvParseError { line: 2, kind: EnvParseErrorKind::MissingEquals },
+            EnvParseError { line: 3, kind: EnvParseErrorKind::InvalidKey },
+            EnvParseError { line: 4, kind: EnvParseErrorKind::UnclosedQuote },
+        ]);
+        assert_eq!(errors[0].to_string(), "line 2: missing '='");
+    }
+
+    #[test]
+    fn text_after_closing_quote() {
+        let (vars, errors) = parse_env_block("A=\"a\" junk\nB='multi\nline'x\nC=\"c\"#ok\nD=1");
+        assert_eq!(vars, vec![("C".to_owned(), "c".to_owned()), ("D".to_owned(), "1".to_owned())]);
+        assert_eq!(errors, vec![
+            EnvParseError { line: 1, kind: EnvParseErrorKind::TrailingText },
+            EnvParseError { line: 2, kind: EnvParseErrorKind::TrailingText },
+        ]);
+        assert_eq!(errors[0].to_string(), "line 1: text after the closing quote");
+    }
+
+    #[test]
+    fn many_repeated_keys() {
+        let text: String = (0..50_000).map(|i| format!("K{}={i}\n", i % 25_000)).collect();
+        let parsed = vars(&text);
+        assert_eq!(parsed.len(), 25_000);
+        assert_eq!(parsed[0], ("K0".to_owned(), "25000".to_owned()));
+        assert_eq!(parsed[24_999], ("K24999".to_owned(), "49999".to_owned()));
+    }
+}
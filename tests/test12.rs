@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod unicode_escapes_tests {
+    use bt_string_utils::escaper::{decode_unicode_escapes, encode_unicode_escapes, EscapeError, EscapeStyle};
+
+
+    #[test]
+    fn decodes_all_styles() {
+        assert_eq!(decode_unicode_escapes(r"A\u{42}\x{43}").unwrap(), "ABC");
+        assert_eq!(decode_unicode_escapes(r"\u4f60\u597d").unwrap(), "你好");
+    }
+
+    #[test]
+    fn no_escapes_is_unchanged() {
+        assert_eq!(decode_unicode_escapes("plain text ✓").unwrap(), "plain text ✓");
+        assert_eq!(decode_unicode_escapes("").unwrap(), "");
+    }
+
+    #[test]
+    fn other_escapes_are_kept() {
+        assert_eq!(decode_unicode_escapes(r"a\tb\x41\").unwrap(), r"a\tb\x41\");
+    }
+
+    #[test]
+    fn errors_report_position() {
+        assert_eq!(decode_unicode_escapes(r"ab\u12"), Err(EscapeError::InvalidHex { position: 2 }));
+        assert_eq!(decode_unicode_escapes(r"\u{110000}"), Err(EscapeError::InvalidCodePoint { position: 0 }));
+        assert_eq!(decode_unicode_escapes(r"x\ude00"), Err(EscapeError::LoneSurrogate { position: 1 }));
+        assert_eq!(decode_unicode_escapes(r"\ud83dA"), Err(EscapeError::LoneSurrogate { position: 0 }));
+        assert_eq!(decode_unicode_escapes(r"\u{}"), Err(EscapeError::InvalidHex { position: 0 }));
+        assert_eq!(decode_unicode_escapes(r"\u{41"), Err(EscapeError::InvalidHex { position: 0 }));
+    }
+
+    #[test]
+    fn error_messages() {
+        assert_eq!(EscapeError::LoneSurrogate { position: 3 }.to_string(), "unpaired UTF-16 surrogate at byte 3");
+    }
+
+    #[test]
+    fn encode_keeps_ascii() {
+        assert_eq!(encode_unicode_escapes("plain", EscapeStyle::Json), "plain");
+        assert_eq!(encode_unicode_escapes("tab\t", EscapeStyle::Java), "tab\t");
+        assert_eq!(encode_unicode_escapes("tab\t", EscapeStyle::Rust), r"tab\u{9}");
+    }
+
+    #[test]
+    fn backslashes_and_quotes() {
+        assert_eq!(decode_unicode_escapes(r"a\\b \\\\").unwrap(), r"a\b \\");
+        assert_eq!(encode_unicode_escapes(r"a\u0041", EscapeStyle::Java), r"a\\u0041");
+        assert_eq!(encode_unicode_escapes(r#"say "hi""#, EscapeStyle::Java), r#"say "hi""#);
+        assert_eq!(encode_unicode_escapes(r#"say "hi""#, EscapeStyle::Json), r#"say \"hi\""#);
+        for original in [r"a\u0041", r"\\u{41} \x{42}", r#"\"q\" \"#, "\\\n é"] {
+            for style in [EscapeStyle::Java, EscapeStyle::Json, EscapeStyle::Rust] {
+                assert_eq!(decode_unicode_escapes(&encode_unicode_escapes(original, style)).unwrap(), original);
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_every_style() {
+        let original = "é 𝄞 👨‍👩‍👧 \u{1}";
+        for style in [EscapeStyle::Java, EscapeStyle::Json, EscapeStyle::Rust] {
+            let encoded = encode_unicode_escapes(original, style);
+            assert!(encoded.is_ascii());
+            assert_eq!(decode_unicode_escapes(&encoded).unwrap(), original);
+        }
+    }
+}
+
+#[cfg(test)]
+mod string_literal_tests {
+    use bt_string_utils::escaper::{escape_c_string, escape_rust_string_literal, unescape_c_string, EscapeError};
+
+
+    #[test]
+    fn simple_escapes() {
+        assert_eq!(unescape_c_string(r#"\a\b\f\n\r\t\v\\\'\"\?"#).unwrap(), "\u{7}\u{8}\u{c}\n\r\t\u{b}\\'\"?");
+    }
+
+    #[test]
+    fn octal_escapes_take_up_to_three_digits() {
+        assert_eq!(unescape_c_string(r"\0").unwrap(), "\0");
+        assert_eq!(unescape_c_string(r"\1014").unwrap(), "A4");
+        assert_eq!(unescape_c_string(r"\18").unwrap(), "\u{1}8");
+    }
+
+    #[test]
+    fn hex_and_universal_escapes() {
+        assert_eq!(unescape_c_string(r"\x41\xe9").unwrap(), "Aé");
+        assert_eq!(unescape_c_string(r"\U0001F600").unwrap(), "😀");
+        assert_eq!(unescape_c_string(r"\u{41}"), Err(EscapeError::InvalidHex { position: 0 }));
+        assert_eq!(unescape_c_string(r"\xZ"), Err(EscapeError::InvalidHex { position: 0 }));
+        assert_eq!(unescape_c_string(r"\U00110000"), Err(EscapeError::InvalidCodePoint { position: 0 }));
+    }
+
+    #[test]
+    fn unknown_and_trailing_escapes() {
+        assert_eq!(unescape_c_string(r"ab\q"), Err(EscapeError::UnknownEscape { position: 2 }));
+        assert_eq!(unescape_c_string("ab\\"), Err(EscapeError::UnknownEscape { position: 2 }));
+    }
+
+    #[test]
+    fn c_round_trip() {
+        let original = "all\u{7}\u{8}\u{c}\n\r\t\u{b}\u{1b}\"\\ 字";
+        assert_eq!(unescape_c_string(&escape_c_string(original)).unwrap(), original);
+    }
+
+    #[test]
+    fn rust_literal() {
+        assert_eq!(escape_rust_string_literal(""), "\"\"");
+        assert_eq!(escape_rust_string_literal("\0\t\\"), r#""\0\t\\""#);
+        assert_eq!(escape_rust_string_literal("\u{85}"), r#""\u{85}""#);
+        assert_eq!(escape_rust_string_literal("ok 🙂"), "\"ok 🙂\"");
+    }
+}
+
+#[cfg(test)]
+mod morse_nato_tests {
+    use bt_string_utils::transcoder::{from_morse, to_morse, to_nato_phonetic};
+
+
+    #[test]
+    fn morse_round_trip() {
+        let text = "The quick brown fox 123?";
+        assert_eq!(from_morse(&to_morse(text)), Some(text.to_uppercase()));
+    }
+
+    #[test]
+    fn morse_skips_unknown_characters() {
+        assert_eq!(to_morse("é e"), ".");
+        assert_eq!(to_morse("🙂"), "");
+        assert_eq!(to_morse(""), "");
+    }
+
+    #[test]
+    fn morse_extra_whitespace() {
+        assert_eq!(from_morse("  ...   ---  ...  /  .  "), Some("SOS E".to_owned()));
+        assert_eq!(from_morse(""), Some("".to_owned()));
+    }
+
+    #[test]
+    fn nato_digits_and_case() {
+        assert_eq!(to_nato_phonetic("Xy 09"), "X-ray Yankee Zero Nine");
+        assert_eq!(to_nato_phonetic(""), "");
+    }
+}
+
+#[cfg(test)]
+mod stylize_tests {
+    use bt_string_utils::analyzer::word_count;
+    use bt_string_utils::stylize::{stylize, unstylize, StyleEnum};
+
+    const ALL: &str = "The Quick Brown Fox 0123456789";
+
+
+    #[test]
+    fn reversible_styles_round_trip() {
+        for style in [StyleEnum::Bold, StyleEnum::Italic, StyleEnum::BoldItalic, StyleEnum::Monospace, StyleEnum::Fullwidth, StyleEnum::Circled] {
+            let styled = stylize(ALL, style);
+            assert_ne!(styled, ALL);
+            assert_eq!(unstylize(&styled), ALL);
+        }
+    }
+
+    #[test]
+    fn small_caps_and_braille_round_trip_lowercase() {
+        assert_eq!(unstylize(&stylize("hello world", StyleEnum::SmallCaps)), "hello world");
+        assert_eq!(unstylize(&stylize("Hello World", StyleEnum::Braille)), "hello world");
+    }
+
+    #[test]
+    fn non_ascii_unchanged() {
+        assert_eq!(stylize("é🙂", StyleEnum::Bold), "é🙂");
+        assert_eq!(unstylize("é🙂 plain"), "é🙂 plain");
+    }
+
+    #[test]
+    fn normalizes_before_word_count() {
+        let styled = stylize("three fancy words", StyleEnum::Fullwidth);
+        // Fullwidth spaces are whitespace too, but unstylize gives plain ASCII
+        assert_eq!(unstylize(&styled), "three fancy words");
+        assert_eq!(word_count(&unstylize(&styled)), 3);
+    }
+}
@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod zalgo_tests {
+    use bt_string_utils::analyzer::combining_mark_density;
+    use bt_string_utils::cleanser::strip_excess_combining_marks;
+
+    const ZALGO: &str = "Z\u{351}\u{357}\u{30e}\u{33e}a\u{36b}\u{346}\u{350}\u{308}l\u{30a}\u{33d}\u{344}g\u{35b}\u{310}o\u{312}\u{343}\u{36e}";
+
+
+    #[test]
+    fn density_of_empty_and_plain_text() {
+        assert_eq!(combining_mark_density(""), 0.0);
+        assert_eq!(combining_mark_density("café"), 0.0);
+    }
+
+    #[test]
+    fn density_flags_zalgo() {
+        assert!(combining_mark_density("cafe\u{301} au lait") < 0.1);
+        assert!(combining_mark_density(ZALGO) > 0.6);
+    }
+
+    #[test]
+    fn strip_keeps_allowed_marks() {
+        let cleaned = strip_excess_combining_marks(ZALGO, 1);
+        assert_eq!(cleaned.chars().count(), 10);
+        assert!(combining_mark_density(&cleaned) <= 0.5);
+        assert_eq!(strip_excess_combining_marks(ZALGO, 0), "Zalgo");
+    }
+
+    #[test]
+    fn strip_leaves_normal_text_alone() {
+        let text = "Tiếng Vie\u{302}\u{323}t, cafe\u{301}";
+        assert_eq!(strip_excess_combining_marks(text, 2), text);
+    }
+
+    #[test]
+    fn leading_marks_are_limited() {
+        assert_eq!(strip_excess_combining_marks("\u{301}\u{302}\u{303}a", 1), "\u{301}a");
+    }
+}
+
+#[cfg(test)]
+mod direction_tests {
+    use bt_string_utils::analyzer::{base_direction, Direction};
+    use bt_string_utils::formatter::{display_width, truncate_display};
+
+
+    #[test]
+    fn first_strong_character_wins() {
+        assert_eq!(base_direction("abc אבג"), Direction::Ltr);
+        assert_eq!(base_direction("אבג abc"), Direction::Rtl);
+        assert_eq!(base_direction("(٣٤) «مرحبا»"), Direction::Rtl);
+        assert_eq!(base_direction("ﺏ"), Direction::Rtl);
+    }
+
+    #[test]
+    fn neutral_text() {
+        assert_eq!(base_direction(""), Direction::Neutral);
+        assert_eq!(base_direction("12:30 - 🙂"), Direction::Neutral);
+        assert_eq!(base_direction("\u{301}"), Direction::Neutral);
+    }
+
+    #[test]
+    fn explicit_marks() {
+        assert_eq!(base_direction("\u{200F}abc"), Direction::Rtl);
+        assert_eq!(base_direction("\u{200E}אבג"), Direction::Ltr);
+    }
+
+    #[test]
+    fn truncate_ltr_and_wide() {
+        assert_eq!(truncate_display("abcdef", 4), "abc…");
+        assert_eq!(truncate_display("你好世界", 5), "你好…");
+        assert_eq!(truncate_display("abcdef", 0), "");
+        assert_eq!(truncate_display("abcdef", 1), "…");
+    }
+
+    #[test]
+    fn truncate_rtl_marks_ellipsis() {
+        let out = truncate_display("مرحبا بالعالم", 6);
+        assert!(out.ends_with("…\u{200F}"));
+        assert!(out.starts_with("مرحبا"));
+        assert_eq!(display_width(&out), 6);
+    }
+}
+
+#[cfg(test)]
+mod newline_stats_tests {
+    use bt_string_utils::analyzer::{has_mixed_line_endings, newline_stats, NewlineStats};
+
+
+    #[test]
+    fn empty_text() {
+        assert_eq!(newline_stats(""), NewlineStats::default());
+        assert!(!has_mixed_line_endings(""));
+    }
+
+    #[test]
+    fn single_kind() {
+        assert_eq!(newline_stats("a\r\nb\r\n").crlf, 2);
+        assert_eq!(newline_stats("a\rb\r").cr, 2);
+        assert!(!has_mixed_line_endings("a\rb\r"));
+    }
+
+    #[test]
+    fn crlf_is_not_counted_as_cr_and_lf() {
+        let stats = newline_stats("a\r\n");
+        assert_eq!((stats.lf, stats.crlf, stats.cr), (0, 1, 0));
+    }
+
+    #[test]
+    fn cr_before_crlf() {
+        let stats = newline_stats("a\r\r\nb\n\n");
+        assert_eq!((stats.lf, stats.crlf, stats.cr), (2, 1, 1));
+        assert!(stats.mixed);
+    }
+}
+
+#[cfg(test)]
+mod fix_whitespace_tests {
+    use bt_string_utils::cleanser::{fix_whitespace, WhitespaceFixOptions};
+
+
+    #[test]
+    fn clean_text_is_unchanged() {
+        let (fixed, report) = fix_whitespace("fn main() {}\n", WhitespaceFixOptions::default());
+        assert_eq!(fixed, "fn main() {}\n");
+        assert!(report.is_clean());
+    }
+
+    #[test]
+    fn empty_and_blank_text() {
+        let (fixed, report) = fix_whitespace("", WhitespaceFixOptions::default());
+        assert_eq!(fixed, "");
+        assert!(report.is_clean());
+
+        let (fixed, report) = fix_whitespace("  \n\n", WhitespaceFixOptions::default());
+        assert_eq!(fixed, "");
+        assert_eq!(report.trailing_whitespace, 1);
+        assert_eq!(report.trailing_blank_lines, 2);
+    }
+
+    #[test]
+    fn keeps_crlf_endings() {
+        let (fixed, report) = fix_whitespace("a \r\nb\r\nc", WhitespaceFixOptions::default());
+        assert_eq!(fixed, "a\r\nb\r\nc\r\n");
+        assert_eq!(report.trailing_whitespace, 1);
+        assert!(report.final_newline_added);
+        assert_eq!(report.trailing_blank_lines, 0);
+    }
+
+    #[test]
+    fn missing_newline_after_blank_line() {
+        let (fixed, report) = fix_whitespace("a\n  ", WhitespaceFixOptions::default());
+        assert_eq!(fixed, "a\n");
+        assert_eq!(report.trailing_whitespace, 1);
+        assert_eq!(report.trailing_blank_lines, 0);
+        assert!(!report.final_newline_added);
+    }
+
+    #[test]
+    fn tabs_expand_to_tab_stops() {
+        let options = WhitespaceFixOptions { tab_width: Some(4) };
+        let (fixed, report) = fix_whitespace("ab\tc\n\tx\n", options);
+        assert_eq!(fixed, "ab  c\n    x\n");
+        assert_eq!(report.tabs_converted, 2);
+
+        let (fixed, _) = fix_whitespace("ab\tc\n", WhitespaceFixOptions::default());
+        assert_eq!(fixed, "ab\tc\n");
+    }
+}
+
+#[cfg(test)]
+mod strip_email_reply_tests {
+    use bt_string_utils::cleanser::strip_email_reply;
+
+
+    #[test]
+    fn signature_delimiter() {
+        assert_eq!(strip_email_reply("See you.\n-- \nBo\nbo@mail.com"), "See you.");
+        assert_eq!(strip_email_reply("See you.\r\n--\r\nBo"), "See you.");
+        assert_eq!(strip_email_reply("Use -- to end options.\n---\nok"), "Use -- to end options.\n---\nok");
+    }
+
+    #[test]
+    fn reply_headers() {
+        let gmail = "Done.\n\nOn Tue, Mar 4, 2025 at 10:12 AM Ana Lopez <ana@example.com>\nwrote:\n\n> Is it done?";
+        assert_eq!(strip_email_reply(gmail), "Done.");
+        let outlook = "Approved.\n\n-----Original Message-----\nFrom: Bo\nSubject: Budget";
+        assert_eq!(strip_email_reply(outlook), "Approved.");
+        assert_eq!(strip_email_reply("On Monday we ship.\nThe team wrote a plan."), "On Monday we ship.\nThe team wrote a plan.");
+    }
+
+    #[test]
+    fn quoted_lines() {
+        let text = "\n> > old\n> older question\nFirst answer\n>\nSecond\n\n\n\nThird\n\n";
+        assert_eq!(strip_email_reply(text), "First answer\nSecond\n\nThird");
+        assert_eq!(strip_email_reply("> only quotes"), "");
+        assert_eq!(strip_email_reply(""), "");
+    }
+}
@@ -0,0 +1,223 @@
+#[cfg(test)]
+mod wrap_tests {
+    use bt_string_utils::formatter::display_width;
+    use bt_string_utils::wrap::{fill, wrap};
+
+
+    #[test]
+    fn lines_fit_width() {
+        let text = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor.";
+        for width in 5..40 {
+            for line in wrap(text, width) {
+                assert!(display_width(&line) <= width, "{line:?} wider than {width}");
+            }
+        }
+    }
+
+    #[test]
+    fn newlines_are_whitespace() {
+        assert_eq!(fill("one\ntwo\n\nthree", 80), "one two three");
+    }
+
+    #[test]
+    fn long_words_are_broken() {
+        assert_eq!(wrap("see https://example.com/a/b", 10), vec!["see", "https://ex", "ample.com/", "a/b"]);
+    }
+
+    #[test]
+    fn styles_do_not_count() {
+        let red = "\x1b[31mred\x1b[0m";
+        assert_eq!(wrap(&format!("{red} {red}"), 7), vec![format!("{red} {red}")]);
+    }
+
+    #[test]
+    fn wide_char_wider_than_line() {
+        assert_eq!(wrap("你好", 1), vec!["你", "好"]);
+    }
+}
+
+#[cfg(test)]
+mod reflow_comment_tests {
+    use bt_string_utils::wrap::reflow_comment_block;
+
+
+    #[test]
+    fn hash_comments_keep_trailing_newline() {
+        let comment = "# one two three four five six\n# seven\n";
+        assert_eq!(reflow_comment_block(comment, "# ", 16), "# one two three\n# four five six\n# seven\n");
+    }
+
+    #[test]
+    fn indentation_and_missing_space() {
+        let comment = "    //a b\n    // c";
+        assert_eq!(reflow_comment_block(comment, "// ", 80), "// a b c");
+    }
+
+    #[test]
+    fn blank_lines_are_kept() {
+        let comment = "// a\n//\n//\n// b";
+        assert_eq!(reflow_comment_block(comment, "// ", 80), "// a\n//\n//\n// b");
+    }
+
+    #[test]
+    fn numbered_lists() {
+        let comment = "// Steps:\n// 1. open the file and read it\n// 2) close it";
+        assert_eq!(reflow_comment_block(comment, "// ", 20),
+                   "// Steps:\n// 1. open the file\n//    and read it\n// 2) close it");
+    }
+
+    #[test]
+    fn not_a_list() {
+        let comment = "// -1 is returned\n// 3.5 times";
+        assert_eq!(reflow_comment_block(comment, "// ", 80), "// -1 is returned 3.5 times");
+    }
+}
+
+#[cfg(test)]
+mod list_items_tests {
+    use bt_string_utils::analyzer::detect_list_items;
+    use bt_string_utils::formatter::renumber_ordered_list;
+
+
+    #[test]
+    fn no_items() {
+        assert!(detect_list_items("plain text\n-not a list\n1.5 neither").is_empty());
+    }
+
+    #[test]
+    fn levels_follow_indentation() {
+        let items = detect_list_items("* a\n    * b\n\t* c\n  * d\n* e");
+        let levels: Vec<usize> = items.iter().map(|i| i.level).collect();
+        assert_eq!(levels, vec![0, 1, 1, 1, 0]);
+        assert_eq!(items[2].indent, 4);
+    }
+
+    #[test]
+    fn unindented_text_ends_list() {
+        let items = detect_list_items("- a\n  continued\n\n- b\nParagraph\n- c");
+        let lists: Vec<usize> = items.iter().map(|i| i.list).collect();
+        assert_eq!(lists, vec![0, 0, 1]);
+    }
+
+    #[test]
+    fn renumber_separate_lists() {
+        let text = "1. a\n1. b\n\nText\n\n7. c\n9. d";
+        assert_eq!(renumber_ordered_list(text), "1. a\n2. b\n\nText\n\n7. c\n8. d");
+    }
+
+    #[test]
+    fn renumber_after_bullet_restarts() {
+        let text = "1. a\n3. b\n- x\n4. c\n5. d";
+        assert_eq!(renumber_ordered_list(text), "1. a\n2. b\n- x\n4. c\n5. d");
+    }
+
+    #[test]
+    fn renumber_keeps_crlf_and_width_changes() {
+        let text = "9. a\r\n9. b\r\n";
+        assert_eq!(renumber_ordered_list(text), "9. a\r\n10. b\r\n");
+    }
+
+    #[test]
+    fn renumber_saturates_at_max() {
+        let text = "18446744073709551615. x\n1. y\n";
+        assert_eq!(renumber_ordered_list(text), "18446744073709551615. x\n18446744073709551615. y\n");
+    }
+}
+
+#[cfg(test)]
+mod justify_tests {
+    use bt_string_utils::formatter::display_width;
+    use bt_string_utils::wrap::justify;
+
+
+    #[test]
+    fn every_line_but_last_is_full() {
+        let text = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore.";
+        let justified = justify(text, 30);
+        let lines: Vec<&str> = justified.lines().collect();
+        for line in &lines[..lines.len() - 1] {
+            assert_eq!(display_width(line), 30);
+        }
+        assert!(display_width(lines[lines.len() - 1]) <= 30);
+    }
+
+    #[test]
+    fn paragraphs_are_kept_apart() {
+        assert_eq!(justify("aa bb cc\ndd\n\n\nee ff gg", 7), "aa   bb\ncc dd\n\nee   ff\ngg");
+    }
+
+    #[test]
+    fn single_word_lines_and_empty_text() {
+        assert_eq!(justify("supercalifragilistic short", 22), "supercalifragilistic\nshort");
+        assert_eq!(justify("", 10), "");
+        assert_eq!(justify("\n  \n", 10), "");
+    }
+}
+
+#[cfg(test)]
+mod block_literal_tests {
+    use bt_string_utils::wrap::{strip_margin, to_raw_block};
+
+
+    #[test]
+    fn margins() {
+        assert_eq!(strip_margin("  |a\n\t| b\r\n|c|\n", '|'), "a\n b\r\nc|\n");
+        assert_eq!(strip_margin("no margin\n   kept as is", '|'), "no margin\n   kept as is");
+        assert_eq!(strip_margin("  #> x\n  #> y", '#'), "> x\n> y");
+        assert_eq!(strip_margin("", '|'), "");
+    }
+
+    #[test]
+    fn dedent_and_trim() {
+        assert_eq!(to_raw_block("\n  a\n    b\n\n  c\n  "), "a\n  b\n\nc\n");
+        assert_eq!(to_raw_block("\r\n\ta\r\n\t\tb\r\n"), "a\r\n\tb\r\n");
+        assert_eq!(to_raw_block("  same line\n  next"), "same line\nnext");
+        assert_eq!(to_raw_block("\n    a\n      \n    b"), "a\n\nb");
+    }
+
+    #[test]
+    fn mixed_indentation_only_common_prefix() {
+        assert_eq!(to_raw_block("\t  a\n\t b"), " a\nb");
+        assert_eq!(to_raw_block(" \ta\n\t b"), " \ta\n\t b");
+        assert_eq!(to_raw_block(""), "");
+        assert_eq!(to_raw_block("\n   "), "");
+    }
+}
+
+#[cfg(test)]
+mod unwrap_lines_tests {
+    use bt_string_utils::wrap::{fill, unwrap_lines};
+
+
+    #[test]
+    fn joins_paragraphs() {
+        let text = "First paragraph\nwritten on   \nthree lines.\n\n\nSecond one.\n";
+        assert_eq!(unwrap_lines(text), "First paragraph written on three lines.\n\n\nSecond one.\n");
+        assert_eq!(unwrap_lines(""), "");
+        assert_eq!(unwrap_lines("one line"), "one line");
+    }
+
+    #[test]
+    fn inverse_of_fill() {
+        let text = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor.";
+        assert_eq!(unwrap_lines(&fill(text, 20)), text);
+    }
+
+    #[test]
+    fn hyphenated_words() {
+        assert_eq!(unwrap_lines("state-of-the-\nart design"), "state-of-the-art design");
+        assert_eq!(unwrap_lines("range 1 -\n5"), "range 1 - 5");
+    }
+
+    #[test]
+    fn list_items() {
+        let text = "Steps:\n1. install the\n   tool\n2) run it\n* done\n  + nested\n  item";
+        assert_eq!(unwrap_lines(text), "Steps:\n1. install the tool\n2) run it\n* done\n  + nested item");
+    }
+
+    #[test]
+    fn quote_prefixes() {
+        let text = "Sure.\n> > Original question\n> > on two lines\n> Reply that\n>wraps too\n>\n> End\nMy answer";
+        assert_eq!(unwrap_lines(text), "Sure.\n> > Original question on two lines\n> Reply that wraps too\n>\n> End\nMy answer");
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod format_positional_tests {
+    use bt_string_utils::template::{format_positional, TemplateError};
+
+
+    #[test]
+    fn reorder_and_repeat() {
+        assert_eq!(format_positional("{1} {0} {1}", &["a", "b"]).unwrap(), "b a b");
+        assert_eq!(format_positional("no placeholders", &[] as &[&str]).unwrap(), "no placeholders");
+    }
+
+    #[test]
+    fn alignment_and_width() {
+        assert_eq!(format_positional("|{0:5}|{0:<5}|{0:>5}|{0:^5}|", &["ab"]).unwrap(), "|ab   |ab   |   ab| ab  |");
+        assert_eq!(format_positional("|{0:-<6}|", &["ab"]).unwrap(), "|ab----|");
+        assert_eq!(format_positional("|{0:>3}|", &["too long"]).unwrap(), "|too long|");
+    }
+
+    #[test]
+    fn width_aware() {
+        assert_eq!(format_positional("|{0:>6}|", &["你好"]).unwrap(), "|  你好|");
+        assert_eq!(format_positional("|{0:.3}|", &["你好"]).unwrap(), "|你|");
+        assert_eq!(format_positional("|{0:>6.2}|", &["abcdef"]).unwrap(), "|    ab|");
+    }
+
+    #[test]
+    fn implicit_indexes() {
+        assert_eq!(format_positional("{} + {} = {2}", &["1", "2", "3"]).unwrap(), "1 + 2 = 3");
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(format_positional("a {0", &["x"]), Err(TemplateError::UnclosedBrace { position: 2 }));
+        assert_eq!(format_positional("a } b", &["x"]), Err(TemplateError::UnmatchedBrace { position: 2 }));
+        assert_eq!(format_positional("{} {}", &["x"]), Err(TemplateError::MissingArgument { index: 1 }));
+        assert_eq!(format_positional("{x}", &["x"]), Err(TemplateError::InvalidSpec { position: 0 }));
+        assert_eq!(format_positional("{0:>x}", &["x"]), Err(TemplateError::InvalidSpec { position: 0 }));
+        assert_eq!(TemplateError::MissingArgument { index: 3 }.to_string(), "missing argument 3");
+    }
+}
+
+#[cfg(test)]
+mod format_message_tests {
+    use bt_string_utils::template::{format_message, format_message_with_rules, PluralCategory, PluralRules, TemplateError};
+
+    struct Polish;
+
+    impl PluralRules for Polish {
+        fn category(&self, n: f64) -> PluralCategory {
+            let i = n as u64;
+            if i == 1 {
+                PluralCategory::One
+            } else if (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+    }
+
+
+    #[test]
+    fn plain_text_and_arguments() {
+        assert_eq!(format_message("Hi {name}!", &[("name", "Bo")]).unwrap(), "Hi Bo!");
+        assert_eq!(format_message("{ name }", &[("name", "Bo")]).unwrap(), "Bo");
+        assert_eq!(format_message("# is plain here", &[]).unwrap(), "# is plain here");
+    }
+
+    #[test]
+    fn nested_select_and_plural() {
+        let message = "{host, select, female {{n, plural, one {she invites # guest} other {she invites # guests}}} other {{n, plural, one {they invite # guest} other {they invite # guests}}}}";
+        assert_eq!(format_message(message, &[("host", "female"), ("n", "3")]).unwrap(), "she invites 3 guests");
+        assert_eq!(format_message(message, &[("host", "x"), ("n", "1")]).unwrap(), "they invite 1 guest");
+    }
+
+    #[test]
+    fn pluggable_rules() {
+        let message = "{n, plural, one {# plik} few {# pliki} many {# plików} other {# pliku}}";
+        assert_eq!(format_message_with_rules(message, &[("n", "1")], &Polish).unwrap(), "1 plik");
+        assert_eq!(format_message_with_rules(message, &[("n", "22")], &Polish).unwrap(), "22 pliki");
+        assert_eq!(format_message_with_rules(message, &[("n", "12")], &Polish).unwrap(), "12 plików");
+    }
+
+    #[test]
+    fn apostrophe_quoting() {
+        assert_eq!(format_message("It''s '{literal}' {x}", &[("x", "ok")]).unwrap(), "It's {literal} ok");
+        assert_eq!(format_message("don't", &[]).unwrap(), "don't");
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(format_message("{missing}", &[]), Err(TemplateError::MissingNamedArgument { name: "missing".to_owned() }));
+        assert_eq!(format_message("{n, plural, one {x}}", &[("n", "2")]), Err(TemplateError::InvalidSpec { position: 0 }));
+        assert_eq!(format_message("{n, plural, other {x}}", &[("n", "abc")]), Err(TemplateError::InvalidSpec { position: 0 }));
+        assert_eq!(format_message("{n, number}", &[("n", "1")]), Err(TemplateError::InvalidSpec { position: 0 }));
+        assert_eq!(format_message("a {n", &[("n", "1")]), Err(TemplateError::UnclosedBrace { position: 2 }));
+        assert_eq!(format_message("{n, plural, other {x}", &[("n", "1")]), Err(TemplateError::UnclosedBrace { position: 0 }));
+        assert_eq!(format_message("a {", &[]), Err(TemplateError::UnclosedBrace { position: 2 }));
+        assert_eq!(format_message("a } b", &[]), Err(TemplateError::UnmatchedBrace { position: 2 }));
+    }
+}
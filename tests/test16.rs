@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod collation_tests {
+    use std::cmp::Ordering;
+    use bt_string_utils::locale::{collation_key, compare_collated, CollationOptions};
+
+    fn sorted(mut words: Vec<&str>, options: CollationOptions) -> Vec<&str> {
+        words.sort_by_key(|w| collation_key(w, options));
+        words
+    }
+
+
+    #[test]
+    fn base_letters_first() {
+        let words = vec!["zebra", "Apple", "éclair", "banana", "Eclipse", "apple"];
+        assert_eq!(sorted(words, CollationOptions::default()), vec!["apple", "Apple", "banana", "éclair", "Eclipse", "zebra"]);
+    }
+
+    #[test]
+    fn accents_break_ties_before_case() {
+        let words = vec!["coté", "Cote", "côte", "cote"];
+        assert_eq!(sorted(words, CollationOptions::default()), vec!["cote", "Cote", "coté", "côte"]);
+    }
+
+    #[test]
+    fn decomposed_input_matches_precomposed() {
+        let options = CollationOptions::default();
+        assert_eq!(collation_key("e\u{301}t\u{e9}", options), collation_key("\u{e9}t\u{e9}", options));
+    }
+
+    #[test]
+    fn ignore_levels() {
+        let both = CollationOptions { ignore_accents: true, ignore_case: true };
+        assert_eq!(compare_collated("Straße", "STRASSE", both), Ordering::Equal);
+        assert_eq!(compare_collated("Łódź", "lodz", both), Ordering::Equal);
+
+        let case_only = CollationOptions { ignore_accents: false, ignore_case: true };
+        assert_eq!(compare_collated("Résumé", "résumé", case_only), Ordering::Equal);
+        assert_eq!(compare_collated("resume", "résumé", case_only), Ordering::Less);
+    }
+
+    #[test]
+    fn prefix_sorts_first() {
+        assert_eq!(compare_collated("ab", "abc", CollationOptions::default()), Ordering::Less);
+        assert_eq!(compare_collated("Ab", "abc", CollationOptions::default()), Ordering::Less);
+        assert_eq!(compare_collated("", "a", CollationOptions::default()), Ordering::Less);
+    }
+}
+
+#[cfg(test)]
+mod locale_case_tests {
+    use bt_string_utils::locale::{to_lower_locale, to_upper_locale, Locale};
+
+
+    #[test]
+    fn turkish_i_round_trip() {
+        let word = "ılık iğne";
+        let upper = to_upper_locale(word, Locale::Turkish);
+        assert_eq!(upper, "ILIK İĞNE");
+        assert_eq!(to_lower_locale(&upper, Locale::Turkish), word);
+        assert_eq!(to_upper_locale(word, Locale::Azerbaijani), upper);
+    }
+
+    #[test]
+    fn turkish_decomposed_dotted_i() {
+        assert_eq!(to_lower_locale("I\u{307}STANBUL", Locale::Turkish), "istanbul");
+        assert_eq!(to_lower_locale("I\u{307}", Locale::Root), "i\u{307}");
+    }
+
+    #[test]
+    fn german_sharp_s() {
+        assert_eq!(to_upper_locale("Fußball", Locale::German), "FUSSBALL");
+        assert_eq!(to_lower_locale("GROẞ", Locale::German), "groß");
+    }
+
+    #[test]
+    fn greek_final_sigma_and_accents() {
+        assert_eq!(to_lower_locale("ΣΟΦΟΣ ΑΝΘΡΩΠΟΣ", Locale::Greek), "σοφος ανθρωπος");
+        assert_eq!(to_upper_locale("ώρα ΐ ευτυχία", Locale::Greek), "ΩΡΑ Ϊ ΕΥΤΥΧΙΑ");
+        assert_eq!(to_upper_locale("ε\u{301}να", Locale::Greek), "ΕΝΑ");
+        assert_eq!(to_upper_locale("café", Locale::Greek), "CAFÉ");
+    }
+}
+
+#[cfg(test)]
+mod capitalize_locale_tests {
+    use bt_string_utils::locale::{capitalize_locale, Locale};
+
+
+    #[test]
+    fn empty_and_no_letters() {
+        assert_eq!(capitalize_locale("", Locale::Root), "");
+        assert_eq!(capitalize_locale("... !", Locale::Root), "... !");
+    }
+
+    #[test]
+    fn keeps_rest_unchanged() {
+        assert_eq!(capitalize_locale("hello World", Locale::Root), "Hello World");
+        assert_eq!(capitalize_locale("  'quoted'", Locale::Root), "  'Quoted'");
+        assert_eq!(capitalize_locale("Already", Locale::Root), "Already");
+    }
+
+    #[test]
+    fn combining_marks_stay_attached() {
+        assert_eq!(capitalize_locale("a\u{30a}\u{301}ngstrom", Locale::Root), "A\u{30a}\u{301}ngstrom");
+    }
+
+    #[test]
+    fn titlecase_digraphs() {
+        assert_eq!(capitalize_locale("ǆungla", Locale::Root), "ǅungla");
+        assert_eq!(capitalize_locale("ßig", Locale::German), "Ssig");
+    }
+
+    #[test]
+    fn locale_differences() {
+        assert_eq!(capitalize_locale("istanbul", Locale::Root), "Istanbul");
+        assert_eq!(capitalize_locale("istanbul", Locale::Azerbaijani), "İstanbul");
+        assert_eq!(capitalize_locale("άλφα", Locale::Greek), "Άλφα");
+    }
+}
+
+#[cfg(test)]
+mod watermark_tests {
+    use bt_string_utils::formatter::display_width;
+    use bt_string_utils::watermark::{embed_watermark, extract_watermark, strip_watermark};
+
+    const BITS: [bool; 8] = [false, true, true, false, true, false, false, true];
+
+
+    #[test]
+    fn round_trip_is_invisible() {
+        let text = "The quick brown fox";
+        let marked = embed_watermark(text, &BITS);
+        assert_ne!(marked, text);
+        assert_eq!(display_width(&marked), display_width(text));
+        assert_eq!(extract_watermark(&marked), Some(BITS.to_vec()));
+        assert_eq!(strip_watermark(&marked), text);
+    }
+
+    #[test]
+    fn text_without_whitespace() {
+        let marked = embed_watermark("word", &[true]);
+        assert_eq!(marked, "\u{200D}word");
+        assert_eq!(extract_watermark(&marked), Some(vec![true]));
+        assert_eq!(extract_watermark(&embed_watermark("", &[false])), Some(vec![false]));
+    }
+
+    #[test]
+    fn emoji_joiners_are_kept() {
+        let text = "I am 👩\u{200D}💻 today";
+        let marked = embed_watermark(text, &BITS);
+        assert_eq!(extract_watermark(&marked), Some(BITS.to_vec()));
+        assert_eq!(strip_watermark(&marked), text);
+        assert_eq!(extract_watermark(text), None);
+    }
+
+    #[test]
+    fn embedding_replaces_previous_watermark() {
+        let marked = embed_watermark(&embed_watermark("a b", &[true, true]), &[false]);
+        assert_eq!(extract_watermark(&marked), Some(vec![false]));
+        assert!(extract_watermark(&embed_watermark("a b", &[])).is_none());
+    }
+}
@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod readable_code_tests {
+    use bt_string_utils::random::{generate_readable_code, normalize_entered_code, READABLE_ALPHABET};
+
+
+    #[test]
+    fn code_shape() {
+        for _ in 0..50 {
+            let code = generate_readable_code(16, 4);
+            let groups: Vec<&str> = code.split('-').collect();
+            assert_eq!(groups.len(), 4);
+            assert!(groups.iter().all(|g| g.len() == 4));
+            assert!(code.chars().all(|c| c == '-' || READABLE_ALPHABET.contains(c)));
+            assert!(!code.contains(['O', 'I', 'L', 'U']));
+        }
+    }
+
+    #[test]
+    fn uneven_and_no_grouping() {
+        let code = generate_readable_code(10, 4);
+        assert_eq!(code.split('-').map(str::len).collect::<Vec<_>>(), vec![4, 4, 2]);
+        assert_eq!(generate_readable_code(6, 0).len(), 6);
+        assert_eq!(generate_readable_code(0, 4), "");
+    }
+
+    #[test]
+    fn normalize_round_trip() {
+        for _ in 0..20 {
+            let code = generate_readable_code(12, 3);
+            let typed = code.replace('-', " ").to_lowercase().replace('0', "o").replace('1', "l");
+            assert_eq!(normalize_entered_code(&typed, 3).as_deref(), Some(code.as_str()));
+        }
+    }
+
+    #[test]
+    fn normalize_corrects_look_alikes() {
+        assert_eq!(normalize_entered_code("abcd-efgo", 4).as_deref(), Some("ABCD-EFG0"));
+        assert_eq!(normalize_entered_code("oO iI lL uU", 0).as_deref(), Some("001111VV"));
+        assert!(normalize_entered_code("OIL", 0).unwrap().chars().all(|c| READABLE_ALPHABET.contains(c)));
+    }
+
+    #[test]
+    fn normalize_rejects_unknown_characters() {
+        assert_eq!(normalize_entered_code("ABCD-É", 4), None);
+        assert_eq!(normalize_entered_code("", 4), Some(String::new()));
+    }
+}
+
+#[cfg(test)]
+mod check_char_tests {
+    use bt_string_utils::checkdigit::{append_check_char, verify_check_char, Scheme};
+    use bt_string_utils::random::{generate_readable_code, normalize_entered_code, READABLE_ALPHABET};
+
+    const SCHEMES: [Scheme; 3] = [Scheme::Luhn, Scheme::Damm, Scheme::Verhoeff];
+
+
+    #[test]
+    fn known_values() {
+        assert_eq!(append_check_char("12345", Scheme::Verhoeff).as_deref(), Some("123451"));
+        assert_eq!(append_check_char("1428570", Scheme::Verhoeff).as_deref(), Some("14285708"));
+        assert!(verify_check_char("4111-1111-1111-1111", Scheme::Luhn));
+    }
+
+    #[test]
+    fn every_single_digit_error_is_detected() {
+        let payload = "3141592653";
+        for scheme in SCHEMES {
+            let code = append_check_char(payload, scheme).unwrap();
+            assert!(verify_check_char(&code, scheme));
+            for position in 0..code.len() {
+                for digit in b'0'..=b'9' {
+                    let mut typo = code.clone().into_bytes();
+                    if typo[position] == digit {
+                        continue;
+                    }
+                    typo[position] = digit;
+                    assert!(!verify_check_char(&String::from_utf8(typo).unwrap(), scheme), "{scheme:?} {position}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn adjacent_swaps_are_detected() {
+        for scheme in [Scheme::Damm, Scheme::Verhoeff] {
+            let code = append_check_char("8675309", scheme).unwrap();
+            let bytes = code.as_bytes();
+            for i in 0..bytes.len() - 1 {
+                if bytes[i] == bytes[i + 1] {
+                    continue;
+                }
+                let mut swapped = bytes.to_vec();
+                swapped.swap(i, i + 1);
+                assert!(!verify_check_char(std::str::from_utf8(&swapped).unwrap(), scheme));
+            }
+        }
+    }
+
+    #[test]
+    fn readable_codes_use_luhn_mod_32() {
+        for _ in 0..20 {
+            let code = append_check_char(&generate_readable_code(8, 4), Scheme::Luhn).unwrap();
+            assert!(READABLE_ALPHABET.contains(code.chars().last().unwrap()));
+            assert!(verify_check_char(&code, Scheme::Luhn));
+            assert!(verify_check_char(&code.to_lowercase(), Scheme::Luhn));
+        }
+    }
+
+    #[test]
+    fn digit_only_readable_codes() {
+        let mut check_digits = Vec::new();
+        for n in 0..100 {
+            let code = append_check_char(&format!("{n:04}"), Scheme::Luhn).unwrap();
+            let check = code.chars().last().unwrap();
+            assert!(check.is_ascii_digit());
+            check_digits.push(check);
+            let typed = code.replace('0', "o").replace('1', "l");
+            assert_eq!(normalize_entered_code(&typed, 0).as_deref(), Some(code.as_str()));
+            assert!(verify_check_char(&code, Scheme::Luhn));
+        }
+        assert!(check_digits.contains(&'0') && check_digits.contains(&'1'));
+    }
+
+    #[test]
+    fn unsupported_codes() {
+        assert_eq!(append_check_char("AB12", Scheme::Damm), None);
+        assert_eq!(append_check_char("AB!2", Scheme::Luhn), None);
+        assert_eq!(append_check_char("", Scheme::Luhn), None);
+        assert!(!verify_check_char("7", Scheme::Luhn));
+        assert!(!verify_check_char("AB12", Scheme::Verhoeff));
+    }
+}
+
+#[cfg(test)]
+mod sampling_tests {
+    use std::collections::HashMap;
+    use bt_string_utils::random::{sample_weighted, shuffle_words};
+
+
+    #[test]
+    fn weights_drive_frequencies() {
+        let items = [("common", 9.0), ("rare", 1.0), ("never", 0.0), ("bad", f64::NAN), ("negative", -5.0)];
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for _ in 0..5000 {
+            *counts.entry(sample_weighted(&items)).or_default() += 1;
+        }
+        assert_eq!(counts.len(), 2);
+        assert!(counts["common"] > counts["rare"] * 4);
+    }
+
+    #[test]
+    fn no_valid_weights() {
+        assert_eq!(sample_weighted(&[("a", 0.0), ("b", f64::INFINITY)]), "");
+        assert_eq!(sample_weighted(&[("only", 0.5)]), "only");
+    }
+
+    #[test]
+    fn shuffle_is_seeded() {
+        let text = "one two three four five six seven eight";
+        assert_eq!(shuffle_words(text, 7), shuffle_words(text, 7));
+        assert_ne!(shuffle_words(text, 7), shuffle_words(text, 8));
+        assert_eq!(shuffle_words("", 1), "");
+        assert_eq!(shuffle_words(" single ", 1), "single");
+    }
+}
+
+#[cfg(test)]
+mod lorem_tests {
+    use bt_string_utils::analyzer::word_count;
+    use bt_string_utils::random::{fake_sentence, lorem_paragraphs, lorem_words};
+
+
+    #[test]
+    fn words_are_stable_prefixes() {
+        let long = lorem_words(50);
+        assert!(long.starts_with(&lorem_words(20)));
+        assert!(long.starts_with("lorem ipsum dolor sit amet consectetur adipiscing elit"));
+        assert_eq!(word_count(&long), 50);
+    }
+
+    #[test]
+    fn paragraphs_shape() {
+        let text = lorem_paragraphs(10);
+        for paragraph in text.split("\n\n") {
+            let sentences = paragraph.matches('.').count();
+            assert!((4..=8).contains(&sentences), "{sentences}");
+        }
+        assert_eq!(lorem_paragraphs(0), "");
+        assert!(lorem_paragraphs(20).starts_with(&lorem_paragraphs(2)));
+    }
+
+    #[test]
+    fn sentences_depend_on_seed() {
+        assert_ne!(fake_sentence(1), fake_sentence(2));
+        for seed in 0..50 {
+            let words = word_count(&fake_sentence(seed));
+            assert!((6..=14).contains(&words));
+        }
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "testkit")]
+
+#[cfg(test)]
+mod testkit_property_tests {
+    use bt_string_utils::formatter::{display_width, truncate_display};
+    use bt_string_utils::segmenter::graphemes;
+    use bt_string_utils::splitter::split_into_chunks;
+    use bt_string_utils::testkit::{cjk_run, edge_case_strings, nasty_string};
+    use bt_string_utils::watermark::{embed_watermark, extract_watermark};
+
+
+    #[test]
+    fn graphemes_cover_the_text() {
+        for seed in 0..200 {
+            let s = nasty_string(seed, 6);
+            assert_eq!(graphemes(&s).collect::<String>(), s);
+        }
+    }
+
+    #[test]
+    fn truncate_display_respects_width() {
+        for s in edge_case_strings().into_iter().chain((0..100).map(|seed| nasty_string(seed, 6))) {
+            for cols in [1, 3, 10] {
+                assert!(display_width(&truncate_display(&s, cols)) <= cols, "{s:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn chunks_are_within_limits() {
+        let text = cjk_run(5000);
+        for chunk in split_into_chunks(&text, 1000) {
+            assert!(chunk.len() <= 1000);
+        }
+    }
+
+    #[test]
+    fn watermark_survives_edge_cases() {
+        for seed in 0..100 {
+            let s = nasty_string(seed, 4);
+            assert_eq!(extract_watermark(&embed_watermark(&s, &[true, false])), Some(vec![true, false]), "{s:?}");
+        }
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod pipe_tests {
+    use std::borrow::Cow;
+    use bt_string_utils::pipe::Pipe;
+
+
+    #[test]
+    fn no_steps_and_slices_borrow() {
+        assert!(matches!(Pipe::from("  text  ").finish_cow(), Cow::Borrowed("  text  ")));
+        assert!(matches!(Pipe::from("  text  ").trim().take_chars(2).finish_cow(), Cow::Borrowed("te")));
+        assert_eq!(Pipe::from("  text  ").trim_start().finish(), "text  ");
+        assert_eq!(Pipe::from("  text  ").trim_end().finish(), "  text");
+    }
+
+    #[test]
+    fn take_chars_is_unicode_aware() {
+        assert_eq!(Pipe::from("héllo wörld").take_chars(7).finish(), "héllo w");
+        assert_eq!(Pipe::from("abc").take_chars(10).finish(), "abc");
+    }
+
+    #[test]
+    fn collapse_whitespace_unicode() {
+        assert_eq!(Pipe::from("a \t\n b\u{a0}\u{3000}c").collapse_whitespace().finish(), "a b c");
+    }
+
+    #[test]
+    fn case_steps_last_wins() {
+        assert_eq!(Pipe::from("MiXeD").to_uppercase().to_lowercase().finish(), "mixed");
+        assert_eq!(Pipe::from("MiXeD").to_lowercase().to_uppercase().finish(), "MIXED");
+    }
+
+    #[test]
+    fn snake_case_words() {
+        for (input, expected) in [
+            ("HTTPServer", "http_server"),
+            ("parseJSONResponse", "parse_json_response"),
+            ("already_snake", "already_snake"),
+            ("Kebab-case words", "kebab_case_words"),
+            ("version2Update", "version2_update"),
+            ("  --weird__input--  ", "weird_input"),
+            ("", ""),
+        ] {
+            assert_eq!(Pipe::from(input).to_snake_case().finish(), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn order_matters() {
+        assert_eq!(Pipe::from("  Hello World  ").take_chars(4).trim().finish(), "He");
+        assert_eq!(Pipe::from("  Hello World  ").trim().take_chars(4).finish(), "Hell");
+    }
+}
+
+#[cfg(test)]
+mod batch_tests {
+    use bt_string_utils::batch::{batch_apply, batch_apply_parallel, Transform};
+
+
+    #[test]
+    fn builtin_transforms() {
+        let items = vec![" Hello  World ".to_owned(), "ΟΔΟΣ".to_owned()];
+        assert_eq!(batch_apply(&items, Transform::Trim), vec!["Hello  World", "ΟΔΟΣ"]);
+        assert_eq!(batch_apply(&items, Transform::Lowercase), vec![" hello  world ", "οδος"]);
+        assert_eq!(batch_apply(&items, Transform::Uppercase), vec![" HELLO  WORLD ", "ΟΔΟΣ"]);
+        assert_eq!(batch_apply(&items, Transform::CollapseWhitespace), vec![" Hello World ", "ΟΔΟΣ"]);
+    }
+
+    #[test]
+    fn empty_input() {
+        let items: Vec<String> = Vec::new();
+        assert!(batch_apply(&items, Transform::Trim).is_empty());
+        assert!(batch_apply_parallel(&items, Transform::Trim, 8).is_empty());
+    }
+
+    #[test]
+    fn scratch_buffer_does_not_leak_between_items() {
+        let items = ["a long first item", "b"];
+        assert_eq!(batch_apply(&items, Transform::SnakeCase), vec!["a_long_first_item", "b"]);
+    }
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let items: Vec<String> = (0..10_001).map(|i| format!("Record {i}\tValue{}", i * 7)).collect();
+        let expected = batch_apply(&items, Transform::SnakeCase);
+        for threads in [0, 1, 3, 16] {
+            assert_eq!(batch_apply_parallel(&items, Transform::SnakeCase, threads), expected);
+        }
+    }
+
+    #[test]
+    fn parallel_custom_transform() {
+        let count = |s: &str, out: &mut String| out.push_str(&s.chars().count().to_string());
+        let items = ["é", "ab", "xyz"];
+        assert_eq!(batch_apply_parallel(&items, Transform::Custom(&count), 2), vec!["1", "2", "3"]);
+    }
+}
@@ -207,4 +207,348 @@ mod split_chunk_tests {
         assert_eq!(chunks.len(), 1);
         assert!(chunks[0].contains("字")); // Ensure that the Chinese character is intact
     }
+
+    // Test 8: Chunk size smaller than a character
+    #[test]
+    fn test_chunk_smaller_than_character() {
+        assert_eq!(split_into_chunks("a字b", 2), vec!["a", "字", "b"]);
+        assert_eq!(split_into_chunks("🦄", 1), vec!["🦄"]);
+        assert_eq!(split_into_chunks("ab", 0), vec!["a", "b"]);
+    }
+
+    // Test 9: Any chunk size terminates and keeps the whole text (sizes below 4 used to loop forever)
+    #[test]
+    fn test_every_chunk_size_terminates() {
+        let input = "aé字🦄 b";
+        for size in 0..=input.len() {
+            let chunks = split_into_chunks(input, size);
+            assert_eq!(chunks.concat(), input, "chunk size {size}");
+            assert!(chunks.iter().all(|chunk| chunk.len() <= size || chunk.chars().count() == 1), "chunk size {size}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod duplicate_paragraphs_tests {
+    use bt_string_utils::analyzer::{count_paragraphs, find_duplicate_paragraphs};
+    use bt_string_utils::splitter::split_paragraphs;
+
+
+    #[test]
+    fn split_matches_count() {
+        for text in ["", "\n", "\r\n", "\r", "Hello", "Hello\n", "A\n\nB", "\nA\r\nB\rC", "A\n\n\nB"] {
+            assert_eq!(split_paragraphs(text).len(), count_paragraphs(text), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn split_mixed_newlines() {
+        assert_eq!(split_paragraphs("A\r\nB\nC\rD"), vec!["A", "B", "C", "D"]);
+    }
+
+    #[test]
+    fn no_duplicates() {
+        assert!(find_duplicate_paragraphs("one\ntwo\nthree").is_empty());
+        assert!(find_duplicate_paragraphs("").is_empty());
+    }
+
+    #[test]
+    fn blank_paragraphs_are_not_duplicates() {
+        assert!(find_duplicate_paragraphs("a\n\n  \nb\n\n").is_empty());
+    }
+
+    #[test]
+    fn reformatted_duplicates_are_found() {
+        let text = "The quick fox.\r\nOther.\r\nTHE QUICK   FOX";
+        assert_eq!(find_duplicate_paragraphs(text), vec![(0, 2)]);
+    }
+}
+
+#[cfg(test)]
+mod top_kgrams_tests {
+    use bt_string_utils::analyzer::top_kgrams;
+
+
+    #[test]
+    fn counts_overlapping_occurrences() {
+        assert_eq!(top_kgrams("abababa", 3, 10), vec![("aba".to_owned(), 3), ("bab".to_owned(), 2)]);
+        assert_eq!(top_kgrams("abc", 3, 10), vec![("abc".to_owned(), 1)]);
+    }
+
+    #[test]
+    fn ties_keep_first_occurrence_order() {
+        assert_eq!(top_kgrams("xyzzyx", 1, 3), vec![("x".to_owned(), 2), ("y".to_owned(), 2), ("z".to_owned(), 2)]);
+    }
+
+    #[test]
+    fn unicode_and_markers() {
+        assert_eq!(top_kgrams("你好你好你", 2, 1), vec![("你好".to_owned(), 2)]);
+        let log = "== Chapter ==\ntext\n== Chapter ==\nmore\n== Chapter ==\n";
+        assert_eq!(top_kgrams(log, 13, 1), vec![("== Chapter ==".to_owned(), 3)]);
+    }
+
+    #[test]
+    fn degenerate_arguments() {
+        assert!(top_kgrams("abc", 0, 5).is_empty());
+        assert!(top_kgrams("abc", 4, 5).is_empty());
+        assert!(top_kgrams("abc", 1, 0).is_empty());
+        assert!(top_kgrams("", 1, 5).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod text_stats_tests {
+    use bt_string_utils::analyzer::{count_paragraphs, sentence_count, text_stats, word_count, TextStats};
+
+    const SAMPLES: [&str; 8] = [
+        "",
+        "Hello",
+        "\n\nA\r\nB\rC\n",
+        "Visit https://example.com/a/. It's state-of-the-art!",
+        "你好世界。 再见!",
+        "  spaced   out\t\ttext  ",
+        "Mr. Smith (really?) left... \"Bye.\" Done",
+        "🙂 emoji 🙂",
+    ];
+
+
+    #[test]
+    fn matches_individual_counters() {
+        for text in SAMPLES {
+            let stats = text_stats(text);
+            assert_eq!(stats.words, word_count(text), "{text:?}");
+            assert_eq!(stats.paragraphs, count_paragraphs(text), "{text:?}");
+            assert_eq!(stats.sentences, sentence_count(text), "{text:?}");
+            assert_eq!(stats.bytes, text.len());
+        }
+    }
+
+    #[test]
+    fn characters_and_lines() {
+        let stats = text_stats("ab c\r\nd é\n");
+        assert_eq!(stats.characters, 7);
+        assert_eq!(stats.characters_no_spaces, 5);
+        assert_eq!(stats.lines, 2);
+        assert_eq!(text_stats("a\rb").lines, 2);
+        assert_eq!(text_stats("\n").lines, 1);
+        assert_eq!(text_stats(""), TextStats::default());
+    }
+
+    #[test]
+    fn sentences() {
+        assert_eq!(text_stats("One. Two! Three? Four").sentences, 4);
+        assert_eq!(text_stats("He said \"stop.\" Then left.").sentences, 2);
+        assert_eq!(text_stats("Wait... what?! ...").sentences, 1);
+        assert_eq!(text_stats("I said no. He left.").sentences, 2);
+        assert_eq!(text_stats("So did I. Then we left.").sentences, 2);
+        assert_eq!(text_stats("See item No. 5 first. Done.").sentences, 2);
+        assert_eq!(text_stats("v1.2 is out").sentences, 1);
+        assert_eq!(text_stats("  ").sentences, 0);
+    }
+}
+
+#[cfg(test)]
+mod split_sections_tests {
+    use bt_string_utils::splitter::{split_sections, Section};
+
+
+    #[test]
+    fn markdown_headings() {
+        let doc = "# Title\r\ntext\r\n\r\n## A\r\na\r\n## B";
+        let sections = split_sections(doc, "## ");
+        assert_eq!(sections, vec![
+            Section { heading: None, body: "# Title\r\ntext\r\n\r\n", span: 0..17 },
+            Section { heading: Some("## A"), body: "a\r\n", span: 17..26 },
+            Section { heading: Some("## B"), body: "", span: 26..30 },
+        ]);
+    }
+
+    #[test]
+    fn blank_preamble_is_skipped() {
+        let sections = split_sections("\n  \n== One ==\nx\n", |line: &str| line.starts_with("==") && line.ends_with("=="));
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].heading, Some("== One =="));
+        assert_eq!(sections[0].span, 4..16);
+    }
+
+    #[test]
+    fn spans_cover_the_text() {
+        let doc = "intro\nChapter 1\none\nChapter 2\ntwo\n";
+        let prefix = String::from("Chapter ");
+        let sections = split_sections(doc, &prefix);
+        let rebuilt: String = sections.iter().map(|section| &doc[section.span.clone()]).collect();
+        assert_eq!(rebuilt, doc);
+    }
+
+    #[test]
+    fn no_headings() {
+        assert_eq!(split_sections("just text", "#"), vec![Section { heading: None, body: "just text", span: 0..9 }]);
+        assert!(split_sections("", "#").is_empty());
+        assert_eq!(split_sections("abc", "").len(), 1);
+    }
+}
+
+#[cfg(test)]
+mod char_count_tests {
+    use bt_string_utils::analyzer::{char_count, CountModeEnum};
+
+
+    #[test]
+    fn scalars_vs_graphemes() {
+        for (text, scalars, graphemes) in [("🙂", 1, 1), ("👍🏽", 2, 1), ("e\u{301}te\u{301}", 5, 3), ("🇫🇷🇩🇪", 4, 2), ("👨‍👩‍👧", 5, 1), ("", 0, 0)] {
+            assert_eq!(char_count(text, CountModeEnum::Scalars), scalars, "{text:?}");
+            assert_eq!(char_count(text, CountModeEnum::Graphemes), graphemes, "{text:?}");
+        }
+        assert_eq!(CountModeEnum::default(), CountModeEnum::Graphemes);
+    }
+
+    #[test]
+    fn exclusions() {
+        let text = " a\tb\u{3000}c\r\n";
+        assert_eq!(char_count(text, CountModeEnum::NoWhitespace), 3);
+        assert_eq!(char_count("«Oui», dit-il… 「はい」", CountModeEnum::NoPunctuation), 12);
+        assert_eq!(char_count("e\u{301}!", CountModeEnum::NoPunctuation), 1);
+    }
+
+    #[test]
+    fn wide() {
+        assert_eq!(char_count("ab你好", CountModeEnum::Wide), 6);
+        assert_eq!(char_count("ｈｉ", CountModeEnum::Wide), 4);
+        assert_eq!(char_count("e\u{301}👨‍👩‍👧", CountModeEnum::Wide), 3);
+    }
+}
+
+#[cfg(test)]
+mod count_lines_tests {
+    use bt_string_utils::analyzer::{count_lines, text_stats, LineCountOptions, LineKindEnum};
+
+
+    #[test]
+    fn physical_lines() {
+        let options = LineCountOptions::default();
+        assert_eq!(count_lines("", options), 0);
+        assert_eq!(count_lines("one", options), 1);
+        assert_eq!(count_lines("one\n", options), 1);
+        assert_eq!(count_lines("\n\n", options), 2);
+        assert_eq!(count_lines("a\r\nb\rc\n\nd", options), 5);
+        for text in ["", "\nx", "a\r\n\r\nb\r", "x\ny\n\n"] {
+            assert_eq!(count_lines(text, options), text_stats(text).lines);
+        }
+    }
+
+    #[test]
+    fn non_empty_lines() {
+        let options = LineCountOptions { kind: LineKindEnum::NonEmpty, comment_prefixes: &["#"] };
+        assert_eq!(count_lines("a\n \t\n\u{3000}\n# note\r\nb", options), 3);
+    }
+
+    #[test]
+    fn code_lines() {
+        let script = "#!/bin/sh\n# setup\nset -e\n\n  -- sql comment\necho \"# not a comment\"\n; ini\n";
+        let options = LineCountOptions { kind: LineKindEnum::Code, comment_prefixes: &["#", "--", ";", ""] };
+        assert_eq!(count_lines(script, options), 2);
+        let no_prefixes = LineCountOptions { kind: LineKindEnum::Code, comment_prefixes: &[] };
+        assert_eq!(count_lines(script, no_prefixes), 6);
+    }
+}
+
+#[cfg(test)]
+mod reading_time_tests {
+    use std::time::Duration;
+    use bt_string_utils::analyzer::{reading_time, word_count};
+
+
+    #[test]
+    fn follows_word_count() {
+        let text = "state-of-the-art, don't: https://example.com/a-b ok... yes ".repeat(53);
+        assert_eq!(word_count(&text), 265);
+        assert_eq!(reading_time(&text, None), Duration::from_secs(60));
+        assert_eq!(reading_time("", None), Duration::ZERO);
+    }
+
+    #[test]
+    fn custom_speed() {
+        let text = "word ".repeat(300);
+        assert_eq!(reading_time(&text, Some(100)), Duration::from_secs(180));
+        assert_eq!(reading_time(&text, Some(0)), reading_time(&text, None));
+    }
+
+    #[test]
+    fn cjk_characters() {
+        let text = "日本語".repeat(100);
+        assert_eq!(reading_time(&text, None), Duration::from_secs(36));
+        // 265 words and 500 characters: one minute each
+        let mixed = format!("{} {}", "word ".repeat(265), "漢字".repeat(250));
+        assert_eq!(reading_time(&mixed, None), Duration::from_secs(120));
+        assert_eq!(reading_time(&mixed, Some(530)), Duration::from_secs(60));
+    }
+}
+
+#[cfg(test)]
+mod word_frequencies_tests {
+    use bt_string_utils::analyzer::{word_count, word_frequencies, FrequencyOptions};
+
+
+    #[test]
+    fn counts_add_up_to_word_count() {
+        let text = "Read https://docs.rs/regex, then read it again! I'm sure it's state-of-the-art. 你好你好";
+        let counts = word_frequencies(text, FrequencyOptions::default());
+        assert_eq!(counts.values().sum::<usize>(), word_count(text));
+        assert_eq!(counts["https://docs.rs/regex"], 1);
+        assert_eq!((counts["你"], counts["好"]), (2, 2));
+        assert_eq!((counts["Read"], counts["read"]), (1, 1));
+        assert!(word_frequencies("", FrequencyOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn case_folding() {
+        let counts = word_frequencies("Straße STRASSE strasse Ὀδυσσεύς ὈΔΥΣΣΕΎΣ", FrequencyOptions { case_fold: true, min_length: 0 });
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts["strasse"], 3);
+    }
+
+    #[test]
+    fn minimum_length_in_characters() {
+        let options = FrequencyOptions { case_fold: false, min_length: 3 };
+        let counts = word_frequencies("a an the été 東京 ok-go", options);
+        let mut words: Vec<&str> = counts.keys().map(String::as_str).collect();
+        words.sort_unstable();
+        assert_eq!(words, vec!["ok-go", "the", "été"]);
+    }
+}
+
+#[cfg(test)]
+mod ngrams_tests {
+    use bt_string_utils::analyzer::{ngrams, tokenize, NgramKind};
+    use bt_string_utils::splitter::split_into_chunks;
+
+
+    #[test]
+    fn character_ngrams_keep_graphemes() {
+        assert_eq!(ngrams("e\u{301}👍🏽🇫🇷", 2, NgramKind::Characters), vec!["e\u{301}👍🏽", "👍🏽🇫🇷"]);
+        assert_eq!(ngrams("a b", 3, NgramKind::Characters), vec!["a b"]);
+        assert_eq!(ngrams("aaa", 2, NgramKind::Characters), vec!["aa", "aa"]);
+        assert!(ngrams("ab", 3, NgramKind::Characters).is_empty());
+        assert!(ngrams("ab", 0, NgramKind::Characters).is_empty());
+        assert!(ngrams("", 1, NgramKind::Characters).is_empty());
+        assert!(ngrams("abc", usize::MAX, NgramKind::Characters).is_empty());
+        assert!(ngrams("abc", 4, NgramKind::Characters).is_empty());
+    }
+
+    #[test]
+    fn word_ngrams_follow_tokenize() {
+        let text = "Don't  panic!\nSee https://example.com/faq, 你好.";
+        assert_eq!(ngrams(text, 1, NgramKind::Words), tokenize(text));
+        assert_eq!(ngrams(text, 3, NgramKind::Words),
+                   vec!["Don't panic See", "panic See https://example.com/faq", "See https://example.com/faq 你", "https://example.com/faq 你 好"]);
+        assert!(ngrams(text, 0, NgramKind::Words).is_empty());
+        assert!(ngrams(text, usize::MAX, NgramKind::Words).is_empty());
+    }
+
+    #[test]
+    fn shingles_of_chunks() {
+        let chunks = split_into_chunks("the quick brown fox the quick brown fox", 20);
+        let shingles: Vec<Vec<String>> = chunks.iter().map(|chunk| ngrams(chunk, 2, NgramKind::Words)).collect();
+        assert_eq!(shingles[0], shingles[1]);
+    }
 }
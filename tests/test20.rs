@@ -0,0 +1,44 @@
+#![cfg(feature = "arena")]
+
+#[cfg(test)]
+mod arena_tests {
+    use bt_string_utils::arena::{parse_properties_in, split_into_chunks_in, tokenize_in, Arena};
+    use bt_string_utils::analyzer::tokenize;
+    use bt_string_utils::kv::parse_properties;
+    use bt_string_utils::splitter::split_into_chunks;
+
+
+    #[test]
+    fn tokenize_matches_owned_variant() {
+        let arena = Arena::new();
+        let text = "Hello, world! It's 東京 -- fine.";
+        assert_eq!(tokenize_in(text, &arena).as_slice(), tokenize(text).as_slice());
+    }
+
+    #[test]
+    fn chunks_match_owned_variant() {
+        let arena = Arena::new();
+        let text = "abc 你好 déjà vu";
+        for size in 0..9 {
+            let owned = split_into_chunks(text, size);
+            assert_eq!(split_into_chunks_in(text, size, &arena).as_slice(), owned.as_slice());
+        }
+    }
+
+    #[test]
+    fn properties_match_owned_variant() {
+        let mut arena = Arena::new();
+        let text = "a = 1\nb: two \\\n    lines\n! comment\nc=x\\ty\na = 3";
+        let map = parse_properties(text);
+        {
+            let entries = parse_properties_in(text, &arena);
+            assert_eq!(entries.len(), 4);
+            for (key, value) in entries.iter().skip(1) {
+                assert_eq!(map.get(*key).map(String::as_str), Some(*value));
+            }
+            assert_eq!(entries[0], ("a", "1"));
+        }
+        arena.reset();
+        assert!(parse_properties_in("", &arena).is_empty());
+    }
+}
@@ -0,0 +1,212 @@
+#[cfg(test)]
+mod into_buffer_tests {
+    use bt_string_utils::case::{to_snake_case, to_snake_case_into};
+    use bt_string_utils::cleanser::{collapse_whitespace, collapse_whitespace_into};
+    use bt_string_utils::escaper::{
+        encode_unicode_escapes, encode_unicode_escapes_into, escape_c_string, escape_c_string_into,
+        escape_rust_string_literal, escape_rust_string_literal_into, EscapeStyle,
+    };
+
+    const SAMPLES: [&str; 4] = ["", "  HTTPServer\tlog\u{a0}\u{a0}file ", "say \"hi\"\n\u{1}", "naïve 你好 🙂\u{202e}"];
+
+
+    #[test]
+    fn same_result_as_allocating_variants() {
+        let mut out = String::new();
+        for s in SAMPLES {
+            out.clear();
+            to_snake_case_into(s, &mut out);
+            assert_eq!(out, to_snake_case(s));
+            out.clear();
+            collapse_whitespace_into(s, &mut out);
+            assert_eq!(out, collapse_whitespace(s));
+            out.clear();
+            encode_unicode_escapes_into(s, EscapeStyle::Json, &mut out);
+            assert_eq!(out, encode_unicode_escapes(s, EscapeStyle::Json));
+            out.clear();
+            escape_c_string_into(s, &mut out);
+            assert_eq!(out, escape_c_string(s));
+            out.clear();
+            escape_rust_string_literal_into(s, &mut out);
+            assert_eq!(out, escape_rust_string_literal(s));
+        }
+    }
+
+    #[test]
+    fn appends_to_existing_content() {
+        let mut out = String::from("key=");
+        escape_rust_string_literal_into("a\tb", &mut out);
+        out.push(' ');
+        to_snake_case_into("__Already_Snake__", &mut out);
+        assert_eq!(out, "key=\"a\\tb\" already_snake");
+
+        // A trailing separator of the buffer is not removed
+        let mut out = String::from("prefix_");
+        to_snake_case_into("!!", &mut out);
+        assert_eq!(out, "prefix_");
+    }
+
+    #[test]
+    fn buffer_is_reused() {
+        let mut out = String::with_capacity(64);
+        let capacity = out.capacity();
+        for s in SAMPLES {
+            out.clear();
+            collapse_whitespace_into(s, &mut out);
+        }
+        assert_eq!(out.capacity(), capacity);
+    }
+}
+
+#[cfg(test)]
+mod case_conversion_tests {
+    use bt_string_utils::case::{to_camel_case, to_kebab_case, to_pascal_case, to_screaming_snake_case, to_snake_case};
+
+
+    #[test]
+    fn acronym_runs() {
+        assert_eq!(to_snake_case("parseHTTPResponse"), "parse_http_response");
+        assert_eq!(to_kebab_case("IOError"), "io-error");
+        assert_eq!(to_camel_case("HTTPServer"), "httpServer");
+        assert_eq!(to_pascal_case("getURL"), "GetUrl");
+        assert_eq!(to_screaming_snake_case("XMLParser"), "XML_PARSER");
+    }
+
+    #[test]
+    fn digits() {
+        assert_eq!(to_snake_case("base64Encode"), "base64_encode");
+        assert_eq!(to_snake_case("utf8"), "utf8");
+        assert_eq!(to_camel_case("ipv6_address"), "ipv6Address");
+        assert_eq!(to_pascal_case("3d model"), "3dModel");
+        assert_eq!(to_kebab_case("Version 2 Beta"), "version-2-beta");
+    }
+
+    #[test]
+    fn round_trips_between_cases() {
+        let name = "userAccountId";
+        assert_eq!(to_camel_case(&to_snake_case(name)), name);
+        assert_eq!(to_camel_case(&to_kebab_case(name)), name);
+        assert_eq!(to_camel_case(&to_screaming_snake_case(name)), name);
+        assert_eq!(to_snake_case(&to_pascal_case(name)), "user_account_id");
+    }
+
+    #[test]
+    fn separators_and_unicode() {
+        assert_eq!(to_pascal_case("  __hello--wörld__ "), "HelloWörld");
+        assert_eq!(to_screaming_snake_case("straßeName"), "STRASSE_NAME");
+        assert_eq!(to_camel_case(""), "");
+        assert_eq!(to_kebab_case("---"), "");
+    }
+}
+
+#[cfg(test)]
+mod detect_case_tests {
+    use bt_string_utils::case::{detect_case, CaseStyle};
+
+
+    #[test]
+    fn styles() {
+        assert_eq!(detect_case("user_id_2"), CaseStyle::Snake);
+        assert_eq!(detect_case("MAX_RETRIES"), CaseStyle::ScreamingSnake);
+        assert_eq!(detect_case("content-type"), CaseStyle::Kebab);
+        assert_eq!(detect_case("parseHTTPResponse"), CaseStyle::Camel);
+        assert_eq!(detect_case("XmlParser"), CaseStyle::Pascal);
+        assert_eq!(detect_case("naïveÉtat"), CaseStyle::Camel);
+    }
+
+    #[test]
+    fn single_words() {
+        assert_eq!(detect_case("user"), CaseStyle::Snake);
+        assert_eq!(detect_case("HTTP"), CaseStyle::ScreamingSnake);
+        assert_eq!(detect_case("User"), CaseStyle::Pascal);
+        assert_eq!(detect_case("v2"), CaseStyle::Snake);
+        assert_eq!(detect_case("42"), CaseStyle::Snake);
+    }
+
+    #[test]
+    fn mixed_and_unknown() {
+        assert_eq!(detect_case("snake_and-kebab"), CaseStyle::Mixed);
+        assert_eq!(detect_case("_private"), CaseStyle::Mixed);
+        assert_eq!(detect_case("a__b"), CaseStyle::Mixed);
+        assert_eq!(detect_case("Content-Type"), CaseStyle::Mixed);
+        assert_eq!(detect_case(""), CaseStyle::Unknown);
+        assert_eq!(detect_case("a.b"), CaseStyle::Unknown);
+        assert_eq!(detect_case("two words"), CaseStyle::Unknown);
+    }
+
+    #[test]
+    fn round_trips_through_conversions() {
+        for name in ["order_line_item", "ORDER_LINE_ITEM", "order-line-item", "orderLineItem", "OrderLineItem"] {
+            let style = detect_case(name);
+            assert_eq!(style.apply(name), name);
+            assert_eq!(style.apply("order line item"), name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod title_case_tests {
+    use bt_string_utils::case::to_title_case;
+
+
+    #[test]
+    fn small_words() {
+        assert_eq!(to_title_case("war and peace"), "War and Peace");
+        assert_eq!(to_title_case("THE rise AND Fall of an empire"), "THE Rise AND Fall of an Empire");
+        assert_eq!(to_title_case("what the web is made of"), "What the Web Is Made Of");
+        assert_eq!(to_title_case("Of Mice And Men"), "Of Mice and Men");
+    }
+
+    #[test]
+    fn phrase_starts() {
+        assert_eq!(to_title_case("star wars: a new hope"), "Star Wars: A New Hope");
+        assert_eq!(to_title_case("why? the answer is in the data"), "Why? The Answer Is in the Data");
+    }
+
+    #[test]
+    fn preserved_words() {
+        assert_eq!(to_title_case("using the GPU with eBay and macOS"), "Using the GPU With eBay and macOS");
+        assert_eq!(to_title_case("notes on example.com and bo@mail.com"), "Notes on example.com and bo@mail.com");
+        assert_eq!(to_title_case("how to read README.md files"), "How to Read README.md Files");
+    }
+
+    #[test]
+    fn punctuation_and_spacing() {
+        assert_eq!(to_title_case("  “the end”\tof   an era  "), "  “The End”\tof   an Era  ");
+        assert_eq!(to_title_case("(in) the beginning"), "(In) the Beginning");
+        assert_eq!(to_title_case("état of the art"), "État of the Art");
+        assert_eq!(to_title_case(""), "");
+        assert_eq!(to_title_case("the 3rd act"), "The 3rd Act");
+    }
+}
+
+#[cfg(test)]
+mod sentence_case_tests {
+    use bt_string_utils::case::{capitalize_first, to_sentence_case};
+
+
+    #[test]
+    fn first_grapheme() {
+        assert_eq!(capitalize_first("ǆungla", false), "ǅungla");
+        assert_eq!(capitalize_first("a\u{308}\u{301}rger", true), "A\u{308}\u{301}rger");
+        assert_eq!(capitalize_first("ßtraße", false), "Sstraße");
+        assert_eq!(capitalize_first("ÉCOLE", true), "École");
+        assert_eq!(capitalize_first("ΟΔΟΣ", true), "Οδος");
+    }
+
+    #[test]
+    fn no_letter_to_capitalize() {
+        assert_eq!(capitalize_first("", true), "");
+        assert_eq!(capitalize_first("...", true), "...");
+        assert_eq!(capitalize_first("1ST PLACE", true), "1st place");
+        assert_eq!(capitalize_first("1st place", false), "1st place");
+    }
+
+    #[test]
+    fn sentences() {
+        assert_eq!(to_sentence_case("one. two!  three?\nfour"), "One. Two!  Three?\nFour");
+        assert_eq!(to_sentence_case("WAIT... \"REALLY?\" yes."), "Wait... \"Really?\" Yes.");
+        assert_eq!(to_sentence_case("v1.2 is OUT"), "V1.2 is out");
+        assert_eq!(to_sentence_case("  "), "  ");
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod const_predicate_tests {
+    use bt_string_utils::analyzer::{is_cjk, is_combining_mark, is_emoji_char, is_punctuation};
+    use bt_string_utils::escaper::{c_short_escape, escape_c_string, rust_short_escape};
+
+    /// A lookup table built at compile time, as a downstream crate would.
+    const ASCII_PUNCTUATION: [bool; 128] = {
+        let mut table = [false; 128];
+        let mut i = 0;
+        while i < 128 {
+            table[i] = is_punctuation(i as u8 as char);
+            i += 1;
+        }
+        table
+    };
+
+    static CLASSES: [(char, bool, bool, bool); 3] = [
+        ('漢', is_cjk('漢'), is_combining_mark('漢'), is_emoji_char('漢')),
+        ('\u{301}', is_cjk('\u{301}'), is_combining_mark('\u{301}'), is_emoji_char('\u{301}')),
+        ('🎉', is_cjk('🎉'), is_combining_mark('🎉'), is_emoji_char('🎉')),
+    ];
+
+
+    #[test]
+    fn usable_in_const_tables() {
+        assert!(ASCII_PUNCTUATION[b'!' as usize]);
+        assert!(!ASCII_PUNCTUATION[b'a' as usize]);
+        assert_eq!(ASCII_PUNCTUATION.iter().filter(|p| **p).count(), 32);
+        assert_eq!(CLASSES[0], ('漢', true, false, false));
+        assert_eq!(CLASSES[1], ('\u{301}', false, true, false));
+        assert_eq!(CLASSES[2], ('🎉', false, false, true));
+    }
+
+    #[test]
+    fn emoji_table() {
+        for c in ['😀', '🚀', '🤖', '🫠', '❤', '✅', '©', '🀄', '🇺', '🏽'] {
+            assert!(is_emoji_char(c), "{c:?}");
+        }
+        for c in ['a', '1', '#', '*', '\u{200D}', '\u{FE0F}', '→', '你', '\u{1F700}'] {
+            assert!(!is_emoji_char(c), "{c:?}");
+        }
+    }
+
+    #[test]
+    fn short_escapes() {
+        for c in ['\\', '"', '\n', '\t', '\u{0B}'] {
+            assert_eq!(c_short_escape(c), Some(escape_c_string(&c.to_string()).as_str()));
+        }
+        assert_eq!(c_short_escape('\0'), None);
+        assert_eq!(rust_short_escape('\0'), Some("\\0"));
+        assert_eq!(rust_short_escape('\u{0B}'), None);
+    }
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "ffi")]
+
+#[cfg(test)]
+mod ffi_tests {
+    use std::ptr;
+    use bt_string_utils::anonymizer::{anonymize, Anonymizer};
+    use bt_string_utils::ffi::{
+        bt_redact, bt_slugify, bt_split_into_chunks, bt_string_array_free, bt_string_free, bt_word_count, BtStatus, BtString,
+        BtStringArray,
+    };
+
+    fn as_str(s: &BtString) -> &str {
+        unsafe { std::str::from_utf8(std::slice::from_raw_parts(s.ptr, s.len)).unwrap() }
+    }
+
+
+    #[test]
+    fn word_count() {
+        let text = "Hello, wide world!";
+        let mut count = 0;
+        assert_eq!(unsafe { bt_word_count(text.as_ptr(), text.len(), &mut count) }, BtStatus::Ok);
+        assert_eq!(count, 3);
+        assert_eq!(unsafe { bt_word_count(ptr::null(), 0, &mut count) }, BtStatus::Ok);
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn split_into_chunks() {
+        let text = "ab字cd";
+        let mut array = BtStringArray { ptr: ptr::null_mut(), len: 0 };
+        assert_eq!(unsafe { bt_split_into_chunks(text.as_ptr(), text.len(), 3, &mut array) }, BtStatus::Ok);
+        let chunks: Vec<&str> = unsafe { std::slice::from_raw_parts(array.ptr, array.len) }.iter().map(as_str).collect();
+        assert_eq!(chunks, ["ab", "字", "cd"]);
+        unsafe { bt_string_array_free(array) };
+    }
+
+    #[test]
+    fn redact_text() {
+        let text = "Mail from Alice at alice@example.com";
+        let mut out = BtString { ptr: ptr::null_mut(), len: 0, capacity: 0 };
+        assert_eq!(unsafe { bt_redact(text.as_ptr(), text.len(), 7, &mut out) }, BtStatus::Ok);
+        assert_eq!(as_str(&out), anonymize(text, &Anonymizer::new(7)));
+        unsafe { bt_string_free(out) };
+    }
+
+    #[test]
+    fn slugify_title() {
+        let text = "Crème Brûlée Recipe";
+        let mut out = BtString { ptr: ptr::null_mut(), len: 0, capacity: 0 };
+        assert_eq!(unsafe { bt_slugify(text.as_ptr(), text.len(), b'_', &mut out) }, BtStatus::Ok);
+        assert_eq!(as_str(&out), "creme_brulee_recipe");
+        unsafe { bt_string_free(out) };
+        let mut unused = BtString { ptr: ptr::null_mut(), len: 0, capacity: 0 };
+        assert_eq!(unsafe { bt_slugify(text.as_ptr(), text.len(), 0xE9, &mut unused) }, BtStatus::InvalidArgument);
+    }
+
+    #[test]
+    fn errors() {
+        let invalid = [b'a', 0xFF];
+        let mut count = 0;
+        assert_eq!(unsafe { bt_word_count(invalid.as_ptr(), invalid.len(), &mut count) }, BtStatus::InvalidUtf8);
+        assert_eq!(unsafe { bt_word_count(ptr::null(), 3, &mut count) }, BtStatus::NullPointer);
+        assert_eq!(unsafe { bt_word_count(b"a".as_ptr(), 1, ptr::null_mut()) }, BtStatus::NullPointer);
+        assert_eq!(unsafe { bt_redact(b"a".as_ptr(), 1, 0, ptr::null_mut()) }, BtStatus::NullPointer);
+    }
+}
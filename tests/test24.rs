@@ -0,0 +1,25 @@
+#![cfg(feature = "wasm")]
+
+#[cfg(test)]
+mod wasm_tests {
+    use bt_string_utils::{analyzer, cleanser, splitter, wasm};
+
+
+    #[test]
+    fn same_rules_as_the_crate() {
+        let text = "  Hello,\u{a0}wide world!\t\n\nSecond   paragraph  ";
+        assert_eq!(wasm::word_count(text), analyzer::word_count(text));
+        assert_eq!(wasm::count_paragraphs(text), analyzer::count_paragraphs(text));
+        assert_eq!(wasm::split_into_chunks(text, 8), splitter::split_into_chunks(text, 8));
+        assert_eq!(wasm::collapse_whitespace(text), cleanser::collapse_whitespace(text));
+        assert_eq!(wasm::remove_whitespace(text), cleanser::remove_whitespace(text));
+        assert_eq!(wasm::fix_whitespace(text), cleanser::fix_whitespace(text, Default::default()).0);
+    }
+
+    #[test]
+    fn sanitizing() {
+        assert_eq!(wasm::remove_tags("a <b>bold</b> move", "<", ">"), cleanser::remove_tags("a <b>bold</b> move", "<", ">"));
+        assert_eq!(wasm::strip_excess_combining_marks("z\u{301}\u{302}\u{303}", 1), "z\u{301}");
+        assert_eq!(wasm::display_width("你好"), 4);
+    }
+}
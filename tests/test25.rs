@@ -0,0 +1,85 @@
+#![cfg(feature = "cli")]
+
+#[cfg(test)]
+mod cli_tests {
+    use std::io::Write;
+    use std::process::{Command, Output, Stdio};
+    use bt_string_utils::analyzer::word_count;
+    use bt_string_utils::anonymizer::{anonymize, Anonymizer};
+
+    fn run(args: &[&str], stdin: &str) -> Output {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_bt-strings"))
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+        child.wait_with_output().unwrap()
+    }
+
+    fn stdout(output: &Output) -> &str {
+        std::str::from_utf8(&output.stdout).unwrap()
+    }
+
+
+    #[test]
+    fn count() {
+        let text = "It's a well-known fact.\n\nSecond paragraph";
+        assert_eq!(stdout(&run(&["count"], text)), format!("{}\n", word_count(text)));
+        assert_eq!(stdout(&run(&["count", "--paragraphs"], text)), "3\n");
+    }
+
+    #[test]
+    fn chunk() {
+        let output = run(&["chunk", "--size", "3"], "ab字cd");
+        assert_eq!(stdout(&output), "ab\0字\0cd");
+    }
+
+    #[test]
+    fn kv_get_from_file() {
+        let path = std::env::temp_dir().join(format!("bt_strings_kv_{}.properties", std::process::id()));
+        std::fs::write(&path, "# settings\nhost = db1\nport: 5432\n").unwrap();
+        let file = path.to_str().unwrap();
+        assert_eq!(stdout(&run(&["kv", "get", "port", file], "")), "5432\n");
+        let missing = run(&["kv", "get", "user", file], "");
+        assert_eq!(missing.status.code(), Some(1));
+        assert!(missing.stdout.is_empty());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn redact() {
+        let text = "Ticket from John at john@mail.com";
+        assert_eq!(stdout(&run(&["redact", "--seed", "9"], text)), anonymize(text, &Anonymizer::new(9)));
+    }
+
+    #[test]
+    fn slugify() {
+        assert_eq!(stdout(&run(&["slugify"], "Hello World!\nCrème brûlée\n")), "hello-world\ncreme-brulee\n");
+        assert_eq!(stdout(&run(&["slugify", "--separator", "_"], "a b")), "a_b\n");
+    }
+
+    #[test]
+    fn help() {
+        for args in [["help"], ["--help"]] {
+            let output = run(&args, "");
+            assert!(output.status.success());
+            assert!(stdout(&output).starts_with("Usage: bt-strings"));
+        }
+    }
+
+    #[test]
+    fn usage_errors() {
+        for args in [&[][..], &["frobnicate"], &["chunk"], &["count", "--bogus"], &["redact", "--seed", "x"], &["slugify", "--separator", "ab"]] {
+            let output = run(args, "");
+            assert_eq!(output.status.code(), Some(2), "{args:?}");
+            assert!(String::from_utf8_lossy(&output.stderr).contains("Usage: bt-strings"));
+        }
+        let output = run(&["slugify", "--separator", "ab"], "");
+        assert!(String::from_utf8_lossy(&output.stderr).contains("--separator expects a single character, got \"ab\""));
+        let output = run(&["count", "/nonexistent/file"], "");
+        assert_eq!(output.status.code(), Some(1));
+    }
+}
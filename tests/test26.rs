@@ -0,0 +1,105 @@
+#![cfg(feature = "tracing")]
+
+#[cfg(test)]
+mod tracing_tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+    use bt_string_utils::anonymizer::{anonymize, Anonymizer};
+    use bt_string_utils::finder::find_value_by_key;
+    use bt_string_utils::kv::{parse_properties, KvParser};
+    use bt_string_utils::splitter::split_into_chunks;
+
+    /// Records span names and event messages.
+    #[derive(Clone, Default)]
+    struct Recorder {
+        log: Arc<Mutex<Vec<String>>>,
+        next_id: Arc<AtomicU64>,
+    }
+
+    struct Message(Option<String>);
+
+    impl Visit for Message {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                self.0 = Some(format!("{value:?}"));
+            }
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            self.log.lock().unwrap().push(format!("span {}", span.metadata().name()));
+            Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut message = Message(None);
+            event.record(&mut message);
+            self.log.lock().unwrap().push(message.0.unwrap_or_default());
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    fn capture(f: impl FnOnce()) -> Vec<String> {
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), f);
+        recorder.log.lock().unwrap().clone()
+    }
+
+
+    #[test]
+    fn kv_parser_events() {
+        let log = capture(|| {
+            KvParser::new().parse("a=1; b=2").unwrap();
+            assert!(KvParser::new().parse("a=1; flag").is_err());
+        });
+        assert_eq!(log.iter().filter(|l| *l == "key found").count(), 3);
+        assert!(log.contains(&"span kv_parse".to_owned()));
+        assert!(log.contains(&"key-value pairs parsed".to_owned()));
+        assert!(log.contains(&"pair without key-value delimiter".to_owned()));
+    }
+
+    #[test]
+    fn lookup_and_properties_events() {
+        let log = capture(|| {
+            assert_eq!(find_value_by_key(&vec!["a:1".to_owned()], "a"), None);
+            parse_properties("x=1\ny=2");
+        });
+        assert!(log.contains(&"key not found".to_owned()));
+        assert_eq!(log.iter().filter(|l| *l == "property found").count(), 2);
+    }
+
+    #[test]
+    fn chunker_events() {
+        let log = capture(|| {
+            split_into_chunks("abcdefg", 3);
+        });
+        assert_eq!(log.iter().filter(|l| *l == "chunk produced").count(), 3);
+        assert!(log.contains(&"span split_into_chunks".to_owned()));
+    }
+
+    #[test]
+    fn anonymizer_events() {
+        let log = capture(|| {
+            anonymize("Ask Maria at maria@x.org or 555-1234", &Anonymizer::new(1));
+        });
+        assert!(log.contains(&"span anonymize".to_owned()));
+        assert!(log.contains(&"name replaced".to_owned()));
+        assert!(log.contains(&"email replaced".to_owned()));
+        assert!(log.contains(&"number replaced".to_owned()));
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod v2_tests {
+    use bt_string_utils::cleanser::{remove_char, RemoveLocationEnum};
+    use bt_string_utils::finder::{find_value_by_key, get_first_occurrance, get_last_occurrance};
+    use bt_string_utils::splitter::{get_first_of_split, get_last_of_split, split_all};
+    use bt_string_utils::v2;
+
+
+    #[test]
+    fn same_results_as_v1_when_found() {
+        let s = "First:Second:Third";
+        assert_eq!(v2::split_first(s, ':').map(|(a, b)| (a.to_owned(), b.to_owned())), Some(get_first_of_split(s, ':')));
+        assert_eq!(v2::split_last(s, ':').map(|(a, b)| (a.to_owned(), b.to_owned())), Some(get_last_of_split(s, ':')));
+        assert_eq!(v2::before_first(s, ":").unwrap(), get_first_occurrance(s, ":"));
+        assert_eq!(v2::after_last(s, ":").unwrap(), get_last_occurrance(s, ":"));
+        assert_eq!(v2::split_all(s, ':', Some(1)), split_all(s, ':', Some(1)));
+        let pairs = vec!["k1=a".to_owned(), "k2=b".to_owned()];
+        assert_eq!(v2::find_value(&pairs, "k2").map(str::to_owned), find_value_by_key(&pairs, "k2"));
+        assert_eq!(v2::strip_char("hello", RemoveLocationEnum::Begin, 'h').unwrap(), remove_char(RemoveLocationEnum::Begin, &"hello".to_owned(), 'h'));
+    }
+
+    #[test]
+    fn none_when_not_found() {
+        assert_eq!(v2::split_first("abc", '*'), None);
+        assert_eq!(v2::split_last("abc", "*"), None);
+        assert_eq!(v2::before_first("abc", '*'), None);
+        assert_eq!(v2::after_last("abc", '*'), None);
+        assert_eq!(v2::find_value(&["a=1"], "b"), None);
+        assert_eq!(v2::strip_char("abc", RemoveLocationEnum::Begin, '*'), None);
+        assert_eq!(v2::strip_char("abc", RemoveLocationEnum::Both, '*'), None);
+    }
+
+    #[test]
+    fn borrowed_results() {
+        let s = String::from("key=value");
+        let (key, value) = v2::split_first(&s, '=').unwrap();
+        assert!(std::ptr::eq(key.as_ptr(), s.as_ptr()));
+        assert_eq!(value, "value");
+        assert_eq!(v2::first_chars("héllo", 2), "hé");
+        assert_eq!(v2::last_chars("héllo", 2), "lo");
+        assert_eq!(v2::skip_chars("héllo", 2), "llo");
+        assert_eq!(v2::strip_char("naïve…", RemoveLocationEnum::End, '…'), Some("naïve"));
+    }
+}
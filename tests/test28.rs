@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod query_tests {
+    use bt_string_utils::query::{find_query_param, parse_query, percent_decode};
+
+
+    #[test]
+    fn decoding() {
+        assert_eq!(percent_decode("a%2Bb%2b"), "a+b+");
+        assert_eq!(percent_decode("%zz%4"), "%zz%4");
+        assert_eq!(percent_decode("%FF"), "\u{FFFD}");
+        assert_eq!(percent_decode("%E4%BD%A0%E5%A5%BD"), "你好");
+        assert_eq!(percent_decode("%+1"), "%+1");
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(parse_query("?a=1&b=hello%20world"), vec![("a".to_owned(), "1".to_owned()), ("b".to_owned(), "hello world".to_owned())]);
+        assert_eq!(parse_query("a=1+%2B+1"), vec![("a".to_owned(), "1 + 1".to_owned())]);
+        assert_eq!(parse_query("x%5B%5D=1&&x%5B%5D=2&=v"), vec![
+            ("x[]".to_owned(), "1".to_owned()),
+            ("x[]".to_owned(), "2".to_owned()),
+            ("".to_owned(), "v".to_owned()),
+        ]);
+        assert_eq!(parse_query("eq=a=b"), vec![("eq".to_owned(), "a=b".to_owned())]);
+        assert!(parse_query("").is_empty());
+        assert!(parse_query("https://example.com/path?#frag?x=1").is_empty());
+    }
+
+    #[test]
+    fn find_param() {
+        assert_eq!(find_query_param("?first+name=Ana&first+name=Bo", "first name"), Some("Ana".to_owned()));
+        assert_eq!(find_query_param("?flag&x=1", "flag"), Some("".to_owned()));
+        assert_eq!(find_query_param("?x=1", "y"), None);
+        assert_eq!(find_query_param("/p?x=1#x=2", "x"), Some("1".to_owned()));
+    }
+}
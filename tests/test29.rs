@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod metrics_cache_tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use bt_string_utils::analyzer::word_count;
+    use bt_string_utils::cache::MetricsCache;
+    use bt_string_utils::hasher::canonical_fingerprint;
+
+
+    #[test]
+    fn memoizes_by_content() {
+        let calls = AtomicUsize::new(0);
+        let cache = MetricsCache::new(10);
+        let count = |text: &str| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            canonical_fingerprint(text)
+        };
+        let owned = String::from("Same text");
+        assert_eq!(cache.get_or_compute("Same text", count), canonical_fingerprint("Same text"));
+        assert_eq!(cache.get_or_compute(&owned, count), canonical_fingerprint("Same text"));
+        cache.get_or_compute("Other text", count);
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = MetricsCache::new(2);
+        cache.get_or_compute("one", word_count);
+        cache.get_or_compute("two words", word_count);
+        assert_eq!(cache.get("one"), Some(1));
+        cache.get_or_compute("three more words", word_count);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("two words"), None);
+        assert_eq!(cache.get("one"), Some(1));
+        assert_eq!(cache.get("three more words"), Some(3));
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn shared_between_threads() {
+        let cache = MetricsCache::new(64);
+        let documents: Vec<String> = (0..32).map(|i| "word ".repeat(i)).collect();
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for document in &documents {
+                        assert_eq!(cache.get_or_compute(document, word_count), word_count(document));
+                    }
+                });
+            }
+        });
+        assert_eq!(cache.len(), 32);
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod parse_sections_tests {
+    use bt_string_utils::sections::parse_sections;
+
+
+    fn pairs(items: &[(&str, &str)]) -> Vec<(String, String)> {
+        items.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn headers_and_global_entries() {
+        let sections = parse_sections("a=1\n[ one ]\nb : 2\n[two]\nc=3\n[one]\nd=4\n");
+        assert_eq!(sections.keys().collect::<Vec<_>>(), vec!["", "one", "two"]);
+        assert_eq!(sections[""], pairs(&[("a", "1")]));
+        assert_eq!(sections["one"], pairs(&[("b", "2"), ("d", "4")]));
+        assert_eq!(sections["two"], pairs(&[("c", "3")]));
+    }
+
+    #[test]
+    fn no_global_section_when_unused() {
+        let sections = parse_sections("; header comment\n[only]\n");
+        assert_eq!(sections.keys().collect::<Vec<_>>(), vec!["only"]);
+        assert!(sections["only"].is_empty());
+    }
+
+    #[test]
+    fn comments_and_invalid_lines() {
+        let sections = parse_sections("# c\n[s]\n  ; indented comment\nnot an entry\nurl = http://x?a=b\nempty=\n");
+        assert_eq!(sections["s"], pairs(&[("url", "http://x?a=b"), ("empty", "")]));
+    }
+
+    #[test]
+    fn continuation_lines() {
+        let sections = parse_sections("[s]\nmotd = first\n  second\n\tthird\n\n  orphan = x\nkey = v\n");
+        assert_eq!(sections["s"], pairs(&[("motd", "first\nsecond\nthird"), ("orphan", "x"), ("key", "v")]));
+        assert!(parse_sections("  leading\n").is_empty());
+    }
+
+    #[test]
+    fn repeated_keys_kept() {
+        let sections = parse_sections("[s]\nk=1\nk=2\n");
+        assert_eq!(sections["s"], pairs(&[("k", "1"), ("k", "2")]));
+    }
+}
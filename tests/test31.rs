@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod transliteration_map_tests {
+    use std::borrow::Cow;
+    use bt_string_utils::translit::TransliterationMap;
+
+
+    #[test]
+    fn single_mappings() {
+        let map = TransliterationMap::builder().map('ä', "ae").map('Ä', "Ae").map('€', " EUR").build();
+        assert_eq!(map.apply("Äpfel für 5€"), "Aepfel für 5 EUR");
+        assert_eq!(map.get('ä'), Some("ae"));
+        assert_eq!(map.get('a'), None);
+    }
+
+    #[test]
+    fn borrowed_when_unchanged() {
+        let map = TransliterationMap::builder().map('x', "y").build();
+        assert!(matches!(map.apply("abc"), Cow::Borrowed("abc")));
+        assert!(matches!(map.apply(""), Cow::Borrowed("")));
+        assert!(matches!(map.apply("abx"), Cow::Owned(_)));
+        assert!(TransliterationMap::builder().build().is_empty());
+        assert!(!map.is_empty());
+    }
+
+    #[test]
+    fn ranges_and_precedence() {
+        let map = TransliterationMap::builder()
+            .map_range('0'..='9', "#")
+            .map_range('5'..='9', "+")
+            .map('7', "seven")
+            .build();
+        assert_eq!(map.apply("a0 5 7"), "a# + seven");
+    }
+
+    #[test]
+    fn table_and_overrides() {
+        let map = TransliterationMap::builder().map_all(&[('ø', "o"), ('å', "aa")]).map('ø', "oe").build();
+        assert_eq!(map.apply("Ålesund øst"), "Ålesund oest");
+        assert_eq!(TransliterationMap::builder().map('-', "").build().apply("a-b-c"), "abc");
+    }
+}
+
+#[cfg(test)]
+mod transliterate_tests {
+    use std::borrow::Cow;
+    use bt_string_utils::translit::transliterate;
+
+
+    #[test]
+    fn cyrillic() {
+        assert_eq!(transliterate("Съешь же ещё этих мягких булок"), "Sesh zhe eshchyo etikh myagkikh bulok");
+        assert_eq!(transliterate("Київ, Ґанок"), "Kiyiv, Ganok");
+        assert_eq!(transliterate("Љубљана Ђурђевдан"), "Ljubljana Djurdjevdan");
+    }
+
+    #[test]
+    fn greek() {
+        assert_eq!(transliterate("Ψυχή και θάλασσα"), "Psychi kai thalassa");
+        assert_eq!(transliterate("ΟΥΡΑΝΟΣ ουρανός"), "OURANOS ouranos");
+    }
+
+    #[test]
+    fn capitalization_and_passthrough() {
+        assert_eq!(transliterate("ШУМ Шум"), "SHUM Shum");
+        assert_eq!(transliterate("Москва-2024 café"), "Moskva-2024 café");
+        assert!(matches!(transliterate("café 東京"), Cow::Borrowed("café 東京")));
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod content_filter_tests {
+    use bt_string_utils::moderation::{ContentFilter, SeverityEnum};
+
+
+    fn spans(filter: &ContentFilter, text: &str) -> Vec<(usize, usize)> {
+        filter.classify(text).matches.iter().map(|m| (m.start, m.end)).collect()
+    }
+
+    #[test]
+    fn severity_levels() {
+        let filter = ContentFilter::new().term("meh", SeverityEnum::Low).term("ugh", SeverityEnum::High);
+        let verdict = filter.classify("meh... UGH");
+        assert_eq!(verdict.max_severity, Some(SeverityEnum::High));
+        assert_eq!(verdict.matches[0].term, "meh");
+        assert_eq!(verdict.matches[1].severity, SeverityEnum::High);
+        assert_eq!(filter.classify("fine").max_severity, None);
+        assert!(filter.classify("fine").is_clean());
+        let relabeled = filter.term("UGH", SeverityEnum::Low);
+        assert_eq!(relabeled.classify("ugh").max_severity, Some(SeverityEnum::Low));
+    }
+
+    #[test]
+    fn allowlist() {
+        let filter = ContentFilter::new().term("cunt", SeverityEnum::High).allow("scunthorpe");
+        assert!(filter.classify("Welcome to Scunthorpe!").is_clean());
+        assert_eq!(spans(&filter, "Scunthorpes"), vec![(1, 5)]);
+        assert_eq!(spans(&ContentFilter::new().term("cunt", SeverityEnum::High), "Scunthorpe"), vec![(1, 5)]);
+    }
+
+    #[test]
+    fn word_boundary_mode() {
+        let filter = ContentFilter::new().term("ass", SeverityEnum::Medium).word_boundary_only(true);
+        assert!(filter.classify("classic assessment").is_clean());
+        assert_eq!(spans(&filter, "Ass, you ass."), vec![(0, 3), (9, 12)]);
+        let loose = filter.word_boundary_only(false);
+        assert_eq!(spans(&loose, "classic"), vec![(2, 5)]);
+    }
+
+    #[test]
+    fn unicode_and_phrases() {
+        let filter = ContentFilter::new().term("Maldito", SeverityEnum::Low).term("dumb idea", SeverityEnum::Medium);
+        assert_eq!(spans(&filter, "¡MALDITO!"), vec![(2, 9)]);
+        assert_eq!(spans(&filter, "a Dumb Idea"), vec![(2, 11)]);
+        assert!(ContentFilter::new().term("", SeverityEnum::High).classify("x").is_clean());
+    }
+}
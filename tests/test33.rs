@@ -0,0 +1,29 @@
+#[cfg(test)]
+mod normalize_for_tests {
+    use bt_string_utils::normalize::{normalize_for, Purpose};
+
+
+    #[test]
+    fn search() {
+        assert_eq!(normalize_for("Ærøskøbing\tHAVN", Purpose::Search), "aeroskobing havn");
+        assert_eq!(normalize_for("STRASSE", Purpose::Search), normalize_for("Straße", Purpose::Search));
+        assert_eq!(normalize_for("Cafe\u{301}", Purpose::Search), normalize_for("CAFÉ", Purpose::Search));
+        assert_eq!(normalize_for("ｈｅｌｌｏ！", Purpose::Search), "hello!");
+        assert_eq!(normalize_for(" \r\n ", Purpose::Search), "");
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(normalize_for("a\u{308}\t\rb", Purpose::Display), "ä\nb");
+        assert_eq!(normalize_for("z\u{301}\u{302}\u{303}\u{304}", Purpose::Display), "ź\u{302}");
+        assert_eq!(normalize_for("o\u{31b}\u{301}", Purpose::Display), "ớ");
+        assert_eq!(normalize_for("line\n\n", Purpose::Display), "line\n\n");
+    }
+
+    #[test]
+    fn storage() {
+        assert_eq!(normalize_for("E\u{301}te\u{301} \r\n\r\n", Purpose::Storage), "Été \n\n");
+        assert_eq!(normalize_for("ＡＢＣ\u{3000}", Purpose::Storage), "ＡＢＣ\u{3000}");
+        assert_eq!(normalize_for("q\u{301}", Purpose::Storage), "q\u{301}");
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod strip_html_tags_tests {
+    use bt_string_utils::analyzer::word_count;
+    use bt_string_utils::html::strip_html_tags;
+
+
+    #[test]
+    fn inline_and_block_elements() {
+        assert_eq!(strip_html_tags("un<b>break</b>able"), "unbreakable");
+        assert_eq!(strip_html_tags("one<br>two<br/>three"), "one\ntwo\nthree");
+        assert_eq!(strip_html_tags("<UL><LI>a</LI><LI>b</LI></UL>"), "\n\na\n\nb\n\n");
+    }
+
+    #[test]
+    fn comments_doctype_and_raw_text() {
+        assert_eq!(strip_html_tags("<!DOCTYPE html><?xml x?>a<!-- <p>hidden</p> -->b"), "ab");
+        assert_eq!(strip_html_tags("x<style>p > a { color: red }</style>y"), "xy");
+        assert_eq!(strip_html_tags("x<SCRIPT>if (a</b) {}</script >y"), "xy");
+        assert_eq!(strip_html_tags("x<script>never closed"), "x");
+        assert_eq!(strip_html_tags("a<!-- unterminated"), "a");
+    }
+
+    #[test]
+    fn attributes_and_literals() {
+        assert_eq!(strip_html_tags("<img alt='a > b' src=\"x.png\">pic"), "pic");
+        assert_eq!(strip_html_tags("if a < b && c <= d or x<"), "if a < b && c <= d or x<");
+        assert_eq!(strip_html_tags("unclosed <span class=x"), "unclosed ");
+    }
+
+    #[test]
+    fn word_counts_ignore_markup() {
+        let html = "<div class=\"post\"><p>Hello <em>wonderful</em> world.</p><p>Bye</p></div>";
+        assert_eq!(word_count(&strip_html_tags(html)), 4);
+    }
+}
+
+#[cfg(test)]
+mod decode_html_entities_tests {
+    use bt_string_utils::html::decode_html_entities;
+
+
+    #[test]
+    fn named() {
+        assert_eq!(decode_html_entities("&lt;&gt;&amp;&quot;&apos;"), "<>&\"'");
+        assert_eq!(decode_html_entities("&Eacute;t&eacute;&nbsp;&euro;&hellip;"), "Été\u{a0}€…");
+        assert_eq!(decode_html_entities("&AMP; &Amp;"), "&AMP; &Amp;");
+    }
+
+    #[test]
+    fn numeric() {
+        assert_eq!(decode_html_entities("&#65;&#x42;&#X43;&#128512;"), "ABC😀");
+        assert_eq!(decode_html_entities("&#xD800;&#1114112;&#99999999999;"), "\u{fffd}\u{fffd}\u{fffd}");
+        assert_eq!(decode_html_entities("&#; &#x; &#12a;"), "&#; &#x; &#12a;");
+    }
+
+    #[test]
+    fn malformed_kept() {
+        assert_eq!(decode_html_entities("&amp"), "&amp");
+        assert_eq!(decode_html_entities("a & b; c"), "a & b; c");
+        assert_eq!(decode_html_entities("&&amp;;"), "&&;");
+        assert_eq!(decode_html_entities("ünï&copy;ode"), "ünï©ode");
+    }
+
+    #[test]
+    fn long_candidates_are_not_scanned() {
+        let padded = format!("&#{}65;", "0".repeat(29));
+        assert_eq!(decode_html_entities(&padded), "A");
+        let too_long = format!("&#{}65;", "0".repeat(30));
+        assert_eq!(decode_html_entities(&too_long), too_long);
+
+        // Bare ampersands far from any ';' decode in linear time
+        let text = format!("{};", "&".repeat(1_000_000));
+        assert_eq!(decode_html_entities(&text), text);
+    }
+}
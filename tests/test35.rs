@@ -0,0 +1,264 @@
+#[cfg(test)]
+mod diff_lines_tests {
+    use bt_string_utils::diff::{diff_lines, DiffKindEnum};
+
+
+    fn lcs_len(a: &[&str], b: &[&str]) -> usize {
+        let mut row = vec![0; b.len() + 1];
+        for x in a {
+            let mut diagonal = 0;
+            for (j, y) in b.iter().enumerate() {
+                let up = row[j + 1];
+                row[j + 1] = if x == y { diagonal + 1 } else { row[j + 1].max(row[j]) };
+                diagonal = up;
+            }
+        }
+        row[b.len()]
+    }
+
+    fn check(old: &str, new: &str) {
+        let old_lines: Vec<&str> = old.split_inclusive('\n').collect();
+        let new_lines: Vec<&str> = new.split_inclusive('\n').collect();
+        let ops = diff_lines(old, new);
+        let (mut rebuilt_old, mut rebuilt_new, mut equal) = (String::new(), String::new(), 0);
+        let (mut i, mut j) = (0, 0);
+        for op in &ops {
+            assert_eq!((op.old.start, op.new.start), (i, j), "{ops:?}");
+            (i, j) = (op.old.end, op.new.end);
+            match op.kind {
+                DiffKindEnum::Equal => {
+                    assert_eq!(old_lines[op.old.clone()], new_lines[op.new.clone()]);
+                    equal += op.old.len();
+                }
+                DiffKindEnum::Delete => assert!(op.new.is_empty()),
+                DiffKindEnum::Insert => assert!(op.old.is_empty()),
+            }
+            rebuilt_old.push_str(&old_lines[op.old.clone()].concat());
+            rebuilt_new.push_str(&new_lines[op.new.clone()].concat());
+        }
+        assert_eq!((rebuilt_old.as_str(), rebuilt_new.as_str()), (old, new));
+        assert_eq!(equal, lcs_len(&old_lines, &new_lines), "{old:?} {new:?}");
+    }
+
+    #[test]
+    fn reconstructs_and_is_minimal() {
+        let texts = ["", "a\n", "a\nb\nc\n", "a\nc\nb\n", "c\nb\na\n", "x\na\nb\nx\nc\n", "a\nb\nc", "b\nb\nb\n", "a\nx\nc\ny\n"];
+        for old in texts {
+            for new in texts {
+                check(old, new);
+            }
+        }
+    }
+
+    #[test]
+    fn minimal_on_pseudo_random_texts() {
+        let mut seed = 0x2545_f491_u32;
+        let mut text = |len: usize| -> String {
+            (0..len)
+                .map(|_| {
+                    seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                    format!("{}\n", (seed >> 16) % 4)
+                })
+                .collect()
+        };
+        for len in [1, 2, 5, 9, 16, 33] {
+            let (old, new) = (text(len), text(len + len / 3));
+            check(&old, &new);
+            check(&new, &old);
+        }
+    }
+
+    #[test]
+    fn large_texts_with_nothing_in_common() {
+        let old: String = (0..4000).map(|i| format!("old {i}\n")).collect();
+        let new: String = (0..4000).map(|i| format!("new {i}\n")).collect();
+        let ops = diff_lines(&old, &new);
+        assert_eq!(ops.iter().map(|op| op.kind).collect::<Vec<_>>(), vec![DiffKindEnum::Delete, DiffKindEnum::Insert]);
+        assert_eq!((ops[0].old.clone(), ops[1].new.clone()), (0..4000, 0..4000));
+    }
+
+    #[test]
+    fn empty_and_identical() {
+        assert!(diff_lines("", "").is_empty());
+        let ops = diff_lines("same\n", "same\n");
+        assert_eq!(ops.len(), 1);
+        assert_eq!(ops[0].kind, DiffKindEnum::Equal);
+    }
+
+    #[test]
+    fn missing_final_newline_is_a_change() {
+        let ops = diff_lines("a\nb\n", "a\nb");
+        assert_eq!(ops.iter().map(|op| op.kind).collect::<Vec<_>>(), vec![DiffKindEnum::Equal, DiffKindEnum::Delete, DiffKindEnum::Insert]);
+    }
+}
+
+#[cfg(test)]
+mod merge3_tests {
+    use bt_string_utils::diff::merge3;
+
+
+    #[test]
+    fn non_overlapping_changes() {
+        let base = "a\nb\nc\nd\ne\n";
+        let merged = merge3(base, "a\nB\nc\nd\ne\n", "a\nb\nc\nd\nE\nf\n");
+        assert!(merged.is_clean());
+        assert_eq!(merged.text, "a\nB\nc\nd\nE\nf\n");
+        let deleted = merge3(base, "a\nc\nd\ne\n", "a\nb\nc\nd\n");
+        assert_eq!(deleted.text, "a\nc\nd\n");
+    }
+
+    #[test]
+    fn identical_changes_merge_once() {
+        let merged = merge3("x\n", "x\ny\n", "x\ny\n");
+        assert!(merged.is_clean());
+        assert_eq!(merged.text, "x\ny\n");
+        assert_eq!(merge3("a\n", "a\n", "a\n").text, "a\n");
+        assert_eq!(merge3("", "", "new\n").text, "new\n");
+    }
+
+    #[test]
+    fn conflicts_are_marked() {
+        let base = "keep\nv=1\nend\n";
+        let merged = merge3(base, "keep\nv=2\nend\n", "keep\nv=3\nw=4\nend\n");
+        assert_eq!(merged.text, "keep\n<<<<<<< ours\nv=2\n=======\nv=3\nw=4\n>>>>>>> theirs\nend\n");
+        assert_eq!(merged.conflicts.len(), 1);
+        let conflict = &merged.conflicts[0];
+        assert_eq!((conflict.line, conflict.base.as_str(), conflict.ours.as_str(), conflict.theirs.as_str()), (2, "v=1\n", "v=2\n", "v=3\nw=4\n"));
+    }
+
+    #[test]
+    fn conflict_lines_and_missing_newline() {
+        let merged = merge3("a\nb\nc\nd", "A\nb\nc\nD", "a2\nb\nc\nd2");
+        assert_eq!(merged.conflicts.len(), 2);
+        assert_eq!(merged.conflicts[0].line, 1);
+        assert_eq!(merged.conflicts[1].line, 8);
+        assert_eq!(merged.text.lines().nth(7), Some("<<<<<<< ours"));
+        assert!(merged.text.ends_with("D\n=======\nd2\n>>>>>>> theirs\n"));
+    }
+
+    #[test]
+    fn delete_versus_edit_conflicts() {
+        let merged = merge3("a\nb\nc\n", "a\nc\n", "a\nB\nc\n");
+        assert_eq!(merged.text, "a\n<<<<<<< ours\n=======\nB\n>>>>>>> theirs\nc\n");
+    }
+}
+
+#[cfg(test)]
+mod unified_diff_tests {
+    use bt_string_utils::diff::{apply_unified_diff, apply_unified_diff_fuzz, format_unified_diff, PatchError};
+
+
+    #[test]
+    fn round_trip() {
+        let texts = ["", "a\n", "a", "a\nb\nc\n", "a\nc\nb\n", "x\na\nb\nx\nc", "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n", "1\n2\nX\n4\n5\n6\n7\n8\nY\n10\n", "b\nb\nb\n"];
+        for old in texts {
+            for new in texts {
+                for context in 0..4 {
+                    let patch = format_unified_diff(old, new, "a", "b", context);
+                    assert_eq!(apply_unified_diff(old, &patch).as_deref(), Ok(new), "{old:?} -> {new:?}\n{patch}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hunk_headers_and_grouping() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+        let new = "1\nTWO\n3\n4\n5\n6\n7\nEIGHT\n9\n";
+        let patch = format_unified_diff(old, new, "a", "b", 1);
+        assert_eq!(patch, "--- a\n+++ b\n@@ -1,3 +1,3 @@\n 1\n-2\n+TWO\n 3\n@@ -7,3 +7,3 @@\n 7\n-8\n+EIGHT\n 9\n");
+        assert_eq!(format_unified_diff(old, new, "a", "b", 3).matches("@@ -").count(), 1);
+        assert_eq!(format_unified_diff("", "x\n", "a", "b", 3), "--- a\n+++ b\n@@ -0,0 +1 @@\n+x\n");
+        assert_eq!(format_unified_diff("same", "same", "a", "b", 3), "");
+        assert_eq!(format_unified_diff("a", "b", "a", "b", 0), "--- a\n+++ b\n@@ -1 +1 @@\n-a\n\\ No newline at end of file\n+b\n\\ No newline at end of file\n");
+    }
+
+    #[test]
+    fn offsets_and_fuzz() {
+        let old = "a\nb\nc\nd\ne\nf\ng\n";
+        let patch = format_unified_diff(old, "a\nb\nc\nD\ne\nf\ng\n", "a", "b", 3);
+        assert_eq!(apply_unified_diff(&format!("new\n{old}"), &patch).unwrap(), "new\na\nb\nc\nD\ne\nf\ng\n");
+        let drifted = "A\nb\nc\nd\ne\nf\nG\n";
+        assert_eq!(apply_unified_diff(drifted, &patch).unwrap(), "A\nb\nc\nD\ne\nf\nG\n");
+        assert_eq!(apply_unified_diff_fuzz(drifted, &patch, 0), Err(PatchError::HunkFailed { hunk: 1 }));
+        assert_eq!(apply_unified_diff("x\ny\n", &patch), Err(PatchError::HunkFailed { hunk: 1 }));
+    }
+
+    #[test]
+    fn gnu_patch_input() {
+        let patch = "diff -u a b\n--- a\t2024-01-01\n+++ b\t2024-01-02\n@@ -2 +2,2 @@\n-two\n+2\n+2.5\n@@ -4,0 +5 @@\n+five\n";
+        assert_eq!(apply_unified_diff("one\ntwo\nthree\nfour\n", patch).unwrap(), "one\n2\n2.5\nthree\nfour\nfive\n");
+        let crlf = "@@ -1,2 +1,2 @@\r\n a\r\n-b\r\n+B\r\n";
+        assert_eq!(apply_unified_diff("a\r\nb\r\n", crlf).unwrap(), "a\r\nB\r\n");
+        assert_eq!(apply_unified_diff("keep", "").unwrap(), "keep");
+    }
+
+    #[test]
+    fn malformed_patches() {
+        assert_eq!(apply_unified_diff("a\n", "@@ -1 +1 @@\n*a\n"), Err(PatchError::Malformed { line: 2 }));
+        assert_eq!(apply_unified_diff("a\n", "@@ -x +1 @@\n"), Err(PatchError::Malformed { line: 1 }));
+        assert_eq!(apply_unified_diff("a\nb\n", "@@ -1,2 +1,2 @@\n a\n"), Err(PatchError::Malformed { line: 2 }));
+        assert_eq!(PatchError::HunkFailed { hunk: 2 }.to_string(), "hunk 2 does not apply");
+    }
+}
+
+#[cfg(test)]
+mod diff_options_tests {
+    use bt_string_utils::diff::{diff_lines, diff_lines_with_options, DiffKindEnum, DiffOp, DiffOptions};
+
+
+    fn kinds(ops: &[DiffOp]) -> Vec<DiffKindEnum> {
+        ops.iter().map(|op| op.kind).collect()
+    }
+
+    #[test]
+    fn default_options_match_diff_lines() {
+        for (old, new) in [("a\nb\nc\n", "a\nc\nd\n"), ("", "x\n"), ("a\n\nb\n", "a\nb\n")] {
+            assert_eq!(diff_lines_with_options(old, new, DiffOptions::default()), diff_lines(old, new));
+        }
+    }
+
+    #[test]
+    fn case_and_unicode() {
+        let ignore_case = DiffOptions { ignore_case: true, ..DiffOptions::default() };
+        assert_eq!(kinds(&diff_lines_with_options("Straße\nX\n", "STRASSE\nx\n", ignore_case)), vec![DiffKindEnum::Equal]);
+        let nfc = DiffOptions { normalize_unicode: true, ..DiffOptions::default() };
+        assert_eq!(kinds(&diff_lines_with_options("cafe\u{301}\n", "café\n", nfc)), vec![DiffKindEnum::Equal]);
+        assert_eq!(kinds(&diff_lines_with_options("Cafe\u{301}\n", "café\n", nfc)), vec![DiffKindEnum::Delete, DiffKindEnum::Insert]);
+    }
+
+    #[test]
+    fn whitespace() {
+        let options = DiffOptions { ignore_whitespace: true, ..DiffOptions::default() };
+        let ops = diff_lines_with_options("let x = 1;\r\nlet y=2;\n", "let x=1;\n  let  y = 3;", options);
+        assert_eq!(kinds(&ops), vec![DiffKindEnum::Equal, DiffKindEnum::Delete, DiffKindEnum::Insert]);
+        assert_eq!((ops[1].old.clone(), ops[2].new.clone()), (1..2, 1..2));
+    }
+
+    #[test]
+    fn blank_lines_keep_original_spans() {
+        let options = DiffOptions { ignore_blank_lines: true, ..DiffOptions::default() };
+        let old = "a\nb\n\nc\nd\n";
+        let new = "\na\n\n\nb\nc\nX\n\n";
+        let ops = diff_lines_with_options(old, new, options);
+        assert_eq!(kinds(&ops), vec![DiffKindEnum::Equal, DiffKindEnum::Delete, DiffKindEnum::Insert]);
+        assert_eq!((ops[0].old.clone(), ops[0].new.clone()), (0..4, 0..6));
+        assert_eq!((ops[1].old.clone(), ops[1].new.clone()), (4..5, 6..6));
+        assert_eq!((ops[2].old.clone(), ops[2].new.clone()), (5..5, 6..8));
+        assert_eq!(kinds(&diff_lines_with_options("\n \n", "", options)), vec![DiffKindEnum::Equal]);
+    }
+
+    #[test]
+    fn blank_lines_in_a_real_change_stay_in_it() {
+        let options = DiffOptions { ignore_blank_lines: true, ..DiffOptions::default() };
+        let ops = diff_lines_with_options("a\n\nb\n", "a\nX\nb\n", options);
+        assert_eq!(kinds(&ops), vec![DiffKindEnum::Equal, DiffKindEnum::Delete, DiffKindEnum::Insert, DiffKindEnum::Equal]);
+        assert_eq!((ops[0].old.clone(), ops[0].new.clone()), (0..1, 0..1));
+        assert_eq!((ops[1].old.clone(), ops[1].new.clone()), (1..2, 1..1));
+        assert_eq!((ops[2].old.clone(), ops[2].new.clone()), (2..2, 1..2));
+        assert_eq!((ops[3].old.clone(), ops[3].new.clone()), (2..3, 2..3));
+
+        let ops = diff_lines_with_options("a\nb\n\n\nc\n", "a\n\nb\nc\n", options);
+        assert_eq!(ops, vec![DiffOp { kind: DiffKindEnum::Equal, old: 0..5, new: 0..4 }]);
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod extract_urls_tests {
+    use bt_string_utils::analyzer::tokenize;
+    use bt_string_utils::extract::extract_urls;
+
+
+    #[test]
+    fn trailing_punctuation() {
+        assert_eq!(extract_urls("Go to https://example.com/a/b/. Or http://x.io?q=1!"), vec!["https://example.com/a/b/", "http://x.io?q=1"]);
+        assert_eq!(extract_urls("\"https://example.com\", 'www.example.com';"), vec!["https://example.com", "www.example.com"]);
+    }
+
+    #[test]
+    fn parentheses_and_markdown() {
+        assert_eq!(extract_urls("(https://a.com/x)"), vec!["https://a.com/x"]);
+        assert_eq!(extract_urls("[https://a.com](https://b.com/(c))."), vec!["https://a.com", "https://b.com/(c)"]);
+        assert_eq!(extract_urls("<https://a.com/q?x=[1]>"), vec!["https://a.com/q?x=[1]"]);
+        assert_eq!(extract_urls("![img](ftp://files.example.com/pic.png \"title\")"), vec!["ftp://files.example.com/pic.png"]);
+    }
+
+    #[test]
+    fn boundaries() {
+        assert_eq!(extract_urls("HTTPS://EXAMPLE.COM and xhttp://no.com and awww.no.com"), vec!["HTTPS://EXAMPLE.COM"]);
+        assert!(extract_urls("http:// www. https://.").is_empty());
+        assert_eq!(extract_urls("https://例え.jp/パス"), vec!["https://例え.jp/パス"]);
+        assert!(extract_urls("").is_empty());
+    }
+
+    #[test]
+    fn consistent_with_tokenize() {
+        let text = "See (https://example.com/a/), then <https://docs.rs/>.";
+        let urls = extract_urls(text);
+        let tokens = tokenize(text);
+        assert!(urls.iter().all(|url| tokens.contains(url)));
+        assert_eq!(tokens, vec!["See", "https://example.com/a/", "then", "https://docs.rs/"]);
+    }
+}
+
+#[cfg(test)]
+mod extract_emails_tests {
+    use bt_string_utils::extract::extract_emails;
+
+
+    #[test]
+    fn finds_addresses() {
+        assert_eq!(extract_emails("Contact a.b@c.com, x_y%z+1@sub.d-e.org."), vec!["a.b@c.com", "x_y%z+1@sub.d-e.org"]);
+        assert_eq!(extract_emails("(me@site.io)"), vec!["me@site.io"]);
+    }
+
+    #[test]
+    fn rejects_non_addresses() {
+        assert!(extract_emails("@user a@b .a@b.com a@.com a@b..com").is_empty());
+        assert!(extract_emails("ftp://user@files.example.com/x").is_empty());
+    }
+}
+
+#[cfg(test)]
+mod social_tags_tests {
+    use bt_string_utils::extract::{extract_hashtags, extract_mentions, TagMatch};
+
+    fn names<'a>(tags: &[TagMatch<'a>]) -> Vec<&'a str> {
+        tags.iter().map(|tag| tag.name).collect()
+    }
+
+
+    #[test]
+    fn hashtags() {
+        assert_eq!(names(&extract_hashtags("#first. (#second) #naïve_tag,#ok")), vec!["first", "second", "naïve_tag", "ok"]);
+        assert_eq!(names(&extract_hashtags("＃全角 and #भारत")), vec!["全角", "भारत"]);
+        assert!(extract_hashtags("# #123 ##double #a#b &#39; https://a.com/#frag").is_empty());
+    }
+
+    #[test]
+    fn mentions() {
+        assert_eq!(names(&extract_mentions("@alice: thanks @Bob_99! (@ünal)")), vec!["alice", "Bob_99", "ünal"]);
+        assert!(extract_mentions("@ @_ @9lives a@b.com @x@y.social ftp://me@host.org").iter().all(|m| m.name == "_"));
+    }
+
+    #[test]
+    fn spans() {
+        let text = "é #tag @user";
+        let tag = extract_hashtags(text)[0];
+        assert_eq!(tag, TagMatch { name: "tag", start: 3, end: 7 });
+        let mention = extract_mentions(text)[0];
+        assert_eq!(&text[mention.start..mention.end], "@user");
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tag_chunks_tests {
+    use bt_string_utils::script::{tag_chunks, ChunkTag, ScriptEnum};
+    use bt_string_utils::splitter::split_into_chunks;
+
+
+    fn lang(text: &str) -> Option<&'static str> {
+        tag_chunks(&[text])[0].detected_lang
+    }
+
+    #[test]
+    fn latin_languages() {
+        assert_eq!(lang("Where is the station? It is not far from here."), Some("en"));
+        assert_eq!(lang("El perro de mi hermano es muy grande y come con nosotros."), Some("es"));
+        assert_eq!(lang("Je ne sais pas où est la gare, mais elle est loin."), Some("fr"));
+        assert_eq!(lang("Ich weiß nicht, wo der Bahnhof ist, aber er ist weit."), Some("de"));
+        assert_eq!(lang("Non so dove sia la stazione, ma è lontana."), Some("it"));
+        assert_eq!(lang("Eu não sei onde fica a estação, mas é longe."), Some("pt"));
+        assert_eq!(lang("Ik weet niet waar het station is, maar het is ver."), Some("nl"));
+        // Too few stopwords, or a tie
+        assert_eq!(lang("Rust"), None);
+        assert_eq!(lang("de la"), None);
+    }
+
+    #[test]
+    fn other_scripts() {
+        assert_eq!(lang("Добрий вечір, як справи? Дякую, все добре."), Some("uk"));
+        assert_eq!(lang("Љубав и пријатељство"), Some("sr"));
+        assert_eq!(lang("Καλημέρα κόσμε"), Some("el"));
+        assert_eq!(lang("שלום עולם"), Some("he"));
+        assert_eq!(lang("مرحبا بالعالم"), Some("ar"));
+        assert_eq!(lang("سلام، چطوری؟"), Some("fa"));
+        assert_eq!(lang("آپ کیسے ہیں؟ میں ٹھیک ہوں"), Some("ur"));
+        assert_eq!(lang("नमस्ते दुनिया"), Some("hi"));
+        assert_eq!(lang("สวัสดีครับ"), Some("th"));
+        assert_eq!(lang("我们今天去北京"), Some("zh"));
+        assert_eq!(lang("ありがとうございます"), Some("ja"));
+        assert_eq!(lang("안녕하세요 세계"), Some("ko"));
+        assert_eq!(lang("ᚠᚢᚦᚨᚱᚲ"), None);
+    }
+
+    #[test]
+    fn dominant_script_and_cjk_ratio() {
+        let tags = tag_chunks(&["Rust 入門", "2024-01-01 🙂", ""]);
+        assert_eq!(tags[0], ChunkTag { dominant_script: ScriptEnum::Latin, detected_lang: None, cjk_ratio: 2.0 / 6.0 });
+        assert_eq!(tags[1], ChunkTag { dominant_script: ScriptEnum::Common, detected_lang: None, cjk_ratio: 0.0 });
+        assert_eq!(tags[2].dominant_script, ScriptEnum::Common);
+        // Ties go to the first script of the enum
+        assert_eq!(tag_chunks(&["ab αβ"])[0].dominant_script, ScriptEnum::Latin);
+        assert_eq!(tag_chunks(&["東京タワー"])[0].cjk_ratio, 1.0);
+    }
+
+    #[test]
+    fn tags_chunker_output() {
+        let document = format!("{}{}", "The sun is up and the sky is blue. ".repeat(3), "今日はとても良い天気ですね。".repeat(3));
+        let chunks = split_into_chunks(&document, 105);
+        let tags = tag_chunks(&chunks);
+        assert_eq!(tags.len(), chunks.len());
+        assert_eq!(tags.first().unwrap().detected_lang, Some("en"));
+        assert_eq!(tags.last().unwrap().detected_lang, Some("ja"));
+    }
+}
@@ -159,4 +159,79 @@ mod finder_n_tests {
         assert_eq!(get_last_n_chars(s, 4), "abc💙");
         assert_eq!(get_last_n_chars(s, 3), "bc💙");
     }    
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod incremental_search_tests {
+    use bt_string_utils::finder::{find_from, rfind_before, Searcher};
+
+
+    #[test]
+    fn forward_search_visits_all_matches() {
+        let s = "abcabcabc";
+        let searcher = Searcher::new("bc");
+        let mut found = Vec::new();
+        let mut pos = 0;
+        while let Some(i) = searcher.find_from(s, pos) {
+            found.push(i);
+            pos = i + 1;
+        }
+        assert_eq!(found, vec![1, 4, 7]);
+    }
+
+    #[test]
+    fn backward_search_visits_all_matches() {
+        let s = "abcabcabc";
+        let searcher = Searcher::new("bc");
+        let mut found = Vec::new();
+        let mut end = s.len();
+        while let Some(i) = searcher.rfind_before(s, end) {
+            found.push(i);
+            end = i + 1;
+        }
+        assert_eq!(found, vec![7, 4, 1]);
+    }
+
+    #[test]
+    fn agrees_with_std() {
+        let s = "the quick brown fox jumps over the lazy dog, the end";
+        for needle in ["the", "o", "fox", "dog,", "missing", "d"] {
+            for start in 0..=s.len() {
+                assert_eq!(find_from(s, needle, start), s[start..].find(needle).map(|i| i + start));
+                assert_eq!(rfind_before(s, needle, start), s[..start].rfind(needle));
+            }
+        }
+    }
+
+    #[test]
+    fn unicode_haystack_and_needle() {
+        let s = "café → café → thé";
+        assert_eq!(find_from(s, "é", 0), Some(3));
+        assert_eq!(find_from(s, "é", 5), Some(13));
+        assert_eq!(find_from(s, "→", 0), Some(6));
+        assert_eq!(rfind_before(s, "café", s.len()), Some(10));
+        // Start inside a multi-byte character does not panic
+        assert_eq!(find_from(s, "é", 4), find_from(s, "é", 5));
+    }
+
+    #[test]
+    fn empty_needle() {
+        assert_eq!(find_from("abc", "", 2), Some(2));
+        assert_eq!(rfind_before("abc", "", 2), Some(2));
+        assert_eq!(find_from("abc", "", 4), None);
+        // Inside a multi-byte character the match moves to a character boundary
+        let s = "aé→b";
+        assert_eq!(rfind_before(s, "", 2), Some(1));
+        assert_eq!(rfind_before(s, "", 5), Some(3));
+        assert_eq!(find_from(s, "", 2), Some(3));
+        assert_eq!(find_from(s, "", 4), Some(6));
+        for i in 0..=s.len() {
+            assert!(s.is_char_boundary(rfind_before(s, "", i).unwrap()));
+            assert!(s.is_char_boundary(find_from(s, "", i).unwrap()));
+        }
+    }
+
+    #[test]
+    fn needle_accessor() {
+        assert_eq!(Searcher::new("ñu").needle(), "ñu");
+    }
+}
@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod elide_middle_tests {
+    use bt_string_utils::formatter::{display_width, elide_middle};
+
+
+    #[test]
+    fn keeps_short_strings_unchanged() {
+        assert_eq!(elide_middle("short", 5, "…"), "short");
+        assert_eq!(elide_middle("", 0, "…"), "");
+    }
+
+    #[test]
+    fn keeps_head_and_tail() {
+        assert_eq!(elide_middle("0123456789", 7, "…"), "012…789");
+        assert_eq!(elide_middle("0123456789", 6, "…"), "012…89");
+    }
+
+    #[test]
+    fn multi_char_ellipsis() {
+        assert_eq!(elide_middle("abcdefghij", 7, "..."), "ab...ij");
+    }
+
+    #[test]
+    fn ellipsis_wider_than_limit() {
+        assert_eq!(elide_middle("abcdefghij", 2, "..."), "..");
+        assert_eq!(elide_middle("abcdefghij", 0, "…"), "");
+    }
+
+    #[test]
+    fn result_never_exceeds_width() {
+        let s = "路径/很长的/目录/文件.txt";
+        for width in 0..display_width(s) {
+            assert!(display_width(&elide_middle(s, width, "…")) <= width);
+        }
+    }
+
+    #[test]
+    fn keeps_combining_marks_with_base() {
+        let s = "e\u{301}e\u{301}e\u{301}e\u{301}e\u{301}";
+        assert_eq!(elide_middle(s, 3, "…"), "e\u{301}…e\u{301}");
+    }
+}
+
+#[cfg(test)]
+mod osc8_link_tests {
+    use bt_string_utils::formatter::{display_width, elide_middle, osc8_link, pad_to_width, truncate_display, AlignmentEnum};
+    use bt_string_utils::template::format_positional;
+    use bt_string_utils::wrap::wrap;
+
+
+    #[test]
+    fn link_has_text_width() {
+        let link = osc8_link("click here", "https://example.com/a/very/long/url");
+        assert_eq!(display_width(&link), 10);
+    }
+
+    #[test]
+    fn styles_have_no_width() {
+        assert_eq!(display_width("\x1b[31;1mred\x1b[0m"), 3);
+        // OSC terminated with BEL
+        assert_eq!(display_width("\x1b]0;title\x07ok"), 2);
+    }
+
+    #[test]
+    fn padding_ignores_link_sequences() {
+        let link = osc8_link("docs", "https://example.com");
+        let padded = pad_to_width(&link, 8, AlignmentEnum::Left);
+        assert_eq!(display_width(&padded), 8);
+        assert!(padded.ends_with("    "));
+    }
+
+    #[test]
+    fn padding_wide_strings_unchanged() {
+        assert_eq!(pad_to_width("toolong", 3, AlignmentEnum::Right), "toolong");
+    }
+
+    #[test]
+    fn elide_never_cuts_inside_escape_sequence() {
+        let s = format!("start {} end", osc8_link("middle", "https://example.com"));
+        let elided = elide_middle(&s, 9, "…");
+        assert_eq!(display_width(&elided), 9);
+        assert!(elided.starts_with("star"));
+        assert!(elided.ends_with(" end"));
+        assert!(!elided.contains("https:/…"));
+    }
+
+    #[test]
+    fn elide_closes_cut_link_and_style() {
+        let s = format!("start {} end-of-it", osc8_link("middle", "https://example.com"));
+        let elided = elide_middle(&s, 14, "…");
+        assert_eq!(elided, "start \x1b]8;;https://example.com\x1b\\m\x1b]8;;\x1b\\…-of-it");
+        assert_eq!(elide_middle("\x1b[1mbold text\x1b[0m tail", 8, "…"), "\x1b[1mbold\x1b[0m…ail");
+    }
+
+    #[test]
+    fn truncation_closes_cut_link_and_style() {
+        let link = osc8_link("documentation", "https://example.com");
+        assert_eq!(truncate_display(&link, 5), "\x1b]8;;https://example.com\x1b\\docu\x1b]8;;\x1b\\…");
+        assert_eq!(truncate_display("\x1b[31;1mred alert\x1b[0m", 4), "\x1b[31;1mred\x1b[0m…");
+        // Closed before the cut: nothing to add
+        assert_eq!(truncate_display("\x1b[1mab\x1b[0m cdef", 5), "\x1b[1mab\x1b[0m c…");
+    }
+
+    #[test]
+    fn wrap_closes_link_on_each_broken_line() {
+        let lines = wrap(&osc8_link("abcdefgh", "https://example.com"), 4);
+        assert_eq!(lines[0], "\x1b]8;;https://example.com\x1b\\abcd\x1b]8;;\x1b\\");
+        assert!(lines.iter().all(|line| display_width(line) <= 4));
+    }
+
+    #[test]
+    fn format_max_closes_cut_link() {
+        let link = osc8_link("documentation", "https://example.com");
+        assert_eq!(format_positional("[{0:.3}]", &[&link]).unwrap(), "[\x1b]8;;https://example.com\x1b\\doc\x1b]8;;\x1b\\]");
+        assert_eq!(format_positional("[{0:.3}]", &["\x1b[4munderlined"]).unwrap(), "[\x1b[4mund\x1b[0m]");
+    }
+}
+
+#[cfg(test)]
+mod strip_ansi_tests {
+    use std::borrow::Cow;
+    use bt_string_utils::analyzer::word_count;
+    use bt_string_utils::formatter::{display_width, strip_ansi};
+
+
+    #[test]
+    fn borrowed_without_escapes() {
+        assert!(matches!(strip_ansi(""), Cow::Borrowed("")));
+        assert!(matches!(strip_ansi("你好 world"), Cow::Borrowed("你好 world")));
+        assert!(matches!(strip_ansi("lone \x1b escape"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn csi_and_osc() {
+        assert_eq!(strip_ansi("\x1b[38;5;196m\x1b[1mA\x1b[0m\x1b[2K"), "A");
+        assert_eq!(strip_ansi("\x1b]0;window title\x07$ ls"), "$ ls");
+        assert_eq!(strip_ansi("x\x1b]8;;http://a\x1b\\y\x1b]8;;\x1b\\z"), "xyz");
+        assert_eq!(strip_ansi("cut \x1b[31"), "cut ");
+    }
+
+    #[test]
+    fn terminal_log_metrics() {
+        let log = "\x1b[32mINFO\x1b[0m server started\r\n\x1b[33mWARN\x1b[0m slow réponse";
+        let stripped = strip_ansi(log);
+        assert_eq!(word_count(&stripped), word_count("INFO server started\r\nWARN slow réponse"));
+        assert_eq!(display_width(&stripped), display_width(log));
+    }
+}
+
+#[cfg(test)]
+mod highlight_tests {
+    use bt_string_utils::formatter::{highlight, strip_ansi};
+
+
+    #[test]
+    fn merges_and_orders_ranges() {
+        assert_eq!(highlight("abcdef", &[3..5, 0..1, 4..6]), "\x1b[1;31ma\x1b[0mbc\x1b[1;31mdef\x1b[0m");
+        assert_eq!(highlight("abcdef", &[0..2, 2..3]), "\x1b[1;31mabc\x1b[0mdef");
+    }
+
+    #[test]
+    fn ignores_invalid_ranges() {
+        assert_eq!(highlight("héllo", &[1..2, 3..3, 4..99]), "héllo");
+        assert_eq!(highlight("héllo", &[1..3, 3..3]), "h\x1b[1;31mé\x1b[0mllo");
+        assert_eq!(strip_ansi(&highlight("some text", &[0..4, 5..9])), "some text");
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod anonymize_tests {
+    use bt_string_utils::anonymizer::{anonymize, Anonymizer};
+
+    fn luhn_valid(number: &str) -> bool {
+        let digits: Vec<u32> = number.chars().filter_map(|c| c.to_digit(10)).collect();
+        let sum: u32 = digits
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, &d)| if i % 2 == 1 { if d * 2 > 9 { d * 2 - 9 } else { d * 2 } } else { d })
+            .sum();
+        sum.is_multiple_of(10)
+    }
+
+
+    #[test]
+    fn is_deterministic() {
+        let options = Anonymizer::new(7);
+        let text = "Call Maria at 555-0134.";
+        assert_eq!(anonymize(text, &options), anonymize(text, &options));
+    }
+
+    #[test]
+    fn different_seeds_give_different_fakes() {
+        let text = "Hello Maria";
+        assert_ne!(anonymize(text, &Anonymizer::new(1)), anonymize(text, &Anonymizer::new(2)));
+    }
+
+    #[test]
+    fn names_keep_length_and_capitalization() {
+        let out = anonymize("Hello John", &Anonymizer::new(3));
+        let fake = out.strip_prefix("Hello ").unwrap();
+        assert_ne!(fake, "John");
+        assert_eq!(fake.len(), 4);
+        assert!(fake.chars().next().unwrap().is_uppercase());
+        assert!(fake.chars().skip(1).all(|c| c.is_lowercase()));
+    }
+
+    #[test]
+    fn same_name_gets_same_fake() {
+        let out = anonymize("Hello John and John", &Anonymizer::new(3));
+        let words: Vec<&str> = out.split(' ').collect();
+        assert_eq!(words[1], words[3]);
+    }
+
+    #[test]
+    fn sentence_start_kept_unless_known() {
+        let mut options = Anonymizer::new(3);
+        assert!(anonymize("John left.", &options).starts_with("John"));
+        options.known_names.push("John".to_owned());
+        assert!(!anonymize("John left.", &options).starts_with("John"));
+    }
+
+    #[test]
+    fn emails_keep_shape_and_tld() {
+        let out = anonymize("mail: first.last@company.org", &Anonymizer::new(5));
+        let email = out.strip_prefix("mail: ").unwrap();
+        assert_ne!(email, "first.last@company.org");
+        assert_eq!(email.len(), "first.last@company.org".len());
+        assert_eq!(&email[5..6], ".");
+        assert_eq!(&email[10..11], "@");
+        assert!(email.ends_with(".org"));
+    }
+
+    #[test]
+    fn numbers_keep_grouping() {
+        let out = anonymize("phone 555-0134", &Anonymizer::new(9));
+        let number = out.strip_prefix("phone ").unwrap();
+        assert_eq!(number.len(), 8);
+        assert_eq!(&number[3..4], "-");
+        assert!(number.chars().filter(|c| *c != '-').all(|c| c.is_ascii_digit()));
+    }
+
+    #[test]
+    fn cards_keep_luhn_validity() {
+        for seed in 0..20 {
+            let out = anonymize("4111 1111 1111 1111", &Anonymizer::new(seed));
+            assert_ne!(out, "4111 1111 1111 1111");
+            assert_eq!(&out[4..5], " ");
+            assert!(luhn_valid(&out), "{out}");
+        }
+    }
+
+    #[test]
+    fn disabled_categories_are_kept() {
+        let mut options = Anonymizer::new(1);
+        options.names = false;
+        options.emails = false;
+        options.numbers = false;
+        let text = "Hi Bob, mail bob@x.io or call 123.";
+        assert_eq!(anonymize(text, &options), text);
+    }
+
+    #[test]
+    fn preserves_unicode_text() {
+        let text = "naïve café 🙂";
+        assert_eq!(anonymize(text, &Anonymizer::new(1)), text);
+    }
+}
+
+#[cfg(test)]
+mod pseudonymize_tests {
+    use bt_string_utils::anonymizer::{pseudonymize, pseudonymize_with_prefix};
+    use bt_string_utils::hasher::siphash24;
+
+    const KEY: [u8; 16] = *b"secret-key-01234";
+
+
+    #[test]
+    fn siphash_reference_vectors() {
+        let key: [u8; 16] = core::array::from_fn(|i| i as u8);
+        assert_eq!(siphash24(&key, &[]), 0x726fdb47dd0e0e31);
+        let input: Vec<u8> = (0..8).collect();
+        assert_eq!(siphash24(&key, &input), 0x93f5f5799a932462);
+    }
+
+    #[test]
+    fn stable_for_same_input_and_key() {
+        assert_eq!(pseudonymize("alice", &KEY), pseudonymize("alice", &KEY));
+    }
+
+    #[test]
+    fn depends_on_key() {
+        let other = *b"another-key-5678";
+        assert_ne!(pseudonymize("alice", &KEY), pseudonymize("alice", &other));
+    }
+
+    #[test]
+    fn is_hexadecimal() {
+        let token = pseudonymize("alice", &KEY);
+        assert_eq!(token.len(), 16);
+        assert!(token.chars().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+    }
+
+    #[test]
+    fn prefix_is_prepended() {
+        let token = pseudonymize_with_prefix("alice", &KEY, "usr_");
+        assert_eq!(token, format!("usr_{}", pseudonymize("alice", &KEY)));
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod trigram_index_tests {
+    use bt_string_utils::matcher::{trigram_similarity, TrigramIndex};
+
+
+    #[test]
+    fn similarity_is_symmetric() {
+        assert_eq!(trigram_similarity("kitten", "sitting"), trigram_similarity("sitting", "kitten"));
+    }
+
+    #[test]
+    fn similarity_ignores_case() {
+        assert_eq!(trigram_similarity("README", "readme"), 1.0);
+    }
+
+    #[test]
+    fn empty_strings_have_no_similarity() {
+        assert_eq!(trigram_similarity("", ""), 0.0);
+        assert_eq!(trigram_similarity("", "abc"), 0.0);
+    }
+
+    #[test]
+    fn search_matches_brute_force() {
+        let items = ["src/main.rs", "src/lib.rs", "tests/tests.rs", "README.md", "Cargo.toml"];
+        let index = TrigramIndex::build(items);
+        let hits = index.search("src/lib", 0.2);
+
+        let mut expected: Vec<(usize, f64)> = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (i, trigram_similarity("src/lib", item)))
+            .filter(|(_, s)| *s >= 0.2)
+            .collect();
+        expected.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        assert_eq!(hits, expected);
+        assert_eq!(hits[0].0, 1);
+    }
+
+    #[test]
+    fn results_sorted_by_score() {
+        let index = TrigramIndex::build(vec!["apple".to_owned(), "apples".to_owned(), "applesauce".to_owned()]);
+        let hits = index.search("apple", 0.0);
+        assert_eq!(hits.len(), 3);
+        assert!(hits.windows(2).all(|w| w[0].1 >= w[1].1));
+        assert_eq!(hits[0], (0, 1.0));
+    }
+
+    #[test]
+    fn empty_index() {
+        let index = TrigramIndex::build(Vec::<String>::new());
+        assert!(index.is_empty());
+        assert!(index.search("anything", 0.0).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod front_code_tests {
+    use bt_string_utils::compressor::front_code;
+
+
+    fn dictionary() -> Vec<String> {
+        let mut words: Vec<String> = (0..100).map(|i| format!("keyword_{i:03}")).collect();
+        words.extend(["naïve", "naïveté", "über", "überall"].map(String::from));
+        words.sort();
+        words
+    }
+
+    #[test]
+    fn round_trip() {
+        let words = dictionary();
+        let coded = front_code(&words);
+        assert_eq!(coded.len(), words.len());
+        assert_eq!(coded.decode(), words);
+    }
+
+    #[test]
+    fn random_access() {
+        let words = dictionary();
+        let coded = front_code(&words);
+        for (i, w) in words.iter().enumerate() {
+            assert_eq!(coded.get(i).as_ref(), Some(w));
+        }
+        assert_eq!(coded.get(words.len()), None);
+    }
+
+    #[test]
+    fn contains_uses_sorted_order() {
+        let words = dictionary();
+        let coded = front_code(&words);
+        assert!(words.iter().all(|w| coded.contains(w)));
+        assert!(!coded.contains("keyword_100"));
+        assert!(!coded.contains("a"));
+        assert!(!coded.contains("zzz"));
+    }
+
+    #[test]
+    fn compresses_shared_prefixes() {
+        let words = dictionary();
+        let coded = front_code(&words);
+        let raw: usize = words.iter().map(String::len).sum();
+        assert!(coded.encoded_size() < raw / 2);
+    }
+
+    #[test]
+    fn empty_list() {
+        let coded = front_code::<&str>(&[]);
+        assert!(coded.is_empty());
+        assert_eq!(coded.get(0), None);
+        assert!(!coded.contains(""));
+        assert!(coded.decode().is_empty());
+    }
+
+    #[test]
+    fn unicode_prefixes_stay_valid() {
+        // "é" and "è" share their first UTF-8 byte
+        let words = vec!["é".to_owned(), "è".to_owned()];
+        assert_eq!(front_code(&words).decode(), words);
+    }
+}
@@ -0,0 +1,478 @@
+#[cfg(test)]
+mod cluster_lines_tests {
+    use bt_string_utils::hasher::simhash;
+    use bt_string_utils::log_analyzer::cluster_similar_lines;
+    use bt_string_utils::matcher::{levenshtein, levenshtein_similarity};
+
+
+    #[test]
+    fn levenshtein_basics() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("abc", "abc"), 0);
+        assert_eq!(levenshtein("abc", ""), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("🙂a", "🙃a"), 1);
+    }
+
+    #[test]
+    fn levenshtein_similarity_range() {
+        assert_eq!(levenshtein_similarity("abc", "xyz"), 0.0);
+        assert_eq!(levenshtein_similarity("abc", "abc"), 1.0);
+    }
+
+    #[test]
+    fn simhash_identical_texts() {
+        assert_eq!(simhash("same text"), simhash("same text"));
+        assert_ne!(simhash("ab"), 0);
+    }
+
+    #[test]
+    fn clusters_repetitive_lines() {
+        let lines = vec![
+            "GET /api/users/1 200 12ms",
+            "GET /api/users/2 200 15ms",
+            "worker 3 restarted",
+            "GET /api/users/3 200 11ms",
+            "worker 4 restarted",
+        ];
+        assert_eq!(cluster_similar_lines(&lines, 0.8), vec![vec![0, 1, 3], vec![2, 4]]);
+    }
+
+    #[test]
+    fn strict_threshold_separates_lines() {
+        let lines = ["alpha 1", "alpha 2"];
+        assert_eq!(cluster_similar_lines(&lines, 1.0), vec![vec![0], vec![1]]);
+        assert_eq!(cluster_similar_lines(&lines, 0.5), vec![vec![0, 1]]);
+    }
+
+    #[test]
+    fn empty_input() {
+        assert!(cluster_similar_lines::<&str>(&[], 0.9).is_empty());
+    }
+
+    #[test]
+    fn identical_lines_always_cluster() {
+        let lines = vec!["x".to_owned(), "x".to_owned(), "".to_owned(), "".to_owned()];
+        assert_eq!(cluster_similar_lines(&lines, 1.0), vec![vec![0, 1], vec![2, 3]]);
+    }
+
+    #[test]
+    fn low_threshold_is_not_capped_by_simhash() {
+        // 21 SimHash bits apart, but 60% similar
+        assert!((simhash("hello") ^ simhash("help")).count_ones() > 20);
+        assert_eq!(cluster_similar_lines(&["hello", "help"], 0.6), vec![vec![0, 1]]);
+        assert_eq!(cluster_similar_lines(&["hello", "help"], 0.7), vec![vec![0], vec![1]]);
+    }
+}
+
+#[cfg(test)]
+mod log_template_tests {
+    use bt_string_utils::log_analyzer::{extract_log_template, TEMPLATE_WILDCARD};
+
+
+    #[test]
+    fn numbers_are_variables() {
+        let template = extract_log_template(&["request took 35 ms"]);
+        assert_eq!(template.patterns, vec![format!("request took {TEMPLATE_WILDCARD} ms")]);
+        assert_eq!(template.bindings[0].variables, vec!["35"]);
+    }
+
+    #[test]
+    fn differing_words_become_variables() {
+        let lines = ["session opened for alice", "session opened for bob", "session closed for bob"];
+        let template = extract_log_template(&lines);
+        assert_eq!(template.patterns, vec!["session <*> for <*>"]);
+        assert_eq!(template.bindings[0].variables, vec!["opened", "alice"]);
+        assert_eq!(template.bindings[2].variables, vec!["closed", "bob"]);
+    }
+
+    #[test]
+    fn different_lengths_never_merge() {
+        let lines = ["disk full", "disk full now"];
+        let template = extract_log_template(&lines);
+        assert_eq!(template.patterns.len(), 2);
+        assert_eq!(template.bindings[1].pattern, 1);
+    }
+
+    #[test]
+    fn dissimilar_lines_get_own_template() {
+        let lines = ["alpha beta gamma", "one two three"];
+        let template = extract_log_template(&lines);
+        assert_eq!(template.patterns, vec!["alpha beta gamma", "one two three"]);
+    }
+
+    #[test]
+    fn empty_and_blank_lines() {
+        assert!(extract_log_template::<&str>(&[]).patterns.is_empty());
+        let template = extract_log_template(&["", "   "]);
+        assert_eq!(template.patterns, vec![""]);
+        assert_eq!(template.bindings.len(), 2);
+    }
+}
+
+#[cfg(test)]
+mod canonical_fingerprint_tests {
+    use bt_string_utils::hasher::{canonical_fingerprint, fnv1a_64};
+
+
+    #[test]
+    fn ignores_whitespace_layout() {
+        assert_eq!(canonical_fingerprint("a b\tc\n\nd"), canonical_fingerprint(" a  b c d "));
+        assert_eq!(canonical_fingerprint("a\u{00A0}b"), canonical_fingerprint("a b"));
+    }
+
+    #[test]
+    fn ignores_case_including_unicode() {
+        assert_eq!(canonical_fingerprint("ÉCOLE Straße"), canonical_fingerprint("école straße"));
+    }
+
+    #[test]
+    fn ignores_punctuation() {
+        assert_eq!(canonical_fingerprint("“Don’t” — stop…"), canonical_fingerprint("dont stop"));
+        assert_eq!(canonical_fingerprint("wait - what"), canonical_fingerprint("wait what"));
+    }
+
+    #[test]
+    fn matches_hash_of_canonical_form() {
+        assert_eq!(canonical_fingerprint("  Hello,  World! "), fnv1a_64(b"hello world"));
+        assert_eq!(canonical_fingerprint(""), fnv1a_64(b""));
+        assert_eq!(canonical_fingerprint(" ... "), fnv1a_64(b""));
+    }
+
+    #[test]
+    fn word_order_matters() {
+        assert_ne!(canonical_fingerprint("one two"), canonical_fingerprint("two one"));
+    }
+}
+
+#[cfg(test)]
+mod levenshtein_automaton_tests {
+    use bt_string_utils::matcher::{levenshtein, LevenshteinAutomaton};
+
+
+    #[test]
+    fn agrees_with_levenshtein() {
+        let words = ["", "a", "ab", "abc", "acb", "bca", "kitten", "sitting", "naïve", "naive"];
+        for query in words {
+            for max_edits in 0..4 {
+                let automaton = LevenshteinAutomaton::new(query, max_edits);
+                for word in words {
+                    let d = levenshtein(query, word);
+                    assert_eq!(automaton.distance(word), (d <= max_edits).then_some(d), "{query} {word} {max_edits}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn stepwise_states() {
+        let automaton = LevenshteinAutomaton::new("cat", 1);
+        let mut state = automaton.start();
+        assert!(!automaton.is_match(&state));
+        for c in "ca".chars() {
+            state = automaton.step(&state, c);
+        }
+        assert!(automaton.is_match(&state));
+        let dead = automaton.step(&automaton.step(&state, 'x'), 'y');
+        assert!(!automaton.can_match(&dead));
+    }
+
+    #[test]
+    fn search_sorted_list() {
+        let mut words = vec!["apple", "apply", "ape", "banana", "band", "bandana", "and", "applesauce", "ample"];
+        words.sort_unstable();
+        let automaton = LevenshteinAutomaton::new("aple", 1);
+        let hits: Vec<_> = automaton.search(&words).collect();
+        let expected: Vec<_> = words
+            .iter()
+            .filter_map(|w| {
+                let d = levenshtein("aple", w);
+                (d <= 1).then_some((*w, d))
+            })
+            .collect();
+        assert_eq!(hits, expected);
+        assert_eq!(hits, vec![("ample", 1), ("ape", 1), ("apple", 1)]);
+        assert_eq!(LevenshteinAutomaton::new("x", 0).search(&[] as &[&str]).count(), 0);
+    }
+
+    #[test]
+    fn search_owned_strings() {
+        let words: Vec<String> = ["get", "got", "gut", "set"].iter().map(|s| s.to_string()).collect();
+        let hits: Vec<_> = LevenshteinAutomaton::new("gt", 1).search(&words).map(|(w, _)| w).collect();
+        assert_eq!(hits, vec!["get", "got", "gut"]);
+    }
+}
+
+#[cfg(test)]
+mod dedupe_log_message_tests {
+    use std::time::{Duration, Instant};
+    use bt_string_utils::log_analyzer::{DedupeWindow, LogDecision};
+
+
+    #[test]
+    fn suppress_within_window() {
+        let t0 = Instant::now();
+        let mut window = DedupeWindow::new(Duration::from_secs(10));
+        assert_eq!(window.check_at("Connection lost", t0), LogDecision::Emit);
+        assert_eq!(window.check_at("connection lost!", t0 + Duration::from_secs(3)), LogDecision::Suppressed(1));
+        assert_eq!(window.check_at("Connection  lost", t0 + Duration::from_secs(9)), LogDecision::Suppressed(2));
+        assert_eq!(window.check_at("Connection restored", t0 + Duration::from_secs(9)), LogDecision::Emit);
+        assert!(window.flush_at(t0 + Duration::from_secs(9)).is_empty());
+    }
+
+    #[test]
+    fn summary_after_window() {
+        let t0 = Instant::now();
+        let mut window = DedupeWindow::new(Duration::from_secs(10));
+        window.check_at("a", t0);
+        window.check_at("b", t0 + Duration::from_secs(1));
+        window.check_at("b", t0 + Duration::from_secs(2));
+        window.check_at("a", t0 + Duration::from_secs(2));
+        window.check_at("a", t0 + Duration::from_secs(3));
+        let summaries = window.flush_at(t0 + Duration::from_secs(20));
+        assert_eq!(summaries.len(), 2);
+        assert_eq!((summaries[0].message.as_str(), summaries[0].count), ("a", 2));
+        assert_eq!((summaries[1].message.as_str(), summaries[1].count), ("b", 1));
+        assert_eq!(summaries[0].to_string(), "(last message repeated 2 times)");
+        assert!(window.flush_at(t0 + Duration::from_secs(40)).is_empty());
+    }
+
+    #[test]
+    fn repeat_after_window_reemits() {
+        let t0 = Instant::now();
+        let mut window = DedupeWindow::new(Duration::from_secs(5));
+        window.check_at("tick", t0);
+        window.check_at("tick", t0 + Duration::from_secs(1));
+        assert_eq!(window.check_at("tick", t0 + Duration::from_secs(5)), LogDecision::Emit);
+        assert_eq!(window.check_at("tick", t0 + Duration::from_secs(6)), LogDecision::Suppressed(1));
+        let summaries = window.flush_at(t0 + Duration::from_secs(6));
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].count, 1);
+    }
+}
+
+#[cfg(test)]
+mod find_approx_tests {
+    use bt_string_utils::matcher::{find_approx, levenshtein};
+
+
+    fn best_anywhere(haystack: &str, needle: &str) -> usize {
+        let bounds: Vec<usize> = haystack.char_indices().map(|(i, _)| i).chain([haystack.len()]).collect();
+        let mut best = usize::MAX;
+        for (a, &i) in bounds.iter().enumerate() {
+            for &j in &bounds[a..] {
+                best = best.min(levenshtein(needle, &haystack[i..j]));
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn agrees_with_brute_force() {
+        let haystacks = ["", "abc", "xxabcxx", "the quick brown fox", "naïve café", "aaaa", "abxcd"];
+        let needles = ["a", "abc", "acb", "quack", "cafe", "xyz", "abcd"];
+        for haystack in haystacks {
+            for needle in needles {
+                let best = best_anywhere(haystack, needle);
+                for k in 0..4 {
+                    match find_approx(haystack, needle, k) {
+                        Some(found) => {
+                            assert!(found.edits <= k);
+                            assert_eq!(levenshtein(needle, &haystack[found.start..found.end]), found.edits, "{haystack:?} {needle:?} {k}");
+                        }
+                        None => assert!(best > k, "{haystack:?} {needle:?} {k}"),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn spans() {
+        let found = find_approx("xxabcxx", "abc", 0).unwrap();
+        assert_eq!((found.start, found.end, found.edits), (2, 5, 0));
+        let found = find_approx("aaaa", "aaa", 0).unwrap();
+        assert_eq!((found.start, found.end), (0, 3));
+        let found = find_approx("recieve the package", "receive", 2).unwrap();
+        assert_eq!((found.start, found.end, found.edits), (0, 7, 2));
+        let found = find_approx("prix: 5 €, naïve", "naive", 1).unwrap();
+        assert_eq!(&"prix: 5 €, naïve"[found.start..found.end], "naïve");
+        assert_eq!(find_approx("abc", "", 0).map(|m| (m.start, m.end)), Some((0, 0)));
+    }
+
+    #[test]
+    fn long_needles() {
+        let needle = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod";
+        let haystack = format!("header: {} trailer", needle.replace("dolor", "dolr").replace("elit", "e1it"));
+        let found = find_approx(&haystack, needle, 3).unwrap();
+        assert_eq!(found.edits, 2);
+        assert_eq!(found.start, 8);
+        assert_eq!(&haystack[found.end..], " trailer");
+        assert!(find_approx(&haystack, needle, 1).is_none());
+    }
+}
+
+#[cfg(test)]
+mod abbrev_match_tests {
+    use bt_string_utils::matcher::abbrev_match;
+
+
+    #[test]
+    fn camel_humps() {
+        assert_eq!(abbrev_match("FiRe", "FileReader").unwrap().ranges, vec![0..2, 4..6]);
+        assert_eq!(abbrev_match("NPE", "NullPointerException").unwrap().ranges, vec![0..1, 4..5, 11..12]);
+        assert_eq!(abbrev_match("HTTPSe", "HTTPServer").unwrap().ranges, vec![0..4, 4..6]);
+        assert_eq!(abbrev_match("sr", "string_reader_v2").unwrap().ranges, vec![0..1, 7..8]);
+        assert!(abbrev_match("Reader", "FileReader").is_some());
+    }
+
+    #[test]
+    fn non_matches() {
+        assert!(abbrev_match("FiRe", "Firewall").is_none());
+        assert!(abbrev_match("RF", "FileReader").is_none());
+        assert!(abbrev_match("ileR", "FileReader").is_none());
+        assert!(abbrev_match("x", "").is_none());
+    }
+
+    #[test]
+    fn ranking() {
+        let score = |candidate: &str| abbrev_match("fr", candidate).map(|found| found.score);
+        // Exact case beats a case-insensitive hit; skipped words cost
+        assert!(abbrev_match("FR", "FileReader").unwrap().score > score("FileReader").unwrap());
+        assert!(score("FileReader") > score("FileBufferedReader"));
+        assert!(score("FileReader") > score("MyFileReader"));
+        // A longer fragment is better than more humps
+        assert!(abbrev_match("fire", "FireReader").unwrap().score > abbrev_match("fire", "FileReader").unwrap().score);
+    }
+
+    #[test]
+    fn pattern_separators_and_unicode() {
+        assert_eq!(abbrev_match("file_r", "FileReader").unwrap().ranges, vec![0..4, 4..5]);
+        assert!(abbrev_match("", "Anything").unwrap().ranges.is_empty());
+        assert_eq!(abbrev_match("ÉtMo", "ÉtatModèle").unwrap().ranges, vec![0..3, 5..7]);
+    }
+}
+
+#[cfg(test)]
+mod grep_lines_tests {
+    use bt_string_utils::log_analyzer::grep_lines;
+
+    const LOG: &str = "a\nb\nhit 1\nc\nd\ne\nf\nhit 2\nhit 3\ng\n";
+
+
+    #[test]
+    fn context_lines() {
+        let hits = grep_lines(LOG, "hit", 2, 1);
+        let numbers: Vec<usize> = hits.iter().map(|hit| hit.line_number).collect();
+        assert_eq!(numbers, vec![3, 8, 9]);
+        assert_eq!(hits[0].before, vec![(1, "a"), (2, "b")]);
+        assert_eq!(hits[0].after, vec![(4, "c")]);
+        assert_eq!(hits[1].before, vec![(6, "e"), (7, "f")]);
+        // The next line is a hit, not context
+        assert!(hits[1].after.is_empty());
+        assert!(hits[2].before.is_empty());
+        assert_eq!(hits[2].after, vec![(10, "g")]);
+    }
+
+    #[test]
+    fn overlapping_context_is_not_repeated() {
+        let hits = grep_lines(LOG, "hit", 4, 4);
+        assert_eq!(hits[0].after, vec![(4, "c"), (5, "d"), (6, "e"), (7, "f")]);
+        assert!(hits[1].before.is_empty());
+    }
+
+    #[test]
+    fn matches_and_line_endings() {
+        let hits = grep_lines("x ab ab\r\nno\r\nab", "ab", 0, 0);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].line, "x ab ab");
+        assert_eq!(hits[0].matches, vec![2..4, 5..7]);
+        assert_eq!(hits[1].line_number, 3);
+        assert!(grep_lines("abc", "AB", 1, 1).is_empty());
+        assert!(grep_lines("", "x", 1, 1).is_empty());
+    }
+
+    #[test]
+    fn empty_pattern_matches_every_line() {
+        let hits = grep_lines("a\nb", "", 1, 1);
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|hit| hit.matches.is_empty() && hit.before.is_empty() && hit.after.is_empty()));
+    }
+
+    #[test]
+    fn unbounded_context() {
+        let hits = grep_lines(LOG, "hit", usize::MAX, usize::MAX);
+        assert_eq!(hits.len(), 3);
+        assert_eq!(hits[0].before.len(), 2);
+        assert_eq!(hits[0].after.len(), 4);
+        assert_eq!(hits[2].after, vec![(10, "g")]);
+    }
+}
+
+#[cfg(test)]
+mod string_metric_tests {
+    use bt_string_utils::matcher::{
+        fuzzy_filter, jaro_winkler_similarity, most_similar, JaroWinkler, Levenshtein, SimHash, StringMetric, Trigram,
+    };
+
+    /// Counts differing bytes of equal-length strings, infinite otherwise.
+    struct Hamming;
+
+    impl StringMetric for Hamming {
+        fn distance(&self, a: &str, b: &str) -> f64 {
+            if a.len() != b.len() {
+                return f64::INFINITY;
+            }
+            a.bytes().zip(b.bytes()).filter(|(x, y)| x != y).count() as f64
+        }
+
+        fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+            if a.is_empty() && b.is_empty() { 1.0 } else { (1.0 - self.distance(a, b) / a.len() as f64).max(0.0) }
+        }
+    }
+
+
+    #[test]
+    fn jaro_winkler() {
+        assert!((jaro_winkler_similarity("DWAYNE", "DUANE") - 0.84).abs() < 1e-4);
+        assert_eq!(jaro_winkler_similarity("", ""), 1.0);
+        assert_eq!(jaro_winkler_similarity("a", ""), 0.0);
+        assert_eq!(jaro_winkler_similarity("José", "José"), 1.0);
+    }
+
+    #[test]
+    fn backends_agree_on_identity_and_range() {
+        let metrics: [&dyn StringMetric; 4] = [&Levenshtein, &JaroWinkler, &Trigram, &SimHash];
+        for metric in metrics {
+            for (a, b) in [("", ""), ("disk full", "disk full"), ("disk full", "disk is full"), ("abc", "xyz")] {
+                let similarity = metric.normalized_similarity(a, b);
+                assert!((0.0..=1.0).contains(&similarity));
+                if a == b {
+                    assert_eq!(similarity, 1.0);
+                    assert_eq!(metric.distance(a, b), 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn search_is_generic_over_the_metric() {
+        let names = ["Martha", "Marhta", "Mark", "Marta"];
+        assert_eq!(most_similar("Martha", &names, &Levenshtein), Some(("Martha", 1.0)));
+        assert_eq!(most_similar("Marhta", &names[2..], &JaroWinkler).map(|(name, _)| name), Some("Marta"));
+        assert_eq!(most_similar::<&str, _>("x", &[], &Trigram), None);
+
+        let owned: Vec<String> = vec!["1010".into(), "1011".into(), "0000".into(), "10".into()];
+        let hits = fuzzy_filter("1010", &owned, &Hamming, 0.7);
+        assert_eq!(hits, vec![("1010", 1.0), ("1011", 0.75)]);
+    }
+
+    #[test]
+    fn fuzzy_filter_orders_by_similarity() {
+        let lines = ["disk /dev/sda1 is full", "user bob logged in", "disk /dev/sdb1 is full"];
+        let hits = fuzzy_filter("disk /dev/sda2 is full", &lines, &Levenshtein, 0.5);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].0, "disk /dev/sda1 is full");
+        assert!(hits[0].1 > hits[1].1);
+    }
+}
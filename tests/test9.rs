@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod graphemes_tests {
+    use bt_string_utils::segmenter::graphemes;
+
+
+    #[test]
+    fn ascii_is_one_per_char() {
+        assert_eq!(graphemes("abc").collect::<Vec<_>>(), vec!["a", "b", "c"]);
+        assert_eq!(graphemes("").count(), 0);
+    }
+
+    #[test]
+    fn keeps_crlf_together() {
+        assert_eq!(graphemes("a\r\nb").collect::<Vec<_>>(), vec!["a", "\r\n", "b"]);
+    }
+
+    #[test]
+    fn multiple_combining_marks() {
+        assert_eq!(graphemes("a\u{300}\u{301}b").collect::<Vec<_>>(), vec!["a\u{300}\u{301}", "b"]);
+    }
+
+    #[test]
+    fn flags_pair_up() {
+        assert_eq!(graphemes("🇪🇸🇲🇽🇺").collect::<Vec<_>>(), vec!["🇪🇸", "🇲🇽", "🇺"]);
+    }
+
+    #[test]
+    fn emoji_variation_selector() {
+        assert_eq!(graphemes("❤️!").collect::<Vec<_>>(), vec!["❤️", "!"]);
+    }
+}
+
+#[cfg(test)]
+mod text_cursor_tests {
+    use bt_string_utils::segmenter::TextCursor;
+
+
+    #[test]
+    fn grapheme_navigation_round_trip() {
+        let text = "a👨‍👩‍👧e\u{301}🇫🇷\r\nz";
+        let mut cursor = TextCursor::new(text);
+        let mut forward = Vec::new();
+        while let Some(g) = cursor.next_grapheme() {
+            forward.push(g);
+        }
+        assert_eq!(forward, vec!["a", "👨‍👩‍👧", "e\u{301}", "🇫🇷", "\r\n", "z"]);
+
+        let mut backward = Vec::new();
+        while let Some(g) = cursor.prev_grapheme() {
+            backward.push(g);
+        }
+        backward.reverse();
+        assert_eq!(backward, forward);
+    }
+
+    #[test]
+    fn flags_backwards() {
+        let mut cursor = TextCursor::new("🇪🇸🇲🇽");
+        cursor.move_to_end();
+        assert_eq!(cursor.prev_grapheme(), Some("🇲🇽"));
+        assert_eq!(cursor.prev_grapheme(), Some("🇪🇸"));
+        assert_eq!(cursor.prev_grapheme(), None);
+    }
+
+    #[test]
+    fn word_navigation() {
+        let mut cursor = TextCursor::new("don't stop, state-of-the-art!");
+        assert!(cursor.next_word());
+        assert_eq!(cursor.rest(), "stop, state-of-the-art!");
+        assert!(cursor.next_word());
+        assert_eq!(cursor.rest(), "state-of-the-art!");
+        assert!(cursor.next_word());
+        assert_eq!(cursor.rest(), "");
+        assert!(!cursor.next_word());
+
+        assert!(cursor.prev_word());
+        assert_eq!(cursor.rest(), "state-of-the-art!");
+        assert!(cursor.prev_word());
+        assert!(cursor.prev_word());
+        assert_eq!(cursor.position(), 0);
+        assert!(!cursor.prev_word());
+    }
+
+    #[test]
+    fn punctuation_separates_words() {
+        let mut cursor = TextCursor::new("see a.b 你好 ok");
+        cursor.next_word();
+        assert_eq!(cursor.rest(), "a.b 你好 ok");
+        cursor.next_word();
+        assert_eq!(cursor.rest(), "b 你好 ok");
+        cursor.next_word();
+        cursor.next_word();
+        assert_eq!(cursor.rest(), "ok");
+    }
+
+    #[test]
+    fn sentence_navigation() {
+        let mut cursor = TextCursor::new("He said \"Stop!\" Then left... Version 1.2 is out?  Yes");
+        assert!(cursor.next_sentence());
+        assert_eq!(cursor.rest(), "Then left... Version 1.2 is out?  Yes");
+        assert!(cursor.next_sentence());
+        assert_eq!(cursor.rest(), "Version 1.2 is out?  Yes");
+        assert!(cursor.next_sentence());
+        assert_eq!(cursor.rest(), "Yes");
+        assert!(cursor.next_sentence());
+        assert_eq!(cursor.rest(), "");
+        assert!(!cursor.next_sentence());
+    }
+
+    #[test]
+    fn line_and_column() {
+        let text = "first\r\nsécond line\nthird";
+        let mut cursor = TextCursor::new(text);
+        assert!(cursor.move_to_line_col(1, 3));
+        assert_eq!(cursor.rest(), "ond line\nthird");
+        assert_eq!(cursor.line_col(), (1, 3));
+
+        // Column past the end of the line stops before the line break
+        assert!(cursor.move_to_line_col(0, 50));
+        assert_eq!(cursor.rest(), "\r\nsécond line\nthird");
+
+        assert!(cursor.move_to_line_col(2, 0));
+        assert_eq!(cursor.rest(), "third");
+        assert!(!cursor.move_to_line_col(3, 0));
+        assert_eq!(cursor.rest(), "third");
+    }
+
+    #[test]
+    fn peek_does_not_move() {
+        let cursor = TextCursor::new("👍🏽ok");
+        assert_eq!(cursor.peek_graphemes(2), "👍🏽o");
+        assert_eq!(cursor.peek_graphemes(10), "👍🏽ok");
+        assert_eq!(cursor.position(), 0);
+    }
+}
+
+#[cfg(test)]
+mod sentences_tests {
+    use bt_string_utils::analyzer::sentence_count;
+    use bt_string_utils::segmenter::sentences;
+
+    fn split(text: &str) -> Vec<&str> {
+        sentences(text).collect()
+    }
+
+
+    #[test]
+    fn abbreviations_and_initials() {
+        assert_eq!(split("See Fig. 3 vs. Fig. 4. Then stop."), vec!["See Fig. 3 vs. Fig. 4.", "Then stop."]);
+        assert_eq!(split("J. R. R. Tolkien wrote it. I.e. a classic."), vec!["J. R. R. Tolkien wrote it.", "I.e. a classic."]);
+        assert_eq!(split("Prof. Ada (Dept. of Math.) spoke."), vec!["Prof. Ada (Dept. of Math.) spoke."]);
+        assert_eq!(split("Ask for no. 12 or No. 7. I said no. He left."), vec!["Ask for no. 12 or No. 7.", "I said no.", "He left."]);
+        assert_eq!(split("So did I. Then we left. John F. Kennedy spoke."), vec!["So did I.", "Then we left.", "John F. Kennedy spoke."]);
+    }
+
+    #[test]
+    fn numbers_and_ellipses() {
+        assert_eq!(split("Pi is 3.14. Visit example.com today."), vec!["Pi is 3.14.", "Visit example.com today."]);
+        assert_eq!(split("Well... maybe. Or…  No."), vec!["Well... maybe.", "Or…", "No."]);
+    }
+
+    #[test]
+    fn quotes_and_brackets() {
+        assert_eq!(split("\"Run!\" he said. (Really?) Yes."), vec!["\"Run!\" he said.", "(Really?)", "Yes."]);
+        assert_eq!(split("«Non.» Oui."), vec!["«Non.»", "Oui."]);
+    }
+
+    #[test]
+    fn cjk_and_edges() {
+        assert_eq!(split("你好。再见！好吗？"), vec!["你好。", "再见！", "好吗？"]);
+        assert_eq!(split("  no terminator  "), vec!["no terminator"]);
+        assert!(split("").is_empty());
+        assert!(split(" ... !! ").is_empty());
+        assert_eq!(sentence_count("One. Two.\n\nThree"), 3);
+    }
+}
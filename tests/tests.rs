@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod sub_strings_test{
-    use bt_string_utils::finder::get_first_occurrance;
-use bt_string_utils::splitter::get_first_of_split;
+    use bt_string_utils::finder::{get_first_occurrance, get_first_occurrance_ref, get_last_occurrance, get_last_occurrance_ref};
+use bt_string_utils::splitter::{get_first_of_split, get_first_of_split_ref, get_last_of_split, get_last_of_split_ref, split_all, split_all_ref};
 
 
     #[test]
@@ -31,6 +31,69 @@ use bt_string_utils::splitter::get_first_of_split;
         println!("Content {:?}",&content);
         assert_eq!(content, ("First:Second:Third".to_owned(),"".to_owned()));
     }
+
+    #[test]
+    fn test_split_all(){
+        assert_eq!(split_all("First:Second:Third",":",None), vec!["First","Second","Third"]);
+        assert_eq!(split_all(":First::",":",None), vec!["","First","",""]);
+        assert_eq!(split_all("First:Second:Third",":",Some(0)), vec!["First:Second:Third"]);
+        assert_eq!(split_all("First:Second:Third",":",Some(1)), vec!["First","Second:Third"]);
+    }
+
+    #[test]
+    fn test_split_all_no_split(){
+        assert_eq!(split_all("First:Second:Third","*",None), vec!["First:Second:Third"]);
+        assert_eq!(split_all("First","",None), vec!["First"]);
+        assert_eq!(split_all("","*",None), vec![""]);
+    }
+
+    #[test]
+    fn test_last_occurance(){
+        assert_eq!(get_last_occurrance("First:Second:Third",":"),"Third");
+        assert_eq!(get_last_occurrance("First:Second:Third","*"),"");
+        assert_eq!(get_last_occurrance("First::Second","::"),"Second");
+    }
+
+    #[test]
+    fn test_last_split(){
+        assert_eq!(get_last_of_split("First:Second:Third",":"), ("First:Second".to_owned(),"Third".to_owned()));
+        assert_eq!(get_last_of_split("First:Second:Third","*"), ("First:Second:Third".to_owned(),"".to_owned()));
+        assert_eq!(get_last_of_split("First::Second","::"), ("First".to_owned(),"Second".to_owned()));
+    }
+
+    #[test]
+    fn test_split_multi_byte_separators(){
+        assert_eq!(get_first_of_split("First→Second→Third","→"), ("First".to_owned(),"Second→Third".to_owned()));
+        assert_eq!(get_first_of_split("First::Second","::"), ("First".to_owned(),"Second".to_owned()));
+        assert_eq!(get_last_of_split("First→Second→Third",'→'), ("First→Second".to_owned(),"Third".to_owned()));
+        assert_eq!(split_all("你好，世界，！",'，',None), vec!["你好","世界","！"]);
+    }
+
+    #[test]
+    fn test_split_char_sets_and_predicates(){
+        assert_eq!(get_first_of_split("key = value",|c: char| c == '=' || c == ':'), ("key ".to_owned()," value".to_owned()));
+        assert_eq!(split_all("a,b;c",[',', ';'],None), vec!["a","b","c"]);
+        let separators: &[char] = &['-', '_'];
+        assert_eq!(split_all("snake_case-kebab",separators,Some(1)), vec!["snake","case-kebab"]);
+        let owned = String::from("::");
+        assert_eq!(split_all("a::b",&owned,None), vec!["a","b"]);
+    }
+
+    #[test]
+    fn test_borrowed_variants(){
+        let line = String::from("First:Second:Third");
+        let (first, rest) = get_first_of_split_ref(&line,':');
+        assert_eq!((first, rest), ("First","Second:Third"));
+        assert!(std::ptr::eq(first.as_ptr(), line.as_ptr()));
+        assert_eq!(get_last_of_split_ref(&line,':'), ("First:Second","Third"));
+        assert_eq!(get_first_of_split_ref(&line,'*'), ("First:Second:Third",""));
+        assert_eq!(split_all_ref(&line,':',Some(1)), vec!["First","Second:Third"]);
+        assert_eq!(get_first_occurrance_ref(&line,":"), Some("First"));
+        assert_eq!(get_last_occurrance_ref(&line,":"), Some("Third"));
+        assert_eq!(get_first_occurrance_ref(&line,"*"), None);
+        assert_eq!(get_last_occurrance_ref(&line,"*"), None);
+        assert_eq!(get_first_occurrance(&line,"*"), "");
+    }
 }
 
 //**************/
@@ -38,7 +101,7 @@ use bt_string_utils::splitter::get_first_of_split;
 //*************/
 #[cfg(test)]
 mod strings_test{
-    use bt_string_utils::finder::find_value_by_key;
+    use bt_string_utils::finder::{find_value_by_key, find_value_by_key_ci, find_values_by_key_prefix};
 
 
     #[test]
@@ -52,11 +115,28 @@ mod strings_test{
         let v = vec!["k1=a".to_owned(),"k2=b".to_owned(),"k3=c".to_string()];
         assert_eq!(find_value_by_key(&v, "k5"),None);
     }    
+
+    #[test]
+    fn find_value_by_key_ci_test(){
+        let v = vec!["Host=a".to_owned(),"STRASSE=b".to_owned(),"ΟΔΟΣ=c".to_owned(),"host=d".to_string()];
+        assert_eq!(find_value_by_key_ci(&v, "HOST").unwrap(),"a");
+        assert_eq!(find_value_by_key_ci(&v, "straße").unwrap(),"b");
+        assert_eq!(find_value_by_key_ci(&v, "οδος").unwrap(),"c");
+        assert_eq!(find_value_by_key_ci(&v, "hos"),None);
+    }
+
+    #[test]
+    fn find_values_by_key_prefix_test(){
+        let v = vec!["k1=a".to_owned(),"x=b".to_owned(),"k2=c".to_string(),"k3".to_string()];
+        assert_eq!(find_values_by_key_prefix(&v, "k"), vec![("k1".to_owned(),"a".to_owned()),("k2".to_owned(),"c".to_owned())]);
+        assert_eq!(find_values_by_key_prefix(&v, "").len(), 3);
+        assert!(find_values_by_key_prefix(&v, "K").is_empty());
+    }
 }
 
 #[cfg(test)]
 mod removed_tests {
-    use bt_string_utils::cleanser::{RemoveLocationEnum, remove_char};
+    use bt_string_utils::cleanser::{RemoveLocationEnum, remove_char, strip_chars, strip_prefix_str, strip_suffix_str};
 
 
     #[test]
@@ -76,6 +156,40 @@ mod removed_tests {
         assert_eq!(remove_char(RemoveLocationEnum::Begin, &"rust".to_string(), 'x'), "rust");
         assert_eq!(remove_char(RemoveLocationEnum::End, &"mars".to_string(), 'z'), "mars");
     }
+
+    #[test]
+    fn test_remove_both_and_unicode() {
+        assert_eq!(remove_char(RemoveLocationEnum::Both, &"'quoted'".to_string(), '\''), "quoted");
+        assert_eq!(remove_char(RemoveLocationEnum::Both, &"''".to_string(), '\''), "");
+        assert_eq!(remove_char(RemoveLocationEnum::End, &"café!".to_string(), '!'), "café");
+        assert_eq!(remove_char(RemoveLocationEnum::End, &"café".to_string(), 'é'), "caf");
+    }
+
+    #[test]
+    fn test_strip_chars() {
+        let set = ['"', '\'', ' '];
+        assert_eq!(strip_chars(RemoveLocationEnum::Both, " \"'a b'\" ", &set, None), "a b");
+        assert_eq!(strip_chars(RemoveLocationEnum::Begin, " \"x\" ", &set, None), "x\" ");
+        assert_eq!(strip_chars(RemoveLocationEnum::End, " \"x\" ", &set, None), " \"x");
+        assert_eq!(strip_chars(RemoveLocationEnum::Both, "**x**", &['*'], Some(1)), "*x*");
+        assert_eq!(strip_chars(RemoveLocationEnum::Both, "ééxé", &['é'], Some(0)), "ééxé");
+        assert_eq!(strip_chars(RemoveLocationEnum::Both, "----", &['-'], None), "");
+        assert_eq!(strip_chars(RemoveLocationEnum::Both, "---", &['-'], Some(2)), "");
+        assert_eq!(strip_chars(RemoveLocationEnum::Both, "abc", &[], None), "abc");
+    }
+
+    #[test]
+    fn test_strip_prefix_suffix_str() {
+        assert_eq!(strip_prefix_str("<<EOF\nbody", "<<EOF", false), ("\nbody", true));
+        assert_eq!(strip_prefix_str("<<eof", "<<EOF", false), ("<<eof", false));
+        assert_eq!(strip_prefix_str("ÉTÉ été", "été", true), (" été", true));
+        assert_eq!(strip_prefix_str("ab", "abc", true), ("ab", false));
+        assert_eq!(strip_prefix_str("abc", "", true), ("abc", true));
+        assert_eq!(strip_suffix_str("line -- ", " -- ", false), ("line", true));
+        assert_eq!(strip_suffix_str("Straße", "SSE", true), ("Straße", false));
+        assert_eq!(strip_suffix_str("ÜBER", "ber", true), ("Ü", true));
+        assert_eq!(strip_suffix_str("r", "er", true), ("r", false));
+    }
 }
 
 #[cfg(test)]
@@ -345,3 +459,144 @@ mod remove_tags_tests {
     }
 }
 
+
+#[cfg(test)]
+mod normalize_spaces_tests {
+    use bt_string_utils::cleanser::{collapse_whitespace, normalize_spaces, LineBreakEnum, SpaceOptions};
+
+
+    fn with(line_breaks: LineBreakEnum) -> SpaceOptions {
+        SpaceOptions { line_breaks, trim: false }
+    }
+
+    #[test]
+    fn collapse_matches_collapse_whitespace() {
+        for text in ["", " ", "a\u{2003}\u{200a}b", "\t\nx\r\n\r\ny \u{a0}", "plain"] {
+            assert_eq!(normalize_spaces(text, SpaceOptions::default()), collapse_whitespace(text));
+        }
+    }
+
+    #[test]
+    fn preserve_newlines() {
+        assert_eq!(normalize_spaces("a \t\n\t b", with(LineBreakEnum::Preserve)), "a\nb");
+        assert_eq!(normalize_spaces("a\r\n\r\n\r\nb\rc", with(LineBreakEnum::Preserve)), "a\n\n\nb\nc");
+        assert_eq!(normalize_spaces("a\u{2028}b\u{2029}c", with(LineBreakEnum::Preserve)), "a\nb\n\nc");
+        assert_eq!(normalize_spaces(" a  b ", with(LineBreakEnum::Preserve)), " a b ");
+    }
+
+    #[test]
+    fn preserve_paragraphs() {
+        assert_eq!(normalize_spaces("one\ntwo\n  \n\n\nthree", with(LineBreakEnum::Paragraphs)), "one two\n\nthree");
+        assert_eq!(normalize_spaces("a\u{2029}b", with(LineBreakEnum::Paragraphs)), "a\n\nb");
+    }
+
+    #[test]
+    fn trim() {
+        let options = SpaceOptions { line_breaks: LineBreakEnum::Preserve, trim: true };
+        assert_eq!(normalize_spaces("\n\n a \n", options), "a");
+        assert_eq!(normalize_spaces(" \u{a0} ", options), "");
+    }
+}
+
+#[cfg(test)]
+mod sanitize_control_chars_tests {
+    use bt_string_utils::cleanser::{sanitize_control_chars, ControlCharActionEnum};
+
+
+    #[test]
+    fn strips_controls_but_keeps_line_structure() {
+        let input = "a\u{0}\u{1b}[0m\u{7f}\u{85}\u{9f}b\r\n\tc";
+        assert_eq!(sanitize_control_chars(input, ControlCharActionEnum::Strip), "a[0mb\r\n\tc");
+    }
+
+    #[test]
+    fn invisible_and_bidi() {
+        let trojan = "if access_level != \"user\u{202e} \u{2066}// Check if admin\u{2069} \u{2066}\"";
+        let clean = sanitize_control_chars(trojan, ControlCharActionEnum::Strip);
+        assert!(!clean.contains(['\u{202e}', '\u{2066}', '\u{2069}']));
+        assert_eq!(sanitize_control_chars("\u{feff}x\u{200b}y\u{2060}z\u{180e}", ControlCharActionEnum::Strip), "xyz");
+    }
+
+    #[test]
+    fn joiners_kept() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        assert_eq!(sanitize_control_chars(family, ControlCharActionEnum::Strip), family);
+        assert_eq!(sanitize_control_chars("می\u{200c}خواهم", ControlCharActionEnum::Strip), "می\u{200c}خواهم");
+    }
+
+    #[test]
+    fn replace_with_placeholder() {
+        assert_eq!(sanitize_control_chars("\u{1}\u{2}x\u{202d}", ControlCharActionEnum::Replace('?')), "??x?");
+        assert_eq!(sanitize_control_chars("", ControlCharActionEnum::Replace('?')), "");
+    }
+}
+
+#[cfg(test)]
+mod remove_diacritics_tests {
+    use bt_string_utils::cleanser::remove_diacritics;
+
+
+    #[test]
+    fn precomposed_and_decomposed_agree() {
+        assert_eq!(remove_diacritics("ÉCOLE élève"), "ECOLE eleve");
+        assert_eq!(remove_diacritics("E\u{301}COLE e\u{301}le\u{300}ve"), "ECOLE eleve");
+        assert_eq!(remove_diacritics("Việt Nam"), "Viet Nam");
+        assert_eq!(remove_diacritics("Vie\u{323}\u{302}t"), "Viet");
+    }
+
+    #[test]
+    fn strokes_and_specials() {
+        assert_eq!(remove_diacritics("Łódź Øresund đ ħ"), "Lodz Oresund d h");
+        assert_eq!(remove_diacritics("Straße Æsir þorn"), "Straße Æsir þorn");
+    }
+
+    #[test]
+    fn other_scripts_and_symbols() {
+        assert_eq!(remove_diacritics("α\u{301}λφα"), "αλφα");
+        assert_eq!(remove_diacritics("שָׁלוֹם"), "שלום");
+        assert_eq!(remove_diacritics("#\u{fe0f}\u{20e3} 日本"), "#\u{fe0f}\u{20e3} 日本");
+        assert_eq!(remove_diacritics(""), "");
+    }
+
+    #[test]
+    fn accent_insensitive_keys() {
+        assert_eq!(remove_diacritics("Zürich").to_lowercase(), remove_diacritics("ZURICH").to_lowercase());
+    }
+}
+
+#[cfg(test)]
+mod slugify_tests {
+    use bt_string_utils::slugify;
+
+
+    #[test]
+    fn test_slugify_titles() {
+        assert_eq!(slugify("Hello, World!", '-'), "hello-world");
+        assert_eq!(slugify("Ærøskøbing & Łódź — 2024", '-'), "aeroskobing-lodz-2024");
+        assert_eq!(slugify("C++/Rust FFI: a how-to", '_'), "c_rust_ffi_a_how_to");
+        assert_eq!(slugify("l’été d'Anaïs", '-'), "lete-danais");
+    }
+
+    #[test]
+    fn test_slugify_non_latin() {
+        assert_eq!(slugify("Привет мир", '-'), "privet-mir");
+        assert_eq!(slugify("Καλημέρα κόσμε!", '_'), "kalimera_kosme");
+        assert_eq!(slugify("Привет мир 2", '-'), "privet-mir-2");
+        assert_eq!(slugify("東京 Tokyo", '-'), "tokyo");
+    }
+
+    #[test]
+    fn test_slugify_separators_collapse() {
+        assert_eq!(slugify("--a  --  b--", '-'), "a-b");
+        assert_eq!(slugify("a.b", '.'), "a.b");
+        assert_eq!(slugify("", '-'), "");
+        assert_eq!(slugify("!!! ???", '-'), "");
+    }
+
+    #[test]
+    fn test_slugify_is_deterministic_and_safe() {
+        let slug = slugify("Straße nach Zürich", '-');
+        assert_eq!(slug, slugify("Straße nach Zürich", '-'));
+        assert!(slug.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'));
+    }
+}